@@ -1,4 +1,20 @@
 library nostr_mls_package;
 
+export 'src/rust/api/batch.dart';
+export 'src/rust/api/commits.dart';
+export 'src/rust/api/error.dart';
+export 'src/rust/api/events.dart';
+export 'src/rust/api/key_packages.dart';
+export 'src/rust/api/keyring.dart';
+export 'src/rust/api/media.dart';
+export 'src/rust/api/messages.dart';
 export 'src/rust/api/mls_api.dart';
+export 'src/rust/api/policy.dart';
+export 'src/rust/api/recovery.dart';
+export 'src/rust/api/retention.dart';
+export 'src/rust/api/rotation.dart';
+export 'src/rust/api/signer.dart';
+export 'src/rust/api/storage.dart';
+export 'src/rust/api/types.dart';
+export 'src/rust/api/welcomes.dart';
 export 'src/rust/frb_generated.dart' show RustLib;
@@ -0,0 +1,55 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'types.dart';
+
+// These functions are ignored because they are not marked as `pub`: `process_and_merge`, `retry_while_progressing`, `sort_oldest_first`
+// These types are ignored because they are not used by any `pub` functions: `Attempt`
+
+/// Process a batch of kind-445 events fetched after being offline
+///
+/// Events are applied oldest first (created_at, then event id). Staged commits are merged
+/// right away so later-epoch messages can be decrypted, and events failing with an epoch
+/// mismatch are retried every time another event of the batch succeeded. Events still
+/// failing at the end are buffered like in `process_message_for_group`.
+/// Parameters: events - JSON strings of the events
+/// Returns: one result per event, in input order
+Future<List<BatchEventResult>> processMessagesBatch(
+        {required String identity, required List<String> events}) =>
+    RustLib.instance.api
+        .crateApiBatchProcessMessagesBatch(identity: identity, events: events);
+
+/// Result of one event of `process_messages_batch`, in the order the events were given
+class BatchEventResult {
+  /// Hex encoded event id, `None` if the event could not be parsed
+  final String? eventId;
+
+  /// Set when the event was processed
+  final ProcessedMessage? processed;
+
+  /// Set when the event could not be processed
+  final MlsBridgeError? error;
+
+  const BatchEventResult({
+    this.eventId,
+    this.processed,
+    this.error,
+  });
+
+  @override
+  int get hashCode => eventId.hashCode ^ processed.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BatchEventResult &&
+          runtimeType == other.runtimeType &&
+          eventId == other.eventId &&
+          processed == other.processed &&
+          error == other.error;
+}
@@ -0,0 +1,251 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'types.dart';
+
+// These functions are ignored because they are not marked as `pub`: `abort_own_commit`, `attach_pending_commit_event`, `delete_signature_key`, `delete_unused_signature_key`, `discard_own_commit`, `ensure_no_pending_commit`, `ensure_pending_commit_awaits_event`, `merge_own_commit`, `own_signature_key`, `propose`, `save_own_pending_commit`, `sign_own_commit`, `wins_over`
+
+/// Update the Nostr group data of a group with a GroupContextExtensions commit
+/// The group policy decides who may update it, admins by default. Fields left `None` keep
+/// their current value.
+/// Parameters: group_id - byte array of group ID, admins - hex public keys of the new admin set,
+/// relays - new relay set
+/// Returns: the signed kind-445 commit event to publish, then `confirm_commit_published`
+Future<EventResult> updateGroupData(
+        {required String identity,
+        required List<int> groupId,
+        String? name,
+        String? description,
+        List<String>? admins,
+        List<String>? relays}) =>
+    RustLib.instance.api.crateApiCommitsUpdateGroupData(
+        identity: identity,
+        groupId: groupId,
+        name: name,
+        description: description,
+        admins: admins,
+        relays: relays);
+
+/// Rotate our leaf in a group with a self-update commit
+/// Both the leaf's encryption key and its signing key are replaced, restoring post-compromise
+/// security; `list_rotation_status` reports the groups due for it.
+/// Parameters: group_id - byte array of group ID
+/// Returns: the signed kind-445 commit event to publish, then `confirm_commit_published`
+Future<EventResult> selfUpdate(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api
+        .crateApiCommitsSelfUpdate(identity: identity, groupId: groupId);
+
+/// Merge our pending commit of a group after the relay acknowledged its event
+/// For a leave proposal nothing is merged; the group ends once an admin commits the removal.
+/// A competing commit of the same epoch that wins but reaches us only after this merge can no
+/// longer roll ours back: it fails with `StaleEpoch` and is counted in `get_group_health`.
+/// The members who applied it are then on another branch, which `compare_group_state` reports
+/// as `Diverged` and `start_group_recovery` resolves.
+/// Parameters: group_id - byte array of group ID, event_id - hex id of the published event
+/// Returns: buffered messages the new epoch can decrypt
+Future<List<DecryptedMessage>> confirmCommitPublished(
+        {required String identity,
+        required List<int> groupId,
+        required String eventId}) =>
+    RustLib.instance.api.crateApiCommitsConfirmCommitPublished(
+        identity: identity, groupId: groupId, eventId: eventId);
+
+/// Discard our pending commit or leave proposal of a group, e.g. when the relay rejected it
+/// Parameters: group_id - byte array of group ID
+/// Returns: whether something was discarded
+Future<bool> abortCommit(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api
+        .crateApiCommitsAbortCommit(identity: identity, groupId: groupId);
+
+/// Merge our pending commit of a group once its event was published
+/// Same as `confirm_commit_published` with the event id recorded for the pending commit.
+/// Parameters: group_id - byte array of group ID
+/// Returns: buffered messages the new epoch can decrypt
+Future<List<DecryptedMessage>> mergePendingCommit(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api.crateApiCommitsMergePendingCommit(
+        identity: identity, groupId: groupId);
+
+/// Discard our pending commit of a group, e.g. when publishing its event failed
+/// Same as `abort_commit`.
+/// Parameters: group_id - byte array of group ID
+/// Returns: whether a pending commit was discarded
+Future<bool> clearPendingCommit(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api.crateApiCommitsClearPendingCommit(
+        identity: identity, groupId: groupId);
+
+/// Get our commit of a group waiting for `confirm_commit_published` or `abort_commit`
+/// Pending commits survive restarts; an app can publish `event_json` again before confirming.
+/// Parameters: group_id - byte array of group ID
+Future<PendingCommit?> getPendingCommit(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api
+        .crateApiCommitsGetPendingCommit(identity: identity, groupId: groupId);
+
+/// Propose adding a member, for an admin to commit later
+/// Parameters: group_id - byte array of group ID, key_package - serialized key package of the member
+/// Returns: the signed kind-445 proposal event to publish
+Future<EventResult> proposeAdd(
+        {required String identity,
+        required List<int> groupId,
+        required String keyPackage}) =>
+    RustLib.instance.api.crateApiCommitsProposeAdd(
+        identity: identity, groupId: groupId, keyPackage: keyPackage);
+
+/// Propose removing a member, for an admin to commit later
+/// Parameters: group_id - byte array of group ID, member_pubkey - hex public key of the member
+/// Returns: the signed kind-445 proposal event to publish
+Future<EventResult> proposeRemove(
+        {required String identity,
+        required List<int> groupId,
+        required String memberPubkey}) =>
+    RustLib.instance.api.crateApiCommitsProposeRemove(
+        identity: identity, groupId: groupId, memberPubkey: memberPubkey);
+
+/// Propose updating our own leaf node, for any member to commit later
+/// Parameters: group_id - byte array of group ID
+/// Returns: the signed kind-445 proposal event to publish
+Future<EventResult> proposeUpdate(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api
+        .crateApiCommitsProposeUpdate(identity: identity, groupId: groupId);
+
+/// List the proposals of the current epoch received or sent but not committed yet
+/// Parameters: group_id - byte array of group ID
+Future<List<ProposalSummary>> listPendingProposals(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api.crateApiCommitsListPendingProposals(
+        identity: identity, groupId: groupId);
+
+/// Commit pending proposals of the current epoch
+/// Proposals left out of the selection are discarded, as a commit ends their epoch anyway.
+/// Parameters: group_id - byte array of group ID, selection - proposal refs to commit, `None` for
+/// all of them
+/// Returns: the signed kind-445 commit event and the welcome of added members, to send once
+/// `confirm_commit_published` succeeded
+Future<ProposalCommitResult> commitPendingProposals(
+        {required String identity,
+        required List<int> groupId,
+        List<String>? selection}) =>
+    RustLib.instance.api.crateApiCommitsCommitPendingProposals(
+        identity: identity, groupId: groupId, selection: selection);
+
+/// Our commit waiting for its event to be published
+class PendingCommit {
+  /// Epoch the commit was created in
+  final BigInt epoch;
+  final PendingCommitKind kind;
+
+  /// `None` until the commit is wrapped with `create_commit_message_for_group`
+  final String? eventId;
+  final String? eventJson;
+
+  const PendingCommit({
+    required this.epoch,
+    required this.kind,
+    this.eventId,
+    this.eventJson,
+  });
+
+  @override
+  int get hashCode =>
+      epoch.hashCode ^ kind.hashCode ^ eventId.hashCode ^ eventJson.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PendingCommit &&
+          runtimeType == other.runtimeType &&
+          epoch == other.epoch &&
+          kind == other.kind &&
+          eventId == other.eventId &&
+          eventJson == other.eventJson;
+}
+
+/// What a pending commit of ours does
+enum PendingCommitKind {
+  commit,
+
+  /// A proposal to remove ourselves, from `leave_group`
+  leave,
+  ;
+}
+
+/// Result of `commit_pending_proposals`
+class ProposalCommitResult {
+  final EventResult commitEvent;
+
+  /// Welcome of the members added by the commit, to gift-wrap with `create_welcome_gift_wraps`
+  final Uint8List? welcomeMessage;
+
+  const ProposalCommitResult({
+    required this.commitEvent,
+    this.welcomeMessage,
+  });
+
+  @override
+  int get hashCode => commitEvent.hashCode ^ welcomeMessage.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProposalCommitResult &&
+          runtimeType == other.runtimeType &&
+          commitEvent == other.commitEvent &&
+          welcomeMessage == other.welcomeMessage;
+}
+
+/// Kind of a pending proposal
+enum ProposalKind {
+  add,
+  remove,
+  update,
+  groupContextExtensions,
+  other,
+  ;
+}
+
+/// A proposal of the current epoch waiting to be committed
+class ProposalSummary {
+  /// Hex encoded proposal reference, used to select it in `commit_pending_proposals`
+  final String proposalRef;
+  final ProposalKind kind;
+
+  /// Hex encoded public key of the member who proposed it, `None` for non-member senders
+  final String? proposer;
+
+  /// Hex encoded public key of the member added or removed
+  final String? target;
+
+  const ProposalSummary({
+    required this.proposalRef,
+    required this.kind,
+    this.proposer,
+    this.target,
+  });
+
+  @override
+  int get hashCode =>
+      proposalRef.hashCode ^
+      kind.hashCode ^
+      proposer.hashCode ^
+      target.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProposalSummary &&
+          runtimeType == other.runtimeType &&
+          proposalRef == other.proposalRef &&
+          kind == other.kind &&
+          proposer == other.proposer &&
+          target == other.target;
+}
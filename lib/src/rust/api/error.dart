@@ -0,0 +1,87 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'error.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `internal`, `invalid_input`, `may_be_later_epoch`, `mls`, `permission_denied`, `signer`, `storage`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `from`, `from`, `from`, `from`
+
+/// Errors returned by every bridge function, surfaced to Dart as a typed exception
+@freezed
+sealed class MlsBridgeError with _$MlsBridgeError implements FrbException {
+  const MlsBridgeError._();
+
+  /// `init_nostr_mls` has not been called
+  const factory MlsBridgeError.notInitialized() = MlsBridgeError_NotInitialized;
+
+  /// An argument could not be parsed (public key, relay url, event JSON, ...)
+  const factory MlsBridgeError.invalidInput({
+    required String field,
+    required String message,
+  }) = MlsBridgeError_InvalidInput;
+
+  /// The group does not exist in local storage
+  const factory MlsBridgeError.groupNotFound() = MlsBridgeError_GroupNotFound;
+
+  /// The message belongs to an epoch other than the group's current one
+  const factory MlsBridgeError.staleEpoch({
+    required String message,
+  }) = MlsBridgeError_StaleEpoch;
+
+  /// The message was sent by ourselves and cannot be decrypted again
+  const factory MlsBridgeError.ownMessage() = MlsBridgeError_OwnMessage;
+
+  /// The outer NIP-44 layer or the MLS ciphertext could not be decrypted
+  const factory MlsBridgeError.decryptionFailed({
+    required String message,
+  }) = MlsBridgeError_DecryptionFailed;
+
+  /// The storage database could not be opened with the given password
+  const factory MlsBridgeError.storageLocked({
+    required String message,
+  }) = MlsBridgeError_StorageLocked;
+
+  /// No private key material exists for the key package
+  const factory MlsBridgeError.keyPackageNotFound() =
+      MlsBridgeError_KeyPackageNotFound;
+
+  /// The group's admin policy does not allow the operation
+  const factory MlsBridgeError.permissionDenied({
+    required String message,
+  }) = MlsBridgeError_PermissionDenied;
+
+  /// The configured signer failed or returned an invalid signature
+  const factory MlsBridgeError.signer({
+    required String message,
+  }) = MlsBridgeError_Signer;
+
+  /// The app's upload or download callback failed
+  const factory MlsBridgeError.transfer({
+    required String message,
+  }) = MlsBridgeError_Transfer;
+
+  /// Any other storage failure
+  const factory MlsBridgeError.storage({
+    required String message,
+  }) = MlsBridgeError_Storage;
+
+  /// Any other MLS protocol failure
+  const factory MlsBridgeError.mls({
+    required String message,
+  }) = MlsBridgeError_Mls;
+
+  /// Internal failure of the bridge itself
+  const factory MlsBridgeError.internal({
+    required String message,
+  }) = MlsBridgeError_Internal;
+
+  /// Stable machine readable code of the error
+  String code() => RustLib.instance.api.crateApiErrorMlsBridgeErrorCode(
+        that: this,
+      );
+}
@@ -0,0 +1,3158 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'error.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$MlsBridgeError {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() notInitialized,
+    required TResult Function(String field, String message) invalidInput,
+    required TResult Function() groupNotFound,
+    required TResult Function(String message) staleEpoch,
+    required TResult Function() ownMessage,
+    required TResult Function(String message) decryptionFailed,
+    required TResult Function(String message) storageLocked,
+    required TResult Function() keyPackageNotFound,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(String message) signer,
+    required TResult Function(String message) transfer,
+    required TResult Function(String message) storage,
+    required TResult Function(String message) mls,
+    required TResult Function(String message) internal,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? notInitialized,
+    TResult? Function(String field, String message)? invalidInput,
+    TResult? Function()? groupNotFound,
+    TResult? Function(String message)? staleEpoch,
+    TResult? Function()? ownMessage,
+    TResult? Function(String message)? decryptionFailed,
+    TResult? Function(String message)? storageLocked,
+    TResult? Function()? keyPackageNotFound,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(String message)? signer,
+    TResult? Function(String message)? transfer,
+    TResult? Function(String message)? storage,
+    TResult? Function(String message)? mls,
+    TResult? Function(String message)? internal,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? notInitialized,
+    TResult Function(String field, String message)? invalidInput,
+    TResult Function()? groupNotFound,
+    TResult Function(String message)? staleEpoch,
+    TResult Function()? ownMessage,
+    TResult Function(String message)? decryptionFailed,
+    TResult Function(String message)? storageLocked,
+    TResult Function()? keyPackageNotFound,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(String message)? signer,
+    TResult Function(String message)? transfer,
+    TResult Function(String message)? storage,
+    TResult Function(String message)? mls,
+    TResult Function(String message)? internal,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MlsBridgeError_NotInitialized value)
+        notInitialized,
+    required TResult Function(MlsBridgeError_InvalidInput value) invalidInput,
+    required TResult Function(MlsBridgeError_GroupNotFound value) groupNotFound,
+    required TResult Function(MlsBridgeError_StaleEpoch value) staleEpoch,
+    required TResult Function(MlsBridgeError_OwnMessage value) ownMessage,
+    required TResult Function(MlsBridgeError_DecryptionFailed value)
+        decryptionFailed,
+    required TResult Function(MlsBridgeError_StorageLocked value) storageLocked,
+    required TResult Function(MlsBridgeError_KeyPackageNotFound value)
+        keyPackageNotFound,
+    required TResult Function(MlsBridgeError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(MlsBridgeError_Signer value) signer,
+    required TResult Function(MlsBridgeError_Transfer value) transfer,
+    required TResult Function(MlsBridgeError_Storage value) storage,
+    required TResult Function(MlsBridgeError_Mls value) mls,
+    required TResult Function(MlsBridgeError_Internal value) internal,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult? Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult? Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult? Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult? Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult? Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult? Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult? Function(MlsBridgeError_Signer value)? signer,
+    TResult? Function(MlsBridgeError_Transfer value)? transfer,
+    TResult? Function(MlsBridgeError_Storage value)? storage,
+    TResult? Function(MlsBridgeError_Mls value)? mls,
+    TResult? Function(MlsBridgeError_Internal value)? internal,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult Function(MlsBridgeError_Signer value)? signer,
+    TResult Function(MlsBridgeError_Transfer value)? transfer,
+    TResult Function(MlsBridgeError_Storage value)? storage,
+    TResult Function(MlsBridgeError_Mls value)? mls,
+    TResult Function(MlsBridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $MlsBridgeErrorCopyWith<$Res> {
+  factory $MlsBridgeErrorCopyWith(
+          MlsBridgeError value, $Res Function(MlsBridgeError) then) =
+      _$MlsBridgeErrorCopyWithImpl<$Res, MlsBridgeError>;
+}
+
+/// @nodoc
+class _$MlsBridgeErrorCopyWithImpl<$Res, $Val extends MlsBridgeError>
+    implements $MlsBridgeErrorCopyWith<$Res> {
+  _$MlsBridgeErrorCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$MlsBridgeError_NotInitializedImplCopyWith<$Res> {
+  factory _$$MlsBridgeError_NotInitializedImplCopyWith(
+          _$MlsBridgeError_NotInitializedImpl value,
+          $Res Function(_$MlsBridgeError_NotInitializedImpl) then) =
+      __$$MlsBridgeError_NotInitializedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$MlsBridgeError_NotInitializedImplCopyWithImpl<$Res>
+    extends _$MlsBridgeErrorCopyWithImpl<$Res,
+        _$MlsBridgeError_NotInitializedImpl>
+    implements _$$MlsBridgeError_NotInitializedImplCopyWith<$Res> {
+  __$$MlsBridgeError_NotInitializedImplCopyWithImpl(
+      _$MlsBridgeError_NotInitializedImpl _value,
+      $Res Function(_$MlsBridgeError_NotInitializedImpl) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$MlsBridgeError_NotInitializedImpl
+    extends MlsBridgeError_NotInitialized {
+  const _$MlsBridgeError_NotInitializedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'MlsBridgeError.notInitialized()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MlsBridgeError_NotInitializedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() notInitialized,
+    required TResult Function(String field, String message) invalidInput,
+    required TResult Function() groupNotFound,
+    required TResult Function(String message) staleEpoch,
+    required TResult Function() ownMessage,
+    required TResult Function(String message) decryptionFailed,
+    required TResult Function(String message) storageLocked,
+    required TResult Function() keyPackageNotFound,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(String message) signer,
+    required TResult Function(String message) transfer,
+    required TResult Function(String message) storage,
+    required TResult Function(String message) mls,
+    required TResult Function(String message) internal,
+  }) {
+    return notInitialized();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? notInitialized,
+    TResult? Function(String field, String message)? invalidInput,
+    TResult? Function()? groupNotFound,
+    TResult? Function(String message)? staleEpoch,
+    TResult? Function()? ownMessage,
+    TResult? Function(String message)? decryptionFailed,
+    TResult? Function(String message)? storageLocked,
+    TResult? Function()? keyPackageNotFound,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(String message)? signer,
+    TResult? Function(String message)? transfer,
+    TResult? Function(String message)? storage,
+    TResult? Function(String message)? mls,
+    TResult? Function(String message)? internal,
+  }) {
+    return notInitialized?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? notInitialized,
+    TResult Function(String field, String message)? invalidInput,
+    TResult Function()? groupNotFound,
+    TResult Function(String message)? staleEpoch,
+    TResult Function()? ownMessage,
+    TResult Function(String message)? decryptionFailed,
+    TResult Function(String message)? storageLocked,
+    TResult Function()? keyPackageNotFound,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(String message)? signer,
+    TResult Function(String message)? transfer,
+    TResult Function(String message)? storage,
+    TResult Function(String message)? mls,
+    TResult Function(String message)? internal,
+    required TResult orElse(),
+  }) {
+    if (notInitialized != null) {
+      return notInitialized();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MlsBridgeError_NotInitialized value)
+        notInitialized,
+    required TResult Function(MlsBridgeError_InvalidInput value) invalidInput,
+    required TResult Function(MlsBridgeError_GroupNotFound value) groupNotFound,
+    required TResult Function(MlsBridgeError_StaleEpoch value) staleEpoch,
+    required TResult Function(MlsBridgeError_OwnMessage value) ownMessage,
+    required TResult Function(MlsBridgeError_DecryptionFailed value)
+        decryptionFailed,
+    required TResult Function(MlsBridgeError_StorageLocked value) storageLocked,
+    required TResult Function(MlsBridgeError_KeyPackageNotFound value)
+        keyPackageNotFound,
+    required TResult Function(MlsBridgeError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(MlsBridgeError_Signer value) signer,
+    required TResult Function(MlsBridgeError_Transfer value) transfer,
+    required TResult Function(MlsBridgeError_Storage value) storage,
+    required TResult Function(MlsBridgeError_Mls value) mls,
+    required TResult Function(MlsBridgeError_Internal value) internal,
+  }) {
+    return notInitialized(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult? Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult? Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult? Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult? Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult? Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult? Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult? Function(MlsBridgeError_Signer value)? signer,
+    TResult? Function(MlsBridgeError_Transfer value)? transfer,
+    TResult? Function(MlsBridgeError_Storage value)? storage,
+    TResult? Function(MlsBridgeError_Mls value)? mls,
+    TResult? Function(MlsBridgeError_Internal value)? internal,
+  }) {
+    return notInitialized?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult Function(MlsBridgeError_Signer value)? signer,
+    TResult Function(MlsBridgeError_Transfer value)? transfer,
+    TResult Function(MlsBridgeError_Storage value)? storage,
+    TResult Function(MlsBridgeError_Mls value)? mls,
+    TResult Function(MlsBridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (notInitialized != null) {
+      return notInitialized(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MlsBridgeError_NotInitialized extends MlsBridgeError {
+  const factory MlsBridgeError_NotInitialized() =
+      _$MlsBridgeError_NotInitializedImpl;
+  const MlsBridgeError_NotInitialized._() : super._();
+}
+
+/// @nodoc
+abstract class _$$MlsBridgeError_InvalidInputImplCopyWith<$Res> {
+  factory _$$MlsBridgeError_InvalidInputImplCopyWith(
+          _$MlsBridgeError_InvalidInputImpl value,
+          $Res Function(_$MlsBridgeError_InvalidInputImpl) then) =
+      __$$MlsBridgeError_InvalidInputImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field, String message});
+}
+
+/// @nodoc
+class __$$MlsBridgeError_InvalidInputImplCopyWithImpl<$Res>
+    extends _$MlsBridgeErrorCopyWithImpl<$Res,
+        _$MlsBridgeError_InvalidInputImpl>
+    implements _$$MlsBridgeError_InvalidInputImplCopyWith<$Res> {
+  __$$MlsBridgeError_InvalidInputImplCopyWithImpl(
+      _$MlsBridgeError_InvalidInputImpl _value,
+      $Res Function(_$MlsBridgeError_InvalidInputImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field = null,
+    Object? message = null,
+  }) {
+    return _then(_$MlsBridgeError_InvalidInputImpl(
+      field: null == field
+          ? _value.field
+          : field // ignore: cast_nullable_to_non_nullable
+              as String,
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$MlsBridgeError_InvalidInputImpl extends MlsBridgeError_InvalidInput {
+  const _$MlsBridgeError_InvalidInputImpl(
+      {required this.field, required this.message})
+      : super._();
+
+  @override
+  final String field;
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'MlsBridgeError.invalidInput(field: $field, message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MlsBridgeError_InvalidInputImpl &&
+            (identical(other.field, field) || other.field == field) &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field, message);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MlsBridgeError_InvalidInputImplCopyWith<_$MlsBridgeError_InvalidInputImpl>
+      get copyWith => __$$MlsBridgeError_InvalidInputImplCopyWithImpl<
+          _$MlsBridgeError_InvalidInputImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() notInitialized,
+    required TResult Function(String field, String message) invalidInput,
+    required TResult Function() groupNotFound,
+    required TResult Function(String message) staleEpoch,
+    required TResult Function() ownMessage,
+    required TResult Function(String message) decryptionFailed,
+    required TResult Function(String message) storageLocked,
+    required TResult Function() keyPackageNotFound,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(String message) signer,
+    required TResult Function(String message) transfer,
+    required TResult Function(String message) storage,
+    required TResult Function(String message) mls,
+    required TResult Function(String message) internal,
+  }) {
+    return invalidInput(field, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? notInitialized,
+    TResult? Function(String field, String message)? invalidInput,
+    TResult? Function()? groupNotFound,
+    TResult? Function(String message)? staleEpoch,
+    TResult? Function()? ownMessage,
+    TResult? Function(String message)? decryptionFailed,
+    TResult? Function(String message)? storageLocked,
+    TResult? Function()? keyPackageNotFound,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(String message)? signer,
+    TResult? Function(String message)? transfer,
+    TResult? Function(String message)? storage,
+    TResult? Function(String message)? mls,
+    TResult? Function(String message)? internal,
+  }) {
+    return invalidInput?.call(field, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? notInitialized,
+    TResult Function(String field, String message)? invalidInput,
+    TResult Function()? groupNotFound,
+    TResult Function(String message)? staleEpoch,
+    TResult Function()? ownMessage,
+    TResult Function(String message)? decryptionFailed,
+    TResult Function(String message)? storageLocked,
+    TResult Function()? keyPackageNotFound,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(String message)? signer,
+    TResult Function(String message)? transfer,
+    TResult Function(String message)? storage,
+    TResult Function(String message)? mls,
+    TResult Function(String message)? internal,
+    required TResult orElse(),
+  }) {
+    if (invalidInput != null) {
+      return invalidInput(field, message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MlsBridgeError_NotInitialized value)
+        notInitialized,
+    required TResult Function(MlsBridgeError_InvalidInput value) invalidInput,
+    required TResult Function(MlsBridgeError_GroupNotFound value) groupNotFound,
+    required TResult Function(MlsBridgeError_StaleEpoch value) staleEpoch,
+    required TResult Function(MlsBridgeError_OwnMessage value) ownMessage,
+    required TResult Function(MlsBridgeError_DecryptionFailed value)
+        decryptionFailed,
+    required TResult Function(MlsBridgeError_StorageLocked value) storageLocked,
+    required TResult Function(MlsBridgeError_KeyPackageNotFound value)
+        keyPackageNotFound,
+    required TResult Function(MlsBridgeError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(MlsBridgeError_Signer value) signer,
+    required TResult Function(MlsBridgeError_Transfer value) transfer,
+    required TResult Function(MlsBridgeError_Storage value) storage,
+    required TResult Function(MlsBridgeError_Mls value) mls,
+    required TResult Function(MlsBridgeError_Internal value) internal,
+  }) {
+    return invalidInput(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult? Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult? Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult? Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult? Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult? Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult? Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult? Function(MlsBridgeError_Signer value)? signer,
+    TResult? Function(MlsBridgeError_Transfer value)? transfer,
+    TResult? Function(MlsBridgeError_Storage value)? storage,
+    TResult? Function(MlsBridgeError_Mls value)? mls,
+    TResult? Function(MlsBridgeError_Internal value)? internal,
+  }) {
+    return invalidInput?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult Function(MlsBridgeError_Signer value)? signer,
+    TResult Function(MlsBridgeError_Transfer value)? transfer,
+    TResult Function(MlsBridgeError_Storage value)? storage,
+    TResult Function(MlsBridgeError_Mls value)? mls,
+    TResult Function(MlsBridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (invalidInput != null) {
+      return invalidInput(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MlsBridgeError_InvalidInput extends MlsBridgeError {
+  const factory MlsBridgeError_InvalidInput(
+          {required final String field, required final String message}) =
+      _$MlsBridgeError_InvalidInputImpl;
+  const MlsBridgeError_InvalidInput._() : super._();
+
+  String get field;
+  String get message;
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MlsBridgeError_InvalidInputImplCopyWith<_$MlsBridgeError_InvalidInputImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MlsBridgeError_GroupNotFoundImplCopyWith<$Res> {
+  factory _$$MlsBridgeError_GroupNotFoundImplCopyWith(
+          _$MlsBridgeError_GroupNotFoundImpl value,
+          $Res Function(_$MlsBridgeError_GroupNotFoundImpl) then) =
+      __$$MlsBridgeError_GroupNotFoundImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$MlsBridgeError_GroupNotFoundImplCopyWithImpl<$Res>
+    extends _$MlsBridgeErrorCopyWithImpl<$Res,
+        _$MlsBridgeError_GroupNotFoundImpl>
+    implements _$$MlsBridgeError_GroupNotFoundImplCopyWith<$Res> {
+  __$$MlsBridgeError_GroupNotFoundImplCopyWithImpl(
+      _$MlsBridgeError_GroupNotFoundImpl _value,
+      $Res Function(_$MlsBridgeError_GroupNotFoundImpl) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$MlsBridgeError_GroupNotFoundImpl extends MlsBridgeError_GroupNotFound {
+  const _$MlsBridgeError_GroupNotFoundImpl() : super._();
+
+  @override
+  String toString() {
+    return 'MlsBridgeError.groupNotFound()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MlsBridgeError_GroupNotFoundImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() notInitialized,
+    required TResult Function(String field, String message) invalidInput,
+    required TResult Function() groupNotFound,
+    required TResult Function(String message) staleEpoch,
+    required TResult Function() ownMessage,
+    required TResult Function(String message) decryptionFailed,
+    required TResult Function(String message) storageLocked,
+    required TResult Function() keyPackageNotFound,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(String message) signer,
+    required TResult Function(String message) transfer,
+    required TResult Function(String message) storage,
+    required TResult Function(String message) mls,
+    required TResult Function(String message) internal,
+  }) {
+    return groupNotFound();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? notInitialized,
+    TResult? Function(String field, String message)? invalidInput,
+    TResult? Function()? groupNotFound,
+    TResult? Function(String message)? staleEpoch,
+    TResult? Function()? ownMessage,
+    TResult? Function(String message)? decryptionFailed,
+    TResult? Function(String message)? storageLocked,
+    TResult? Function()? keyPackageNotFound,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(String message)? signer,
+    TResult? Function(String message)? transfer,
+    TResult? Function(String message)? storage,
+    TResult? Function(String message)? mls,
+    TResult? Function(String message)? internal,
+  }) {
+    return groupNotFound?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? notInitialized,
+    TResult Function(String field, String message)? invalidInput,
+    TResult Function()? groupNotFound,
+    TResult Function(String message)? staleEpoch,
+    TResult Function()? ownMessage,
+    TResult Function(String message)? decryptionFailed,
+    TResult Function(String message)? storageLocked,
+    TResult Function()? keyPackageNotFound,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(String message)? signer,
+    TResult Function(String message)? transfer,
+    TResult Function(String message)? storage,
+    TResult Function(String message)? mls,
+    TResult Function(String message)? internal,
+    required TResult orElse(),
+  }) {
+    if (groupNotFound != null) {
+      return groupNotFound();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MlsBridgeError_NotInitialized value)
+        notInitialized,
+    required TResult Function(MlsBridgeError_InvalidInput value) invalidInput,
+    required TResult Function(MlsBridgeError_GroupNotFound value) groupNotFound,
+    required TResult Function(MlsBridgeError_StaleEpoch value) staleEpoch,
+    required TResult Function(MlsBridgeError_OwnMessage value) ownMessage,
+    required TResult Function(MlsBridgeError_DecryptionFailed value)
+        decryptionFailed,
+    required TResult Function(MlsBridgeError_StorageLocked value) storageLocked,
+    required TResult Function(MlsBridgeError_KeyPackageNotFound value)
+        keyPackageNotFound,
+    required TResult Function(MlsBridgeError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(MlsBridgeError_Signer value) signer,
+    required TResult Function(MlsBridgeError_Transfer value) transfer,
+    required TResult Function(MlsBridgeError_Storage value) storage,
+    required TResult Function(MlsBridgeError_Mls value) mls,
+    required TResult Function(MlsBridgeError_Internal value) internal,
+  }) {
+    return groupNotFound(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult? Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult? Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult? Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult? Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult? Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult? Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult? Function(MlsBridgeError_Signer value)? signer,
+    TResult? Function(MlsBridgeError_Transfer value)? transfer,
+    TResult? Function(MlsBridgeError_Storage value)? storage,
+    TResult? Function(MlsBridgeError_Mls value)? mls,
+    TResult? Function(MlsBridgeError_Internal value)? internal,
+  }) {
+    return groupNotFound?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult Function(MlsBridgeError_Signer value)? signer,
+    TResult Function(MlsBridgeError_Transfer value)? transfer,
+    TResult Function(MlsBridgeError_Storage value)? storage,
+    TResult Function(MlsBridgeError_Mls value)? mls,
+    TResult Function(MlsBridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (groupNotFound != null) {
+      return groupNotFound(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MlsBridgeError_GroupNotFound extends MlsBridgeError {
+  const factory MlsBridgeError_GroupNotFound() =
+      _$MlsBridgeError_GroupNotFoundImpl;
+  const MlsBridgeError_GroupNotFound._() : super._();
+}
+
+/// @nodoc
+abstract class _$$MlsBridgeError_StaleEpochImplCopyWith<$Res> {
+  factory _$$MlsBridgeError_StaleEpochImplCopyWith(
+          _$MlsBridgeError_StaleEpochImpl value,
+          $Res Function(_$MlsBridgeError_StaleEpochImpl) then) =
+      __$$MlsBridgeError_StaleEpochImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$MlsBridgeError_StaleEpochImplCopyWithImpl<$Res>
+    extends _$MlsBridgeErrorCopyWithImpl<$Res, _$MlsBridgeError_StaleEpochImpl>
+    implements _$$MlsBridgeError_StaleEpochImplCopyWith<$Res> {
+  __$$MlsBridgeError_StaleEpochImplCopyWithImpl(
+      _$MlsBridgeError_StaleEpochImpl _value,
+      $Res Function(_$MlsBridgeError_StaleEpochImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$MlsBridgeError_StaleEpochImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$MlsBridgeError_StaleEpochImpl extends MlsBridgeError_StaleEpoch {
+  const _$MlsBridgeError_StaleEpochImpl({required this.message}) : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'MlsBridgeError.staleEpoch(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MlsBridgeError_StaleEpochImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MlsBridgeError_StaleEpochImplCopyWith<_$MlsBridgeError_StaleEpochImpl>
+      get copyWith => __$$MlsBridgeError_StaleEpochImplCopyWithImpl<
+          _$MlsBridgeError_StaleEpochImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() notInitialized,
+    required TResult Function(String field, String message) invalidInput,
+    required TResult Function() groupNotFound,
+    required TResult Function(String message) staleEpoch,
+    required TResult Function() ownMessage,
+    required TResult Function(String message) decryptionFailed,
+    required TResult Function(String message) storageLocked,
+    required TResult Function() keyPackageNotFound,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(String message) signer,
+    required TResult Function(String message) transfer,
+    required TResult Function(String message) storage,
+    required TResult Function(String message) mls,
+    required TResult Function(String message) internal,
+  }) {
+    return staleEpoch(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? notInitialized,
+    TResult? Function(String field, String message)? invalidInput,
+    TResult? Function()? groupNotFound,
+    TResult? Function(String message)? staleEpoch,
+    TResult? Function()? ownMessage,
+    TResult? Function(String message)? decryptionFailed,
+    TResult? Function(String message)? storageLocked,
+    TResult? Function()? keyPackageNotFound,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(String message)? signer,
+    TResult? Function(String message)? transfer,
+    TResult? Function(String message)? storage,
+    TResult? Function(String message)? mls,
+    TResult? Function(String message)? internal,
+  }) {
+    return staleEpoch?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? notInitialized,
+    TResult Function(String field, String message)? invalidInput,
+    TResult Function()? groupNotFound,
+    TResult Function(String message)? staleEpoch,
+    TResult Function()? ownMessage,
+    TResult Function(String message)? decryptionFailed,
+    TResult Function(String message)? storageLocked,
+    TResult Function()? keyPackageNotFound,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(String message)? signer,
+    TResult Function(String message)? transfer,
+    TResult Function(String message)? storage,
+    TResult Function(String message)? mls,
+    TResult Function(String message)? internal,
+    required TResult orElse(),
+  }) {
+    if (staleEpoch != null) {
+      return staleEpoch(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MlsBridgeError_NotInitialized value)
+        notInitialized,
+    required TResult Function(MlsBridgeError_InvalidInput value) invalidInput,
+    required TResult Function(MlsBridgeError_GroupNotFound value) groupNotFound,
+    required TResult Function(MlsBridgeError_StaleEpoch value) staleEpoch,
+    required TResult Function(MlsBridgeError_OwnMessage value) ownMessage,
+    required TResult Function(MlsBridgeError_DecryptionFailed value)
+        decryptionFailed,
+    required TResult Function(MlsBridgeError_StorageLocked value) storageLocked,
+    required TResult Function(MlsBridgeError_KeyPackageNotFound value)
+        keyPackageNotFound,
+    required TResult Function(MlsBridgeError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(MlsBridgeError_Signer value) signer,
+    required TResult Function(MlsBridgeError_Transfer value) transfer,
+    required TResult Function(MlsBridgeError_Storage value) storage,
+    required TResult Function(MlsBridgeError_Mls value) mls,
+    required TResult Function(MlsBridgeError_Internal value) internal,
+  }) {
+    return staleEpoch(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult? Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult? Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult? Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult? Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult? Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult? Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult? Function(MlsBridgeError_Signer value)? signer,
+    TResult? Function(MlsBridgeError_Transfer value)? transfer,
+    TResult? Function(MlsBridgeError_Storage value)? storage,
+    TResult? Function(MlsBridgeError_Mls value)? mls,
+    TResult? Function(MlsBridgeError_Internal value)? internal,
+  }) {
+    return staleEpoch?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult Function(MlsBridgeError_Signer value)? signer,
+    TResult Function(MlsBridgeError_Transfer value)? transfer,
+    TResult Function(MlsBridgeError_Storage value)? storage,
+    TResult Function(MlsBridgeError_Mls value)? mls,
+    TResult Function(MlsBridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (staleEpoch != null) {
+      return staleEpoch(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MlsBridgeError_StaleEpoch extends MlsBridgeError {
+  const factory MlsBridgeError_StaleEpoch({required final String message}) =
+      _$MlsBridgeError_StaleEpochImpl;
+  const MlsBridgeError_StaleEpoch._() : super._();
+
+  String get message;
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MlsBridgeError_StaleEpochImplCopyWith<_$MlsBridgeError_StaleEpochImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MlsBridgeError_OwnMessageImplCopyWith<$Res> {
+  factory _$$MlsBridgeError_OwnMessageImplCopyWith(
+          _$MlsBridgeError_OwnMessageImpl value,
+          $Res Function(_$MlsBridgeError_OwnMessageImpl) then) =
+      __$$MlsBridgeError_OwnMessageImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$MlsBridgeError_OwnMessageImplCopyWithImpl<$Res>
+    extends _$MlsBridgeErrorCopyWithImpl<$Res, _$MlsBridgeError_OwnMessageImpl>
+    implements _$$MlsBridgeError_OwnMessageImplCopyWith<$Res> {
+  __$$MlsBridgeError_OwnMessageImplCopyWithImpl(
+      _$MlsBridgeError_OwnMessageImpl _value,
+      $Res Function(_$MlsBridgeError_OwnMessageImpl) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$MlsBridgeError_OwnMessageImpl extends MlsBridgeError_OwnMessage {
+  const _$MlsBridgeError_OwnMessageImpl() : super._();
+
+  @override
+  String toString() {
+    return 'MlsBridgeError.ownMessage()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MlsBridgeError_OwnMessageImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() notInitialized,
+    required TResult Function(String field, String message) invalidInput,
+    required TResult Function() groupNotFound,
+    required TResult Function(String message) staleEpoch,
+    required TResult Function() ownMessage,
+    required TResult Function(String message) decryptionFailed,
+    required TResult Function(String message) storageLocked,
+    required TResult Function() keyPackageNotFound,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(String message) signer,
+    required TResult Function(String message) transfer,
+    required TResult Function(String message) storage,
+    required TResult Function(String message) mls,
+    required TResult Function(String message) internal,
+  }) {
+    return ownMessage();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? notInitialized,
+    TResult? Function(String field, String message)? invalidInput,
+    TResult? Function()? groupNotFound,
+    TResult? Function(String message)? staleEpoch,
+    TResult? Function()? ownMessage,
+    TResult? Function(String message)? decryptionFailed,
+    TResult? Function(String message)? storageLocked,
+    TResult? Function()? keyPackageNotFound,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(String message)? signer,
+    TResult? Function(String message)? transfer,
+    TResult? Function(String message)? storage,
+    TResult? Function(String message)? mls,
+    TResult? Function(String message)? internal,
+  }) {
+    return ownMessage?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? notInitialized,
+    TResult Function(String field, String message)? invalidInput,
+    TResult Function()? groupNotFound,
+    TResult Function(String message)? staleEpoch,
+    TResult Function()? ownMessage,
+    TResult Function(String message)? decryptionFailed,
+    TResult Function(String message)? storageLocked,
+    TResult Function()? keyPackageNotFound,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(String message)? signer,
+    TResult Function(String message)? transfer,
+    TResult Function(String message)? storage,
+    TResult Function(String message)? mls,
+    TResult Function(String message)? internal,
+    required TResult orElse(),
+  }) {
+    if (ownMessage != null) {
+      return ownMessage();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MlsBridgeError_NotInitialized value)
+        notInitialized,
+    required TResult Function(MlsBridgeError_InvalidInput value) invalidInput,
+    required TResult Function(MlsBridgeError_GroupNotFound value) groupNotFound,
+    required TResult Function(MlsBridgeError_StaleEpoch value) staleEpoch,
+    required TResult Function(MlsBridgeError_OwnMessage value) ownMessage,
+    required TResult Function(MlsBridgeError_DecryptionFailed value)
+        decryptionFailed,
+    required TResult Function(MlsBridgeError_StorageLocked value) storageLocked,
+    required TResult Function(MlsBridgeError_KeyPackageNotFound value)
+        keyPackageNotFound,
+    required TResult Function(MlsBridgeError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(MlsBridgeError_Signer value) signer,
+    required TResult Function(MlsBridgeError_Transfer value) transfer,
+    required TResult Function(MlsBridgeError_Storage value) storage,
+    required TResult Function(MlsBridgeError_Mls value) mls,
+    required TResult Function(MlsBridgeError_Internal value) internal,
+  }) {
+    return ownMessage(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult? Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult? Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult? Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult? Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult? Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult? Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult? Function(MlsBridgeError_Signer value)? signer,
+    TResult? Function(MlsBridgeError_Transfer value)? transfer,
+    TResult? Function(MlsBridgeError_Storage value)? storage,
+    TResult? Function(MlsBridgeError_Mls value)? mls,
+    TResult? Function(MlsBridgeError_Internal value)? internal,
+  }) {
+    return ownMessage?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult Function(MlsBridgeError_Signer value)? signer,
+    TResult Function(MlsBridgeError_Transfer value)? transfer,
+    TResult Function(MlsBridgeError_Storage value)? storage,
+    TResult Function(MlsBridgeError_Mls value)? mls,
+    TResult Function(MlsBridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (ownMessage != null) {
+      return ownMessage(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MlsBridgeError_OwnMessage extends MlsBridgeError {
+  const factory MlsBridgeError_OwnMessage() = _$MlsBridgeError_OwnMessageImpl;
+  const MlsBridgeError_OwnMessage._() : super._();
+}
+
+/// @nodoc
+abstract class _$$MlsBridgeError_DecryptionFailedImplCopyWith<$Res> {
+  factory _$$MlsBridgeError_DecryptionFailedImplCopyWith(
+          _$MlsBridgeError_DecryptionFailedImpl value,
+          $Res Function(_$MlsBridgeError_DecryptionFailedImpl) then) =
+      __$$MlsBridgeError_DecryptionFailedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$MlsBridgeError_DecryptionFailedImplCopyWithImpl<$Res>
+    extends _$MlsBridgeErrorCopyWithImpl<$Res,
+        _$MlsBridgeError_DecryptionFailedImpl>
+    implements _$$MlsBridgeError_DecryptionFailedImplCopyWith<$Res> {
+  __$$MlsBridgeError_DecryptionFailedImplCopyWithImpl(
+      _$MlsBridgeError_DecryptionFailedImpl _value,
+      $Res Function(_$MlsBridgeError_DecryptionFailedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$MlsBridgeError_DecryptionFailedImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$MlsBridgeError_DecryptionFailedImpl
+    extends MlsBridgeError_DecryptionFailed {
+  const _$MlsBridgeError_DecryptionFailedImpl({required this.message})
+      : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'MlsBridgeError.decryptionFailed(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MlsBridgeError_DecryptionFailedImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MlsBridgeError_DecryptionFailedImplCopyWith<
+          _$MlsBridgeError_DecryptionFailedImpl>
+      get copyWith => __$$MlsBridgeError_DecryptionFailedImplCopyWithImpl<
+          _$MlsBridgeError_DecryptionFailedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() notInitialized,
+    required TResult Function(String field, String message) invalidInput,
+    required TResult Function() groupNotFound,
+    required TResult Function(String message) staleEpoch,
+    required TResult Function() ownMessage,
+    required TResult Function(String message) decryptionFailed,
+    required TResult Function(String message) storageLocked,
+    required TResult Function() keyPackageNotFound,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(String message) signer,
+    required TResult Function(String message) transfer,
+    required TResult Function(String message) storage,
+    required TResult Function(String message) mls,
+    required TResult Function(String message) internal,
+  }) {
+    return decryptionFailed(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? notInitialized,
+    TResult? Function(String field, String message)? invalidInput,
+    TResult? Function()? groupNotFound,
+    TResult? Function(String message)? staleEpoch,
+    TResult? Function()? ownMessage,
+    TResult? Function(String message)? decryptionFailed,
+    TResult? Function(String message)? storageLocked,
+    TResult? Function()? keyPackageNotFound,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(String message)? signer,
+    TResult? Function(String message)? transfer,
+    TResult? Function(String message)? storage,
+    TResult? Function(String message)? mls,
+    TResult? Function(String message)? internal,
+  }) {
+    return decryptionFailed?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? notInitialized,
+    TResult Function(String field, String message)? invalidInput,
+    TResult Function()? groupNotFound,
+    TResult Function(String message)? staleEpoch,
+    TResult Function()? ownMessage,
+    TResult Function(String message)? decryptionFailed,
+    TResult Function(String message)? storageLocked,
+    TResult Function()? keyPackageNotFound,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(String message)? signer,
+    TResult Function(String message)? transfer,
+    TResult Function(String message)? storage,
+    TResult Function(String message)? mls,
+    TResult Function(String message)? internal,
+    required TResult orElse(),
+  }) {
+    if (decryptionFailed != null) {
+      return decryptionFailed(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MlsBridgeError_NotInitialized value)
+        notInitialized,
+    required TResult Function(MlsBridgeError_InvalidInput value) invalidInput,
+    required TResult Function(MlsBridgeError_GroupNotFound value) groupNotFound,
+    required TResult Function(MlsBridgeError_StaleEpoch value) staleEpoch,
+    required TResult Function(MlsBridgeError_OwnMessage value) ownMessage,
+    required TResult Function(MlsBridgeError_DecryptionFailed value)
+        decryptionFailed,
+    required TResult Function(MlsBridgeError_StorageLocked value) storageLocked,
+    required TResult Function(MlsBridgeError_KeyPackageNotFound value)
+        keyPackageNotFound,
+    required TResult Function(MlsBridgeError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(MlsBridgeError_Signer value) signer,
+    required TResult Function(MlsBridgeError_Transfer value) transfer,
+    required TResult Function(MlsBridgeError_Storage value) storage,
+    required TResult Function(MlsBridgeError_Mls value) mls,
+    required TResult Function(MlsBridgeError_Internal value) internal,
+  }) {
+    return decryptionFailed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult? Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult? Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult? Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult? Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult? Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult? Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult? Function(MlsBridgeError_Signer value)? signer,
+    TResult? Function(MlsBridgeError_Transfer value)? transfer,
+    TResult? Function(MlsBridgeError_Storage value)? storage,
+    TResult? Function(MlsBridgeError_Mls value)? mls,
+    TResult? Function(MlsBridgeError_Internal value)? internal,
+  }) {
+    return decryptionFailed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult Function(MlsBridgeError_Signer value)? signer,
+    TResult Function(MlsBridgeError_Transfer value)? transfer,
+    TResult Function(MlsBridgeError_Storage value)? storage,
+    TResult Function(MlsBridgeError_Mls value)? mls,
+    TResult Function(MlsBridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (decryptionFailed != null) {
+      return decryptionFailed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MlsBridgeError_DecryptionFailed extends MlsBridgeError {
+  const factory MlsBridgeError_DecryptionFailed(
+          {required final String message}) =
+      _$MlsBridgeError_DecryptionFailedImpl;
+  const MlsBridgeError_DecryptionFailed._() : super._();
+
+  String get message;
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MlsBridgeError_DecryptionFailedImplCopyWith<
+          _$MlsBridgeError_DecryptionFailedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MlsBridgeError_StorageLockedImplCopyWith<$Res> {
+  factory _$$MlsBridgeError_StorageLockedImplCopyWith(
+          _$MlsBridgeError_StorageLockedImpl value,
+          $Res Function(_$MlsBridgeError_StorageLockedImpl) then) =
+      __$$MlsBridgeError_StorageLockedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$MlsBridgeError_StorageLockedImplCopyWithImpl<$Res>
+    extends _$MlsBridgeErrorCopyWithImpl<$Res,
+        _$MlsBridgeError_StorageLockedImpl>
+    implements _$$MlsBridgeError_StorageLockedImplCopyWith<$Res> {
+  __$$MlsBridgeError_StorageLockedImplCopyWithImpl(
+      _$MlsBridgeError_StorageLockedImpl _value,
+      $Res Function(_$MlsBridgeError_StorageLockedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$MlsBridgeError_StorageLockedImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$MlsBridgeError_StorageLockedImpl extends MlsBridgeError_StorageLocked {
+  const _$MlsBridgeError_StorageLockedImpl({required this.message}) : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'MlsBridgeError.storageLocked(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MlsBridgeError_StorageLockedImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MlsBridgeError_StorageLockedImplCopyWith<
+          _$MlsBridgeError_StorageLockedImpl>
+      get copyWith => __$$MlsBridgeError_StorageLockedImplCopyWithImpl<
+          _$MlsBridgeError_StorageLockedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() notInitialized,
+    required TResult Function(String field, String message) invalidInput,
+    required TResult Function() groupNotFound,
+    required TResult Function(String message) staleEpoch,
+    required TResult Function() ownMessage,
+    required TResult Function(String message) decryptionFailed,
+    required TResult Function(String message) storageLocked,
+    required TResult Function() keyPackageNotFound,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(String message) signer,
+    required TResult Function(String message) transfer,
+    required TResult Function(String message) storage,
+    required TResult Function(String message) mls,
+    required TResult Function(String message) internal,
+  }) {
+    return storageLocked(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? notInitialized,
+    TResult? Function(String field, String message)? invalidInput,
+    TResult? Function()? groupNotFound,
+    TResult? Function(String message)? staleEpoch,
+    TResult? Function()? ownMessage,
+    TResult? Function(String message)? decryptionFailed,
+    TResult? Function(String message)? storageLocked,
+    TResult? Function()? keyPackageNotFound,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(String message)? signer,
+    TResult? Function(String message)? transfer,
+    TResult? Function(String message)? storage,
+    TResult? Function(String message)? mls,
+    TResult? Function(String message)? internal,
+  }) {
+    return storageLocked?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? notInitialized,
+    TResult Function(String field, String message)? invalidInput,
+    TResult Function()? groupNotFound,
+    TResult Function(String message)? staleEpoch,
+    TResult Function()? ownMessage,
+    TResult Function(String message)? decryptionFailed,
+    TResult Function(String message)? storageLocked,
+    TResult Function()? keyPackageNotFound,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(String message)? signer,
+    TResult Function(String message)? transfer,
+    TResult Function(String message)? storage,
+    TResult Function(String message)? mls,
+    TResult Function(String message)? internal,
+    required TResult orElse(),
+  }) {
+    if (storageLocked != null) {
+      return storageLocked(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MlsBridgeError_NotInitialized value)
+        notInitialized,
+    required TResult Function(MlsBridgeError_InvalidInput value) invalidInput,
+    required TResult Function(MlsBridgeError_GroupNotFound value) groupNotFound,
+    required TResult Function(MlsBridgeError_StaleEpoch value) staleEpoch,
+    required TResult Function(MlsBridgeError_OwnMessage value) ownMessage,
+    required TResult Function(MlsBridgeError_DecryptionFailed value)
+        decryptionFailed,
+    required TResult Function(MlsBridgeError_StorageLocked value) storageLocked,
+    required TResult Function(MlsBridgeError_KeyPackageNotFound value)
+        keyPackageNotFound,
+    required TResult Function(MlsBridgeError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(MlsBridgeError_Signer value) signer,
+    required TResult Function(MlsBridgeError_Transfer value) transfer,
+    required TResult Function(MlsBridgeError_Storage value) storage,
+    required TResult Function(MlsBridgeError_Mls value) mls,
+    required TResult Function(MlsBridgeError_Internal value) internal,
+  }) {
+    return storageLocked(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult? Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult? Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult? Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult? Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult? Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult? Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult? Function(MlsBridgeError_Signer value)? signer,
+    TResult? Function(MlsBridgeError_Transfer value)? transfer,
+    TResult? Function(MlsBridgeError_Storage value)? storage,
+    TResult? Function(MlsBridgeError_Mls value)? mls,
+    TResult? Function(MlsBridgeError_Internal value)? internal,
+  }) {
+    return storageLocked?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult Function(MlsBridgeError_Signer value)? signer,
+    TResult Function(MlsBridgeError_Transfer value)? transfer,
+    TResult Function(MlsBridgeError_Storage value)? storage,
+    TResult Function(MlsBridgeError_Mls value)? mls,
+    TResult Function(MlsBridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (storageLocked != null) {
+      return storageLocked(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MlsBridgeError_StorageLocked extends MlsBridgeError {
+  const factory MlsBridgeError_StorageLocked({required final String message}) =
+      _$MlsBridgeError_StorageLockedImpl;
+  const MlsBridgeError_StorageLocked._() : super._();
+
+  String get message;
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MlsBridgeError_StorageLockedImplCopyWith<
+          _$MlsBridgeError_StorageLockedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MlsBridgeError_KeyPackageNotFoundImplCopyWith<$Res> {
+  factory _$$MlsBridgeError_KeyPackageNotFoundImplCopyWith(
+          _$MlsBridgeError_KeyPackageNotFoundImpl value,
+          $Res Function(_$MlsBridgeError_KeyPackageNotFoundImpl) then) =
+      __$$MlsBridgeError_KeyPackageNotFoundImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$MlsBridgeError_KeyPackageNotFoundImplCopyWithImpl<$Res>
+    extends _$MlsBridgeErrorCopyWithImpl<$Res,
+        _$MlsBridgeError_KeyPackageNotFoundImpl>
+    implements _$$MlsBridgeError_KeyPackageNotFoundImplCopyWith<$Res> {
+  __$$MlsBridgeError_KeyPackageNotFoundImplCopyWithImpl(
+      _$MlsBridgeError_KeyPackageNotFoundImpl _value,
+      $Res Function(_$MlsBridgeError_KeyPackageNotFoundImpl) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$MlsBridgeError_KeyPackageNotFoundImpl
+    extends MlsBridgeError_KeyPackageNotFound {
+  const _$MlsBridgeError_KeyPackageNotFoundImpl() : super._();
+
+  @override
+  String toString() {
+    return 'MlsBridgeError.keyPackageNotFound()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MlsBridgeError_KeyPackageNotFoundImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() notInitialized,
+    required TResult Function(String field, String message) invalidInput,
+    required TResult Function() groupNotFound,
+    required TResult Function(String message) staleEpoch,
+    required TResult Function() ownMessage,
+    required TResult Function(String message) decryptionFailed,
+    required TResult Function(String message) storageLocked,
+    required TResult Function() keyPackageNotFound,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(String message) signer,
+    required TResult Function(String message) transfer,
+    required TResult Function(String message) storage,
+    required TResult Function(String message) mls,
+    required TResult Function(String message) internal,
+  }) {
+    return keyPackageNotFound();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? notInitialized,
+    TResult? Function(String field, String message)? invalidInput,
+    TResult? Function()? groupNotFound,
+    TResult? Function(String message)? staleEpoch,
+    TResult? Function()? ownMessage,
+    TResult? Function(String message)? decryptionFailed,
+    TResult? Function(String message)? storageLocked,
+    TResult? Function()? keyPackageNotFound,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(String message)? signer,
+    TResult? Function(String message)? transfer,
+    TResult? Function(String message)? storage,
+    TResult? Function(String message)? mls,
+    TResult? Function(String message)? internal,
+  }) {
+    return keyPackageNotFound?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? notInitialized,
+    TResult Function(String field, String message)? invalidInput,
+    TResult Function()? groupNotFound,
+    TResult Function(String message)? staleEpoch,
+    TResult Function()? ownMessage,
+    TResult Function(String message)? decryptionFailed,
+    TResult Function(String message)? storageLocked,
+    TResult Function()? keyPackageNotFound,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(String message)? signer,
+    TResult Function(String message)? transfer,
+    TResult Function(String message)? storage,
+    TResult Function(String message)? mls,
+    TResult Function(String message)? internal,
+    required TResult orElse(),
+  }) {
+    if (keyPackageNotFound != null) {
+      return keyPackageNotFound();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MlsBridgeError_NotInitialized value)
+        notInitialized,
+    required TResult Function(MlsBridgeError_InvalidInput value) invalidInput,
+    required TResult Function(MlsBridgeError_GroupNotFound value) groupNotFound,
+    required TResult Function(MlsBridgeError_StaleEpoch value) staleEpoch,
+    required TResult Function(MlsBridgeError_OwnMessage value) ownMessage,
+    required TResult Function(MlsBridgeError_DecryptionFailed value)
+        decryptionFailed,
+    required TResult Function(MlsBridgeError_StorageLocked value) storageLocked,
+    required TResult Function(MlsBridgeError_KeyPackageNotFound value)
+        keyPackageNotFound,
+    required TResult Function(MlsBridgeError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(MlsBridgeError_Signer value) signer,
+    required TResult Function(MlsBridgeError_Transfer value) transfer,
+    required TResult Function(MlsBridgeError_Storage value) storage,
+    required TResult Function(MlsBridgeError_Mls value) mls,
+    required TResult Function(MlsBridgeError_Internal value) internal,
+  }) {
+    return keyPackageNotFound(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult? Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult? Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult? Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult? Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult? Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult? Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult? Function(MlsBridgeError_Signer value)? signer,
+    TResult? Function(MlsBridgeError_Transfer value)? transfer,
+    TResult? Function(MlsBridgeError_Storage value)? storage,
+    TResult? Function(MlsBridgeError_Mls value)? mls,
+    TResult? Function(MlsBridgeError_Internal value)? internal,
+  }) {
+    return keyPackageNotFound?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult Function(MlsBridgeError_Signer value)? signer,
+    TResult Function(MlsBridgeError_Transfer value)? transfer,
+    TResult Function(MlsBridgeError_Storage value)? storage,
+    TResult Function(MlsBridgeError_Mls value)? mls,
+    TResult Function(MlsBridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (keyPackageNotFound != null) {
+      return keyPackageNotFound(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MlsBridgeError_KeyPackageNotFound extends MlsBridgeError {
+  const factory MlsBridgeError_KeyPackageNotFound() =
+      _$MlsBridgeError_KeyPackageNotFoundImpl;
+  const MlsBridgeError_KeyPackageNotFound._() : super._();
+}
+
+/// @nodoc
+abstract class _$$MlsBridgeError_PermissionDeniedImplCopyWith<$Res> {
+  factory _$$MlsBridgeError_PermissionDeniedImplCopyWith(
+          _$MlsBridgeError_PermissionDeniedImpl value,
+          $Res Function(_$MlsBridgeError_PermissionDeniedImpl) then) =
+      __$$MlsBridgeError_PermissionDeniedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$MlsBridgeError_PermissionDeniedImplCopyWithImpl<$Res>
+    extends _$MlsBridgeErrorCopyWithImpl<$Res,
+        _$MlsBridgeError_PermissionDeniedImpl>
+    implements _$$MlsBridgeError_PermissionDeniedImplCopyWith<$Res> {
+  __$$MlsBridgeError_PermissionDeniedImplCopyWithImpl(
+      _$MlsBridgeError_PermissionDeniedImpl _value,
+      $Res Function(_$MlsBridgeError_PermissionDeniedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$MlsBridgeError_PermissionDeniedImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$MlsBridgeError_PermissionDeniedImpl
+    extends MlsBridgeError_PermissionDenied {
+  const _$MlsBridgeError_PermissionDeniedImpl({required this.message})
+      : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'MlsBridgeError.permissionDenied(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MlsBridgeError_PermissionDeniedImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MlsBridgeError_PermissionDeniedImplCopyWith<
+          _$MlsBridgeError_PermissionDeniedImpl>
+      get copyWith => __$$MlsBridgeError_PermissionDeniedImplCopyWithImpl<
+          _$MlsBridgeError_PermissionDeniedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() notInitialized,
+    required TResult Function(String field, String message) invalidInput,
+    required TResult Function() groupNotFound,
+    required TResult Function(String message) staleEpoch,
+    required TResult Function() ownMessage,
+    required TResult Function(String message) decryptionFailed,
+    required TResult Function(String message) storageLocked,
+    required TResult Function() keyPackageNotFound,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(String message) signer,
+    required TResult Function(String message) transfer,
+    required TResult Function(String message) storage,
+    required TResult Function(String message) mls,
+    required TResult Function(String message) internal,
+  }) {
+    return permissionDenied(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? notInitialized,
+    TResult? Function(String field, String message)? invalidInput,
+    TResult? Function()? groupNotFound,
+    TResult? Function(String message)? staleEpoch,
+    TResult? Function()? ownMessage,
+    TResult? Function(String message)? decryptionFailed,
+    TResult? Function(String message)? storageLocked,
+    TResult? Function()? keyPackageNotFound,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(String message)? signer,
+    TResult? Function(String message)? transfer,
+    TResult? Function(String message)? storage,
+    TResult? Function(String message)? mls,
+    TResult? Function(String message)? internal,
+  }) {
+    return permissionDenied?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? notInitialized,
+    TResult Function(String field, String message)? invalidInput,
+    TResult Function()? groupNotFound,
+    TResult Function(String message)? staleEpoch,
+    TResult Function()? ownMessage,
+    TResult Function(String message)? decryptionFailed,
+    TResult Function(String message)? storageLocked,
+    TResult Function()? keyPackageNotFound,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(String message)? signer,
+    TResult Function(String message)? transfer,
+    TResult Function(String message)? storage,
+    TResult Function(String message)? mls,
+    TResult Function(String message)? internal,
+    required TResult orElse(),
+  }) {
+    if (permissionDenied != null) {
+      return permissionDenied(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MlsBridgeError_NotInitialized value)
+        notInitialized,
+    required TResult Function(MlsBridgeError_InvalidInput value) invalidInput,
+    required TResult Function(MlsBridgeError_GroupNotFound value) groupNotFound,
+    required TResult Function(MlsBridgeError_StaleEpoch value) staleEpoch,
+    required TResult Function(MlsBridgeError_OwnMessage value) ownMessage,
+    required TResult Function(MlsBridgeError_DecryptionFailed value)
+        decryptionFailed,
+    required TResult Function(MlsBridgeError_StorageLocked value) storageLocked,
+    required TResult Function(MlsBridgeError_KeyPackageNotFound value)
+        keyPackageNotFound,
+    required TResult Function(MlsBridgeError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(MlsBridgeError_Signer value) signer,
+    required TResult Function(MlsBridgeError_Transfer value) transfer,
+    required TResult Function(MlsBridgeError_Storage value) storage,
+    required TResult Function(MlsBridgeError_Mls value) mls,
+    required TResult Function(MlsBridgeError_Internal value) internal,
+  }) {
+    return permissionDenied(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult? Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult? Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult? Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult? Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult? Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult? Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult? Function(MlsBridgeError_Signer value)? signer,
+    TResult? Function(MlsBridgeError_Transfer value)? transfer,
+    TResult? Function(MlsBridgeError_Storage value)? storage,
+    TResult? Function(MlsBridgeError_Mls value)? mls,
+    TResult? Function(MlsBridgeError_Internal value)? internal,
+  }) {
+    return permissionDenied?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult Function(MlsBridgeError_Signer value)? signer,
+    TResult Function(MlsBridgeError_Transfer value)? transfer,
+    TResult Function(MlsBridgeError_Storage value)? storage,
+    TResult Function(MlsBridgeError_Mls value)? mls,
+    TResult Function(MlsBridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (permissionDenied != null) {
+      return permissionDenied(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MlsBridgeError_PermissionDenied extends MlsBridgeError {
+  const factory MlsBridgeError_PermissionDenied(
+          {required final String message}) =
+      _$MlsBridgeError_PermissionDeniedImpl;
+  const MlsBridgeError_PermissionDenied._() : super._();
+
+  String get message;
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MlsBridgeError_PermissionDeniedImplCopyWith<
+          _$MlsBridgeError_PermissionDeniedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MlsBridgeError_SignerImplCopyWith<$Res> {
+  factory _$$MlsBridgeError_SignerImplCopyWith(
+          _$MlsBridgeError_SignerImpl value,
+          $Res Function(_$MlsBridgeError_SignerImpl) then) =
+      __$$MlsBridgeError_SignerImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$MlsBridgeError_SignerImplCopyWithImpl<$Res>
+    extends _$MlsBridgeErrorCopyWithImpl<$Res, _$MlsBridgeError_SignerImpl>
+    implements _$$MlsBridgeError_SignerImplCopyWith<$Res> {
+  __$$MlsBridgeError_SignerImplCopyWithImpl(
+      _$MlsBridgeError_SignerImpl _value,
+      $Res Function(_$MlsBridgeError_SignerImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$MlsBridgeError_SignerImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$MlsBridgeError_SignerImpl extends MlsBridgeError_Signer {
+  const _$MlsBridgeError_SignerImpl({required this.message}) : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'MlsBridgeError.signer(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MlsBridgeError_SignerImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MlsBridgeError_SignerImplCopyWith<_$MlsBridgeError_SignerImpl>
+      get copyWith => __$$MlsBridgeError_SignerImplCopyWithImpl<
+          _$MlsBridgeError_SignerImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() notInitialized,
+    required TResult Function(String field, String message) invalidInput,
+    required TResult Function() groupNotFound,
+    required TResult Function(String message) staleEpoch,
+    required TResult Function() ownMessage,
+    required TResult Function(String message) decryptionFailed,
+    required TResult Function(String message) storageLocked,
+    required TResult Function() keyPackageNotFound,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(String message) signer,
+    required TResult Function(String message) transfer,
+    required TResult Function(String message) storage,
+    required TResult Function(String message) mls,
+    required TResult Function(String message) internal,
+  }) {
+    return signer(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? notInitialized,
+    TResult? Function(String field, String message)? invalidInput,
+    TResult? Function()? groupNotFound,
+    TResult? Function(String message)? staleEpoch,
+    TResult? Function()? ownMessage,
+    TResult? Function(String message)? decryptionFailed,
+    TResult? Function(String message)? storageLocked,
+    TResult? Function()? keyPackageNotFound,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(String message)? signer,
+    TResult? Function(String message)? transfer,
+    TResult? Function(String message)? storage,
+    TResult? Function(String message)? mls,
+    TResult? Function(String message)? internal,
+  }) {
+    return signer?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? notInitialized,
+    TResult Function(String field, String message)? invalidInput,
+    TResult Function()? groupNotFound,
+    TResult Function(String message)? staleEpoch,
+    TResult Function()? ownMessage,
+    TResult Function(String message)? decryptionFailed,
+    TResult Function(String message)? storageLocked,
+    TResult Function()? keyPackageNotFound,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(String message)? signer,
+    TResult Function(String message)? transfer,
+    TResult Function(String message)? storage,
+    TResult Function(String message)? mls,
+    TResult Function(String message)? internal,
+    required TResult orElse(),
+  }) {
+    if (signer != null) {
+      return signer(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MlsBridgeError_NotInitialized value)
+        notInitialized,
+    required TResult Function(MlsBridgeError_InvalidInput value) invalidInput,
+    required TResult Function(MlsBridgeError_GroupNotFound value) groupNotFound,
+    required TResult Function(MlsBridgeError_StaleEpoch value) staleEpoch,
+    required TResult Function(MlsBridgeError_OwnMessage value) ownMessage,
+    required TResult Function(MlsBridgeError_DecryptionFailed value)
+        decryptionFailed,
+    required TResult Function(MlsBridgeError_StorageLocked value) storageLocked,
+    required TResult Function(MlsBridgeError_KeyPackageNotFound value)
+        keyPackageNotFound,
+    required TResult Function(MlsBridgeError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(MlsBridgeError_Signer value) signer,
+    required TResult Function(MlsBridgeError_Transfer value) transfer,
+    required TResult Function(MlsBridgeError_Storage value) storage,
+    required TResult Function(MlsBridgeError_Mls value) mls,
+    required TResult Function(MlsBridgeError_Internal value) internal,
+  }) {
+    return signer(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult? Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult? Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult? Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult? Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult? Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult? Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult? Function(MlsBridgeError_Signer value)? signer,
+    TResult? Function(MlsBridgeError_Transfer value)? transfer,
+    TResult? Function(MlsBridgeError_Storage value)? storage,
+    TResult? Function(MlsBridgeError_Mls value)? mls,
+    TResult? Function(MlsBridgeError_Internal value)? internal,
+  }) {
+    return signer?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult Function(MlsBridgeError_Signer value)? signer,
+    TResult Function(MlsBridgeError_Transfer value)? transfer,
+    TResult Function(MlsBridgeError_Storage value)? storage,
+    TResult Function(MlsBridgeError_Mls value)? mls,
+    TResult Function(MlsBridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (signer != null) {
+      return signer(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MlsBridgeError_Signer extends MlsBridgeError {
+  const factory MlsBridgeError_Signer({required final String message}) =
+      _$MlsBridgeError_SignerImpl;
+  const MlsBridgeError_Signer._() : super._();
+
+  String get message;
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MlsBridgeError_SignerImplCopyWith<_$MlsBridgeError_SignerImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MlsBridgeError_TransferImplCopyWith<$Res> {
+  factory _$$MlsBridgeError_TransferImplCopyWith(
+          _$MlsBridgeError_TransferImpl value,
+          $Res Function(_$MlsBridgeError_TransferImpl) then) =
+      __$$MlsBridgeError_TransferImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$MlsBridgeError_TransferImplCopyWithImpl<$Res>
+    extends _$MlsBridgeErrorCopyWithImpl<$Res, _$MlsBridgeError_TransferImpl>
+    implements _$$MlsBridgeError_TransferImplCopyWith<$Res> {
+  __$$MlsBridgeError_TransferImplCopyWithImpl(
+      _$MlsBridgeError_TransferImpl _value,
+      $Res Function(_$MlsBridgeError_TransferImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$MlsBridgeError_TransferImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$MlsBridgeError_TransferImpl extends MlsBridgeError_Transfer {
+  const _$MlsBridgeError_TransferImpl({required this.message}) : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'MlsBridgeError.transfer(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MlsBridgeError_TransferImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MlsBridgeError_TransferImplCopyWith<_$MlsBridgeError_TransferImpl>
+      get copyWith => __$$MlsBridgeError_TransferImplCopyWithImpl<
+          _$MlsBridgeError_TransferImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() notInitialized,
+    required TResult Function(String field, String message) invalidInput,
+    required TResult Function() groupNotFound,
+    required TResult Function(String message) staleEpoch,
+    required TResult Function() ownMessage,
+    required TResult Function(String message) decryptionFailed,
+    required TResult Function(String message) storageLocked,
+    required TResult Function() keyPackageNotFound,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(String message) signer,
+    required TResult Function(String message) transfer,
+    required TResult Function(String message) storage,
+    required TResult Function(String message) mls,
+    required TResult Function(String message) internal,
+  }) {
+    return transfer(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? notInitialized,
+    TResult? Function(String field, String message)? invalidInput,
+    TResult? Function()? groupNotFound,
+    TResult? Function(String message)? staleEpoch,
+    TResult? Function()? ownMessage,
+    TResult? Function(String message)? decryptionFailed,
+    TResult? Function(String message)? storageLocked,
+    TResult? Function()? keyPackageNotFound,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(String message)? signer,
+    TResult? Function(String message)? transfer,
+    TResult? Function(String message)? storage,
+    TResult? Function(String message)? mls,
+    TResult? Function(String message)? internal,
+  }) {
+    return transfer?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? notInitialized,
+    TResult Function(String field, String message)? invalidInput,
+    TResult Function()? groupNotFound,
+    TResult Function(String message)? staleEpoch,
+    TResult Function()? ownMessage,
+    TResult Function(String message)? decryptionFailed,
+    TResult Function(String message)? storageLocked,
+    TResult Function()? keyPackageNotFound,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(String message)? signer,
+    TResult Function(String message)? transfer,
+    TResult Function(String message)? storage,
+    TResult Function(String message)? mls,
+    TResult Function(String message)? internal,
+    required TResult orElse(),
+  }) {
+    if (transfer != null) {
+      return transfer(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MlsBridgeError_NotInitialized value)
+        notInitialized,
+    required TResult Function(MlsBridgeError_InvalidInput value) invalidInput,
+    required TResult Function(MlsBridgeError_GroupNotFound value) groupNotFound,
+    required TResult Function(MlsBridgeError_StaleEpoch value) staleEpoch,
+    required TResult Function(MlsBridgeError_OwnMessage value) ownMessage,
+    required TResult Function(MlsBridgeError_DecryptionFailed value)
+        decryptionFailed,
+    required TResult Function(MlsBridgeError_StorageLocked value) storageLocked,
+    required TResult Function(MlsBridgeError_KeyPackageNotFound value)
+        keyPackageNotFound,
+    required TResult Function(MlsBridgeError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(MlsBridgeError_Signer value) signer,
+    required TResult Function(MlsBridgeError_Transfer value) transfer,
+    required TResult Function(MlsBridgeError_Storage value) storage,
+    required TResult Function(MlsBridgeError_Mls value) mls,
+    required TResult Function(MlsBridgeError_Internal value) internal,
+  }) {
+    return transfer(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult? Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult? Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult? Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult? Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult? Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult? Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult? Function(MlsBridgeError_Signer value)? signer,
+    TResult? Function(MlsBridgeError_Transfer value)? transfer,
+    TResult? Function(MlsBridgeError_Storage value)? storage,
+    TResult? Function(MlsBridgeError_Mls value)? mls,
+    TResult? Function(MlsBridgeError_Internal value)? internal,
+  }) {
+    return transfer?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult Function(MlsBridgeError_Signer value)? signer,
+    TResult Function(MlsBridgeError_Transfer value)? transfer,
+    TResult Function(MlsBridgeError_Storage value)? storage,
+    TResult Function(MlsBridgeError_Mls value)? mls,
+    TResult Function(MlsBridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (transfer != null) {
+      return transfer(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MlsBridgeError_Transfer extends MlsBridgeError {
+  const factory MlsBridgeError_Transfer({required final String message}) =
+      _$MlsBridgeError_TransferImpl;
+  const MlsBridgeError_Transfer._() : super._();
+
+  String get message;
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MlsBridgeError_TransferImplCopyWith<_$MlsBridgeError_TransferImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MlsBridgeError_StorageImplCopyWith<$Res> {
+  factory _$$MlsBridgeError_StorageImplCopyWith(
+          _$MlsBridgeError_StorageImpl value,
+          $Res Function(_$MlsBridgeError_StorageImpl) then) =
+      __$$MlsBridgeError_StorageImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$MlsBridgeError_StorageImplCopyWithImpl<$Res>
+    extends _$MlsBridgeErrorCopyWithImpl<$Res, _$MlsBridgeError_StorageImpl>
+    implements _$$MlsBridgeError_StorageImplCopyWith<$Res> {
+  __$$MlsBridgeError_StorageImplCopyWithImpl(
+      _$MlsBridgeError_StorageImpl _value,
+      $Res Function(_$MlsBridgeError_StorageImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$MlsBridgeError_StorageImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$MlsBridgeError_StorageImpl extends MlsBridgeError_Storage {
+  const _$MlsBridgeError_StorageImpl({required this.message}) : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'MlsBridgeError.storage(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MlsBridgeError_StorageImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MlsBridgeError_StorageImplCopyWith<_$MlsBridgeError_StorageImpl>
+      get copyWith => __$$MlsBridgeError_StorageImplCopyWithImpl<
+          _$MlsBridgeError_StorageImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() notInitialized,
+    required TResult Function(String field, String message) invalidInput,
+    required TResult Function() groupNotFound,
+    required TResult Function(String message) staleEpoch,
+    required TResult Function() ownMessage,
+    required TResult Function(String message) decryptionFailed,
+    required TResult Function(String message) storageLocked,
+    required TResult Function() keyPackageNotFound,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(String message) signer,
+    required TResult Function(String message) transfer,
+    required TResult Function(String message) storage,
+    required TResult Function(String message) mls,
+    required TResult Function(String message) internal,
+  }) {
+    return storage(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? notInitialized,
+    TResult? Function(String field, String message)? invalidInput,
+    TResult? Function()? groupNotFound,
+    TResult? Function(String message)? staleEpoch,
+    TResult? Function()? ownMessage,
+    TResult? Function(String message)? decryptionFailed,
+    TResult? Function(String message)? storageLocked,
+    TResult? Function()? keyPackageNotFound,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(String message)? signer,
+    TResult? Function(String message)? transfer,
+    TResult? Function(String message)? storage,
+    TResult? Function(String message)? mls,
+    TResult? Function(String message)? internal,
+  }) {
+    return storage?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? notInitialized,
+    TResult Function(String field, String message)? invalidInput,
+    TResult Function()? groupNotFound,
+    TResult Function(String message)? staleEpoch,
+    TResult Function()? ownMessage,
+    TResult Function(String message)? decryptionFailed,
+    TResult Function(String message)? storageLocked,
+    TResult Function()? keyPackageNotFound,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(String message)? signer,
+    TResult Function(String message)? transfer,
+    TResult Function(String message)? storage,
+    TResult Function(String message)? mls,
+    TResult Function(String message)? internal,
+    required TResult orElse(),
+  }) {
+    if (storage != null) {
+      return storage(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MlsBridgeError_NotInitialized value)
+        notInitialized,
+    required TResult Function(MlsBridgeError_InvalidInput value) invalidInput,
+    required TResult Function(MlsBridgeError_GroupNotFound value) groupNotFound,
+    required TResult Function(MlsBridgeError_StaleEpoch value) staleEpoch,
+    required TResult Function(MlsBridgeError_OwnMessage value) ownMessage,
+    required TResult Function(MlsBridgeError_DecryptionFailed value)
+        decryptionFailed,
+    required TResult Function(MlsBridgeError_StorageLocked value) storageLocked,
+    required TResult Function(MlsBridgeError_KeyPackageNotFound value)
+        keyPackageNotFound,
+    required TResult Function(MlsBridgeError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(MlsBridgeError_Signer value) signer,
+    required TResult Function(MlsBridgeError_Transfer value) transfer,
+    required TResult Function(MlsBridgeError_Storage value) storage,
+    required TResult Function(MlsBridgeError_Mls value) mls,
+    required TResult Function(MlsBridgeError_Internal value) internal,
+  }) {
+    return storage(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult? Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult? Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult? Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult? Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult? Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult? Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult? Function(MlsBridgeError_Signer value)? signer,
+    TResult? Function(MlsBridgeError_Transfer value)? transfer,
+    TResult? Function(MlsBridgeError_Storage value)? storage,
+    TResult? Function(MlsBridgeError_Mls value)? mls,
+    TResult? Function(MlsBridgeError_Internal value)? internal,
+  }) {
+    return storage?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult Function(MlsBridgeError_Signer value)? signer,
+    TResult Function(MlsBridgeError_Transfer value)? transfer,
+    TResult Function(MlsBridgeError_Storage value)? storage,
+    TResult Function(MlsBridgeError_Mls value)? mls,
+    TResult Function(MlsBridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (storage != null) {
+      return storage(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MlsBridgeError_Storage extends MlsBridgeError {
+  const factory MlsBridgeError_Storage({required final String message}) =
+      _$MlsBridgeError_StorageImpl;
+  const MlsBridgeError_Storage._() : super._();
+
+  String get message;
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MlsBridgeError_StorageImplCopyWith<_$MlsBridgeError_StorageImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MlsBridgeError_MlsImplCopyWith<$Res> {
+  factory _$$MlsBridgeError_MlsImplCopyWith(
+          _$MlsBridgeError_MlsImpl value,
+          $Res Function(_$MlsBridgeError_MlsImpl) then) =
+      __$$MlsBridgeError_MlsImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$MlsBridgeError_MlsImplCopyWithImpl<$Res>
+    extends _$MlsBridgeErrorCopyWithImpl<$Res, _$MlsBridgeError_MlsImpl>
+    implements _$$MlsBridgeError_MlsImplCopyWith<$Res> {
+  __$$MlsBridgeError_MlsImplCopyWithImpl(
+      _$MlsBridgeError_MlsImpl _value,
+      $Res Function(_$MlsBridgeError_MlsImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$MlsBridgeError_MlsImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$MlsBridgeError_MlsImpl extends MlsBridgeError_Mls {
+  const _$MlsBridgeError_MlsImpl({required this.message}) : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'MlsBridgeError.mls(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MlsBridgeError_MlsImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MlsBridgeError_MlsImplCopyWith<_$MlsBridgeError_MlsImpl> get copyWith =>
+      __$$MlsBridgeError_MlsImplCopyWithImpl<
+          _$MlsBridgeError_MlsImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() notInitialized,
+    required TResult Function(String field, String message) invalidInput,
+    required TResult Function() groupNotFound,
+    required TResult Function(String message) staleEpoch,
+    required TResult Function() ownMessage,
+    required TResult Function(String message) decryptionFailed,
+    required TResult Function(String message) storageLocked,
+    required TResult Function() keyPackageNotFound,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(String message) signer,
+    required TResult Function(String message) transfer,
+    required TResult Function(String message) storage,
+    required TResult Function(String message) mls,
+    required TResult Function(String message) internal,
+  }) {
+    return mls(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? notInitialized,
+    TResult? Function(String field, String message)? invalidInput,
+    TResult? Function()? groupNotFound,
+    TResult? Function(String message)? staleEpoch,
+    TResult? Function()? ownMessage,
+    TResult? Function(String message)? decryptionFailed,
+    TResult? Function(String message)? storageLocked,
+    TResult? Function()? keyPackageNotFound,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(String message)? signer,
+    TResult? Function(String message)? transfer,
+    TResult? Function(String message)? storage,
+    TResult? Function(String message)? mls,
+    TResult? Function(String message)? internal,
+  }) {
+    return mls?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? notInitialized,
+    TResult Function(String field, String message)? invalidInput,
+    TResult Function()? groupNotFound,
+    TResult Function(String message)? staleEpoch,
+    TResult Function()? ownMessage,
+    TResult Function(String message)? decryptionFailed,
+    TResult Function(String message)? storageLocked,
+    TResult Function()? keyPackageNotFound,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(String message)? signer,
+    TResult Function(String message)? transfer,
+    TResult Function(String message)? storage,
+    TResult Function(String message)? mls,
+    TResult Function(String message)? internal,
+    required TResult orElse(),
+  }) {
+    if (mls != null) {
+      return mls(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MlsBridgeError_NotInitialized value)
+        notInitialized,
+    required TResult Function(MlsBridgeError_InvalidInput value) invalidInput,
+    required TResult Function(MlsBridgeError_GroupNotFound value) groupNotFound,
+    required TResult Function(MlsBridgeError_StaleEpoch value) staleEpoch,
+    required TResult Function(MlsBridgeError_OwnMessage value) ownMessage,
+    required TResult Function(MlsBridgeError_DecryptionFailed value)
+        decryptionFailed,
+    required TResult Function(MlsBridgeError_StorageLocked value) storageLocked,
+    required TResult Function(MlsBridgeError_KeyPackageNotFound value)
+        keyPackageNotFound,
+    required TResult Function(MlsBridgeError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(MlsBridgeError_Signer value) signer,
+    required TResult Function(MlsBridgeError_Transfer value) transfer,
+    required TResult Function(MlsBridgeError_Storage value) storage,
+    required TResult Function(MlsBridgeError_Mls value) mls,
+    required TResult Function(MlsBridgeError_Internal value) internal,
+  }) {
+    return mls(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult? Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult? Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult? Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult? Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult? Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult? Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult? Function(MlsBridgeError_Signer value)? signer,
+    TResult? Function(MlsBridgeError_Transfer value)? transfer,
+    TResult? Function(MlsBridgeError_Storage value)? storage,
+    TResult? Function(MlsBridgeError_Mls value)? mls,
+    TResult? Function(MlsBridgeError_Internal value)? internal,
+  }) {
+    return mls?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult Function(MlsBridgeError_Signer value)? signer,
+    TResult Function(MlsBridgeError_Transfer value)? transfer,
+    TResult Function(MlsBridgeError_Storage value)? storage,
+    TResult Function(MlsBridgeError_Mls value)? mls,
+    TResult Function(MlsBridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (mls != null) {
+      return mls(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MlsBridgeError_Mls extends MlsBridgeError {
+  const factory MlsBridgeError_Mls({required final String message}) =
+      _$MlsBridgeError_MlsImpl;
+  const MlsBridgeError_Mls._() : super._();
+
+  String get message;
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MlsBridgeError_MlsImplCopyWith<_$MlsBridgeError_MlsImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MlsBridgeError_InternalImplCopyWith<$Res> {
+  factory _$$MlsBridgeError_InternalImplCopyWith(
+          _$MlsBridgeError_InternalImpl value,
+          $Res Function(_$MlsBridgeError_InternalImpl) then) =
+      __$$MlsBridgeError_InternalImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$MlsBridgeError_InternalImplCopyWithImpl<$Res>
+    extends _$MlsBridgeErrorCopyWithImpl<$Res, _$MlsBridgeError_InternalImpl>
+    implements _$$MlsBridgeError_InternalImplCopyWith<$Res> {
+  __$$MlsBridgeError_InternalImplCopyWithImpl(
+      _$MlsBridgeError_InternalImpl _value,
+      $Res Function(_$MlsBridgeError_InternalImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$MlsBridgeError_InternalImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$MlsBridgeError_InternalImpl extends MlsBridgeError_Internal {
+  const _$MlsBridgeError_InternalImpl({required this.message}) : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'MlsBridgeError.internal(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MlsBridgeError_InternalImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MlsBridgeError_InternalImplCopyWith<_$MlsBridgeError_InternalImpl>
+      get copyWith => __$$MlsBridgeError_InternalImplCopyWithImpl<
+          _$MlsBridgeError_InternalImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() notInitialized,
+    required TResult Function(String field, String message) invalidInput,
+    required TResult Function() groupNotFound,
+    required TResult Function(String message) staleEpoch,
+    required TResult Function() ownMessage,
+    required TResult Function(String message) decryptionFailed,
+    required TResult Function(String message) storageLocked,
+    required TResult Function() keyPackageNotFound,
+    required TResult Function(String message) permissionDenied,
+    required TResult Function(String message) signer,
+    required TResult Function(String message) transfer,
+    required TResult Function(String message) storage,
+    required TResult Function(String message) mls,
+    required TResult Function(String message) internal,
+  }) {
+    return internal(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? notInitialized,
+    TResult? Function(String field, String message)? invalidInput,
+    TResult? Function()? groupNotFound,
+    TResult? Function(String message)? staleEpoch,
+    TResult? Function()? ownMessage,
+    TResult? Function(String message)? decryptionFailed,
+    TResult? Function(String message)? storageLocked,
+    TResult? Function()? keyPackageNotFound,
+    TResult? Function(String message)? permissionDenied,
+    TResult? Function(String message)? signer,
+    TResult? Function(String message)? transfer,
+    TResult? Function(String message)? storage,
+    TResult? Function(String message)? mls,
+    TResult? Function(String message)? internal,
+  }) {
+    return internal?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? notInitialized,
+    TResult Function(String field, String message)? invalidInput,
+    TResult Function()? groupNotFound,
+    TResult Function(String message)? staleEpoch,
+    TResult Function()? ownMessage,
+    TResult Function(String message)? decryptionFailed,
+    TResult Function(String message)? storageLocked,
+    TResult Function()? keyPackageNotFound,
+    TResult Function(String message)? permissionDenied,
+    TResult Function(String message)? signer,
+    TResult Function(String message)? transfer,
+    TResult Function(String message)? storage,
+    TResult Function(String message)? mls,
+    TResult Function(String message)? internal,
+    required TResult orElse(),
+  }) {
+    if (internal != null) {
+      return internal(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MlsBridgeError_NotInitialized value)
+        notInitialized,
+    required TResult Function(MlsBridgeError_InvalidInput value) invalidInput,
+    required TResult Function(MlsBridgeError_GroupNotFound value) groupNotFound,
+    required TResult Function(MlsBridgeError_StaleEpoch value) staleEpoch,
+    required TResult Function(MlsBridgeError_OwnMessage value) ownMessage,
+    required TResult Function(MlsBridgeError_DecryptionFailed value)
+        decryptionFailed,
+    required TResult Function(MlsBridgeError_StorageLocked value) storageLocked,
+    required TResult Function(MlsBridgeError_KeyPackageNotFound value)
+        keyPackageNotFound,
+    required TResult Function(MlsBridgeError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(MlsBridgeError_Signer value) signer,
+    required TResult Function(MlsBridgeError_Transfer value) transfer,
+    required TResult Function(MlsBridgeError_Storage value) storage,
+    required TResult Function(MlsBridgeError_Mls value) mls,
+    required TResult Function(MlsBridgeError_Internal value) internal,
+  }) {
+    return internal(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult? Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult? Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult? Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult? Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult? Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult? Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult? Function(MlsBridgeError_Signer value)? signer,
+    TResult? Function(MlsBridgeError_Transfer value)? transfer,
+    TResult? Function(MlsBridgeError_Storage value)? storage,
+    TResult? Function(MlsBridgeError_Mls value)? mls,
+    TResult? Function(MlsBridgeError_Internal value)? internal,
+  }) {
+    return internal?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MlsBridgeError_NotInitialized value)? notInitialized,
+    TResult Function(MlsBridgeError_InvalidInput value)? invalidInput,
+    TResult Function(MlsBridgeError_GroupNotFound value)? groupNotFound,
+    TResult Function(MlsBridgeError_StaleEpoch value)? staleEpoch,
+    TResult Function(MlsBridgeError_OwnMessage value)? ownMessage,
+    TResult Function(MlsBridgeError_DecryptionFailed value)? decryptionFailed,
+    TResult Function(MlsBridgeError_StorageLocked value)? storageLocked,
+    TResult Function(MlsBridgeError_KeyPackageNotFound value)?
+        keyPackageNotFound,
+    TResult Function(MlsBridgeError_PermissionDenied value)? permissionDenied,
+    TResult Function(MlsBridgeError_Signer value)? signer,
+    TResult Function(MlsBridgeError_Transfer value)? transfer,
+    TResult Function(MlsBridgeError_Storage value)? storage,
+    TResult Function(MlsBridgeError_Mls value)? mls,
+    TResult Function(MlsBridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (internal != null) {
+      return internal(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MlsBridgeError_Internal extends MlsBridgeError {
+  const factory MlsBridgeError_Internal({required final String message}) =
+      _$MlsBridgeError_InternalImpl;
+  const MlsBridgeError_Internal._() : super._();
+
+  String get message;
+
+  /// Create a copy of MlsBridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MlsBridgeError_InternalImplCopyWith<_$MlsBridgeError_InternalImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'types.dart';
+part 'events.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `capture`, `changes`, `emit_changes`, `take`
+// These types are ignored because they are not used by any `pub` functions: `GroupSnapshot`
+
+/// Subscribe to the group events of an identity
+/// The stream stays open until Dart cancels it or the identity is closed
+Stream<GroupEvent> subscribeGroupEvents({required String identity}) =>
+    RustLib.instance.api.crateApiEventsSubscribeGroupEvents(identity: identity);
+
+/// State change of an identity's groups, pushed to `subscribe_group_events` sinks
+@freezed
+sealed class GroupEvent with _$GroupEvent {
+  const GroupEvent._();
+
+  const factory GroupEvent.messageDecrypted({
+    required DecryptedMessage message,
+  }) = GroupEvent_MessageDecrypted;
+  const factory GroupEvent.membersAdded({
+    required Uint8List mlsGroupId,
+    required List<String> members,
+  }) = GroupEvent_MembersAdded;
+  const factory GroupEvent.membersRemoved({
+    required Uint8List mlsGroupId,
+    required List<String> members,
+  }) = GroupEvent_MembersRemoved;
+  const factory GroupEvent.epochAdvanced({
+    required Uint8List mlsGroupId,
+    required BigInt epoch,
+  }) = GroupEvent_EpochAdvanced;
+  const factory GroupEvent.groupMetadataChanged({
+    required GroupInfo group,
+  }) = GroupEvent_GroupMetadataChanged;
+  const factory GroupEvent.welcomeReceived({
+    /// Hex encoded id of the gift wrap carrying the welcome
+    required String wrapperEventId,
+    required GroupInfo group,
+  }) = GroupEvent_WelcomeReceived;
+
+  /// A welcome we joined used a key package of the key package manager
+  const factory GroupEvent.keyPackageConsumed({
+    /// Hex encoded id of the gift wrap carrying the welcome that used the key package
+    required String wrapperEventId,
+
+    /// Id of the `ManagedKeyPackage` used
+    required String keyPackageId,
+  }) = GroupEvent_KeyPackageConsumed;
+  const factory GroupEvent.pendingCommitRolledBack({
+    required Uint8List mlsGroupId,
+
+    /// Hex encoded id of our commit event, which must not be published anymore
+    String? eventId,
+  }) = GroupEvent_PendingCommitRolledBack;
+
+  /// Everything stored about a group we are no longer in was deleted
+  const factory GroupEvent.groupPurged({
+    required Uint8List mlsGroupId,
+  }) = GroupEvent_GroupPurged;
+}
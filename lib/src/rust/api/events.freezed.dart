@@ -0,0 +1,2124 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'events.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$GroupEvent {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(DecryptedMessage message) messageDecrypted,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersAdded,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersRemoved,
+    required TResult Function(Uint8List mlsGroupId, BigInt epoch) epochAdvanced,
+    required TResult Function(GroupInfo group) groupMetadataChanged,
+    required TResult Function(String wrapperEventId, GroupInfo group)
+        welcomeReceived,
+    required TResult Function(String wrapperEventId, String keyPackageId)
+        keyPackageConsumed,
+    required TResult Function(Uint8List mlsGroupId, String? eventId)
+        pendingCommitRolledBack,
+    required TResult Function(Uint8List mlsGroupId) groupPurged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(DecryptedMessage message)? messageDecrypted,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult? Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult? Function(GroupInfo group)? groupMetadataChanged,
+    TResult? Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult? Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult? Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult? Function(Uint8List mlsGroupId)? groupPurged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(DecryptedMessage message)? messageDecrypted,
+    TResult Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult Function(GroupInfo group)? groupMetadataChanged,
+    TResult Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult Function(Uint8List mlsGroupId)? groupPurged,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupEvent_MessageDecrypted value)
+        messageDecrypted,
+    required TResult Function(GroupEvent_MembersAdded value) membersAdded,
+    required TResult Function(GroupEvent_MembersRemoved value) membersRemoved,
+    required TResult Function(GroupEvent_EpochAdvanced value) epochAdvanced,
+    required TResult Function(GroupEvent_GroupMetadataChanged value)
+        groupMetadataChanged,
+    required TResult Function(GroupEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(GroupEvent_KeyPackageConsumed value)
+        keyPackageConsumed,
+    required TResult Function(GroupEvent_PendingCommitRolledBack value)
+        pendingCommitRolledBack,
+    required TResult Function(GroupEvent_GroupPurged value) groupPurged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult? Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult? Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult? Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult? Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult? Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult? Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult? Function(GroupEvent_GroupPurged value)? groupPurged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult Function(GroupEvent_GroupPurged value)? groupPurged,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $GroupEventCopyWith<$Res> {
+  factory $GroupEventCopyWith(
+          GroupEvent value, $Res Function(GroupEvent) then) =
+      _$GroupEventCopyWithImpl<$Res, GroupEvent>;
+}
+
+/// @nodoc
+class _$GroupEventCopyWithImpl<$Res, $Val extends GroupEvent>
+    implements $GroupEventCopyWith<$Res> {
+  _$GroupEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$GroupEvent_MessageDecryptedImplCopyWith<$Res> {
+  factory _$$GroupEvent_MessageDecryptedImplCopyWith(
+          _$GroupEvent_MessageDecryptedImpl value,
+          $Res Function(_$GroupEvent_MessageDecryptedImpl) then) =
+      __$$GroupEvent_MessageDecryptedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({DecryptedMessage message});
+}
+
+/// @nodoc
+class __$$GroupEvent_MessageDecryptedImplCopyWithImpl<$Res>
+    extends _$GroupEventCopyWithImpl<$Res, _$GroupEvent_MessageDecryptedImpl>
+    implements _$$GroupEvent_MessageDecryptedImplCopyWith<$Res> {
+  __$$GroupEvent_MessageDecryptedImplCopyWithImpl(
+      _$GroupEvent_MessageDecryptedImpl _value,
+      $Res Function(_$GroupEvent_MessageDecryptedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$GroupEvent_MessageDecryptedImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as DecryptedMessage,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$GroupEvent_MessageDecryptedImpl extends GroupEvent_MessageDecrypted {
+  const _$GroupEvent_MessageDecryptedImpl({required this.message}) : super._();
+
+  @override
+  final DecryptedMessage message;
+
+  @override
+  String toString() {
+    return 'GroupEvent.messageDecrypted(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupEvent_MessageDecryptedImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupEvent_MessageDecryptedImplCopyWith<_$GroupEvent_MessageDecryptedImpl>
+      get copyWith => __$$GroupEvent_MessageDecryptedImplCopyWithImpl<
+          _$GroupEvent_MessageDecryptedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(DecryptedMessage message) messageDecrypted,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersAdded,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersRemoved,
+    required TResult Function(Uint8List mlsGroupId, BigInt epoch) epochAdvanced,
+    required TResult Function(GroupInfo group) groupMetadataChanged,
+    required TResult Function(String wrapperEventId, GroupInfo group)
+        welcomeReceived,
+    required TResult Function(String wrapperEventId, String keyPackageId)
+        keyPackageConsumed,
+    required TResult Function(Uint8List mlsGroupId, String? eventId)
+        pendingCommitRolledBack,
+    required TResult Function(Uint8List mlsGroupId) groupPurged,
+  }) {
+    return messageDecrypted(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(DecryptedMessage message)? messageDecrypted,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult? Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult? Function(GroupInfo group)? groupMetadataChanged,
+    TResult? Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult? Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult? Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult? Function(Uint8List mlsGroupId)? groupPurged,
+  }) {
+    return messageDecrypted?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(DecryptedMessage message)? messageDecrypted,
+    TResult Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult Function(GroupInfo group)? groupMetadataChanged,
+    TResult Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult Function(Uint8List mlsGroupId)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (messageDecrypted != null) {
+      return messageDecrypted(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupEvent_MessageDecrypted value)
+        messageDecrypted,
+    required TResult Function(GroupEvent_MembersAdded value) membersAdded,
+    required TResult Function(GroupEvent_MembersRemoved value) membersRemoved,
+    required TResult Function(GroupEvent_EpochAdvanced value) epochAdvanced,
+    required TResult Function(GroupEvent_GroupMetadataChanged value)
+        groupMetadataChanged,
+    required TResult Function(GroupEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(GroupEvent_KeyPackageConsumed value)
+        keyPackageConsumed,
+    required TResult Function(GroupEvent_PendingCommitRolledBack value)
+        pendingCommitRolledBack,
+    required TResult Function(GroupEvent_GroupPurged value) groupPurged,
+  }) {
+    return messageDecrypted(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult? Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult? Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult? Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult? Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult? Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult? Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult? Function(GroupEvent_GroupPurged value)? groupPurged,
+  }) {
+    return messageDecrypted?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult Function(GroupEvent_GroupPurged value)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (messageDecrypted != null) {
+      return messageDecrypted(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupEvent_MessageDecrypted extends GroupEvent {
+  const factory GroupEvent_MessageDecrypted(
+          {required final DecryptedMessage message}) =
+      _$GroupEvent_MessageDecryptedImpl;
+  const GroupEvent_MessageDecrypted._() : super._();
+
+  DecryptedMessage get message;
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupEvent_MessageDecryptedImplCopyWith<_$GroupEvent_MessageDecryptedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GroupEvent_MembersAddedImplCopyWith<$Res> {
+  factory _$$GroupEvent_MembersAddedImplCopyWith(
+          _$GroupEvent_MembersAddedImpl value,
+          $Res Function(_$GroupEvent_MembersAddedImpl) then) =
+      __$$GroupEvent_MembersAddedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Uint8List mlsGroupId, List<String> members});
+}
+
+/// @nodoc
+class __$$GroupEvent_MembersAddedImplCopyWithImpl<$Res>
+    extends _$GroupEventCopyWithImpl<$Res, _$GroupEvent_MembersAddedImpl>
+    implements _$$GroupEvent_MembersAddedImplCopyWith<$Res> {
+  __$$GroupEvent_MembersAddedImplCopyWithImpl(
+      _$GroupEvent_MembersAddedImpl _value,
+      $Res Function(_$GroupEvent_MembersAddedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? mlsGroupId = null,
+    Object? members = null,
+  }) {
+    return _then(_$GroupEvent_MembersAddedImpl(
+      mlsGroupId: null == mlsGroupId
+          ? _value.mlsGroupId
+          : mlsGroupId // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+      members: null == members
+          ? _value._members
+          : members // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$GroupEvent_MembersAddedImpl extends GroupEvent_MembersAdded {
+  const _$GroupEvent_MembersAddedImpl(
+      {required this.mlsGroupId, required final List<String> members})
+      : _members = members,
+        super._();
+
+  @override
+  final Uint8List mlsGroupId;
+  final List<String> _members;
+  @override
+  List<String> get members {
+    if (_members is EqualUnmodifiableListView) return _members;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_members);
+  }
+
+  @override
+  String toString() {
+    return 'GroupEvent.membersAdded(mlsGroupId: $mlsGroupId, members: $members)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupEvent_MembersAddedImpl &&
+            const DeepCollectionEquality()
+                .equals(other.mlsGroupId, mlsGroupId) &&
+            const DeepCollectionEquality().equals(other._members, _members));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType,
+      const DeepCollectionEquality().hash(mlsGroupId),
+      const DeepCollectionEquality().hash(_members));
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupEvent_MembersAddedImplCopyWith<_$GroupEvent_MembersAddedImpl>
+      get copyWith => __$$GroupEvent_MembersAddedImplCopyWithImpl<
+          _$GroupEvent_MembersAddedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(DecryptedMessage message) messageDecrypted,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersAdded,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersRemoved,
+    required TResult Function(Uint8List mlsGroupId, BigInt epoch) epochAdvanced,
+    required TResult Function(GroupInfo group) groupMetadataChanged,
+    required TResult Function(String wrapperEventId, GroupInfo group)
+        welcomeReceived,
+    required TResult Function(String wrapperEventId, String keyPackageId)
+        keyPackageConsumed,
+    required TResult Function(Uint8List mlsGroupId, String? eventId)
+        pendingCommitRolledBack,
+    required TResult Function(Uint8List mlsGroupId) groupPurged,
+  }) {
+    return membersAdded(mlsGroupId, members);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(DecryptedMessage message)? messageDecrypted,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult? Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult? Function(GroupInfo group)? groupMetadataChanged,
+    TResult? Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult? Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult? Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult? Function(Uint8List mlsGroupId)? groupPurged,
+  }) {
+    return membersAdded?.call(mlsGroupId, members);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(DecryptedMessage message)? messageDecrypted,
+    TResult Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult Function(GroupInfo group)? groupMetadataChanged,
+    TResult Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult Function(Uint8List mlsGroupId)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (membersAdded != null) {
+      return membersAdded(mlsGroupId, members);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupEvent_MessageDecrypted value)
+        messageDecrypted,
+    required TResult Function(GroupEvent_MembersAdded value) membersAdded,
+    required TResult Function(GroupEvent_MembersRemoved value) membersRemoved,
+    required TResult Function(GroupEvent_EpochAdvanced value) epochAdvanced,
+    required TResult Function(GroupEvent_GroupMetadataChanged value)
+        groupMetadataChanged,
+    required TResult Function(GroupEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(GroupEvent_KeyPackageConsumed value)
+        keyPackageConsumed,
+    required TResult Function(GroupEvent_PendingCommitRolledBack value)
+        pendingCommitRolledBack,
+    required TResult Function(GroupEvent_GroupPurged value) groupPurged,
+  }) {
+    return membersAdded(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult? Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult? Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult? Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult? Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult? Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult? Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult? Function(GroupEvent_GroupPurged value)? groupPurged,
+  }) {
+    return membersAdded?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult Function(GroupEvent_GroupPurged value)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (membersAdded != null) {
+      return membersAdded(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupEvent_MembersAdded extends GroupEvent {
+  const factory GroupEvent_MembersAdded(
+          {required final Uint8List mlsGroupId,
+          required final List<String> members}) =
+      _$GroupEvent_MembersAddedImpl;
+  const GroupEvent_MembersAdded._() : super._();
+
+  Uint8List get mlsGroupId;
+  List<String> get members;
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupEvent_MembersAddedImplCopyWith<_$GroupEvent_MembersAddedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GroupEvent_MembersRemovedImplCopyWith<$Res> {
+  factory _$$GroupEvent_MembersRemovedImplCopyWith(
+          _$GroupEvent_MembersRemovedImpl value,
+          $Res Function(_$GroupEvent_MembersRemovedImpl) then) =
+      __$$GroupEvent_MembersRemovedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Uint8List mlsGroupId, List<String> members});
+}
+
+/// @nodoc
+class __$$GroupEvent_MembersRemovedImplCopyWithImpl<$Res>
+    extends _$GroupEventCopyWithImpl<$Res, _$GroupEvent_MembersRemovedImpl>
+    implements _$$GroupEvent_MembersRemovedImplCopyWith<$Res> {
+  __$$GroupEvent_MembersRemovedImplCopyWithImpl(
+      _$GroupEvent_MembersRemovedImpl _value,
+      $Res Function(_$GroupEvent_MembersRemovedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? mlsGroupId = null,
+    Object? members = null,
+  }) {
+    return _then(_$GroupEvent_MembersRemovedImpl(
+      mlsGroupId: null == mlsGroupId
+          ? _value.mlsGroupId
+          : mlsGroupId // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+      members: null == members
+          ? _value._members
+          : members // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$GroupEvent_MembersRemovedImpl extends GroupEvent_MembersRemoved {
+  const _$GroupEvent_MembersRemovedImpl(
+      {required this.mlsGroupId, required final List<String> members})
+      : _members = members,
+        super._();
+
+  @override
+  final Uint8List mlsGroupId;
+  final List<String> _members;
+  @override
+  List<String> get members {
+    if (_members is EqualUnmodifiableListView) return _members;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_members);
+  }
+
+  @override
+  String toString() {
+    return 'GroupEvent.membersRemoved(mlsGroupId: $mlsGroupId, members: $members)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupEvent_MembersRemovedImpl &&
+            const DeepCollectionEquality()
+                .equals(other.mlsGroupId, mlsGroupId) &&
+            const DeepCollectionEquality().equals(other._members, _members));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType,
+      const DeepCollectionEquality().hash(mlsGroupId),
+      const DeepCollectionEquality().hash(_members));
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupEvent_MembersRemovedImplCopyWith<_$GroupEvent_MembersRemovedImpl>
+      get copyWith => __$$GroupEvent_MembersRemovedImplCopyWithImpl<
+          _$GroupEvent_MembersRemovedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(DecryptedMessage message) messageDecrypted,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersAdded,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersRemoved,
+    required TResult Function(Uint8List mlsGroupId, BigInt epoch) epochAdvanced,
+    required TResult Function(GroupInfo group) groupMetadataChanged,
+    required TResult Function(String wrapperEventId, GroupInfo group)
+        welcomeReceived,
+    required TResult Function(String wrapperEventId, String keyPackageId)
+        keyPackageConsumed,
+    required TResult Function(Uint8List mlsGroupId, String? eventId)
+        pendingCommitRolledBack,
+    required TResult Function(Uint8List mlsGroupId) groupPurged,
+  }) {
+    return membersRemoved(mlsGroupId, members);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(DecryptedMessage message)? messageDecrypted,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult? Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult? Function(GroupInfo group)? groupMetadataChanged,
+    TResult? Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult? Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult? Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult? Function(Uint8List mlsGroupId)? groupPurged,
+  }) {
+    return membersRemoved?.call(mlsGroupId, members);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(DecryptedMessage message)? messageDecrypted,
+    TResult Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult Function(GroupInfo group)? groupMetadataChanged,
+    TResult Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult Function(Uint8List mlsGroupId)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (membersRemoved != null) {
+      return membersRemoved(mlsGroupId, members);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupEvent_MessageDecrypted value)
+        messageDecrypted,
+    required TResult Function(GroupEvent_MembersAdded value) membersAdded,
+    required TResult Function(GroupEvent_MembersRemoved value) membersRemoved,
+    required TResult Function(GroupEvent_EpochAdvanced value) epochAdvanced,
+    required TResult Function(GroupEvent_GroupMetadataChanged value)
+        groupMetadataChanged,
+    required TResult Function(GroupEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(GroupEvent_KeyPackageConsumed value)
+        keyPackageConsumed,
+    required TResult Function(GroupEvent_PendingCommitRolledBack value)
+        pendingCommitRolledBack,
+    required TResult Function(GroupEvent_GroupPurged value) groupPurged,
+  }) {
+    return membersRemoved(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult? Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult? Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult? Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult? Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult? Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult? Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult? Function(GroupEvent_GroupPurged value)? groupPurged,
+  }) {
+    return membersRemoved?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult Function(GroupEvent_GroupPurged value)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (membersRemoved != null) {
+      return membersRemoved(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupEvent_MembersRemoved extends GroupEvent {
+  const factory GroupEvent_MembersRemoved(
+          {required final Uint8List mlsGroupId,
+          required final List<String> members}) =
+      _$GroupEvent_MembersRemovedImpl;
+  const GroupEvent_MembersRemoved._() : super._();
+
+  Uint8List get mlsGroupId;
+  List<String> get members;
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupEvent_MembersRemovedImplCopyWith<_$GroupEvent_MembersRemovedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GroupEvent_EpochAdvancedImplCopyWith<$Res> {
+  factory _$$GroupEvent_EpochAdvancedImplCopyWith(
+          _$GroupEvent_EpochAdvancedImpl value,
+          $Res Function(_$GroupEvent_EpochAdvancedImpl) then) =
+      __$$GroupEvent_EpochAdvancedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Uint8List mlsGroupId, BigInt epoch});
+}
+
+/// @nodoc
+class __$$GroupEvent_EpochAdvancedImplCopyWithImpl<$Res>
+    extends _$GroupEventCopyWithImpl<$Res, _$GroupEvent_EpochAdvancedImpl>
+    implements _$$GroupEvent_EpochAdvancedImplCopyWith<$Res> {
+  __$$GroupEvent_EpochAdvancedImplCopyWithImpl(
+      _$GroupEvent_EpochAdvancedImpl _value,
+      $Res Function(_$GroupEvent_EpochAdvancedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? mlsGroupId = null,
+    Object? epoch = null,
+  }) {
+    return _then(_$GroupEvent_EpochAdvancedImpl(
+      mlsGroupId: null == mlsGroupId
+          ? _value.mlsGroupId
+          : mlsGroupId // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+      epoch: null == epoch
+          ? _value.epoch
+          : epoch // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$GroupEvent_EpochAdvancedImpl extends GroupEvent_EpochAdvanced {
+  const _$GroupEvent_EpochAdvancedImpl(
+      {required this.mlsGroupId, required this.epoch})
+      : super._();
+
+  @override
+  final Uint8List mlsGroupId;
+  @override
+  final BigInt epoch;
+
+  @override
+  String toString() {
+    return 'GroupEvent.epochAdvanced(mlsGroupId: $mlsGroupId, epoch: $epoch)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupEvent_EpochAdvancedImpl &&
+            const DeepCollectionEquality()
+                .equals(other.mlsGroupId, mlsGroupId) &&
+            (identical(other.epoch, epoch) || other.epoch == epoch));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType, const DeepCollectionEquality().hash(mlsGroupId), epoch);
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupEvent_EpochAdvancedImplCopyWith<_$GroupEvent_EpochAdvancedImpl>
+      get copyWith => __$$GroupEvent_EpochAdvancedImplCopyWithImpl<
+          _$GroupEvent_EpochAdvancedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(DecryptedMessage message) messageDecrypted,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersAdded,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersRemoved,
+    required TResult Function(Uint8List mlsGroupId, BigInt epoch) epochAdvanced,
+    required TResult Function(GroupInfo group) groupMetadataChanged,
+    required TResult Function(String wrapperEventId, GroupInfo group)
+        welcomeReceived,
+    required TResult Function(String wrapperEventId, String keyPackageId)
+        keyPackageConsumed,
+    required TResult Function(Uint8List mlsGroupId, String? eventId)
+        pendingCommitRolledBack,
+    required TResult Function(Uint8List mlsGroupId) groupPurged,
+  }) {
+    return epochAdvanced(mlsGroupId, epoch);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(DecryptedMessage message)? messageDecrypted,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult? Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult? Function(GroupInfo group)? groupMetadataChanged,
+    TResult? Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult? Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult? Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult? Function(Uint8List mlsGroupId)? groupPurged,
+  }) {
+    return epochAdvanced?.call(mlsGroupId, epoch);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(DecryptedMessage message)? messageDecrypted,
+    TResult Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult Function(GroupInfo group)? groupMetadataChanged,
+    TResult Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult Function(Uint8List mlsGroupId)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (epochAdvanced != null) {
+      return epochAdvanced(mlsGroupId, epoch);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupEvent_MessageDecrypted value)
+        messageDecrypted,
+    required TResult Function(GroupEvent_MembersAdded value) membersAdded,
+    required TResult Function(GroupEvent_MembersRemoved value) membersRemoved,
+    required TResult Function(GroupEvent_EpochAdvanced value) epochAdvanced,
+    required TResult Function(GroupEvent_GroupMetadataChanged value)
+        groupMetadataChanged,
+    required TResult Function(GroupEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(GroupEvent_KeyPackageConsumed value)
+        keyPackageConsumed,
+    required TResult Function(GroupEvent_PendingCommitRolledBack value)
+        pendingCommitRolledBack,
+    required TResult Function(GroupEvent_GroupPurged value) groupPurged,
+  }) {
+    return epochAdvanced(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult? Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult? Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult? Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult? Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult? Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult? Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult? Function(GroupEvent_GroupPurged value)? groupPurged,
+  }) {
+    return epochAdvanced?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult Function(GroupEvent_GroupPurged value)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (epochAdvanced != null) {
+      return epochAdvanced(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupEvent_EpochAdvanced extends GroupEvent {
+  const factory GroupEvent_EpochAdvanced(
+          {required final Uint8List mlsGroupId, required final BigInt epoch}) =
+      _$GroupEvent_EpochAdvancedImpl;
+  const GroupEvent_EpochAdvanced._() : super._();
+
+  Uint8List get mlsGroupId;
+  BigInt get epoch;
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupEvent_EpochAdvancedImplCopyWith<_$GroupEvent_EpochAdvancedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GroupEvent_GroupMetadataChangedImplCopyWith<$Res> {
+  factory _$$GroupEvent_GroupMetadataChangedImplCopyWith(
+          _$GroupEvent_GroupMetadataChangedImpl value,
+          $Res Function(_$GroupEvent_GroupMetadataChangedImpl) then) =
+      __$$GroupEvent_GroupMetadataChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({GroupInfo group});
+}
+
+/// @nodoc
+class __$$GroupEvent_GroupMetadataChangedImplCopyWithImpl<$Res>
+    extends _$GroupEventCopyWithImpl<$Res,
+        _$GroupEvent_GroupMetadataChangedImpl>
+    implements _$$GroupEvent_GroupMetadataChangedImplCopyWith<$Res> {
+  __$$GroupEvent_GroupMetadataChangedImplCopyWithImpl(
+      _$GroupEvent_GroupMetadataChangedImpl _value,
+      $Res Function(_$GroupEvent_GroupMetadataChangedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? group = null,
+  }) {
+    return _then(_$GroupEvent_GroupMetadataChangedImpl(
+      group: null == group
+          ? _value.group
+          : group // ignore: cast_nullable_to_non_nullable
+              as GroupInfo,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$GroupEvent_GroupMetadataChangedImpl
+    extends GroupEvent_GroupMetadataChanged {
+  const _$GroupEvent_GroupMetadataChangedImpl({required this.group})
+      : super._();
+
+  @override
+  final GroupInfo group;
+
+  @override
+  String toString() {
+    return 'GroupEvent.groupMetadataChanged(group: $group)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupEvent_GroupMetadataChangedImpl &&
+            (identical(other.group, group) || other.group == group));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, group);
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupEvent_GroupMetadataChangedImplCopyWith<
+          _$GroupEvent_GroupMetadataChangedImpl>
+      get copyWith => __$$GroupEvent_GroupMetadataChangedImplCopyWithImpl<
+          _$GroupEvent_GroupMetadataChangedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(DecryptedMessage message) messageDecrypted,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersAdded,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersRemoved,
+    required TResult Function(Uint8List mlsGroupId, BigInt epoch) epochAdvanced,
+    required TResult Function(GroupInfo group) groupMetadataChanged,
+    required TResult Function(String wrapperEventId, GroupInfo group)
+        welcomeReceived,
+    required TResult Function(String wrapperEventId, String keyPackageId)
+        keyPackageConsumed,
+    required TResult Function(Uint8List mlsGroupId, String? eventId)
+        pendingCommitRolledBack,
+    required TResult Function(Uint8List mlsGroupId) groupPurged,
+  }) {
+    return groupMetadataChanged(group);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(DecryptedMessage message)? messageDecrypted,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult? Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult? Function(GroupInfo group)? groupMetadataChanged,
+    TResult? Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult? Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult? Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult? Function(Uint8List mlsGroupId)? groupPurged,
+  }) {
+    return groupMetadataChanged?.call(group);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(DecryptedMessage message)? messageDecrypted,
+    TResult Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult Function(GroupInfo group)? groupMetadataChanged,
+    TResult Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult Function(Uint8List mlsGroupId)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (groupMetadataChanged != null) {
+      return groupMetadataChanged(group);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupEvent_MessageDecrypted value)
+        messageDecrypted,
+    required TResult Function(GroupEvent_MembersAdded value) membersAdded,
+    required TResult Function(GroupEvent_MembersRemoved value) membersRemoved,
+    required TResult Function(GroupEvent_EpochAdvanced value) epochAdvanced,
+    required TResult Function(GroupEvent_GroupMetadataChanged value)
+        groupMetadataChanged,
+    required TResult Function(GroupEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(GroupEvent_KeyPackageConsumed value)
+        keyPackageConsumed,
+    required TResult Function(GroupEvent_PendingCommitRolledBack value)
+        pendingCommitRolledBack,
+    required TResult Function(GroupEvent_GroupPurged value) groupPurged,
+  }) {
+    return groupMetadataChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult? Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult? Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult? Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult? Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult? Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult? Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult? Function(GroupEvent_GroupPurged value)? groupPurged,
+  }) {
+    return groupMetadataChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult Function(GroupEvent_GroupPurged value)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (groupMetadataChanged != null) {
+      return groupMetadataChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupEvent_GroupMetadataChanged extends GroupEvent {
+  const factory GroupEvent_GroupMetadataChanged(
+          {required final GroupInfo group}) =
+      _$GroupEvent_GroupMetadataChangedImpl;
+  const GroupEvent_GroupMetadataChanged._() : super._();
+
+  GroupInfo get group;
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupEvent_GroupMetadataChangedImplCopyWith<
+          _$GroupEvent_GroupMetadataChangedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GroupEvent_WelcomeReceivedImplCopyWith<$Res> {
+  factory _$$GroupEvent_WelcomeReceivedImplCopyWith(
+          _$GroupEvent_WelcomeReceivedImpl value,
+          $Res Function(_$GroupEvent_WelcomeReceivedImpl) then) =
+      __$$GroupEvent_WelcomeReceivedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String wrapperEventId, GroupInfo group});
+}
+
+/// @nodoc
+class __$$GroupEvent_WelcomeReceivedImplCopyWithImpl<$Res>
+    extends _$GroupEventCopyWithImpl<$Res, _$GroupEvent_WelcomeReceivedImpl>
+    implements _$$GroupEvent_WelcomeReceivedImplCopyWith<$Res> {
+  __$$GroupEvent_WelcomeReceivedImplCopyWithImpl(
+      _$GroupEvent_WelcomeReceivedImpl _value,
+      $Res Function(_$GroupEvent_WelcomeReceivedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? wrapperEventId = null,
+    Object? group = null,
+  }) {
+    return _then(_$GroupEvent_WelcomeReceivedImpl(
+      wrapperEventId: null == wrapperEventId
+          ? _value.wrapperEventId
+          : wrapperEventId // ignore: cast_nullable_to_non_nullable
+              as String,
+      group: null == group
+          ? _value.group
+          : group // ignore: cast_nullable_to_non_nullable
+              as GroupInfo,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$GroupEvent_WelcomeReceivedImpl extends GroupEvent_WelcomeReceived {
+  const _$GroupEvent_WelcomeReceivedImpl(
+      {required this.wrapperEventId, required this.group})
+      : super._();
+
+  /// Hex encoded id of the gift wrap carrying the welcome
+  @override
+  final String wrapperEventId;
+  @override
+  final GroupInfo group;
+
+  @override
+  String toString() {
+    return 'GroupEvent.welcomeReceived(wrapperEventId: $wrapperEventId, group: $group)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupEvent_WelcomeReceivedImpl &&
+            (identical(other.wrapperEventId, wrapperEventId) ||
+                other.wrapperEventId == wrapperEventId) &&
+            (identical(other.group, group) || other.group == group));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, wrapperEventId, group);
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupEvent_WelcomeReceivedImplCopyWith<_$GroupEvent_WelcomeReceivedImpl>
+      get copyWith => __$$GroupEvent_WelcomeReceivedImplCopyWithImpl<
+          _$GroupEvent_WelcomeReceivedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(DecryptedMessage message) messageDecrypted,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersAdded,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersRemoved,
+    required TResult Function(Uint8List mlsGroupId, BigInt epoch) epochAdvanced,
+    required TResult Function(GroupInfo group) groupMetadataChanged,
+    required TResult Function(String wrapperEventId, GroupInfo group)
+        welcomeReceived,
+    required TResult Function(String wrapperEventId, String keyPackageId)
+        keyPackageConsumed,
+    required TResult Function(Uint8List mlsGroupId, String? eventId)
+        pendingCommitRolledBack,
+    required TResult Function(Uint8List mlsGroupId) groupPurged,
+  }) {
+    return welcomeReceived(wrapperEventId, group);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(DecryptedMessage message)? messageDecrypted,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult? Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult? Function(GroupInfo group)? groupMetadataChanged,
+    TResult? Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult? Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult? Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult? Function(Uint8List mlsGroupId)? groupPurged,
+  }) {
+    return welcomeReceived?.call(wrapperEventId, group);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(DecryptedMessage message)? messageDecrypted,
+    TResult Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult Function(GroupInfo group)? groupMetadataChanged,
+    TResult Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult Function(Uint8List mlsGroupId)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (welcomeReceived != null) {
+      return welcomeReceived(wrapperEventId, group);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupEvent_MessageDecrypted value)
+        messageDecrypted,
+    required TResult Function(GroupEvent_MembersAdded value) membersAdded,
+    required TResult Function(GroupEvent_MembersRemoved value) membersRemoved,
+    required TResult Function(GroupEvent_EpochAdvanced value) epochAdvanced,
+    required TResult Function(GroupEvent_GroupMetadataChanged value)
+        groupMetadataChanged,
+    required TResult Function(GroupEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(GroupEvent_KeyPackageConsumed value)
+        keyPackageConsumed,
+    required TResult Function(GroupEvent_PendingCommitRolledBack value)
+        pendingCommitRolledBack,
+    required TResult Function(GroupEvent_GroupPurged value) groupPurged,
+  }) {
+    return welcomeReceived(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult? Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult? Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult? Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult? Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult? Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult? Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult? Function(GroupEvent_GroupPurged value)? groupPurged,
+  }) {
+    return welcomeReceived?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult Function(GroupEvent_GroupPurged value)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (welcomeReceived != null) {
+      return welcomeReceived(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupEvent_WelcomeReceived extends GroupEvent {
+  const factory GroupEvent_WelcomeReceived(
+          {required final String wrapperEventId,
+          required final GroupInfo group}) =
+      _$GroupEvent_WelcomeReceivedImpl;
+  const GroupEvent_WelcomeReceived._() : super._();
+
+  /// Hex encoded id of the gift wrap carrying the welcome
+  String get wrapperEventId;
+  GroupInfo get group;
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupEvent_WelcomeReceivedImplCopyWith<_$GroupEvent_WelcomeReceivedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GroupEvent_KeyPackageConsumedImplCopyWith<$Res> {
+  factory _$$GroupEvent_KeyPackageConsumedImplCopyWith(
+          _$GroupEvent_KeyPackageConsumedImpl value,
+          $Res Function(_$GroupEvent_KeyPackageConsumedImpl) then) =
+      __$$GroupEvent_KeyPackageConsumedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String wrapperEventId, String keyPackageId});
+}
+
+/// @nodoc
+class __$$GroupEvent_KeyPackageConsumedImplCopyWithImpl<$Res>
+    extends _$GroupEventCopyWithImpl<$Res, _$GroupEvent_KeyPackageConsumedImpl>
+    implements _$$GroupEvent_KeyPackageConsumedImplCopyWith<$Res> {
+  __$$GroupEvent_KeyPackageConsumedImplCopyWithImpl(
+      _$GroupEvent_KeyPackageConsumedImpl _value,
+      $Res Function(_$GroupEvent_KeyPackageConsumedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? wrapperEventId = null,
+    Object? keyPackageId = null,
+  }) {
+    return _then(_$GroupEvent_KeyPackageConsumedImpl(
+      wrapperEventId: null == wrapperEventId
+          ? _value.wrapperEventId
+          : wrapperEventId // ignore: cast_nullable_to_non_nullable
+              as String,
+      keyPackageId: null == keyPackageId
+          ? _value.keyPackageId
+          : keyPackageId // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$GroupEvent_KeyPackageConsumedImpl
+    extends GroupEvent_KeyPackageConsumed {
+  const _$GroupEvent_KeyPackageConsumedImpl(
+      {required this.wrapperEventId, required this.keyPackageId})
+      : super._();
+
+  /// Hex encoded id of the gift wrap carrying the welcome that used the key package
+  @override
+  final String wrapperEventId;
+
+  /// Id of the `ManagedKeyPackage` used
+  @override
+  final String keyPackageId;
+
+  @override
+  String toString() {
+    return 'GroupEvent.keyPackageConsumed(wrapperEventId: $wrapperEventId, keyPackageId: $keyPackageId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupEvent_KeyPackageConsumedImpl &&
+            (identical(other.wrapperEventId, wrapperEventId) ||
+                other.wrapperEventId == wrapperEventId) &&
+            (identical(other.keyPackageId, keyPackageId) ||
+                other.keyPackageId == keyPackageId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, wrapperEventId, keyPackageId);
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupEvent_KeyPackageConsumedImplCopyWith<
+          _$GroupEvent_KeyPackageConsumedImpl>
+      get copyWith => __$$GroupEvent_KeyPackageConsumedImplCopyWithImpl<
+          _$GroupEvent_KeyPackageConsumedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(DecryptedMessage message) messageDecrypted,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersAdded,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersRemoved,
+    required TResult Function(Uint8List mlsGroupId, BigInt epoch) epochAdvanced,
+    required TResult Function(GroupInfo group) groupMetadataChanged,
+    required TResult Function(String wrapperEventId, GroupInfo group)
+        welcomeReceived,
+    required TResult Function(String wrapperEventId, String keyPackageId)
+        keyPackageConsumed,
+    required TResult Function(Uint8List mlsGroupId, String? eventId)
+        pendingCommitRolledBack,
+    required TResult Function(Uint8List mlsGroupId) groupPurged,
+  }) {
+    return keyPackageConsumed(wrapperEventId, keyPackageId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(DecryptedMessage message)? messageDecrypted,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult? Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult? Function(GroupInfo group)? groupMetadataChanged,
+    TResult? Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult? Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult? Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult? Function(Uint8List mlsGroupId)? groupPurged,
+  }) {
+    return keyPackageConsumed?.call(wrapperEventId, keyPackageId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(DecryptedMessage message)? messageDecrypted,
+    TResult Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult Function(GroupInfo group)? groupMetadataChanged,
+    TResult Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult Function(Uint8List mlsGroupId)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (keyPackageConsumed != null) {
+      return keyPackageConsumed(wrapperEventId, keyPackageId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupEvent_MessageDecrypted value)
+        messageDecrypted,
+    required TResult Function(GroupEvent_MembersAdded value) membersAdded,
+    required TResult Function(GroupEvent_MembersRemoved value) membersRemoved,
+    required TResult Function(GroupEvent_EpochAdvanced value) epochAdvanced,
+    required TResult Function(GroupEvent_GroupMetadataChanged value)
+        groupMetadataChanged,
+    required TResult Function(GroupEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(GroupEvent_KeyPackageConsumed value)
+        keyPackageConsumed,
+    required TResult Function(GroupEvent_PendingCommitRolledBack value)
+        pendingCommitRolledBack,
+    required TResult Function(GroupEvent_GroupPurged value) groupPurged,
+  }) {
+    return keyPackageConsumed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult? Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult? Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult? Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult? Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult? Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult? Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult? Function(GroupEvent_GroupPurged value)? groupPurged,
+  }) {
+    return keyPackageConsumed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult Function(GroupEvent_GroupPurged value)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (keyPackageConsumed != null) {
+      return keyPackageConsumed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupEvent_KeyPackageConsumed extends GroupEvent {
+  const factory GroupEvent_KeyPackageConsumed(
+          {required final String wrapperEventId,
+          required final String keyPackageId}) =
+      _$GroupEvent_KeyPackageConsumedImpl;
+  const GroupEvent_KeyPackageConsumed._() : super._();
+
+  /// Hex encoded id of the gift wrap carrying the welcome that used the key package
+  String get wrapperEventId;
+
+  /// Id of the `ManagedKeyPackage` used
+  String get keyPackageId;
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupEvent_KeyPackageConsumedImplCopyWith<
+          _$GroupEvent_KeyPackageConsumedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GroupEvent_PendingCommitRolledBackImplCopyWith<$Res> {
+  factory _$$GroupEvent_PendingCommitRolledBackImplCopyWith(
+          _$GroupEvent_PendingCommitRolledBackImpl value,
+          $Res Function(_$GroupEvent_PendingCommitRolledBackImpl) then) =
+      __$$GroupEvent_PendingCommitRolledBackImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Uint8List mlsGroupId, String? eventId});
+}
+
+/// @nodoc
+class __$$GroupEvent_PendingCommitRolledBackImplCopyWithImpl<$Res>
+    extends _$GroupEventCopyWithImpl<$Res,
+        _$GroupEvent_PendingCommitRolledBackImpl>
+    implements _$$GroupEvent_PendingCommitRolledBackImplCopyWith<$Res> {
+  __$$GroupEvent_PendingCommitRolledBackImplCopyWithImpl(
+      _$GroupEvent_PendingCommitRolledBackImpl _value,
+      $Res Function(_$GroupEvent_PendingCommitRolledBackImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? mlsGroupId = null,
+    Object? eventId = freezed,
+  }) {
+    return _then(_$GroupEvent_PendingCommitRolledBackImpl(
+      mlsGroupId: null == mlsGroupId
+          ? _value.mlsGroupId
+          : mlsGroupId // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+      eventId: freezed == eventId
+          ? _value.eventId
+          : eventId // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$GroupEvent_PendingCommitRolledBackImpl
+    extends GroupEvent_PendingCommitRolledBack {
+  const _$GroupEvent_PendingCommitRolledBackImpl(
+      {required this.mlsGroupId, this.eventId})
+      : super._();
+
+  @override
+  final Uint8List mlsGroupId;
+
+  /// Hex encoded id of our commit event, which must not be published anymore
+  @override
+  final String? eventId;
+
+  @override
+  String toString() {
+    return 'GroupEvent.pendingCommitRolledBack(mlsGroupId: $mlsGroupId, eventId: $eventId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupEvent_PendingCommitRolledBackImpl &&
+            const DeepCollectionEquality()
+                .equals(other.mlsGroupId, mlsGroupId) &&
+            (identical(other.eventId, eventId) || other.eventId == eventId));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType, const DeepCollectionEquality().hash(mlsGroupId), eventId);
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupEvent_PendingCommitRolledBackImplCopyWith<
+          _$GroupEvent_PendingCommitRolledBackImpl>
+      get copyWith => __$$GroupEvent_PendingCommitRolledBackImplCopyWithImpl<
+          _$GroupEvent_PendingCommitRolledBackImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(DecryptedMessage message) messageDecrypted,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersAdded,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersRemoved,
+    required TResult Function(Uint8List mlsGroupId, BigInt epoch) epochAdvanced,
+    required TResult Function(GroupInfo group) groupMetadataChanged,
+    required TResult Function(String wrapperEventId, GroupInfo group)
+        welcomeReceived,
+    required TResult Function(String wrapperEventId, String keyPackageId)
+        keyPackageConsumed,
+    required TResult Function(Uint8List mlsGroupId, String? eventId)
+        pendingCommitRolledBack,
+    required TResult Function(Uint8List mlsGroupId) groupPurged,
+  }) {
+    return pendingCommitRolledBack(mlsGroupId, eventId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(DecryptedMessage message)? messageDecrypted,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult? Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult? Function(GroupInfo group)? groupMetadataChanged,
+    TResult? Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult? Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult? Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult? Function(Uint8List mlsGroupId)? groupPurged,
+  }) {
+    return pendingCommitRolledBack?.call(mlsGroupId, eventId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(DecryptedMessage message)? messageDecrypted,
+    TResult Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult Function(GroupInfo group)? groupMetadataChanged,
+    TResult Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult Function(Uint8List mlsGroupId)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (pendingCommitRolledBack != null) {
+      return pendingCommitRolledBack(mlsGroupId, eventId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupEvent_MessageDecrypted value)
+        messageDecrypted,
+    required TResult Function(GroupEvent_MembersAdded value) membersAdded,
+    required TResult Function(GroupEvent_MembersRemoved value) membersRemoved,
+    required TResult Function(GroupEvent_EpochAdvanced value) epochAdvanced,
+    required TResult Function(GroupEvent_GroupMetadataChanged value)
+        groupMetadataChanged,
+    required TResult Function(GroupEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(GroupEvent_KeyPackageConsumed value)
+        keyPackageConsumed,
+    required TResult Function(GroupEvent_PendingCommitRolledBack value)
+        pendingCommitRolledBack,
+    required TResult Function(GroupEvent_GroupPurged value) groupPurged,
+  }) {
+    return pendingCommitRolledBack(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult? Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult? Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult? Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult? Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult? Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult? Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult? Function(GroupEvent_GroupPurged value)? groupPurged,
+  }) {
+    return pendingCommitRolledBack?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult Function(GroupEvent_GroupPurged value)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (pendingCommitRolledBack != null) {
+      return pendingCommitRolledBack(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupEvent_PendingCommitRolledBack extends GroupEvent {
+  const factory GroupEvent_PendingCommitRolledBack(
+          {required final Uint8List mlsGroupId, final String? eventId}) =
+      _$GroupEvent_PendingCommitRolledBackImpl;
+  const GroupEvent_PendingCommitRolledBack._() : super._();
+
+  Uint8List get mlsGroupId;
+
+  /// Hex encoded id of our commit event, which must not be published anymore
+  String? get eventId;
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupEvent_PendingCommitRolledBackImplCopyWith<
+          _$GroupEvent_PendingCommitRolledBackImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GroupEvent_GroupPurgedImplCopyWith<$Res> {
+  factory _$$GroupEvent_GroupPurgedImplCopyWith(
+          _$GroupEvent_GroupPurgedImpl value,
+          $Res Function(_$GroupEvent_GroupPurgedImpl) then) =
+      __$$GroupEvent_GroupPurgedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Uint8List mlsGroupId});
+}
+
+/// @nodoc
+class __$$GroupEvent_GroupPurgedImplCopyWithImpl<$Res>
+    extends _$GroupEventCopyWithImpl<$Res, _$GroupEvent_GroupPurgedImpl>
+    implements _$$GroupEvent_GroupPurgedImplCopyWith<$Res> {
+  __$$GroupEvent_GroupPurgedImplCopyWithImpl(
+      _$GroupEvent_GroupPurgedImpl _value,
+      $Res Function(_$GroupEvent_GroupPurgedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? mlsGroupId = null,
+  }) {
+    return _then(_$GroupEvent_GroupPurgedImpl(
+      mlsGroupId: null == mlsGroupId
+          ? _value.mlsGroupId
+          : mlsGroupId // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$GroupEvent_GroupPurgedImpl extends GroupEvent_GroupPurged {
+  const _$GroupEvent_GroupPurgedImpl({required this.mlsGroupId}) : super._();
+
+  @override
+  final Uint8List mlsGroupId;
+
+  @override
+  String toString() {
+    return 'GroupEvent.groupPurged(mlsGroupId: $mlsGroupId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GroupEvent_GroupPurgedImpl &&
+            const DeepCollectionEquality()
+                .equals(other.mlsGroupId, mlsGroupId));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType, const DeepCollectionEquality().hash(mlsGroupId));
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GroupEvent_GroupPurgedImplCopyWith<_$GroupEvent_GroupPurgedImpl>
+      get copyWith => __$$GroupEvent_GroupPurgedImplCopyWithImpl<
+          _$GroupEvent_GroupPurgedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(DecryptedMessage message) messageDecrypted,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersAdded,
+    required TResult Function(Uint8List mlsGroupId, List<String> members)
+        membersRemoved,
+    required TResult Function(Uint8List mlsGroupId, BigInt epoch) epochAdvanced,
+    required TResult Function(GroupInfo group) groupMetadataChanged,
+    required TResult Function(String wrapperEventId, GroupInfo group)
+        welcomeReceived,
+    required TResult Function(String wrapperEventId, String keyPackageId)
+        keyPackageConsumed,
+    required TResult Function(Uint8List mlsGroupId, String? eventId)
+        pendingCommitRolledBack,
+    required TResult Function(Uint8List mlsGroupId) groupPurged,
+  }) {
+    return groupPurged(mlsGroupId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(DecryptedMessage message)? messageDecrypted,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult? Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult? Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult? Function(GroupInfo group)? groupMetadataChanged,
+    TResult? Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult? Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult? Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult? Function(Uint8List mlsGroupId)? groupPurged,
+  }) {
+    return groupPurged?.call(mlsGroupId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(DecryptedMessage message)? messageDecrypted,
+    TResult Function(Uint8List mlsGroupId, List<String> members)? membersAdded,
+    TResult Function(Uint8List mlsGroupId, List<String> members)?
+        membersRemoved,
+    TResult Function(Uint8List mlsGroupId, BigInt epoch)? epochAdvanced,
+    TResult Function(GroupInfo group)? groupMetadataChanged,
+    TResult Function(String wrapperEventId, GroupInfo group)? welcomeReceived,
+    TResult Function(String wrapperEventId, String keyPackageId)?
+        keyPackageConsumed,
+    TResult Function(Uint8List mlsGroupId, String? eventId)?
+        pendingCommitRolledBack,
+    TResult Function(Uint8List mlsGroupId)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (groupPurged != null) {
+      return groupPurged(mlsGroupId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GroupEvent_MessageDecrypted value)
+        messageDecrypted,
+    required TResult Function(GroupEvent_MembersAdded value) membersAdded,
+    required TResult Function(GroupEvent_MembersRemoved value) membersRemoved,
+    required TResult Function(GroupEvent_EpochAdvanced value) epochAdvanced,
+    required TResult Function(GroupEvent_GroupMetadataChanged value)
+        groupMetadataChanged,
+    required TResult Function(GroupEvent_WelcomeReceived value) welcomeReceived,
+    required TResult Function(GroupEvent_KeyPackageConsumed value)
+        keyPackageConsumed,
+    required TResult Function(GroupEvent_PendingCommitRolledBack value)
+        pendingCommitRolledBack,
+    required TResult Function(GroupEvent_GroupPurged value) groupPurged,
+  }) {
+    return groupPurged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult? Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult? Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult? Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult? Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult? Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult? Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult? Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult? Function(GroupEvent_GroupPurged value)? groupPurged,
+  }) {
+    return groupPurged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GroupEvent_MessageDecrypted value)? messageDecrypted,
+    TResult Function(GroupEvent_MembersAdded value)? membersAdded,
+    TResult Function(GroupEvent_MembersRemoved value)? membersRemoved,
+    TResult Function(GroupEvent_EpochAdvanced value)? epochAdvanced,
+    TResult Function(GroupEvent_GroupMetadataChanged value)?
+        groupMetadataChanged,
+    TResult Function(GroupEvent_WelcomeReceived value)? welcomeReceived,
+    TResult Function(GroupEvent_KeyPackageConsumed value)? keyPackageConsumed,
+    TResult Function(GroupEvent_PendingCommitRolledBack value)?
+        pendingCommitRolledBack,
+    TResult Function(GroupEvent_GroupPurged value)? groupPurged,
+    required TResult orElse(),
+  }) {
+    if (groupPurged != null) {
+      return groupPurged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GroupEvent_GroupPurged extends GroupEvent {
+  const factory GroupEvent_GroupPurged({required final Uint8List mlsGroupId}) =
+      _$GroupEvent_GroupPurgedImpl;
+  const GroupEvent_GroupPurged._() : super._();
+
+  Uint8List get mlsGroupId;
+
+  /// Create a copy of GroupEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GroupEvent_GroupPurgedImplCopyWith<_$GroupEvent_GroupPurgedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
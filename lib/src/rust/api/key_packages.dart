@@ -0,0 +1,193 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `consume_key_package`, `create_managed_key_package`, `forget_retired_key_packages`, `key_package_for_welcome`, `key_package_signature_keys`, `retire_unused_key_package`, `retire`, `rotate_declined_key_package`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `try_from`
+
+/// Bring the key package pool of an identity to the target of a policy
+///
+/// Expired key packages and consumed or declined last resort ones are retired and their private
+/// material deleted, then new key packages are created until the policy's counts of unused ones
+/// are met.
+/// Parameters: public_key - hex public key of the identity, relay - relays of the kind-443 events
+/// Returns: the key packages to publish and the kind-443 events to delete
+Future<KeyPackageRotation> maintainKeyPackages(
+        {required String identity,
+        required String publicKey,
+        List<String>? relay,
+        String? client,
+        required KeyPackagePolicy policy}) =>
+    RustLib.instance.api.crateApiKeyPackagesMaintainKeyPackages(
+        identity: identity,
+        publicKey: publicKey,
+        relay: relay,
+        client: client,
+        policy: policy);
+
+/// Record that the kind-443 event of a key package was published
+/// Parameters: key_package_id - id from `ManagedKeyPackage`, event_id - hex encoded id of the event
+/// Returns: whether the key package is managed
+Future<bool> markKeyPackagePublished(
+        {required String identity,
+        required String keyPackageId,
+        required String eventId}) =>
+    RustLib.instance.api.crateApiKeyPackagesMarkKeyPackagePublished(
+        identity: identity, keyPackageId: keyPackageId, eventId: eventId);
+
+/// Record that the kind-443 event of a retired key package was deleted
+/// Parameters: key_package_id - id from `ManagedKeyPackage`
+/// Returns: whether the key package is managed and retired
+Future<bool> markKeyPackageDeleted(
+        {required String identity, required String keyPackageId}) =>
+    RustLib.instance.api.crateApiKeyPackagesMarkKeyPackageDeleted(
+        identity: identity, keyPackageId: keyPackageId);
+
+/// List the key packages tracked by the key package manager, oldest first
+Future<List<ManagedKeyPackage>> listKeyPackages({required String identity}) =>
+    RustLib.instance.api.crateApiKeyPackagesListKeyPackages(identity: identity);
+
+/// How many unused key packages `maintain_key_packages` keeps available
+class KeyPackagePolicy {
+  /// Key packages that stay usable by several welcomes until they are rotated
+  final int lastResortCount;
+
+  /// Key packages whose private material is deleted by the first welcome using them
+  ///
+  /// nostr-mls marks every key package last resort in MLS, so single use is enforced here.
+  final int oneTimeCount;
+
+  /// Key packages older than this are retired and replaced
+  final BigInt maxAgeSecs;
+
+  const KeyPackagePolicy({
+    required this.lastResortCount,
+    required this.oneTimeCount,
+    required this.maxAgeSecs,
+  });
+
+  static Future<KeyPackagePolicy> default_() =>
+      RustLib.instance.api.crateApiKeyPackagesKeyPackagePolicyDefault();
+
+  @override
+  int get hashCode =>
+      lastResortCount.hashCode ^ oneTimeCount.hashCode ^ maxAgeSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is KeyPackagePolicy &&
+          runtimeType == other.runtimeType &&
+          lastResortCount == other.lastResortCount &&
+          oneTimeCount == other.oneTimeCount &&
+          maxAgeSecs == other.maxAgeSecs;
+}
+
+/// Work left to the app after `maintain_key_packages`
+class KeyPackageRotation {
+  /// Key packages to publish as kind-443 events, each confirmed with `mark_key_package_published`
+  final List<ManagedKeyPackage> toPublish;
+
+  /// Retired key packages whose kind-443 event is to delete with a kind-5 event, each confirmed
+  /// with `mark_key_package_deleted`; listed again until then
+  final List<ManagedKeyPackage> toDelete;
+
+  const KeyPackageRotation({
+    required this.toPublish,
+    required this.toDelete,
+  });
+
+  @override
+  int get hashCode => toPublish.hashCode ^ toDelete.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is KeyPackageRotation &&
+          runtimeType == other.runtimeType &&
+          toPublish == other.toPublish &&
+          toDelete == other.toDelete;
+}
+
+/// Lifecycle state of a managed key package
+enum KeyPackageState {
+  /// Created but its kind-443 event is not confirmed published yet
+  unpublished,
+  published,
+
+  /// Used by a welcome we joined
+  consumed,
+
+  /// A last resort one a declined welcome was sent to, usable by other welcomes until the next
+  /// `maintain_key_packages` rotates it
+  declined,
+
+  /// Rotated out, its private material is deleted; its kind-443 event is still to delete
+  retired,
+
+  /// Retired and its kind-443 event deleted, forgotten by `apply_retention_policy`
+  deleted,
+  ;
+}
+
+/// A key package tracked by the key package manager
+class ManagedKeyPackage {
+  /// Hex encoded sha256 of the encoded key package
+  final String id;
+  final String encodedKeyPackage;
+
+  /// Tags of its kind-443 event
+  final List<List<String>> tags;
+  final bool lastResort;
+  final KeyPackageState state;
+
+  /// Hex encoded id of the published kind-443 event
+  final String? eventId;
+  final BigInt createdAt;
+  final BigInt? publishedAt;
+  final BigInt? consumedAt;
+
+  const ManagedKeyPackage({
+    required this.id,
+    required this.encodedKeyPackage,
+    required this.tags,
+    required this.lastResort,
+    required this.state,
+    this.eventId,
+    required this.createdAt,
+    this.publishedAt,
+    this.consumedAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      encodedKeyPackage.hashCode ^
+      tags.hashCode ^
+      lastResort.hashCode ^
+      state.hashCode ^
+      eventId.hashCode ^
+      createdAt.hashCode ^
+      publishedAt.hashCode ^
+      consumedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ManagedKeyPackage &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          encodedKeyPackage == other.encodedKeyPackage &&
+          tags == other.tags &&
+          lastResort == other.lastResort &&
+          state == other.state &&
+          eventId == other.eventId &&
+          createdAt == other.createdAt &&
+          publishedAt == other.publishedAt &&
+          consumedAt == other.consumedAt;
+}
@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'types.dart';
+
+// These functions are ignored because they are not marked as `pub`: `current_exporter_secret`, `exporter_secret_for_epoch`, `keyring_policy`, `opens_at_current_epoch`, `opens_outer_layer`, `past_epoch_of_event`, `record_exporter_secret`
+
+/// Set the keyring policy of an identity and purge the secrets its active groups no longer retain
+Future<void> setKeyringPolicy(
+        {required String identity, required KeyringPolicy policy}) =>
+    RustLib.instance.api
+        .crateApiKeyringSetKeyringPolicy(identity: identity, policy: policy);
+
+/// Get the keyring policy of an identity, 5 epochs up to 30 days by default
+Future<KeyringPolicy> getKeyringPolicy({required String identity}) =>
+    RustLib.instance.api.crateApiKeyringGetKeyringPolicy(identity: identity);
+
+/// Export the group secret of the current or a retained past epoch
+/// Parameters: group_id - byte array of group ID, epoch - epoch of the secret
+/// Returns: secret key and epoch, `StaleEpoch` if the epoch is not retained
+Future<ExportedSecret> exportSecretForEpoch(
+        {required String identity,
+        required List<int> groupId,
+        required BigInt epoch}) =>
+    RustLib.instance.api.crateApiKeyringExportSecretForEpoch(
+        identity: identity, groupId: groupId, epoch: epoch);
+
+/// List the epochs of a group whose secret is retained, current epoch included
+/// Parameters: group_id - byte array of group ID
+/// Returns: epochs in ascending order
+Future<Uint64List> listRetainedEpochs(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api.crateApiKeyringListRetainedEpochs(
+        identity: identity, groupId: groupId);
+
+/// How long the exporter secrets of past epochs are kept
+/// A past secret is purged once either limit is reached; `None` disables the age limit.
+/// The current epoch's secret is always kept. nostr-mls's own copy of a past secret is purged
+/// with the keyring's, so a purged epoch cannot be decrypted from this device anymore.
+class KeyringPolicy {
+  /// Past epochs kept before the current one, 0 keeping only the current epoch
+  final int retainedEpochs;
+
+  /// Seconds since the secret of an epoch was first seen
+  final BigInt? maxAgeSecs;
+
+  const KeyringPolicy({
+    required this.retainedEpochs,
+    this.maxAgeSecs,
+  });
+
+  static Future<KeyringPolicy> default_() =>
+      RustLib.instance.api.crateApiKeyringKeyringPolicyDefault();
+
+  @override
+  int get hashCode => retainedEpochs.hashCode ^ maxAgeSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is KeyringPolicy &&
+          runtimeType == other.runtimeType &&
+          retainedEpochs == other.retainedEpochs &&
+          maxAgeSecs == other.maxAgeSecs;
+}
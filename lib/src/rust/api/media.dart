@@ -0,0 +1,191 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `decode_hex`, `decrypt`, `encrypt`, `file_key`, `imeta_tag`, `media_context`, `open_media`, `parse_imeta_tag`, `seal_media`, `validate_filename`, `validate_mime_type`
+
+/// Encrypt an attachment for a group with a key derived from the group's exporter secret
+/// Parameters: group_id - byte array of group ID, data - file content, mime_type - e.g. image/png
+/// Returns: the encrypted blob to upload and the reference for its imeta tag
+Future<EncryptedMedia> encryptMedia(
+        {required String identity,
+        required List<int> groupId,
+        required List<int> data,
+        required String mimeType,
+        required String filename}) =>
+    RustLib.instance.api.crateApiMediaEncryptMedia(
+        identity: identity,
+        groupId: groupId,
+        data: data,
+        mimeType: mimeType,
+        filename: filename);
+
+/// Decrypt an attachment of a group message and check it against its imeta tag
+/// Attachments of past epochs decrypt as long as the keyring retains the epoch's secret.
+/// Parameters: group_id - byte array of group ID, imeta_tag - the tag from the rumor,
+/// encrypted_data - the downloaded blob
+/// Returns: the file content
+Future<Uint8List> decryptMedia(
+        {required String identity,
+        required List<int> groupId,
+        required List<String> imetaTag,
+        required List<int> encryptedData}) =>
+    RustLib.instance.api.crateApiMediaDecryptMedia(
+        identity: identity,
+        groupId: groupId,
+        imetaTag: imetaTag,
+        encryptedData: encryptedData);
+
+/// Encrypt an attachment and upload it with the app's Blossom-style client
+/// Parameters: group_id - byte array of group ID, upload - takes the encrypted blob and its hex
+/// sha256 and returns the URL it is served at, or `null` on failure
+/// Returns: the imeta tag to add to the message rumor
+Future<UploadedMedia> uploadMedia(
+        {required String identity,
+        required List<int> groupId,
+        required List<int> data,
+        required String mimeType,
+        required String filename,
+        required FutureOr<String?> Function(Uint8List, String) upload}) =>
+    RustLib.instance.api.crateApiMediaUploadMedia(
+        identity: identity,
+        groupId: groupId,
+        data: data,
+        mimeType: mimeType,
+        filename: filename,
+        upload: upload);
+
+/// Download an attachment with the app's client, then decrypt and check it
+/// Parameters: group_id - byte array of group ID, imeta_tag - the tag from the rumor, download -
+/// takes the URL and returns the blob, or `null` on failure
+/// Returns: the file content
+Future<Uint8List> downloadMedia(
+        {required String identity,
+        required List<int> groupId,
+        required List<String> imetaTag,
+        required FutureOr<Uint8List?> Function(String) download}) =>
+    RustLib.instance.api.crateApiMediaDownloadMedia(
+        identity: identity,
+        groupId: groupId,
+        imetaTag: imetaTag,
+        download: download);
+
+/// Build the imeta tag of an uploaded attachment
+/// Parameters: url - where the encrypted blob is served
+Future<List<String>> buildMediaImetaTag(
+        {required MediaReference reference, required String url}) =>
+    RustLib.instance.api
+        .crateApiMediaBuildMediaImetaTag(reference: reference, url: url);
+
+/// Parse and validate the imeta tag of an attachment
+Future<MediaReference> parseMediaImetaTag({required List<String> imetaTag}) =>
+    RustLib.instance.api.crateApiMediaParseMediaImetaTag(imetaTag: imetaTag);
+
+/// Result of `encrypt_media`
+class EncryptedMedia {
+  final Uint8List encryptedData;
+
+  /// Hex encoded sha256 of `encrypted_data`, the blob address on Blossom-style servers
+  final String encryptedHash;
+  final MediaReference reference;
+
+  const EncryptedMedia({
+    required this.encryptedData,
+    required this.encryptedHash,
+    required this.reference,
+  });
+
+  @override
+  int get hashCode =>
+      encryptedData.hashCode ^ encryptedHash.hashCode ^ reference.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EncryptedMedia &&
+          runtimeType == other.runtimeType &&
+          encryptedData == other.encryptedData &&
+          encryptedHash == other.encryptedHash &&
+          reference == other.reference;
+}
+
+/// What a receiver needs to fetch, decrypt and check an attachment, as carried by its imeta tag
+class MediaReference {
+  /// Where the encrypted blob was uploaded, `None` before `upload_media`
+  final String? url;
+  final String mimeType;
+  final String filename;
+
+  /// Hex encoded sha256 of the plaintext, binding the file key to the content
+  final String originalHash;
+
+  /// Hex encoded ChaCha20-Poly1305 nonce
+  final String nonce;
+
+  /// Epoch of the exporter secret the file key was derived from
+  final BigInt epoch;
+
+  /// Size of the plaintext in bytes
+  final BigInt size;
+
+  const MediaReference({
+    this.url,
+    required this.mimeType,
+    required this.filename,
+    required this.originalHash,
+    required this.nonce,
+    required this.epoch,
+    required this.size,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      mimeType.hashCode ^
+      filename.hashCode ^
+      originalHash.hashCode ^
+      nonce.hashCode ^
+      epoch.hashCode ^
+      size.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaReference &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          mimeType == other.mimeType &&
+          filename == other.filename &&
+          originalHash == other.originalHash &&
+          nonce == other.nonce &&
+          epoch == other.epoch &&
+          size == other.size;
+}
+
+/// Result of `upload_media`
+class UploadedMedia {
+  /// imeta tag to add to the message rumor
+  final List<String> imetaTag;
+  final MediaReference reference;
+
+  const UploadedMedia({
+    required this.imetaTag,
+    required this.reference,
+  });
+
+  @override
+  int get hashCode => imetaTag.hashCode ^ reference.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UploadedMedia &&
+          runtimeType == other.runtimeType &&
+          imetaTag == other.imetaTag &&
+          reference == other.reference;
+}
@@ -0,0 +1,65 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'types.dart';
+
+// These functions are ignored because they are not marked as `pub`: `ensure_group_indexed`, `load_messages`
+
+/// Get the stored messages of a group, newest first
+/// Parameters: group_id - byte array of group ID, before - cursor of the last message of the
+/// previous page, `None` for the newest page, limit - maximum number of messages
+/// Returns: one page of decrypted messages
+Future<List<DecryptedMessage>> getMessages(
+        {required String identity,
+        required List<int> groupId,
+        MessageCursor? before,
+        required int limit}) =>
+    RustLib.instance.api.crateApiMessagesGetMessages(
+        identity: identity, groupId: groupId, before: before, limit: limit);
+
+/// Get a stored message by the id of its rumor, `None` once expired or purged
+/// Parameters: event_id - hex encoded rumor id
+Future<DecryptedMessage?> getMessage(
+        {required String identity, required String eventId}) =>
+    RustLib.instance.api
+        .crateApiMessagesGetMessage(identity: identity, eventId: eventId);
+
+/// Full-text search over the stored messages of a group
+/// Parameters: group_id - byte array of group ID, query - space separated terms that must all match
+/// Returns: matching messages, best match first
+Future<List<DecryptedMessage>> searchMessages(
+        {required String identity,
+        required List<int> groupId,
+        required String query,
+        required int limit}) =>
+    RustLib.instance.api.crateApiMessagesSearchMessages(
+        identity: identity, groupId: groupId, query: query, limit: limit);
+
+/// Position of a message in the pages of `get_messages`
+class MessageCursor {
+  final BigInt createdAt;
+
+  /// Hex encoded rumor id
+  final String eventId;
+
+  const MessageCursor({
+    required this.createdAt,
+    required this.eventId,
+  });
+
+  @override
+  int get hashCode => createdAt.hashCode ^ eventId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessageCursor &&
+          runtimeType == other.runtimeType &&
+          createdAt == other.createdAt &&
+          eventId == other.eventId;
+}
@@ -4,40 +4,56 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
-import 'package:collection/collection.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'types.dart';
 
-// These types are ignored because they are not used by any `pub` functions: `NOSTR_MLS`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `deref`, `initialize`
+// These functions are ignored because they are not marked as `pub`: `buffer_event`, `ephemeral_group_event`, `event_result`, `group_info`, `group_status`, `message_error`, `open_identity`, `parse_relays`, `process_commit`, `process_event`, `replay_pending_messages`, `save_staged_commit`, `welcome_error`
 
-/// Initialize the NostrMls instance
-/// Returns: JSON {"status": "success"} on success, or error message on failure
-Future<String> initNostrMls(
-        {required String path, String? identity, String? password}) =>
+/// Initialize the NostrMls instance of an identity
+/// Other identities stay open; re-initializing an identity replaces only its own instance
+/// A database that does not open with the password fails with `StorageLocked`, telling a wrong
+/// password apart from a missing or unneeded one
+/// Parameters: path - directory of the database, identity - key used by every other call
+Future<void> initNostrMls(
+        {required String path, required String identity, String? password}) =>
     RustLib.instance.api.crateApiMlsApiInitNostrMls(
         path: path, identity: identity, password: password);
 
+/// Close the NostrMls instance of an identity
+/// Returns: whether an instance was open
+Future<bool> closeNostrMls({required String identity}) =>
+    RustLib.instance.api.crateApiMlsApiCloseNostrMls(identity: identity);
+
+/// List the identities with an open NostrMls instance
+Future<List<String>> listNostrMlsIdentities() =>
+    RustLib.instance.api.crateApiMlsApiListNostrMlsIdentities();
+
 /// Get the current ciphersuite
-/// Returns: JSON formatted ciphersuite information
-Future<String> getCiphersuite() =>
-    RustLib.instance.api.crateApiMlsApiGetCiphersuite();
+/// Returns: the ciphersuite identifier
+Future<int> getCiphersuite({required String identity}) =>
+    RustLib.instance.api.crateApiMlsApiGetCiphersuite(identity: identity);
 
 /// Get the list of enabled extensions
-/// Returns: JSON formatted list of extensions
-Future<String> getExtensions() =>
-    RustLib.instance.api.crateApiMlsApiGetExtensions();
+/// Returns: list of extension names
+Future<List<String>> getExtensions({required String identity}) =>
+    RustLib.instance.api.crateApiMlsApiGetExtensions(identity: identity);
 
 /// Create a key package for an event
-/// Returns: JSON formatted key package information, including encoded key package and tags
-Future<String> createKeyPackageForEvent(
-        {required String publicKey, List<String>? relay, String? client}) =>
+/// Returns: encoded key package and the tags of its kind-443 event
+Future<KeyPackageResult> createKeyPackageForEvent(
+        {required String identity,
+        required String publicKey,
+        List<String>? relay,
+        String? client}) =>
     RustLib.instance.api.crateApiMlsApiCreateKeyPackageForEvent(
-        publicKey: publicKey, relay: relay, client: client);
+        identity: identity, publicKey: publicKey, relay: relay, client: client);
 
 /// Create a group
-/// Returns: JSON formatted group information
-Future<String> createGroup(
-        {required String groupName,
+/// Returns: group information and the serialized welcome message for the invited members
+Future<CreateGroupResult> createGroup(
+        {required String identity,
+        required String groupName,
         required String groupDescription,
         required List<String> groupMembersSerializedKeyPackages,
         required List<String> groupMembersPubkeys,
@@ -45,6 +61,7 @@ Future<String> createGroup(
         required List<String> groupAdminPublicKeys,
         required List<String> relays}) =>
     RustLib.instance.api.crateApiMlsApiCreateGroup(
+        identity: identity,
         groupName: groupName,
         groupDescription: groupDescription,
         groupMembersSerializedKeyPackages: groupMembersSerializedKeyPackages,
@@ -55,133 +72,182 @@ Future<String> createGroup(
 
 /// Create a message for a group
 /// Parameters: group_id - byte array of group ID, rumor_event_string - JSON string of the event
-/// Returns: JSON formatted message information
-Future<String> createMessageForGroup(
-        {required List<int> groupId, required String rumorEventString}) =>
+/// Returns: the signed kind-445 event
+Future<EventResult> createMessageForGroup(
+        {required String identity,
+        required List<int> groupId,
+        required String rumorEventString}) =>
     RustLib.instance.api.crateApiMlsApiCreateMessageForGroup(
-        groupId: groupId, rumorEventString: rumorEventString);
+        identity: identity,
+        groupId: groupId,
+        rumorEventString: rumorEventString);
 
 /// Create a commit message for a group
-/// Parameters: group_id - byte array of group ID, serialized_commit - serialized commit
-/// Returns: JSON formatted message information
-Future<String> createCommitMessageForGroup(
-        {required String nostrGroupId,
-        required List<int> serializedCommit,
-        required U8Array32 secretKey}) =>
+/// The event is signed with a new random key, or by the identity's signer when its scope covers
+/// commits. It becomes the event of the group's pending commit, which fails if that commit was
+/// rolled back by a competing one meanwhile.
+/// Parameters: nostr_group_id - hex encoded nostr group ID, serialized_commit - serialized commit
+/// from `add_members`, `remove_members`, `leave_group` or `readd_member`
+/// Returns: the signed kind-445 event, to confirm with `confirm_commit_published`
+Future<EventResult> createCommitMessageForGroup(
+        {required String identity,
+        required String nostrGroupId,
+        required List<int> serializedCommit}) =>
     RustLib.instance.api.crateApiMlsApiCreateCommitMessageForGroup(
+        identity: identity,
         nostrGroupId: nostrGroupId,
-        serializedCommit: serializedCommit,
-        secretKey: secretKey);
+        serializedCommit: serializedCommit);
 
 /// Export group secret
+/// Past epochs' secrets are available through `export_secret_for_epoch`
+/// Parameters: group_id - byte array of group ID
+/// Returns: secret key and epoch
+Future<ExportedSecret> exportSecret(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api
+        .crateApiMlsApiExportSecret(identity: identity, groupId: groupId);
+
+/// Get the number of buffered messages waiting for a later epoch of a group
 /// Parameters: group_id - byte array of group ID
-/// Returns: JSON formatted secret information, including secret key and epoch
-Future<String> exportSecret({required List<int> groupId}) =>
-    RustLib.instance.api.crateApiMlsApiExportSecret(groupId: groupId);
+Future<int> getPendingMessageCount(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api.crateApiMlsApiGetPendingMessageCount(
+        identity: identity, groupId: groupId);
 
 /// Process a message for a group
-/// Parameters: group_id - byte array of group ID, serialized_message - serialized message
-/// Returns: JSON formatted processing result
-Future<String> processMessageForGroup({required String eventString}) =>
-    RustLib.instance.api
-        .crateApiMlsApiProcessMessageForGroup(eventString: eventString);
+/// Messages for an epoch the group has not reached yet are buffered in storage and
+/// replayed once a commit advances the group
+/// Parameters: event_string - JSON string of the kind-445 event
+/// Returns: the decrypted message and/or staged commit information
+Future<ProcessedMessage> processMessageForGroup(
+        {required String identity, required String eventString}) =>
+    RustLib.instance.api.crateApiMlsApiProcessMessageForGroup(
+        identity: identity, eventString: eventString);
 
 /// Process a commit message for a specific group
+/// Commits of the same epoch that pass the group policy are ordered by their kind-445 events as
+/// seen by `process_message_for_group`, earliest `created_at` then lowest event id. A losing
+/// commit is reported as `superseded`; a winning one replaces our own pending commit, which is
+/// rolled back. A commit arriving after we merged another one of its epoch cannot be applied
+/// anymore: it fails with `StaleEpoch` and is counted in `GroupHealth`. If the other members
+/// applied it, `compare_group_state` reports `Diverged` and `start_group_recovery` gets us back.
+/// Buffered messages the new epoch can decrypt are returned in `released_messages`
 /// Parameters: group_id - byte array of group ID, message_bytes - serialized message bytes
-/// Returns: JSON formatted processing result
-Future<String> processCommitMessageForGroup(
-        {required List<int> groupId, required List<int> messageBytes}) =>
+/// Returns: the processing result
+Future<ProcessedMessage> processCommitMessageForGroup(
+        {required String identity,
+        required List<int> groupId,
+        required List<int> messageBytes}) =>
     RustLib.instance.api.crateApiMlsApiProcessCommitMessageForGroup(
-        groupId: groupId, messageBytes: messageBytes);
+        identity: identity, groupId: groupId, messageBytes: messageBytes);
 
 /// Preview a group from a welcome message without joining it
 /// Parameters: wrapper_event_id - byte array of event ID, rumor_event_string - JSON string of the event
-/// Returns: JSON formatted group preview information
-Future<String> previewGroupFromWelcome(
-        {required List<int> wrapperEventId,
+/// Returns: group preview information
+Future<GroupInfo> previewGroupFromWelcome(
+        {required String identity,
+        required List<int> wrapperEventId,
         required String rumorEventString}) =>
     RustLib.instance.api.crateApiMlsApiPreviewGroupFromWelcome(
-        wrapperEventId: wrapperEventId, rumorEventString: rumorEventString);
+        identity: identity,
+        wrapperEventId: wrapperEventId,
+        rumorEventString: rumorEventString);
 
 /// Join a group from a welcome message
 /// Parameters: wrapper_event_id - byte array of event ID, rumor_event_string - JSON string of the event
-/// Returns: JSON formatted join result
-Future<String> joinGroupFromWelcome(
-        {required List<int> wrapperEventId,
+/// Returns: information about the joined group
+Future<GroupInfo> joinGroupFromWelcome(
+        {required String identity,
+        required List<int> wrapperEventId,
         required String rumorEventString}) =>
     RustLib.instance.api.crateApiMlsApiJoinGroupFromWelcome(
-        wrapperEventId: wrapperEventId, rumorEventString: rumorEventString);
+        identity: identity,
+        wrapperEventId: wrapperEventId,
+        rumorEventString: rumorEventString);
 
-Future<String> getMembers({required List<int> groupId}) =>
-    RustLib.instance.api.crateApiMlsApiGetMembers(groupId: groupId);
+/// Get the members of a group
+/// Parameters: group_id - byte array of group ID
+/// Returns: hex encoded member public keys
+Future<List<String>> getMembers(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api
+        .crateApiMlsApiGetMembers(identity: identity, groupId: groupId);
 
 /// Get group information by group ID
 /// Parameters: group_id - byte array of group ID
-/// Returns: JSON formatted group information including group ID, members, and nostr group data
-Future<String> getGroup({required List<int> groupId}) =>
-    RustLib.instance.api.crateApiMlsApiGetGroup(groupId: groupId);
+/// Returns: group information including group ID, members, and nostr group data
+Future<GroupInfo> getGroup(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api
+        .crateApiMlsApiGetGroup(identity: identity, groupId: groupId);
+
+/// List the groups stored locally
+/// Parameters: filter - which group states to include
+/// Returns: group summaries, most recently active first
+Future<List<GroupSummary>> listGroups(
+        {required String identity, required GroupStateFilter filter}) =>
+    RustLib.instance.api
+        .crateApiMlsApiListGroups(identity: identity, filter: filter);
+
+/// List the welcomes stored locally that have not been accepted or declined
+/// Returns: welcome summaries
+Future<List<WelcomeSummary>> listPendingWelcomes({required String identity}) =>
+    RustLib.instance.api.crateApiMlsApiListPendingWelcomes(identity: identity);
 
 /// Add members to an existing group
+/// The commit stays pending until `confirm_commit_published` merges it or `abort_commit`
+/// discards it; send the welcome only after it was merged
 /// Parameters: group_id - byte array of group ID, serialized_key_packages - array of serialized key packages
-/// Returns: JSON formatted result containing serialized commit and welcome messages
-Future<String> addMembers(
-        {required List<int> groupId,
+/// Returns: serialized commit and welcome messages
+Future<AddMembersResult> addMembers(
+        {required String identity,
+        required List<int> groupId,
         required List<String> serializedKeyPackages}) =>
     RustLib.instance.api.crateApiMlsApiAddMembers(
-        groupId: groupId, serializedKeyPackages: serializedKeyPackages);
+        identity: identity,
+        groupId: groupId,
+        serializedKeyPackages: serializedKeyPackages);
 
 /// Remove members from a group
+/// The commit stays pending until `confirm_commit_published` merges it or `abort_commit`
+/// discards it
 /// Parameters: group_id - byte array of group ID, member_pubkeys - array of member public keys to remove
-/// Returns: JSON formatted result containing serialized commit message
-Future<String> removeMembers(
-        {required List<int> groupId, required List<String> memberPubkeys}) =>
+/// Returns: serialized commit message
+Future<CommitResult> removeMembers(
+        {required String identity,
+        required List<int> groupId,
+        required List<String> memberPubkeys}) =>
     RustLib.instance.api.crateApiMlsApiRemoveMembers(
-        groupId: groupId, memberPubkeys: memberPubkeys);
-
-/// Commit a proposal
-/// Parameters: group_id - byte array of group ID, proposal - serialized proposal
-/// Returns: JSON formatted result containing commit and welcome messages
-Future<String> commitProposal(
-        {required List<int> groupId, required String proposal}) =>
-    RustLib.instance.api
-        .crateApiMlsApiCommitProposal(groupId: groupId, proposal: proposal);
+        identity: identity, groupId: groupId, memberPubkeys: memberPubkeys);
 
 /// Leave a group
+/// The proposal to remove ourselves stays pending until `confirm_commit_published` or
+/// `abort_commit`; the group ends once an admin commits it
 /// Parameters: group_id - byte array of group ID
-/// Returns: JSON formatted result containing serialized leave message
-Future<String> leaveGroup({required List<int> groupId}) =>
-    RustLib.instance.api.crateApiMlsApiLeaveGroup(groupId: groupId);
+/// Returns: serialized leave message
+Future<CommitResult> leaveGroup(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api
+        .crateApiMlsApiLeaveGroup(identity: identity, groupId: groupId);
 
 /// Get key package from storage
 /// Parameters: serialized_key_package - serialized key package string
-/// Returns: JSON formatted key package information
-Future<String> getKeyPackageFromStorage(
-        {required String serializedKeyPackage}) =>
+/// Returns: whether the key package was found, with its debug representation
+Future<KeyPackageLookup> getKeyPackageFromStorage(
+        {required String identity, required String serializedKeyPackage}) =>
     RustLib.instance.api.crateApiMlsApiGetKeyPackageFromStorage(
-        serializedKeyPackage: serializedKeyPackage);
+        identity: identity, serializedKeyPackage: serializedKeyPackage);
 
 /// Find encoded key package from welcome event
 /// Parameters: encoded_keypackages - array of encoded key package strings, wrapper_event_id - byte array of event ID, rumor_event_string - JSON string of the event
-/// Returns: JSON formatted result containing the matched key package index and info if found
-Future<String> findEncodedKeypackageFromWelcomeEvent(
-        {required List<String> encodedKeypackages,
+/// Returns: the matched key package index if found
+Future<WelcomeKeyPackageMatch> findEncodedKeypackageFromWelcomeEvent(
+        {required String identity,
+        required List<String> encodedKeypackages,
         required List<int> wrapperEventId,
         required String rumorEventString}) =>
     RustLib.instance.api.crateApiMlsApiFindEncodedKeypackageFromWelcomeEvent(
+        identity: identity,
         encodedKeypackages: encodedKeypackages,
         wrapperEventId: wrapperEventId,
         rumorEventString: rumorEventString);
-
-class U8Array32 extends NonGrowableListView<int> {
-  static const arraySize = 32;
-
-  @internal
-  Uint8List get inner => _inner;
-  final Uint8List _inner;
-
-  U8Array32(this._inner)
-      : assert(_inner.length == arraySize),
-        super(_inner);
-
-  U8Array32.init() : this(Uint8List(arraySize));
-}
@@ -0,0 +1,122 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `check_commit`, `check_new_proposals`, `check_own_action`, `check_own_proposals`, `group_policy`, `reject`, `role_from_str`, `role_to_str`, `role`, `sender_pubkey`, `violation`
+// These types are ignored because they are not used by any `pub` functions: `GroupAction`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
+
+/// Set the policy of a group
+/// Parameters: group_id - byte array of group ID
+Future<void> setGroupPolicy(
+        {required String identity,
+        required List<int> groupId,
+        required GroupPolicy policy}) =>
+    RustLib.instance.api.crateApiPolicySetGroupPolicy(
+        identity: identity, groupId: groupId, policy: policy);
+
+/// Get the policy of a group, the admin-only default if none was set
+/// Parameters: group_id - byte array of group ID
+Future<GroupPolicy> getGroupPolicy(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api
+        .crateApiPolicyGetGroupPolicy(identity: identity, groupId: groupId);
+
+/// List the commits and proposals of a group rejected by its policy, newest first
+/// Parameters: group_id - byte array of group ID, limit - maximum number of entries
+Future<List<CommitAuditEntry>> listCommitAudit(
+        {required String identity,
+        required List<int> groupId,
+        required int limit}) =>
+    RustLib.instance.api.crateApiPolicyListCommitAudit(
+        identity: identity, groupId: groupId, limit: limit);
+
+/// A commit or proposal rejected by the group policy
+class CommitAuditEntry {
+  /// Epoch the commit was sent in
+  final BigInt epoch;
+
+  /// Hex encoded public key of the committer or proposer, `None` for non-member senders
+  final String? sender;
+  final String reason;
+  final BigInt createdAt;
+
+  const CommitAuditEntry({
+    required this.epoch,
+    this.sender,
+    required this.reason,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      epoch.hashCode ^ sender.hashCode ^ reason.hashCode ^ createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CommitAuditEntry &&
+          runtimeType == other.runtimeType &&
+          epoch == other.epoch &&
+          sender == other.sender &&
+          reason == other.reason &&
+          createdAt == other.createdAt;
+}
+
+/// Rules every incoming commit of a group is checked against before it is merged
+/// Roles apply to the committer: any member may propose, and an allowed member commits the
+/// proposals it accepts. Proposals from non-members are dropped when they arrive.
+///
+/// The policy is local: each member enforces its own copy, and a rejected commit leaves this
+/// member at the previous epoch.
+class GroupPolicy {
+  final PolicyRole addMembers;
+  final PolicyRole removeMembers;
+  final PolicyRole updateGroupData;
+
+  /// Whether a member's proposal to remove itself may be committed even by members who are
+  /// not allowed to remove others
+  final bool allowSelfRemove;
+
+  const GroupPolicy({
+    required this.addMembers,
+    required this.removeMembers,
+    required this.updateGroupData,
+    required this.allowSelfRemove,
+  });
+
+  static Future<GroupPolicy> default_() =>
+      RustLib.instance.api.crateApiPolicyGroupPolicyDefault();
+
+  @override
+  int get hashCode =>
+      addMembers.hashCode ^
+      removeMembers.hashCode ^
+      updateGroupData.hashCode ^
+      allowSelfRemove.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupPolicy &&
+          runtimeType == other.runtimeType &&
+          addMembers == other.addMembers &&
+          removeMembers == other.removeMembers &&
+          updateGroupData == other.updateGroupData &&
+          allowSelfRemove == other.allowSelfRemove;
+}
+
+/// Who may perform an operation in a group
+enum PolicyRole {
+  /// Only the admins listed in the group data
+  admins,
+
+  /// Any member
+  members,
+  ;
+}
@@ -0,0 +1,215 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'key_packages.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'types.dart';
+
+// These functions are ignored because they are not marked as `pub`: `count_undecryptable_event`, `join_recovery_welcome`, `record_outcome`
+
+/// Report whether our copy of a group still follows the other members
+/// Parameters: group_id - byte array of group ID
+Future<GroupHealth> getGroupHealth(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api
+        .crateApiRecoveryGetGroupHealth(identity: identity, groupId: groupId);
+
+/// Compare our group state with the epoch and tree hash of another member's `get_group_health`
+/// Parameters: group_id - byte array of group ID, tree_hash - hex encoded tree hash of the member
+Future<GroupStateComparison> compareGroupState(
+        {required String identity,
+        required List<int> groupId,
+        required BigInt epoch,
+        required String treeHash}) =>
+    RustLib.instance.api.crateApiRecoveryCompareGroupState(
+        identity: identity, groupId: groupId, epoch: epoch, treeHash: treeHash);
+
+/// Ask to be re-added to a group we fell out of sync with
+///
+/// NIP-EE groups publish no GroupInfo to join by external commit, so an admin removes our stale
+/// leaf and adds the fresh key package in one commit with `readd_member`. The welcome is then
+/// joined as usual, replacing our stale copy of the group.
+/// Parameters: group_id - byte array of group ID, relay - relays of the kind-443 event
+/// Returns: the key package to publish and the admins to ask
+Future<GroupRecoveryRequest> startGroupRecovery(
+        {required String identity,
+        required List<int> groupId,
+        List<String>? relay,
+        String? client}) =>
+    RustLib.instance.api.crateApiRecoveryStartGroupRecovery(
+        identity: identity, groupId: groupId, relay: relay, client: client);
+
+/// Stop waiting to be re-added to a group
+/// The key package of the recovery is retired; `maintain_key_packages` reports its kind-443
+/// event to delete.
+/// Parameters: group_id - byte array of group ID
+/// Returns: whether a recovery was in progress
+Future<bool> cancelGroupRecovery(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api.crateApiRecoveryCancelGroupRecovery(
+        identity: identity, groupId: groupId);
+
+/// Re-add a member who fell out of sync, removing its stale leaf in the same commit
+/// The commit stays pending until `confirm_commit_published`; send the welcome after it.
+/// Parameters: group_id - byte array of group ID, member_pubkey - hex public key of the member,
+/// key_package - serialized key package from the member's `start_group_recovery`
+/// Returns: serialized commit and welcome messages
+Future<AddMembersResult> readdMember(
+        {required String identity,
+        required List<int> groupId,
+        required String memberPubkey,
+        required String keyPackage}) =>
+    RustLib.instance.api.crateApiRecoveryReaddMember(
+        identity: identity,
+        groupId: groupId,
+        memberPubkey: memberPubkey,
+        keyPackage: keyPackage);
+
+/// Report of `get_group_health`
+class GroupHealth {
+  final Uint8List mlsGroupId;
+  final BigInt epoch;
+
+  /// Hex encoded tree hash of our epoch, equal for every member in sync
+  final String treeHash;
+
+  /// Events buffered because they could not be decrypted at our epoch
+  /// Anyone can post an event to a group, so buffered events alone never mean a fork;
+  /// confirm with `compare_group_state` against another member before recovering.
+  final int pendingMessageCount;
+  final BigInt? oldestPendingMessageAt;
+
+  /// Events dropped because they were of a past epoch, never decrypted or overflowed the buffer
+  final int undecryptableEventCount;
+  final BigInt? lastUndecryptableEventAt;
+
+  /// Failures and dropped events since the last event processed; a member who missed a
+  /// commit drops every later event once they no longer fit the buffer
+  final int consecutiveFailures;
+  final String? lastFailure;
+  final BigInt? lastFailureAt;
+  final BigInt? lastSuccessAt;
+
+  /// When `start_group_recovery` was called, `None` if no recovery is in progress
+  final BigInt? recoveryRequestedAt;
+
+  /// Id of the managed key package waiting for the re-add
+  final String? recoveryKeyPackageId;
+  final GroupHealthStatus status;
+
+  const GroupHealth({
+    required this.mlsGroupId,
+    required this.epoch,
+    required this.treeHash,
+    required this.pendingMessageCount,
+    this.oldestPendingMessageAt,
+    required this.undecryptableEventCount,
+    this.lastUndecryptableEventAt,
+    required this.consecutiveFailures,
+    this.lastFailure,
+    this.lastFailureAt,
+    this.lastSuccessAt,
+    this.recoveryRequestedAt,
+    this.recoveryKeyPackageId,
+    required this.status,
+  });
+
+  @override
+  int get hashCode =>
+      mlsGroupId.hashCode ^
+      epoch.hashCode ^
+      treeHash.hashCode ^
+      pendingMessageCount.hashCode ^
+      oldestPendingMessageAt.hashCode ^
+      undecryptableEventCount.hashCode ^
+      lastUndecryptableEventAt.hashCode ^
+      consecutiveFailures.hashCode ^
+      lastFailure.hashCode ^
+      lastFailureAt.hashCode ^
+      lastSuccessAt.hashCode ^
+      recoveryRequestedAt.hashCode ^
+      recoveryKeyPackageId.hashCode ^
+      status.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupHealth &&
+          runtimeType == other.runtimeType &&
+          mlsGroupId == other.mlsGroupId &&
+          epoch == other.epoch &&
+          treeHash == other.treeHash &&
+          pendingMessageCount == other.pendingMessageCount &&
+          oldestPendingMessageAt == other.oldestPendingMessageAt &&
+          undecryptableEventCount == other.undecryptableEventCount &&
+          lastUndecryptableEventAt == other.lastUndecryptableEventAt &&
+          consecutiveFailures == other.consecutiveFailures &&
+          lastFailure == other.lastFailure &&
+          lastFailureAt == other.lastFailureAt &&
+          lastSuccessAt == other.lastSuccessAt &&
+          recoveryRequestedAt == other.recoveryRequestedAt &&
+          recoveryKeyPackageId == other.recoveryKeyPackageId &&
+          status == other.status;
+}
+
+/// How well our copy of a group keeps up with the other members
+enum GroupHealthStatus {
+  healthy,
+
+  /// Recent failures or buffered events that may still resolve
+  lagging,
+
+  /// We most likely missed a commit; `start_group_recovery` gets us re-added
+  forked,
+  ;
+}
+
+/// Result of `start_group_recovery`, to hand to an admin of the group
+class GroupRecoveryRequest {
+  final Uint8List mlsGroupId;
+
+  /// Fresh key package to publish, confirmed with `mark_key_package_published`, for an admin
+  /// to pass to `readd_member`
+  final ManagedKeyPackage keyPackage;
+
+  /// Hex public keys of the admins who can re-add us
+  final List<String> admins;
+
+  const GroupRecoveryRequest({
+    required this.mlsGroupId,
+    required this.keyPackage,
+    required this.admins,
+  });
+
+  @override
+  int get hashCode =>
+      mlsGroupId.hashCode ^ keyPackage.hashCode ^ admins.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupRecoveryRequest &&
+          runtimeType == other.runtimeType &&
+          mlsGroupId == other.mlsGroupId &&
+          keyPackage == other.keyPackage &&
+          admins == other.admins;
+}
+
+/// Our group state compared to the epoch and tree hash reported by another member
+enum GroupStateComparison {
+  inSync,
+
+  /// The other member is at a later epoch
+  behind,
+
+  /// The other member is at an earlier epoch
+  ahead,
+
+  /// Same epoch with a different tree: one of us forked
+  diverged,
+  ;
+}
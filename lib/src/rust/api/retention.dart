@@ -0,0 +1,104 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `has_left`, `purge_group_state`, `retention_policy`
+
+/// Set the retention policy of an identity, applied by `apply_retention_policy`
+Future<void> setRetentionPolicy(
+        {required String identity, required RetentionPolicy policy}) =>
+    RustLib.instance.api.crateApiRetentionSetRetentionPolicy(
+        identity: identity, policy: policy);
+
+/// Get the retention policy of an identity, keeping messages and purging left groups by default
+Future<RetentionPolicy> getRetentionPolicy({required String identity}) =>
+    RustLib.instance.api
+        .crateApiRetentionGetRetentionPolicy(identity: identity);
+
+/// Delete everything stored about a group we are no longer in
+/// Its MLS state, the exporter secrets the keyring recorded and the bridge's bookkeeping and
+/// message indexes are deleted, and the database is compacted so none of it can be recovered
+/// from the file. nostr-mls has no API to delete its record of the group and its messages:
+/// those stay in its tables, but the bridge no longer returns them.
+/// Parameters: group_id - byte array of group ID of a group we left or were removed from
+Future<void> purgeGroup(
+        {required String identity, required List<int> groupId}) =>
+    RustLib.instance.api
+        .crateApiRetentionPurgeGroup(identity: identity, groupId: groupId);
+
+/// Purge the groups we are no longer in if the policy asks to, expire old messages and forget
+/// retired key packages, then compact the database
+/// Nothing else applies the policy: the app must call this periodically, e.g. at startup and
+/// then daily like `maintain_key_packages`. Expired messages are dropped from the bridge's
+/// indexes and no longer returned; nostr-mls keeps its copy, having no API to delete it.
+Future<RetentionReport> applyRetentionPolicy({required String identity}) =>
+    RustLib.instance.api
+        .crateApiRetentionApplyRetentionPolicy(identity: identity);
+
+/// Rewrite the database of an identity so deleted state cannot be recovered from the file
+Future<void> compactStorage({required String identity}) =>
+    RustLib.instance.api.crateApiRetentionCompactStorage(identity: identity);
+
+/// What an identity keeps of old messages and of the groups it is no longer in
+/// Nothing is expired or purged until the app calls `apply_retention_policy`: processing the
+/// commit that removed us keeps the group, so the app must schedule that call itself.
+class RetentionPolicy {
+  /// Seconds the bridge returns a decrypted message after we received it, `None` keeping
+  /// messages forever
+  final BigInt? messageMaxAgeSecs;
+
+  /// Purge the groups we left or were removed from when the policy is applied, on by default
+  final bool purgeInactiveGroups;
+
+  const RetentionPolicy({
+    this.messageMaxAgeSecs,
+    required this.purgeInactiveGroups,
+  });
+
+  static Future<RetentionPolicy> default_() =>
+      RustLib.instance.api.crateApiRetentionRetentionPolicyDefault();
+
+  @override
+  int get hashCode => messageMaxAgeSecs.hashCode ^ purgeInactiveGroups.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RetentionPolicy &&
+          runtimeType == other.runtimeType &&
+          messageMaxAgeSecs == other.messageMaxAgeSecs &&
+          purgeInactiveGroups == other.purgeInactiveGroups;
+}
+
+/// What `apply_retention_policy` deleted
+class RetentionReport {
+  final List<Uint8List> purgedGroups;
+  final int expiredMessages;
+  final int forgottenKeyPackages;
+
+  const RetentionReport({
+    required this.purgedGroups,
+    required this.expiredMessages,
+    required this.forgottenKeyPackages,
+  });
+
+  @override
+  int get hashCode =>
+      purgedGroups.hashCode ^
+      expiredMessages.hashCode ^
+      forgottenKeyPackages.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RetentionReport &&
+          runtimeType == other.runtimeType &&
+          purgedGroups == other.purgedGroups &&
+          expiredMessages == other.expiredMessages &&
+          forgottenKeyPackages == other.forgottenKeyPackages;
+}
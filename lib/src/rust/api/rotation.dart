@@ -0,0 +1,90 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `count_message`, `record_rotation`, `rotation_policy`
+
+/// Set the rotation policy applying to every group of an identity
+Future<void> setRotationPolicy(
+        {required String identity, required RotationPolicy policy}) =>
+    RustLib.instance.api
+        .crateApiRotationSetRotationPolicy(identity: identity, policy: policy);
+
+/// Get the rotation policy of an identity, a weekly or every 1000 messages default if none was set
+Future<RotationPolicy> getRotationPolicy({required String identity}) =>
+    RustLib.instance.api.crateApiRotationGetRotationPolicy(identity: identity);
+
+/// Get the rotation state of our leaf in every active group
+/// Parameters: due_only - only return the groups due for `self_update`
+Future<List<RotationStatus>> listRotationStatus(
+        {required String identity, required bool dueOnly}) =>
+    RustLib.instance.api.crateApiRotationListRotationStatus(
+        identity: identity, dueOnly: dueOnly);
+
+/// When our leaf in each group is due for a self-update
+/// A group is due once either limit is reached; `None` disables a limit.
+class RotationPolicy {
+  /// Messages sent and received in the group since the last rotation
+  final BigInt? maxMessages;
+
+  /// Seconds since the last rotation
+  final BigInt? maxAgeSecs;
+
+  const RotationPolicy({
+    this.maxMessages,
+    this.maxAgeSecs,
+  });
+
+  static Future<RotationPolicy> default_() =>
+      RustLib.instance.api.crateApiRotationRotationPolicyDefault();
+
+  @override
+  int get hashCode => maxMessages.hashCode ^ maxAgeSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RotationPolicy &&
+          runtimeType == other.runtimeType &&
+          maxMessages == other.maxMessages &&
+          maxAgeSecs == other.maxAgeSecs;
+}
+
+/// Rotation state of our leaf in a group
+class RotationStatus {
+  final Uint8List mlsGroupId;
+
+  /// Last self-update, or when the bridge started tracking the group
+  final BigInt lastRotatedAt;
+  final BigInt messagesSinceRotation;
+  final bool due;
+
+  const RotationStatus({
+    required this.mlsGroupId,
+    required this.lastRotatedAt,
+    required this.messagesSinceRotation,
+    required this.due,
+  });
+
+  @override
+  int get hashCode =>
+      mlsGroupId.hashCode ^
+      lastRotatedAt.hashCode ^
+      messagesSinceRotation.hashCode ^
+      due.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RotationStatus &&
+          runtimeType == other.runtimeType &&
+          mlsGroupId == other.mlsGroupId &&
+          lastRotatedAt == other.lastRotatedAt &&
+          messagesSinceRotation == other.messagesSinceRotation &&
+          due == other.due;
+}
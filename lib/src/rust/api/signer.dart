@@ -0,0 +1,64 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `declined`, `next_request_id`, `nip44_decrypt`, `nip44_encrypt`, `nip46_request`, `no_identity_key`, `public_key`, `resign`, `sign_event`
+// These types are ignored because they are not used by any `pub` functions: `Signer`
+
+/// Forget the identity's signer set by `use_dart_signer` or `use_nip46_signer`
+/// Gift-wrapped welcomes cannot be created or unwrapped until another one is set, and kind-445
+/// commit events are signed with a new random key again.
+Future<void> clearSigner({required String identity}) =>
+    RustLib.instance.api.crateApiSignerClearSigner(identity: identity);
+
+/// Sign with the identity's key held in Dart, e.g. in a platform keystore
+/// Each callback returns `None` when the key holder refuses or fails, failing the call using it.
+/// Parameters: scope - events signed with the key, public_key - hex public key of the identity,
+/// sign_event - takes the unsigned event JSON and returns the signed event JSON, nip44_encrypt /
+/// nip44_decrypt - take the hex public key of the other party and the plaintext / payload
+Future<void> useDartSigner(
+        {required String identity,
+        required SignerScope scope,
+        required String publicKey,
+        required FutureOr<String?> Function(String) signEvent,
+        required FutureOr<String?> Function(String, String) nip44Encrypt,
+        required FutureOr<String?> Function(String, String) nip44Decrypt}) =>
+    RustLib.instance.api.crateApiSignerUseDartSigner(
+        identity: identity,
+        scope: scope,
+        publicKey: publicKey,
+        signEvent: signEvent,
+        nip44Encrypt: nip44Encrypt,
+        nip44Decrypt: nip44Decrypt);
+
+/// Sign with the identity's key held by a NIP-46 remote signer
+/// Parameters: scope - events signed with the key, public_key - hex public key of the user at
+/// the remote signer, send_request - delivers the NIP-46 request JSON to the remote signer and
+/// returns its response JSON, or `None` when it did not answer
+Future<void> useNip46Signer(
+        {required String identity,
+        required SignerScope scope,
+        required String publicKey,
+        required FutureOr<String?> Function(String) sendRequest}) =>
+    RustLib.instance.api.crateApiSignerUseNip46Signer(
+        identity: identity,
+        scope: scope,
+        publicKey: publicKey,
+        sendRequest: sendRequest);
+
+/// Events signed by an identity's signer
+enum SignerScope {
+  /// Only the kind-13 seals of NIP-59 gift wraps; kind-445 events keep a new random key each,
+  /// so they never link the identity to a group as NIP-EE recommends
+  seals,
+
+  /// Seals and the kind-445 events of our own commits and proposals, e.g. for relays that only
+  /// accept group events from known keys
+  sealsAndCommits,
+  ;
+}
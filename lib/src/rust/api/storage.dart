@@ -0,0 +1,85 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'storage.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `db_path`, `ensure_closed`
+
+/// Initialize the NostrMls instance of an identity with a database key unwrapped in Rust
+/// On first use a random key is created, and an existing unencrypted database is encrypted
+/// with it. A database encrypted with a password must be decrypted with `rekey_storage` first.
+/// Parameters: path - directory of the database, identity - key used by every other call,
+/// source - what unwraps the database key
+Future<void> initNostrMlsWithKeySource(
+        {required String path,
+        required String identity,
+        required StorageKeySource source}) =>
+    RustLib.instance.api.crateApiStorageInitNostrMlsWithKeySource(
+        path: path, identity: identity, source: source);
+
+/// Initialize the NostrMls instance of an identity with a database key wrapped by the platform
+/// keystore (Android Keystore, iOS Keychain), so the key-encryption key never leaves it
+/// On first use a random key is created and wrapped, and an existing unencrypted database is
+/// encrypted with it. A database encrypted with a password must be decrypted with
+/// `rekey_storage` first.
+/// Parameters: path - directory of the database, identity - key used by every other call,
+/// wrap_key - takes the identity and the database key and returns the blob to store, or `null`
+/// on failure, unwrap_key - takes the identity and the stored blob and returns the database
+/// key, or `null` if the keystore cannot unwrap it
+Future<void> initNostrMlsWithKeystore(
+        {required String path,
+        required String identity,
+        required FutureOr<Uint8List?> Function(String, Uint8List) wrapKey,
+        required FutureOr<Uint8List?> Function(String, Uint8List) unwrapKey}) =>
+    RustLib.instance.api.crateApiStorageInitNostrMlsWithKeystore(
+        path: path, identity: identity, wrapKey: wrapKey, unwrapKey: unwrapKey);
+
+/// Change or remove the password of an identity's database
+/// The identity must be closed, and is opened again with `init_nostr_mls` and the new password.
+/// Parameters: path - directory of the database, old_password - the current password,
+/// new_password - `None` to store the database unencrypted
+Future<void> rekeyStorage(
+        {required String path,
+        required String identity,
+        required String oldPassword,
+        String? newPassword}) =>
+    RustLib.instance.api.crateApiStorageRekeyStorage(
+        path: path,
+        identity: identity,
+        oldPassword: oldPassword,
+        newPassword: newPassword);
+
+/// Encrypt the unencrypted database of an identity with a password
+/// The identity must be closed, and is opened again with `init_nostr_mls` and the password.
+/// Parameters: path - directory of the database, password - the new password
+Future<void> encryptExistingStorage(
+        {required String path,
+        required String identity,
+        required String password}) =>
+    RustLib.instance.api.crateApiStorageEncryptExistingStorage(
+        path: path, identity: identity, password: password);
+
+/// Where the key wrapping an identity's database key comes from
+/// The database key itself is random and never leaves Rust. A platform keystore wraps it
+/// through `init_nostr_mls_with_keystore` instead.
+/// No `Debug`, so a passphrase never ends up in a log.
+@freezed
+sealed class StorageKeySource with _$StorageKeySource {
+  const StorageKeySource._();
+
+  /// Derived from a passphrase with Argon2id, for platforms without a keystore
+  const factory StorageKeySource.passphrase({
+    required String passphrase,
+  }) = StorageKeySource_Passphrase;
+
+  /// Read from a file, created on first use; for tests and development only
+  const factory StorageKeySource.keyFile({
+    required String path,
+  }) = StorageKeySource_KeyFile;
+}
@@ -0,0 +1,372 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'storage.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$StorageKeySource {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String passphrase) passphrase,
+    required TResult Function(String path) keyFile,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String passphrase)? passphrase,
+    TResult? Function(String path)? keyFile,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String passphrase)? passphrase,
+    TResult Function(String path)? keyFile,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(StorageKeySource_Passphrase value) passphrase,
+    required TResult Function(StorageKeySource_KeyFile value) keyFile,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(StorageKeySource_Passphrase value)? passphrase,
+    TResult? Function(StorageKeySource_KeyFile value)? keyFile,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(StorageKeySource_Passphrase value)? passphrase,
+    TResult Function(StorageKeySource_KeyFile value)? keyFile,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $StorageKeySourceCopyWith<$Res> {
+  factory $StorageKeySourceCopyWith(
+          StorageKeySource value, $Res Function(StorageKeySource) then) =
+      _$StorageKeySourceCopyWithImpl<$Res, StorageKeySource>;
+}
+
+/// @nodoc
+class _$StorageKeySourceCopyWithImpl<$Res, $Val extends StorageKeySource>
+    implements $StorageKeySourceCopyWith<$Res> {
+  _$StorageKeySourceCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of StorageKeySource
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$StorageKeySource_PassphraseImplCopyWith<$Res> {
+  factory _$$StorageKeySource_PassphraseImplCopyWith(
+          _$StorageKeySource_PassphraseImpl value,
+          $Res Function(_$StorageKeySource_PassphraseImpl) then) =
+      __$$StorageKeySource_PassphraseImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String passphrase});
+}
+
+/// @nodoc
+class __$$StorageKeySource_PassphraseImplCopyWithImpl<$Res>
+    extends _$StorageKeySourceCopyWithImpl<$Res,
+        _$StorageKeySource_PassphraseImpl>
+    implements _$$StorageKeySource_PassphraseImplCopyWith<$Res> {
+  __$$StorageKeySource_PassphraseImplCopyWithImpl(
+      _$StorageKeySource_PassphraseImpl _value,
+      $Res Function(_$StorageKeySource_PassphraseImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of StorageKeySource
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? passphrase = null,
+  }) {
+    return _then(_$StorageKeySource_PassphraseImpl(
+      passphrase: null == passphrase
+          ? _value.passphrase
+          : passphrase // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$StorageKeySource_PassphraseImpl extends StorageKeySource_Passphrase {
+  const _$StorageKeySource_PassphraseImpl({required this.passphrase})
+      : super._();
+
+  @override
+  final String passphrase;
+
+  @override
+  String toString() {
+    return 'StorageKeySource.passphrase(passphrase: $passphrase)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$StorageKeySource_PassphraseImpl &&
+            (identical(other.passphrase, passphrase) ||
+                other.passphrase == passphrase));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, passphrase);
+
+  /// Create a copy of StorageKeySource
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$StorageKeySource_PassphraseImplCopyWith<_$StorageKeySource_PassphraseImpl>
+      get copyWith => __$$StorageKeySource_PassphraseImplCopyWithImpl<
+          _$StorageKeySource_PassphraseImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String passphrase) passphrase,
+    required TResult Function(String path) keyFile,
+  }) {
+    return passphrase(this.passphrase);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String passphrase)? passphrase,
+    TResult? Function(String path)? keyFile,
+  }) {
+    return passphrase?.call(this.passphrase);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String passphrase)? passphrase,
+    TResult Function(String path)? keyFile,
+    required TResult orElse(),
+  }) {
+    if (passphrase != null) {
+      return passphrase(this.passphrase);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(StorageKeySource_Passphrase value) passphrase,
+    required TResult Function(StorageKeySource_KeyFile value) keyFile,
+  }) {
+    return passphrase(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(StorageKeySource_Passphrase value)? passphrase,
+    TResult? Function(StorageKeySource_KeyFile value)? keyFile,
+  }) {
+    return passphrase?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(StorageKeySource_Passphrase value)? passphrase,
+    TResult Function(StorageKeySource_KeyFile value)? keyFile,
+    required TResult orElse(),
+  }) {
+    if (passphrase != null) {
+      return passphrase(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class StorageKeySource_Passphrase extends StorageKeySource {
+  const factory StorageKeySource_Passphrase(
+          {required final String passphrase}) =
+      _$StorageKeySource_PassphraseImpl;
+  const StorageKeySource_Passphrase._() : super._();
+
+  String get passphrase;
+
+  /// Create a copy of StorageKeySource
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$StorageKeySource_PassphraseImplCopyWith<_$StorageKeySource_PassphraseImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$StorageKeySource_KeyFileImplCopyWith<$Res> {
+  factory _$$StorageKeySource_KeyFileImplCopyWith(
+          _$StorageKeySource_KeyFileImpl value,
+          $Res Function(_$StorageKeySource_KeyFileImpl) then) =
+      __$$StorageKeySource_KeyFileImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String path});
+}
+
+/// @nodoc
+class __$$StorageKeySource_KeyFileImplCopyWithImpl<$Res>
+    extends _$StorageKeySourceCopyWithImpl<$Res, _$StorageKeySource_KeyFileImpl>
+    implements _$$StorageKeySource_KeyFileImplCopyWith<$Res> {
+  __$$StorageKeySource_KeyFileImplCopyWithImpl(
+      _$StorageKeySource_KeyFileImpl _value,
+      $Res Function(_$StorageKeySource_KeyFileImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of StorageKeySource
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? path = null,
+  }) {
+    return _then(_$StorageKeySource_KeyFileImpl(
+      path: null == path
+          ? _value.path
+          : path // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$StorageKeySource_KeyFileImpl extends StorageKeySource_KeyFile {
+  const _$StorageKeySource_KeyFileImpl({required this.path}) : super._();
+
+  @override
+  final String path;
+
+  @override
+  String toString() {
+    return 'StorageKeySource.keyFile(path: $path)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$StorageKeySource_KeyFileImpl &&
+            (identical(other.path, path) || other.path == path));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, path);
+
+  /// Create a copy of StorageKeySource
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$StorageKeySource_KeyFileImplCopyWith<_$StorageKeySource_KeyFileImpl>
+      get copyWith => __$$StorageKeySource_KeyFileImplCopyWithImpl<
+          _$StorageKeySource_KeyFileImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String passphrase) passphrase,
+    required TResult Function(String path) keyFile,
+  }) {
+    return keyFile(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String passphrase)? passphrase,
+    TResult? Function(String path)? keyFile,
+  }) {
+    return keyFile?.call(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String passphrase)? passphrase,
+    TResult Function(String path)? keyFile,
+    required TResult orElse(),
+  }) {
+    if (keyFile != null) {
+      return keyFile(path);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(StorageKeySource_Passphrase value) passphrase,
+    required TResult Function(StorageKeySource_KeyFile value) keyFile,
+  }) {
+    return keyFile(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(StorageKeySource_Passphrase value)? passphrase,
+    TResult? Function(StorageKeySource_KeyFile value)? keyFile,
+  }) {
+    return keyFile?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(StorageKeySource_Passphrase value)? passphrase,
+    TResult Function(StorageKeySource_KeyFile value)? keyFile,
+    required TResult orElse(),
+  }) {
+    if (keyFile != null) {
+      return keyFile(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class StorageKeySource_KeyFile extends StorageKeySource {
+  const factory StorageKeySource_KeyFile({required final String path}) =
+      _$StorageKeySource_KeyFileImpl;
+  const StorageKeySource_KeyFile._() : super._();
+
+  String get path;
+
+  /// Create a copy of StorageKeySource
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$StorageKeySource_KeyFileImplCopyWith<_$StorageKeySource_KeyFileImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
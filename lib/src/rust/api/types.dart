@@ -0,0 +1,491 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `matches`, `new`, `new`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`, `from`, `from`
+
+/// Result of `add_members` and `readd_member`
+/// Committing received proposals with `commit_pending_proposals` returns a `ProposalCommitResult`.
+class AddMembersResult {
+  final Uint8List commitMessage;
+  final Uint8List welcomeMessage;
+
+  const AddMembersResult({
+    required this.commitMessage,
+    required this.welcomeMessage,
+  });
+
+  @override
+  int get hashCode => commitMessage.hashCode ^ welcomeMessage.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AddMembersResult &&
+          runtimeType == other.runtimeType &&
+          commitMessage == other.commitMessage &&
+          welcomeMessage == other.welcomeMessage;
+}
+
+/// Result of `remove_members` and `leave_group`
+class CommitResult {
+  final Uint8List serializedCommit;
+
+  const CommitResult({
+    required this.serializedCommit,
+  });
+
+  @override
+  int get hashCode => serializedCommit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CommitResult &&
+          runtimeType == other.runtimeType &&
+          serializedCommit == other.serializedCommit;
+}
+
+/// Result of `create_group`
+class CreateGroupResult {
+  final GroupInfo group;
+  final Uint8List serializedWelcomeMessage;
+
+  const CreateGroupResult({
+    required this.group,
+    required this.serializedWelcomeMessage,
+  });
+
+  @override
+  int get hashCode => group.hashCode ^ serializedWelcomeMessage.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CreateGroupResult &&
+          runtimeType == other.runtimeType &&
+          group == other.group &&
+          serializedWelcomeMessage == other.serializedWelcomeMessage;
+}
+
+/// A decrypted application message (the inner rumor of a kind-445 event)
+class DecryptedMessage {
+  /// Hex encoded rumor id
+  final String eventId;
+
+  /// Hex encoded author public key
+  final String pubkey;
+  final int kind;
+  final Uint8List mlsGroupId;
+  final BigInt createdAt;
+  final String content;
+  final List<List<String>> tags;
+
+  /// JSON serialized rumor
+  final String rumorJson;
+
+  const DecryptedMessage({
+    required this.eventId,
+    required this.pubkey,
+    required this.kind,
+    required this.mlsGroupId,
+    required this.createdAt,
+    required this.content,
+    required this.tags,
+    required this.rumorJson,
+  });
+
+  @override
+  int get hashCode =>
+      eventId.hashCode ^
+      pubkey.hashCode ^
+      kind.hashCode ^
+      mlsGroupId.hashCode ^
+      createdAt.hashCode ^
+      content.hashCode ^
+      tags.hashCode ^
+      rumorJson.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DecryptedMessage &&
+          runtimeType == other.runtimeType &&
+          eventId == other.eventId &&
+          pubkey == other.pubkey &&
+          kind == other.kind &&
+          mlsGroupId == other.mlsGroupId &&
+          createdAt == other.createdAt &&
+          content == other.content &&
+          tags == other.tags &&
+          rumorJson == other.rumorJson;
+}
+
+/// A signed nostr event ready to be published
+class EventResult {
+  /// Hex encoded event id
+  final String eventId;
+
+  /// JSON serialized event
+  final String eventJson;
+
+  const EventResult({
+    required this.eventId,
+    required this.eventJson,
+  });
+
+  @override
+  int get hashCode => eventId.hashCode ^ eventJson.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EventResult &&
+          runtimeType == other.runtimeType &&
+          eventId == other.eventId &&
+          eventJson == other.eventJson;
+}
+
+/// Exporter secret of a group at a given epoch
+class ExportedSecret {
+  final Uint8List secret;
+  final BigInt epoch;
+
+  const ExportedSecret({
+    required this.secret,
+    required this.epoch,
+  });
+
+  @override
+  int get hashCode => secret.hashCode ^ epoch.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExportedSecret &&
+          runtimeType == other.runtimeType &&
+          secret == other.secret &&
+          epoch == other.epoch;
+}
+
+/// Group information returned by group creation, lookup and join calls
+class GroupInfo {
+  final Uint8List mlsGroupId;
+
+  /// Hex encoded member public keys
+  final List<String> members;
+  final NostrGroupData nostrGroupData;
+
+  const GroupInfo({
+    required this.mlsGroupId,
+    required this.members,
+    required this.nostrGroupData,
+  });
+
+  @override
+  int get hashCode =>
+      mlsGroupId.hashCode ^ members.hashCode ^ nostrGroupData.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupInfo &&
+          runtimeType == other.runtimeType &&
+          mlsGroupId == other.mlsGroupId &&
+          members == other.members &&
+          nostrGroupData == other.nostrGroupData;
+}
+
+/// Which groups `list_groups` returns
+enum GroupStateFilter {
+  all,
+  active,
+
+  /// Groups we left or were removed from
+  left,
+  pending,
+  ;
+}
+
+/// State of a locally stored group
+enum GroupStatus {
+  active,
+  left,
+  pending,
+  ;
+}
+
+/// Summary of a locally stored group
+class GroupSummary {
+  final Uint8List mlsGroupId;
+
+  /// Hex encoded nostr group id
+  final String nostrGroupId;
+  final String name;
+  final String description;
+  final BigInt epoch;
+  final int memberCount;
+
+  /// Hex encoded id of the last message
+  final String? lastMessageId;
+  final BigInt? lastMessageAt;
+  final GroupStatus status;
+
+  const GroupSummary({
+    required this.mlsGroupId,
+    required this.nostrGroupId,
+    required this.name,
+    required this.description,
+    required this.epoch,
+    required this.memberCount,
+    this.lastMessageId,
+    this.lastMessageAt,
+    required this.status,
+  });
+
+  @override
+  int get hashCode =>
+      mlsGroupId.hashCode ^
+      nostrGroupId.hashCode ^
+      name.hashCode ^
+      description.hashCode ^
+      epoch.hashCode ^
+      memberCount.hashCode ^
+      lastMessageId.hashCode ^
+      lastMessageAt.hashCode ^
+      status.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupSummary &&
+          runtimeType == other.runtimeType &&
+          mlsGroupId == other.mlsGroupId &&
+          nostrGroupId == other.nostrGroupId &&
+          name == other.name &&
+          description == other.description &&
+          epoch == other.epoch &&
+          memberCount == other.memberCount &&
+          lastMessageId == other.lastMessageId &&
+          lastMessageAt == other.lastMessageAt &&
+          status == other.status;
+}
+
+/// Result of `get_key_package_from_storage`
+class KeyPackageLookup {
+  final bool found;
+  final String? keyPackage;
+
+  const KeyPackageLookup({
+    required this.found,
+    this.keyPackage,
+  });
+
+  @override
+  int get hashCode => found.hashCode ^ keyPackage.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is KeyPackageLookup &&
+          runtimeType == other.runtimeType &&
+          found == other.found &&
+          keyPackage == other.keyPackage;
+}
+
+/// Encoded key package and the tags to publish with its kind-443 event
+class KeyPackageResult {
+  final String encodedKeyPackage;
+  final List<List<String>> tags;
+
+  const KeyPackageResult({
+    required this.encodedKeyPackage,
+    required this.tags,
+  });
+
+  @override
+  int get hashCode => encodedKeyPackage.hashCode ^ tags.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is KeyPackageResult &&
+          runtimeType == other.runtimeType &&
+          encodedKeyPackage == other.encodedKeyPackage &&
+          tags == other.tags;
+}
+
+/// Nostr specific group data carried in the NostrGroupData extension
+class NostrGroupData {
+  /// Hex encoded nostr group id, used in the `h` tag of kind-445 events
+  final String nostrGroupId;
+  final String name;
+  final String description;
+
+  /// Hex encoded admin public keys
+  final List<String> adminPubkeys;
+  final List<String> relays;
+
+  const NostrGroupData({
+    required this.nostrGroupId,
+    required this.name,
+    required this.description,
+    required this.adminPubkeys,
+    required this.relays,
+  });
+
+  @override
+  int get hashCode =>
+      nostrGroupId.hashCode ^
+      name.hashCode ^
+      description.hashCode ^
+      adminPubkeys.hashCode ^
+      relays.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NostrGroupData &&
+          runtimeType == other.runtimeType &&
+          nostrGroupId == other.nostrGroupId &&
+          name == other.name &&
+          description == other.description &&
+          adminPubkeys == other.adminPubkeys &&
+          relays == other.relays;
+}
+
+/// Result of processing a kind-445 group event or a staged commit
+class ProcessedMessage {
+  final DecryptedMessage? message;
+  final List<String> addedMembers;
+  final List<String> removedMembers;
+  final Uint8List? commit;
+  final Uint8List? welcome;
+  final Uint8List? stagedMessageBytes;
+
+  /// The event is for an epoch not reached yet and was buffered for a later retry
+  final bool pending;
+
+  /// Previously buffered messages decrypted after this commit advanced the group
+  final List<DecryptedMessage> releasedMessages;
+
+  /// The commit lost the race for its epoch to an earlier one and was not applied
+  final bool superseded;
+
+  /// Our own pending commit of the same epoch lost to this commit and was discarded
+  final bool ownCommitRolledBack;
+
+  const ProcessedMessage({
+    this.message,
+    required this.addedMembers,
+    required this.removedMembers,
+    this.commit,
+    this.welcome,
+    this.stagedMessageBytes,
+    required this.pending,
+    required this.releasedMessages,
+    required this.superseded,
+    required this.ownCommitRolledBack,
+  });
+
+  @override
+  int get hashCode =>
+      message.hashCode ^
+      addedMembers.hashCode ^
+      removedMembers.hashCode ^
+      commit.hashCode ^
+      welcome.hashCode ^
+      stagedMessageBytes.hashCode ^
+      pending.hashCode ^
+      releasedMessages.hashCode ^
+      superseded.hashCode ^
+      ownCommitRolledBack.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessedMessage &&
+          runtimeType == other.runtimeType &&
+          message == other.message &&
+          addedMembers == other.addedMembers &&
+          removedMembers == other.removedMembers &&
+          commit == other.commit &&
+          welcome == other.welcome &&
+          stagedMessageBytes == other.stagedMessageBytes &&
+          pending == other.pending &&
+          releasedMessages == other.releasedMessages &&
+          superseded == other.superseded &&
+          ownCommitRolledBack == other.ownCommitRolledBack;
+}
+
+/// Result of `find_encoded_keypackage_from_welcome_event`
+class WelcomeKeyPackageMatch {
+  final bool found;
+  final int? matchedIndex;
+
+  const WelcomeKeyPackageMatch({
+    required this.found,
+    this.matchedIndex,
+  });
+
+  @override
+  int get hashCode => found.hashCode ^ matchedIndex.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WelcomeKeyPackageMatch &&
+          runtimeType == other.runtimeType &&
+          found == other.found &&
+          matchedIndex == other.matchedIndex;
+}
+
+/// Summary of a welcome stored locally
+class WelcomeSummary {
+  /// Hex encoded id of the kind-444 welcome rumor
+  final String welcomeEventId;
+
+  /// Hex encoded id of the gift wrap carrying the welcome
+  final String wrapperEventId;
+  final Uint8List mlsGroupId;
+  final NostrGroupData nostrGroupData;
+
+  /// Hex encoded public key of the member who sent the welcome
+  final String welcomer;
+  final int memberCount;
+
+  const WelcomeSummary({
+    required this.welcomeEventId,
+    required this.wrapperEventId,
+    required this.mlsGroupId,
+    required this.nostrGroupData,
+    required this.welcomer,
+    required this.memberCount,
+  });
+
+  @override
+  int get hashCode =>
+      welcomeEventId.hashCode ^
+      wrapperEventId.hashCode ^
+      mlsGroupId.hashCode ^
+      nostrGroupData.hashCode ^
+      welcomer.hashCode ^
+      memberCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WelcomeSummary &&
+          runtimeType == other.runtimeType &&
+          welcomeEventId == other.welcomeEventId &&
+          wrapperEventId == other.wrapperEventId &&
+          mlsGroupId == other.mlsGroupId &&
+          nostrGroupData == other.nostrGroupData &&
+          welcomer == other.welcomer &&
+          memberCount == other.memberCount;
+}
@@ -0,0 +1,256 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'types.dart';
+
+// These functions are ignored because they are not marked as `pub`: `delete_unmanaged_key_packages`, `from_json`, `matches`, `pending_welcome_uses_key_package`, `to_json`, `tweaked_timestamp`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `try_from`
+
+/// Build one NIP-59 gift-wrapped welcome per invited member
+///
+/// The kind-444 rumor is sealed (kind 13) by the identity's signer and wrapped (kind 1059)
+/// with a new random key for each member.
+/// Parameters: serialized_welcome_message - welcome returned by `create_group` or `add_members`,
+/// relays - relays the members should read group messages from
+/// Returns: the gift wraps to publish, in recipient order
+Future<List<GiftWrappedWelcome>> createWelcomeGiftWraps(
+        {required String identity,
+        required List<int> serializedWelcomeMessage,
+        required List<WelcomeRecipient> recipients,
+        required List<String> relays}) =>
+    RustLib.instance.api.crateApiWelcomesCreateWelcomeGiftWraps(
+        identity: identity,
+        serializedWelcomeMessage: serializedWelcomeMessage,
+        recipients: recipients,
+        relays: relays);
+
+/// Unwrap an incoming kind-1059 gift wrap into its kind-444 welcome rumor
+/// Parameters: gift_wrap_event_json - JSON string of the gift wrap
+Future<UnwrappedWelcome> unwrapWelcome(
+        {required String identity, required String giftWrapEventJson}) =>
+    RustLib.instance.api.crateApiWelcomesUnwrapWelcome(
+        identity: identity, giftWrapEventJson: giftWrapEventJson);
+
+/// Preview the group of a gift-wrapped welcome without joining it
+/// Parameters: gift_wrap_event_json - JSON string of the kind-1059 gift wrap
+Future<GroupInfo> previewGiftWrappedWelcome(
+        {required String identity, required String giftWrapEventJson}) =>
+    RustLib.instance.api.crateApiWelcomesPreviewGiftWrappedWelcome(
+        identity: identity, giftWrapEventJson: giftWrapEventJson);
+
+/// Join the group of a gift-wrapped welcome
+/// Parameters: gift_wrap_event_json - JSON string of the kind-1059 gift wrap
+Future<GroupInfo> joinGiftWrappedWelcome(
+        {required String identity, required String giftWrapEventJson}) =>
+    RustLib.instance.api.crateApiWelcomesJoinGiftWrappedWelcome(
+        identity: identity, giftWrapEventJson: giftWrapEventJson);
+
+/// Put a welcome in the inbox with a preview of its group
+/// Receiving a welcome already in the inbox returns it unchanged
+/// Parameters: wrapper_event_id - byte array of event ID, rumor_event_string - JSON string of the event
+Future<InboxWelcome> receiveWelcome(
+        {required String identity,
+        required List<int> wrapperEventId,
+        required String rumorEventString}) =>
+    RustLib.instance.api.crateApiWelcomesReceiveWelcome(
+        identity: identity,
+        wrapperEventId: wrapperEventId,
+        rumorEventString: rumorEventString);
+
+/// Unwrap a kind-1059 gift wrap and put its welcome in the inbox
+/// Parameters: gift_wrap_event_json - JSON string of the gift wrap
+Future<InboxWelcome> receiveGiftWrappedWelcome(
+        {required String identity, required String giftWrapEventJson}) =>
+    RustLib.instance.api.crateApiWelcomesReceiveGiftWrappedWelcome(
+        identity: identity, giftWrapEventJson: giftWrapEventJson);
+
+/// Join the group of a welcome in the inbox
+/// Parameters: id - id of the `InboxWelcome`
+/// Returns: information about the joined group
+Future<GroupInfo> acceptWelcome(
+        {required String identity, required String id}) =>
+    RustLib.instance.api
+        .crateApiWelcomesAcceptWelcome(identity: identity, id: id);
+
+/// Decline a welcome in the inbox
+/// A one-time managed key package it was sent to, or one made with
+/// `create_key_package_for_event`, loses its private material unless another pending welcome was
+/// sent to it too; a last resort one is rotated by the next `maintain_key_packages`.
+/// Parameters: id - id of the `InboxWelcome`
+/// Returns: whether the welcome is in the inbox
+Future<bool> declineWelcome({required String identity, required String id}) =>
+    RustLib.instance.api
+        .crateApiWelcomesDeclineWelcome(identity: identity, id: id);
+
+/// List the welcomes of the inbox, newest first
+/// Welcomes whose stored row cannot be read are skipped and counted in `unreadable`
+Future<WelcomeList> listWelcomes(
+        {required String identity, required WelcomeStateFilter filter}) =>
+    RustLib.instance.api
+        .crateApiWelcomesListWelcomes(identity: identity, filter: filter);
+
+/// A kind-1059 gift wrap carrying a welcome to one member
+class GiftWrappedWelcome {
+  /// Hex encoded public key of the member
+  final String recipient;
+  final EventResult event;
+
+  const GiftWrappedWelcome({
+    required this.recipient,
+    required this.event,
+  });
+
+  @override
+  int get hashCode => recipient.hashCode ^ event.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GiftWrappedWelcome &&
+          runtimeType == other.runtimeType &&
+          recipient == other.recipient &&
+          event == other.event;
+}
+
+/// A welcome kept in the inbox
+class InboxWelcome {
+  /// Hex encoded id of the gift wrap carrying the welcome
+  final String id;
+
+  /// Hex encoded public key of the member who sent the welcome
+  final String sender;
+
+  /// Group as previewed when the welcome was received
+  final GroupInfo group;
+  final WelcomeState state;
+  final BigInt receivedAt;
+
+  const InboxWelcome({
+    required this.id,
+    required this.sender,
+    required this.group,
+    required this.state,
+    required this.receivedAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      sender.hashCode ^
+      group.hashCode ^
+      state.hashCode ^
+      receivedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InboxWelcome &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          sender == other.sender &&
+          group == other.group &&
+          state == other.state &&
+          receivedAt == other.receivedAt;
+}
+
+/// The kind-444 welcome rumor taken out of a gift wrap
+class UnwrappedWelcome {
+  /// Hex encoded id of the gift wrap
+  final String wrapperEventId;
+
+  /// Hex encoded public key of the member who sent the welcome
+  final String sender;
+
+  /// JSON serialized kind-444 rumor
+  final String rumorJson;
+
+  const UnwrappedWelcome({
+    required this.wrapperEventId,
+    required this.sender,
+    required this.rumorJson,
+  });
+
+  @override
+  int get hashCode =>
+      wrapperEventId.hashCode ^ sender.hashCode ^ rumorJson.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UnwrappedWelcome &&
+          runtimeType == other.runtimeType &&
+          wrapperEventId == other.wrapperEventId &&
+          sender == other.sender &&
+          rumorJson == other.rumorJson;
+}
+
+/// Result of `list_welcomes`
+class WelcomeList {
+  final List<InboxWelcome> welcomes;
+
+  /// Welcomes left out because their stored row cannot be read
+  final int unreadable;
+
+  const WelcomeList({
+    required this.welcomes,
+    required this.unreadable,
+  });
+
+  @override
+  int get hashCode => welcomes.hashCode ^ unreadable.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WelcomeList &&
+          runtimeType == other.runtimeType &&
+          welcomes == other.welcomes &&
+          unreadable == other.unreadable;
+}
+
+/// A member invited by `create_group` or `add_members`
+class WelcomeRecipient {
+  /// Hex encoded public key of the member
+  final String publicKey;
+
+  /// Hex encoded id of the kind-443 event of the key package the member was added with
+  final String keyPackageEventId;
+
+  const WelcomeRecipient({
+    required this.publicKey,
+    required this.keyPackageEventId,
+  });
+
+  @override
+  int get hashCode => publicKey.hashCode ^ keyPackageEventId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WelcomeRecipient &&
+          runtimeType == other.runtimeType &&
+          publicKey == other.publicKey &&
+          keyPackageEventId == other.keyPackageEventId;
+}
+
+/// State of a welcome in the inbox
+enum WelcomeState {
+  pending,
+  accepted,
+  declined,
+  ;
+}
+
+/// Which welcomes `list_welcomes` returns
+enum WelcomeStateFilter {
+  all,
+  pending,
+  accepted,
+  declined,
+  ;
+}
@@ -3,7 +3,23 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/batch.dart';
+import 'api/commits.dart';
+import 'api/error.dart';
+import 'api/events.dart';
+import 'api/key_packages.dart';
+import 'api/keyring.dart';
+import 'api/media.dart';
+import 'api/messages.dart';
 import 'api/mls_api.dart';
+import 'api/policy.dart';
+import 'api/recovery.dart';
+import 'api/retention.dart';
+import 'api/rotation.dart';
+import 'api/signer.dart';
+import 'api/storage.dart';
+import 'api/types.dart';
+import 'api/welcomes.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -66,7 +82,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.7.0';
 
   @override
-  int get rustContentHash => -2106810556;
+  int get rustContentHash => 848021627;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -77,20 +93,162 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
-  Future<String> crateApiMlsApiAddMembers(
-      {required List<int> groupId,
+  Future<List<BatchEventResult>> crateApiBatchProcessMessagesBatch(
+      {required String identity, required List<String> events});
+
+  Future<bool> crateApiCommitsAbortCommit(
+      {required String identity, required List<int> groupId});
+
+  Future<bool> crateApiCommitsClearPendingCommit(
+      {required String identity, required List<int> groupId});
+
+  Future<ProposalCommitResult> crateApiCommitsCommitPendingProposals(
+      {required String identity,
+      required List<int> groupId,
+      List<String>? selection});
+
+  Future<List<DecryptedMessage>> crateApiCommitsConfirmCommitPublished(
+      {required String identity,
+      required List<int> groupId,
+      required String eventId});
+
+  Future<PendingCommit?> crateApiCommitsGetPendingCommit(
+      {required String identity, required List<int> groupId});
+
+  Future<List<ProposalSummary>> crateApiCommitsListPendingProposals(
+      {required String identity, required List<int> groupId});
+
+  Future<List<DecryptedMessage>> crateApiCommitsMergePendingCommit(
+      {required String identity, required List<int> groupId});
+
+  Future<EventResult> crateApiCommitsProposeAdd(
+      {required String identity,
+      required List<int> groupId,
+      required String keyPackage});
+
+  Future<EventResult> crateApiCommitsProposeRemove(
+      {required String identity,
+      required List<int> groupId,
+      required String memberPubkey});
+
+  Future<EventResult> crateApiCommitsProposeUpdate(
+      {required String identity, required List<int> groupId});
+
+  Future<EventResult> crateApiCommitsSelfUpdate(
+      {required String identity, required List<int> groupId});
+
+  Future<EventResult> crateApiCommitsUpdateGroupData(
+      {required String identity,
+      required List<int> groupId,
+      String? name,
+      String? description,
+      List<String>? admins,
+      List<String>? relays});
+
+  String crateApiErrorMlsBridgeErrorCode({required MlsBridgeError that});
+
+  Stream<GroupEvent> crateApiEventsSubscribeGroupEvents(
+      {required String identity});
+
+  Future<KeyPackagePolicy> crateApiKeyPackagesKeyPackagePolicyDefault();
+
+  Future<List<ManagedKeyPackage>> crateApiKeyPackagesListKeyPackages(
+      {required String identity});
+
+  Future<KeyPackageRotation> crateApiKeyPackagesMaintainKeyPackages(
+      {required String identity,
+      required String publicKey,
+      List<String>? relay,
+      String? client,
+      required KeyPackagePolicy policy});
+
+  Future<bool> crateApiKeyPackagesMarkKeyPackageDeleted(
+      {required String identity, required String keyPackageId});
+
+  Future<bool> crateApiKeyPackagesMarkKeyPackagePublished(
+      {required String identity,
+      required String keyPackageId,
+      required String eventId});
+
+  Future<KeyringPolicy> crateApiKeyringKeyringPolicyDefault();
+
+  Future<ExportedSecret> crateApiKeyringExportSecretForEpoch(
+      {required String identity,
+      required List<int> groupId,
+      required BigInt epoch});
+
+  Future<KeyringPolicy> crateApiKeyringGetKeyringPolicy(
+      {required String identity});
+
+  Future<Uint64List> crateApiKeyringListRetainedEpochs(
+      {required String identity, required List<int> groupId});
+
+  Future<void> crateApiKeyringSetKeyringPolicy(
+      {required String identity, required KeyringPolicy policy});
+
+  Future<List<String>> crateApiMediaBuildMediaImetaTag(
+      {required MediaReference reference, required String url});
+
+  Future<Uint8List> crateApiMediaDecryptMedia(
+      {required String identity,
+      required List<int> groupId,
+      required List<String> imetaTag,
+      required List<int> encryptedData});
+
+  Future<Uint8List> crateApiMediaDownloadMedia(
+      {required String identity,
+      required List<int> groupId,
+      required List<String> imetaTag,
+      required FutureOr<Uint8List?> Function(String) download});
+
+  Future<EncryptedMedia> crateApiMediaEncryptMedia(
+      {required String identity,
+      required List<int> groupId,
+      required List<int> data,
+      required String mimeType,
+      required String filename});
+
+  Future<MediaReference> crateApiMediaParseMediaImetaTag(
+      {required List<String> imetaTag});
+
+  Future<UploadedMedia> crateApiMediaUploadMedia(
+      {required String identity,
+      required List<int> groupId,
+      required List<int> data,
+      required String mimeType,
+      required String filename,
+      required FutureOr<String?> Function(Uint8List, String) upload});
+
+  Future<DecryptedMessage?> crateApiMessagesGetMessage(
+      {required String identity, required String eventId});
+
+  Future<List<DecryptedMessage>> crateApiMessagesGetMessages(
+      {required String identity,
+      required List<int> groupId,
+      MessageCursor? before,
+      required int limit});
+
+  Future<List<DecryptedMessage>> crateApiMessagesSearchMessages(
+      {required String identity,
+      required List<int> groupId,
+      required String query,
+      required int limit});
+
+  Future<AddMembersResult> crateApiMlsApiAddMembers(
+      {required String identity,
+      required List<int> groupId,
       required List<String> serializedKeyPackages});
 
-  Future<String> crateApiMlsApiCommitProposal(
-      {required List<int> groupId, required String proposal});
+  Future<bool> crateApiMlsApiCloseNostrMls({required String identity});
 
-  Future<String> crateApiMlsApiCreateCommitMessageForGroup(
-      {required String nostrGroupId,
-      required List<int> serializedCommit,
-      required U8Array32 secretKey});
+  Future<EventResult> crateApiMlsApiCreateCommitMessageForGroup(
+      {required String identity,
+      required String nostrGroupId,
+      required List<int> serializedCommit});
 
-  Future<String> crateApiMlsApiCreateGroup(
-      {required String groupName,
+  Future<CreateGroupResult> crateApiMlsApiCreateGroup(
+      {required String identity,
+      required String groupName,
       required String groupDescription,
       required List<String> groupMembersSerializedKeyPackages,
       required List<String> groupMembersPubkeys,
@@ -98,49 +256,215 @@ abstract class RustLibApi extends BaseApi {
       required List<String> groupAdminPublicKeys,
       required List<String> relays});
 
-  Future<String> crateApiMlsApiCreateKeyPackageForEvent(
-      {required String publicKey, List<String>? relay, String? client});
+  Future<KeyPackageResult> crateApiMlsApiCreateKeyPackageForEvent(
+      {required String identity,
+      required String publicKey,
+      List<String>? relay,
+      String? client});
+
+  Future<EventResult> crateApiMlsApiCreateMessageForGroup(
+      {required String identity,
+      required List<int> groupId,
+      required String rumorEventString});
+
+  Future<ExportedSecret> crateApiMlsApiExportSecret(
+      {required String identity, required List<int> groupId});
+
+  Future<WelcomeKeyPackageMatch>
+      crateApiMlsApiFindEncodedKeypackageFromWelcomeEvent(
+          {required String identity,
+          required List<String> encodedKeypackages,
+          required List<int> wrapperEventId,
+          required String rumorEventString});
+
+  Future<int> crateApiMlsApiGetCiphersuite({required String identity});
+
+  Future<List<String>> crateApiMlsApiGetExtensions({required String identity});
+
+  Future<GroupInfo> crateApiMlsApiGetGroup(
+      {required String identity, required List<int> groupId});
+
+  Future<KeyPackageLookup> crateApiMlsApiGetKeyPackageFromStorage(
+      {required String identity, required String serializedKeyPackage});
+
+  Future<List<String>> crateApiMlsApiGetMembers(
+      {required String identity, required List<int> groupId});
+
+  Future<int> crateApiMlsApiGetPendingMessageCount(
+      {required String identity, required List<int> groupId});
+
+  Future<void> crateApiMlsApiInitNostrMls(
+      {required String path, required String identity, String? password});
+
+  Future<GroupInfo> crateApiMlsApiJoinGroupFromWelcome(
+      {required String identity,
+      required List<int> wrapperEventId,
+      required String rumorEventString});
+
+  Future<CommitResult> crateApiMlsApiLeaveGroup(
+      {required String identity, required List<int> groupId});
+
+  Future<List<GroupSummary>> crateApiMlsApiListGroups(
+      {required String identity, required GroupStateFilter filter});
 
-  Future<String> crateApiMlsApiCreateMessageForGroup(
-      {required List<int> groupId, required String rumorEventString});
+  Future<List<String>> crateApiMlsApiListNostrMlsIdentities();
 
-  Future<String> crateApiMlsApiExportSecret({required List<int> groupId});
+  Future<List<WelcomeSummary>> crateApiMlsApiListPendingWelcomes(
+      {required String identity});
 
-  Future<String> crateApiMlsApiFindEncodedKeypackageFromWelcomeEvent(
-      {required List<String> encodedKeypackages,
+  Future<GroupInfo> crateApiMlsApiPreviewGroupFromWelcome(
+      {required String identity,
       required List<int> wrapperEventId,
       required String rumorEventString});
 
-  Future<String> crateApiMlsApiGetCiphersuite();
+  Future<ProcessedMessage> crateApiMlsApiProcessCommitMessageForGroup(
+      {required String identity,
+      required List<int> groupId,
+      required List<int> messageBytes});
+
+  Future<ProcessedMessage> crateApiMlsApiProcessMessageForGroup(
+      {required String identity, required String eventString});
+
+  Future<CommitResult> crateApiMlsApiRemoveMembers(
+      {required String identity,
+      required List<int> groupId,
+      required List<String> memberPubkeys});
+
+  Future<GroupPolicy> crateApiPolicyGroupPolicyDefault();
+
+  Future<GroupPolicy> crateApiPolicyGetGroupPolicy(
+      {required String identity, required List<int> groupId});
+
+  Future<List<CommitAuditEntry>> crateApiPolicyListCommitAudit(
+      {required String identity,
+      required List<int> groupId,
+      required int limit});
+
+  Future<void> crateApiPolicySetGroupPolicy(
+      {required String identity,
+      required List<int> groupId,
+      required GroupPolicy policy});
+
+  Future<bool> crateApiRecoveryCancelGroupRecovery(
+      {required String identity, required List<int> groupId});
+
+  Future<GroupStateComparison> crateApiRecoveryCompareGroupState(
+      {required String identity,
+      required List<int> groupId,
+      required BigInt epoch,
+      required String treeHash});
+
+  Future<GroupHealth> crateApiRecoveryGetGroupHealth(
+      {required String identity, required List<int> groupId});
+
+  Future<AddMembersResult> crateApiRecoveryReaddMember(
+      {required String identity,
+      required List<int> groupId,
+      required String memberPubkey,
+      required String keyPackage});
+
+  Future<GroupRecoveryRequest> crateApiRecoveryStartGroupRecovery(
+      {required String identity,
+      required List<int> groupId,
+      List<String>? relay,
+      String? client});
+
+  Future<RetentionPolicy> crateApiRetentionRetentionPolicyDefault();
+
+  Future<RetentionReport> crateApiRetentionApplyRetentionPolicy(
+      {required String identity});
+
+  Future<void> crateApiRetentionCompactStorage({required String identity});
 
-  Future<String> crateApiMlsApiGetExtensions();
+  Future<RetentionPolicy> crateApiRetentionGetRetentionPolicy(
+      {required String identity});
 
-  Future<String> crateApiMlsApiGetGroup({required List<int> groupId});
+  Future<void> crateApiRetentionPurgeGroup(
+      {required String identity, required List<int> groupId});
 
-  Future<String> crateApiMlsApiGetKeyPackageFromStorage(
-      {required String serializedKeyPackage});
+  Future<void> crateApiRetentionSetRetentionPolicy(
+      {required String identity, required RetentionPolicy policy});
 
-  Future<String> crateApiMlsApiGetMembers({required List<int> groupId});
+  Future<RotationPolicy> crateApiRotationRotationPolicyDefault();
 
-  Future<String> crateApiMlsApiInitNostrMls(
-      {required String path, String? identity, String? password});
+  Future<RotationPolicy> crateApiRotationGetRotationPolicy(
+      {required String identity});
 
-  Future<String> crateApiMlsApiJoinGroupFromWelcome(
-      {required List<int> wrapperEventId, required String rumorEventString});
+  Future<List<RotationStatus>> crateApiRotationListRotationStatus(
+      {required String identity, required bool dueOnly});
 
-  Future<String> crateApiMlsApiLeaveGroup({required List<int> groupId});
+  Future<void> crateApiRotationSetRotationPolicy(
+      {required String identity, required RotationPolicy policy});
 
-  Future<String> crateApiMlsApiPreviewGroupFromWelcome(
-      {required List<int> wrapperEventId, required String rumorEventString});
+  Future<void> crateApiSignerClearSigner({required String identity});
 
-  Future<String> crateApiMlsApiProcessCommitMessageForGroup(
-      {required List<int> groupId, required List<int> messageBytes});
+  Future<void> crateApiSignerUseDartSigner(
+      {required String identity,
+      required SignerScope scope,
+      required String publicKey,
+      required FutureOr<String?> Function(String) signEvent,
+      required FutureOr<String?> Function(String, String) nip44Encrypt,
+      required FutureOr<String?> Function(String, String) nip44Decrypt});
 
-  Future<String> crateApiMlsApiProcessMessageForGroup(
-      {required String eventString});
+  Future<void> crateApiSignerUseNip46Signer(
+      {required String identity,
+      required SignerScope scope,
+      required String publicKey,
+      required FutureOr<String?> Function(String) sendRequest});
 
-  Future<String> crateApiMlsApiRemoveMembers(
-      {required List<int> groupId, required List<String> memberPubkeys});
+  Future<void> crateApiStorageEncryptExistingStorage(
+      {required String path,
+      required String identity,
+      required String password});
+
+  Future<void> crateApiStorageInitNostrMlsWithKeySource(
+      {required String path,
+      required String identity,
+      required StorageKeySource source});
+
+  Future<void> crateApiStorageInitNostrMlsWithKeystore(
+      {required String path,
+      required String identity,
+      required FutureOr<Uint8List?> Function(String, Uint8List) wrapKey,
+      required FutureOr<Uint8List?> Function(String, Uint8List) unwrapKey});
+
+  Future<void> crateApiStorageRekeyStorage(
+      {required String path,
+      required String identity,
+      required String oldPassword,
+      String? newPassword});
+
+  Future<GroupInfo> crateApiWelcomesAcceptWelcome(
+      {required String identity, required String id});
+
+  Future<List<GiftWrappedWelcome>> crateApiWelcomesCreateWelcomeGiftWraps(
+      {required String identity,
+      required List<int> serializedWelcomeMessage,
+      required List<WelcomeRecipient> recipients,
+      required List<String> relays});
+
+  Future<bool> crateApiWelcomesDeclineWelcome(
+      {required String identity, required String id});
+
+  Future<GroupInfo> crateApiWelcomesJoinGiftWrappedWelcome(
+      {required String identity, required String giftWrapEventJson});
+
+  Future<WelcomeList> crateApiWelcomesListWelcomes(
+      {required String identity, required WelcomeStateFilter filter});
+
+  Future<GroupInfo> crateApiWelcomesPreviewGiftWrappedWelcome(
+      {required String identity, required String giftWrapEventJson});
+
+  Future<InboxWelcome> crateApiWelcomesReceiveGiftWrappedWelcome(
+      {required String identity, required String giftWrapEventJson});
+
+  Future<InboxWelcome> crateApiWelcomesReceiveWelcome(
+      {required String identity,
+      required List<int> wrapperEventId,
+      required String rumorEventString});
+
+  Future<UnwrappedWelcome> crateApiWelcomesUnwrapWelcome(
+      {required String identity, required String giftWrapEventJson});
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -152,764 +476,6198 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<String> crateApiMlsApiAddMembers(
-      {required List<int> groupId,
-      required List<String> serializedKeyPackages}) {
+  Future<List<BatchEventResult>> crateApiBatchProcessMessagesBatch(
+      {required String identity, required List<String> events}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_u_8_loose(groupId, serializer);
-        sse_encode_list_String(serializedKeyPackages, serializer);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_String(events, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 1, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_list_batch_event_result,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiAddMembersConstMeta,
-      argValues: [groupId, serializedKeyPackages],
+      constMeta: kCrateApiBatchProcessMessagesBatchConstMeta,
+      argValues: [identity, events],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiAddMembersConstMeta => const TaskConstMeta(
-        debugName: "add_members",
-        argNames: ["groupId", "serializedKeyPackages"],
+  TaskConstMeta get kCrateApiBatchProcessMessagesBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "process_messages_batch",
+        argNames: ["identity", "events"],
       );
 
   @override
-  Future<String> crateApiMlsApiCommitProposal(
-      {required List<int> groupId, required String proposal}) {
+  Future<bool> crateApiCommitsAbortCommit(
+      {required String identity, required List<int> groupId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
         sse_encode_list_prim_u_8_loose(groupId, serializer);
-        sse_encode_String(proposal, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 2, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiCommitProposalConstMeta,
-      argValues: [groupId, proposal],
+      constMeta: kCrateApiCommitsAbortCommitConstMeta,
+      argValues: [identity, groupId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiCommitProposalConstMeta =>
-      const TaskConstMeta(
-        debugName: "commit_proposal",
-        argNames: ["groupId", "proposal"],
+  TaskConstMeta get kCrateApiCommitsAbortCommitConstMeta => const TaskConstMeta(
+        debugName: "abort_commit",
+        argNames: ["identity", "groupId"],
       );
 
   @override
-  Future<String> crateApiMlsApiCreateCommitMessageForGroup(
-      {required String nostrGroupId,
-      required List<int> serializedCommit,
-      required U8Array32 secretKey}) {
+  Future<bool> crateApiCommitsClearPendingCommit(
+      {required String identity, required List<int> groupId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(nostrGroupId, serializer);
-        sse_encode_list_prim_u_8_loose(serializedCommit, serializer);
-        sse_encode_u_8_array_32(secretKey, serializer);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 3, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiCreateCommitMessageForGroupConstMeta,
-      argValues: [nostrGroupId, serializedCommit, secretKey],
+      constMeta: kCrateApiCommitsClearPendingCommitConstMeta,
+      argValues: [identity, groupId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiCreateCommitMessageForGroupConstMeta =>
+  TaskConstMeta get kCrateApiCommitsClearPendingCommitConstMeta =>
       const TaskConstMeta(
-        debugName: "create_commit_message_for_group",
-        argNames: ["nostrGroupId", "serializedCommit", "secretKey"],
+        debugName: "clear_pending_commit",
+        argNames: ["identity", "groupId"],
       );
 
   @override
-  Future<String> crateApiMlsApiCreateGroup(
-      {required String groupName,
-      required String groupDescription,
-      required List<String> groupMembersSerializedKeyPackages,
-      required List<String> groupMembersPubkeys,
-      required String groupCreatorPublicKey,
-      required List<String> groupAdminPublicKeys,
-      required List<String> relays}) {
+  Future<ProposalCommitResult> crateApiCommitsCommitPendingProposals(
+      {required String identity,
+      required List<int> groupId,
+      List<String>? selection}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(groupName, serializer);
-        sse_encode_String(groupDescription, serializer);
-        sse_encode_list_String(groupMembersSerializedKeyPackages, serializer);
-        sse_encode_list_String(groupMembersPubkeys, serializer);
-        sse_encode_String(groupCreatorPublicKey, serializer);
-        sse_encode_list_String(groupAdminPublicKeys, serializer);
-        sse_encode_list_String(relays, serializer);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_opt_list_String(selection, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 4, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_proposal_commit_result,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiCreateGroupConstMeta,
-      argValues: [
-        groupName,
-        groupDescription,
-        groupMembersSerializedKeyPackages,
-        groupMembersPubkeys,
-        groupCreatorPublicKey,
-        groupAdminPublicKeys,
-        relays
-      ],
+      constMeta: kCrateApiCommitsCommitPendingProposalsConstMeta,
+      argValues: [identity, groupId, selection],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiCreateGroupConstMeta => const TaskConstMeta(
-        debugName: "create_group",
-        argNames: [
-          "groupName",
-          "groupDescription",
-          "groupMembersSerializedKeyPackages",
-          "groupMembersPubkeys",
-          "groupCreatorPublicKey",
-          "groupAdminPublicKeys",
-          "relays"
-        ],
+  TaskConstMeta get kCrateApiCommitsCommitPendingProposalsConstMeta =>
+      const TaskConstMeta(
+        debugName: "commit_pending_proposals",
+        argNames: ["identity", "groupId", "selection"],
       );
 
   @override
-  Future<String> crateApiMlsApiCreateKeyPackageForEvent(
-      {required String publicKey, List<String>? relay, String? client}) {
+  Future<List<DecryptedMessage>> crateApiCommitsConfirmCommitPublished(
+      {required String identity,
+      required List<int> groupId,
+      required String eventId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(publicKey, serializer);
-        sse_encode_opt_list_String(relay, serializer);
-        sse_encode_opt_String(client, serializer);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_String(eventId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 5, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_list_decrypted_message,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiCreateKeyPackageForEventConstMeta,
-      argValues: [publicKey, relay, client],
+      constMeta: kCrateApiCommitsConfirmCommitPublishedConstMeta,
+      argValues: [identity, groupId, eventId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiCreateKeyPackageForEventConstMeta =>
+  TaskConstMeta get kCrateApiCommitsConfirmCommitPublishedConstMeta =>
       const TaskConstMeta(
-        debugName: "create_key_package_for_event",
-        argNames: ["publicKey", "relay", "client"],
+        debugName: "confirm_commit_published",
+        argNames: ["identity", "groupId", "eventId"],
       );
 
   @override
-  Future<String> crateApiMlsApiCreateMessageForGroup(
-      {required List<int> groupId, required String rumorEventString}) {
+  Future<PendingCommit?> crateApiCommitsGetPendingCommit(
+      {required String identity, required List<int> groupId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
         sse_encode_list_prim_u_8_loose(groupId, serializer);
-        sse_encode_String(rumorEventString, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 6, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_opt_box_autoadd_pending_commit,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiCreateMessageForGroupConstMeta,
-      argValues: [groupId, rumorEventString],
+      constMeta: kCrateApiCommitsGetPendingCommitConstMeta,
+      argValues: [identity, groupId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiCreateMessageForGroupConstMeta =>
+  TaskConstMeta get kCrateApiCommitsGetPendingCommitConstMeta =>
       const TaskConstMeta(
-        debugName: "create_message_for_group",
-        argNames: ["groupId", "rumorEventString"],
+        debugName: "get_pending_commit",
+        argNames: ["identity", "groupId"],
       );
 
   @override
-  Future<String> crateApiMlsApiExportSecret({required List<int> groupId}) {
+  Future<List<ProposalSummary>> crateApiCommitsListPendingProposals(
+      {required String identity, required List<int> groupId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
         sse_encode_list_prim_u_8_loose(groupId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 7, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_list_proposal_summary,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiExportSecretConstMeta,
-      argValues: [groupId],
+      constMeta: kCrateApiCommitsListPendingProposalsConstMeta,
+      argValues: [identity, groupId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiExportSecretConstMeta => const TaskConstMeta(
-        debugName: "export_secret",
-        argNames: ["groupId"],
+  TaskConstMeta get kCrateApiCommitsListPendingProposalsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_pending_proposals",
+        argNames: ["identity", "groupId"],
       );
 
   @override
-  Future<String> crateApiMlsApiFindEncodedKeypackageFromWelcomeEvent(
-      {required List<String> encodedKeypackages,
-      required List<int> wrapperEventId,
-      required String rumorEventString}) {
+  Future<List<DecryptedMessage>> crateApiCommitsMergePendingCommit(
+      {required String identity, required List<int> groupId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_String(encodedKeypackages, serializer);
-        sse_encode_list_prim_u_8_loose(wrapperEventId, serializer);
-        sse_encode_String(rumorEventString, serializer);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 8, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_list_decrypted_message,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiFindEncodedKeypackageFromWelcomeEventConstMeta,
-      argValues: [encodedKeypackages, wrapperEventId, rumorEventString],
+      constMeta: kCrateApiCommitsMergePendingCommitConstMeta,
+      argValues: [identity, groupId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta
-      get kCrateApiMlsApiFindEncodedKeypackageFromWelcomeEventConstMeta =>
-          const TaskConstMeta(
-            debugName: "find_encoded_keypackage_from_welcome_event",
-            argNames: [
-              "encodedKeypackages",
-              "wrapperEventId",
-              "rumorEventString"
-            ],
-          );
+  TaskConstMeta get kCrateApiCommitsMergePendingCommitConstMeta =>
+      const TaskConstMeta(
+        debugName: "merge_pending_commit",
+        argNames: ["identity", "groupId"],
+      );
 
   @override
-  Future<String> crateApiMlsApiGetCiphersuite() {
+  Future<EventResult> crateApiCommitsProposeAdd(
+      {required String identity,
+      required List<int> groupId,
+      required String keyPackage}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_String(keyPackage, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 9, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_event_result,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiGetCiphersuiteConstMeta,
-      argValues: [],
+      constMeta: kCrateApiCommitsProposeAddConstMeta,
+      argValues: [identity, groupId, keyPackage],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiGetCiphersuiteConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_ciphersuite",
-        argNames: [],
+  TaskConstMeta get kCrateApiCommitsProposeAddConstMeta => const TaskConstMeta(
+        debugName: "propose_add",
+        argNames: ["identity", "groupId", "keyPackage"],
       );
 
   @override
-  Future<String> crateApiMlsApiGetExtensions() {
+  Future<EventResult> crateApiCommitsProposeRemove(
+      {required String identity,
+      required List<int> groupId,
+      required String memberPubkey}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_String(memberPubkey, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 10, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_event_result,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiGetExtensionsConstMeta,
-      argValues: [],
+      constMeta: kCrateApiCommitsProposeRemoveConstMeta,
+      argValues: [identity, groupId, memberPubkey],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiGetExtensionsConstMeta =>
+  TaskConstMeta get kCrateApiCommitsProposeRemoveConstMeta =>
       const TaskConstMeta(
-        debugName: "get_extensions",
-        argNames: [],
+        debugName: "propose_remove",
+        argNames: ["identity", "groupId", "memberPubkey"],
       );
 
   @override
-  Future<String> crateApiMlsApiGetGroup({required List<int> groupId}) {
+  Future<EventResult> crateApiCommitsProposeUpdate(
+      {required String identity, required List<int> groupId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
         sse_encode_list_prim_u_8_loose(groupId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 11, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_event_result,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiGetGroupConstMeta,
-      argValues: [groupId],
+      constMeta: kCrateApiCommitsProposeUpdateConstMeta,
+      argValues: [identity, groupId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiGetGroupConstMeta => const TaskConstMeta(
-        debugName: "get_group",
-        argNames: ["groupId"],
+  TaskConstMeta get kCrateApiCommitsProposeUpdateConstMeta =>
+      const TaskConstMeta(
+        debugName: "propose_update",
+        argNames: ["identity", "groupId"],
       );
 
   @override
-  Future<String> crateApiMlsApiGetKeyPackageFromStorage(
-      {required String serializedKeyPackage}) {
+  Future<EventResult> crateApiCommitsSelfUpdate(
+      {required String identity, required List<int> groupId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(serializedKeyPackage, serializer);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 12, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_event_result,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiGetKeyPackageFromStorageConstMeta,
-      argValues: [serializedKeyPackage],
+      constMeta: kCrateApiCommitsSelfUpdateConstMeta,
+      argValues: [identity, groupId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiGetKeyPackageFromStorageConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_key_package_from_storage",
-        argNames: ["serializedKeyPackage"],
+  TaskConstMeta get kCrateApiCommitsSelfUpdateConstMeta => const TaskConstMeta(
+        debugName: "self_update",
+        argNames: ["identity", "groupId"],
       );
 
   @override
-  Future<String> crateApiMlsApiGetMembers({required List<int> groupId}) {
+  Future<EventResult> crateApiCommitsUpdateGroupData(
+      {required String identity,
+      required List<int> groupId,
+      String? name,
+      String? description,
+      List<String>? admins,
+      List<String>? relays}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
         sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_opt_String(name, serializer);
+        sse_encode_opt_String(description, serializer);
+        sse_encode_opt_list_String(admins, serializer);
+        sse_encode_opt_list_String(relays, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 13, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_event_result,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiGetMembersConstMeta,
-      argValues: [groupId],
+      constMeta: kCrateApiCommitsUpdateGroupDataConstMeta,
+      argValues: [identity, groupId, name, description, admins, relays],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiGetMembersConstMeta => const TaskConstMeta(
-        debugName: "get_members",
-        argNames: ["groupId"],
+  TaskConstMeta get kCrateApiCommitsUpdateGroupDataConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_group_data",
+        argNames: [
+          "identity",
+          "groupId",
+          "name",
+          "description",
+          "admins",
+          "relays"
+        ],
       );
 
   @override
-  Future<String> crateApiMlsApiInitNostrMls(
-      {required String path, String? identity, String? password}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  String crateApiErrorMlsBridgeErrorCode({required MlsBridgeError that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        sse_encode_opt_String(identity, serializer);
-        sse_encode_opt_String(password, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 14, port: port_);
+        sse_encode_box_autoadd_mls_bridge_error(that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiMlsApiInitNostrMlsConstMeta,
-      argValues: [path, identity, password],
+      constMeta: kCrateApiErrorMlsBridgeErrorCodeConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiInitNostrMlsConstMeta => const TaskConstMeta(
-        debugName: "init_nostr_mls",
-        argNames: ["path", "identity", "password"],
+  TaskConstMeta get kCrateApiErrorMlsBridgeErrorCodeConstMeta =>
+      const TaskConstMeta(
+        debugName: "MlsBridgeError_code",
+        argNames: ["that"],
       );
 
   @override
-  Future<String> crateApiMlsApiJoinGroupFromWelcome(
-      {required List<int> wrapperEventId, required String rumorEventString}) {
-    return handler.executeNormal(NormalTask(
+  Stream<GroupEvent> crateApiEventsSubscribeGroupEvents(
+      {required String identity}) {
+    final sink = RustStreamSink<GroupEvent>();
+    unawaited(handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_u_8_loose(wrapperEventId, serializer);
-        sse_encode_String(rumorEventString, serializer);
+        sse_encode_String(identity, serializer);
+        sse_encode_StreamSink_group_event_Sse(sink, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 15, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiJoinGroupFromWelcomeConstMeta,
-      argValues: [wrapperEventId, rumorEventString],
+      constMeta: kCrateApiEventsSubscribeGroupEventsConstMeta,
+      argValues: [identity, sink],
       apiImpl: this,
-    ));
+    )));
+    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiMlsApiJoinGroupFromWelcomeConstMeta =>
+  TaskConstMeta get kCrateApiEventsSubscribeGroupEventsConstMeta =>
       const TaskConstMeta(
-        debugName: "join_group_from_welcome",
-        argNames: ["wrapperEventId", "rumorEventString"],
+        debugName: "subscribe_group_events",
+        argNames: ["identity", "sink"],
       );
 
   @override
-  Future<String> crateApiMlsApiLeaveGroup({required List<int> groupId}) {
+  Future<KeyPackagePolicy> crateApiKeyPackagesKeyPackagePolicyDefault() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_u_8_loose(groupId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 16, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_key_package_policy,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiMlsApiLeaveGroupConstMeta,
-      argValues: [groupId],
+      constMeta: kCrateApiKeyPackagesKeyPackagePolicyDefaultConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiLeaveGroupConstMeta => const TaskConstMeta(
-        debugName: "leave_group",
-        argNames: ["groupId"],
+  TaskConstMeta get kCrateApiKeyPackagesKeyPackagePolicyDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "KeyPackagePolicy_default",
+        argNames: [],
       );
 
   @override
-  Future<String> crateApiMlsApiPreviewGroupFromWelcome(
-      {required List<int> wrapperEventId, required String rumorEventString}) {
+  Future<List<ManagedKeyPackage>> crateApiKeyPackagesListKeyPackages(
+      {required String identity}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_u_8_loose(wrapperEventId, serializer);
-        sse_encode_String(rumorEventString, serializer);
+        sse_encode_String(identity, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 17, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_list_managed_key_package,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiPreviewGroupFromWelcomeConstMeta,
-      argValues: [wrapperEventId, rumorEventString],
+      constMeta: kCrateApiKeyPackagesListKeyPackagesConstMeta,
+      argValues: [identity],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiPreviewGroupFromWelcomeConstMeta =>
+  TaskConstMeta get kCrateApiKeyPackagesListKeyPackagesConstMeta =>
       const TaskConstMeta(
-        debugName: "preview_group_from_welcome",
-        argNames: ["wrapperEventId", "rumorEventString"],
+        debugName: "list_key_packages",
+        argNames: ["identity"],
       );
 
   @override
-  Future<String> crateApiMlsApiProcessCommitMessageForGroup(
-      {required List<int> groupId, required List<int> messageBytes}) {
+  Future<KeyPackageRotation> crateApiKeyPackagesMaintainKeyPackages(
+      {required String identity,
+      required String publicKey,
+      List<String>? relay,
+      String? client,
+      required KeyPackagePolicy policy}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_u_8_loose(groupId, serializer);
-        sse_encode_list_prim_u_8_loose(messageBytes, serializer);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(publicKey, serializer);
+        sse_encode_opt_list_String(relay, serializer);
+        sse_encode_opt_String(client, serializer);
+        sse_encode_box_autoadd_key_package_policy(policy, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 18, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_key_package_rotation,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiProcessCommitMessageForGroupConstMeta,
-      argValues: [groupId, messageBytes],
+      constMeta: kCrateApiKeyPackagesMaintainKeyPackagesConstMeta,
+      argValues: [identity, publicKey, relay, client, policy],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiProcessCommitMessageForGroupConstMeta =>
+  TaskConstMeta get kCrateApiKeyPackagesMaintainKeyPackagesConstMeta =>
       const TaskConstMeta(
-        debugName: "process_commit_message_for_group",
-        argNames: ["groupId", "messageBytes"],
+        debugName: "maintain_key_packages",
+        argNames: ["identity", "publicKey", "relay", "client", "policy"],
       );
 
   @override
-  Future<String> crateApiMlsApiProcessMessageForGroup(
-      {required String eventString}) {
+  Future<bool> crateApiKeyPackagesMarkKeyPackageDeleted(
+      {required String identity, required String keyPackageId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(eventString, serializer);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(keyPackageId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 19, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiProcessMessageForGroupConstMeta,
-      argValues: [eventString],
+      constMeta: kCrateApiKeyPackagesMarkKeyPackageDeletedConstMeta,
+      argValues: [identity, keyPackageId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiProcessMessageForGroupConstMeta =>
+  TaskConstMeta get kCrateApiKeyPackagesMarkKeyPackageDeletedConstMeta =>
       const TaskConstMeta(
-        debugName: "process_message_for_group",
-        argNames: ["eventString"],
+        debugName: "mark_key_package_deleted",
+        argNames: ["identity", "keyPackageId"],
       );
 
   @override
-  Future<String> crateApiMlsApiRemoveMembers(
-      {required List<int> groupId, required List<String> memberPubkeys}) {
+  Future<bool> crateApiKeyPackagesMarkKeyPackagePublished(
+      {required String identity,
+      required String keyPackageId,
+      required String eventId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_u_8_loose(groupId, serializer);
-        sse_encode_list_String(memberPubkeys, serializer);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(keyPackageId, serializer);
+        sse_encode_String(eventId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 20, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_mls_bridge_error,
       ),
-      constMeta: kCrateApiMlsApiRemoveMembersConstMeta,
-      argValues: [groupId, memberPubkeys],
+      constMeta: kCrateApiKeyPackagesMarkKeyPackagePublishedConstMeta,
+      argValues: [identity, keyPackageId, eventId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiKeyPackagesMarkKeyPackagePublishedConstMeta =>
+      const TaskConstMeta(
+        debugName: "mark_key_package_published",
+        argNames: ["identity", "keyPackageId", "eventId"],
+      );
+
+  @override
+  Future<KeyringPolicy> crateApiKeyringKeyringPolicyDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 21, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_keyring_policy,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiKeyringKeyringPolicyDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiKeyringKeyringPolicyDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "KeyringPolicy_default",
+        argNames: [],
+      );
+
+  @override
+  Future<ExportedSecret> crateApiKeyringExportSecretForEpoch(
+      {required String identity,
+      required List<int> groupId,
+      required BigInt epoch}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_u_64(epoch, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 22, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_exported_secret,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiKeyringExportSecretForEpochConstMeta,
+      argValues: [identity, groupId, epoch],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiKeyringExportSecretForEpochConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_secret_for_epoch",
+        argNames: ["identity", "groupId", "epoch"],
+      );
+
+  @override
+  Future<KeyringPolicy> crateApiKeyringGetKeyringPolicy(
+      {required String identity}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 23, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_keyring_policy,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiKeyringGetKeyringPolicyConstMeta,
+      argValues: [identity],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiKeyringGetKeyringPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_keyring_policy",
+        argNames: ["identity"],
+      );
+
+  @override
+  Future<Uint64List> crateApiKeyringListRetainedEpochs(
+      {required String identity, required List<int> groupId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 24, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_64_strict,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiKeyringListRetainedEpochsConstMeta,
+      argValues: [identity, groupId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiKeyringListRetainedEpochsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_retained_epochs",
+        argNames: ["identity", "groupId"],
+      );
+
+  @override
+  Future<void> crateApiKeyringSetKeyringPolicy(
+      {required String identity, required KeyringPolicy policy}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_box_autoadd_keyring_policy(policy, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 25, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiKeyringSetKeyringPolicyConstMeta,
+      argValues: [identity, policy],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiKeyringSetKeyringPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_keyring_policy",
+        argNames: ["identity", "policy"],
+      );
+
+  @override
+  Future<List<String>> crateApiMediaBuildMediaImetaTag(
+      {required MediaReference reference, required String url}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_media_reference(reference, serializer);
+        sse_encode_String(url, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 26, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMediaBuildMediaImetaTagConstMeta,
+      argValues: [reference, url],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMediaBuildMediaImetaTagConstMeta =>
+      const TaskConstMeta(
+        debugName: "build_media_imeta_tag",
+        argNames: ["reference", "url"],
+      );
+
+  @override
+  Future<Uint8List> crateApiMediaDecryptMedia(
+      {required String identity,
+      required List<int> groupId,
+      required List<String> imetaTag,
+      required List<int> encryptedData}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_list_String(imetaTag, serializer);
+        sse_encode_list_prim_u_8_loose(encryptedData, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 27, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMediaDecryptMediaConstMeta,
+      argValues: [identity, groupId, imetaTag, encryptedData],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMediaDecryptMediaConstMeta => const TaskConstMeta(
+        debugName: "decrypt_media",
+        argNames: ["identity", "groupId", "imetaTag", "encryptedData"],
+      );
+
+  @override
+  Future<Uint8List> crateApiMediaDownloadMedia(
+      {required String identity,
+      required List<int> groupId,
+      required List<String> imetaTag,
+      required FutureOr<Uint8List?> Function(String) download}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_list_String(imetaTag, serializer);
+        sse_encode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+            download, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 28, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMediaDownloadMediaConstMeta,
+      argValues: [identity, groupId, imetaTag, download],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMediaDownloadMediaConstMeta => const TaskConstMeta(
+        debugName: "download_media",
+        argNames: ["identity", "groupId", "imetaTag", "download"],
+      );
+
+  @override
+  Future<EncryptedMedia> crateApiMediaEncryptMedia(
+      {required String identity,
+      required List<int> groupId,
+      required List<int> data,
+      required String mimeType,
+      required String filename}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_list_prim_u_8_loose(data, serializer);
+        sse_encode_String(mimeType, serializer);
+        sse_encode_String(filename, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 29, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_encrypted_media,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMediaEncryptMediaConstMeta,
+      argValues: [identity, groupId, data, mimeType, filename],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMediaEncryptMediaConstMeta => const TaskConstMeta(
+        debugName: "encrypt_media",
+        argNames: ["identity", "groupId", "data", "mimeType", "filename"],
+      );
+
+  @override
+  Future<MediaReference> crateApiMediaParseMediaImetaTag(
+      {required List<String> imetaTag}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_String(imetaTag, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 30, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_media_reference,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMediaParseMediaImetaTagConstMeta,
+      argValues: [imetaTag],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMediaParseMediaImetaTagConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_media_imeta_tag",
+        argNames: ["imetaTag"],
+      );
+
+  @override
+  Future<UploadedMedia> crateApiMediaUploadMedia(
+      {required String identity,
+      required List<int> groupId,
+      required List<int> data,
+      required String mimeType,
+      required String filename,
+      required FutureOr<String?> Function(Uint8List, String) upload}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_list_prim_u_8_loose(data, serializer);
+        sse_encode_String(mimeType, serializer);
+        sse_encode_String(filename, serializer);
+        sse_encode_DartFn_Inputs_list_prim_u_8_strict_String_Output_opt_String_AnyhowException(
+            upload, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 31, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_uploaded_media,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMediaUploadMediaConstMeta,
+      argValues: [identity, groupId, data, mimeType, filename, upload],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMediaUploadMediaConstMeta => const TaskConstMeta(
+        debugName: "upload_media",
+        argNames: [
+          "identity",
+          "groupId",
+          "data",
+          "mimeType",
+          "filename",
+          "upload"
+        ],
+      );
+
+  @override
+  Future<DecryptedMessage?> crateApiMessagesGetMessage(
+      {required String identity, required String eventId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(eventId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 32, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_decrypted_message,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMessagesGetMessageConstMeta,
+      argValues: [identity, eventId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMessagesGetMessageConstMeta => const TaskConstMeta(
+        debugName: "get_message",
+        argNames: ["identity", "eventId"],
+      );
+
+  @override
+  Future<List<DecryptedMessage>> crateApiMessagesGetMessages(
+      {required String identity,
+      required List<int> groupId,
+      MessageCursor? before,
+      required int limit}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_opt_box_autoadd_message_cursor(before, serializer);
+        sse_encode_u_32(limit, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 33, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_decrypted_message,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMessagesGetMessagesConstMeta,
+      argValues: [identity, groupId, before, limit],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMessagesGetMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_messages",
+        argNames: ["identity", "groupId", "before", "limit"],
+      );
+
+  @override
+  Future<List<DecryptedMessage>> crateApiMessagesSearchMessages(
+      {required String identity,
+      required List<int> groupId,
+      required String query,
+      required int limit}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_String(query, serializer);
+        sse_encode_u_32(limit, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 34, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_decrypted_message,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMessagesSearchMessagesConstMeta,
+      argValues: [identity, groupId, query, limit],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMessagesSearchMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_messages",
+        argNames: ["identity", "groupId", "query", "limit"],
+      );
+
+  @override
+  Future<AddMembersResult> crateApiMlsApiAddMembers(
+      {required String identity,
+      required List<int> groupId,
+      required List<String> serializedKeyPackages}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_list_String(serializedKeyPackages, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 35, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_add_members_result,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiAddMembersConstMeta,
+      argValues: [identity, groupId, serializedKeyPackages],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiAddMembersConstMeta => const TaskConstMeta(
+        debugName: "add_members",
+        argNames: ["identity", "groupId", "serializedKeyPackages"],
+      );
+
+  @override
+  Future<bool> crateApiMlsApiCloseNostrMls({required String identity}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 36, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiCloseNostrMlsConstMeta,
+      argValues: [identity],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMlsApiRemoveMembersConstMeta =>
-      const TaskConstMeta(
-        debugName: "remove_members",
-        argNames: ["groupId", "memberPubkeys"],
-      );
-
+  TaskConstMeta get kCrateApiMlsApiCloseNostrMlsConstMeta =>
+      const TaskConstMeta(
+        debugName: "close_nostr_mls",
+        argNames: ["identity"],
+      );
+
+  @override
+  Future<EventResult> crateApiMlsApiCreateCommitMessageForGroup(
+      {required String identity,
+      required String nostrGroupId,
+      required List<int> serializedCommit}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(nostrGroupId, serializer);
+        sse_encode_list_prim_u_8_loose(serializedCommit, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 37, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_event_result,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiCreateCommitMessageForGroupConstMeta,
+      argValues: [identity, nostrGroupId, serializedCommit],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiCreateCommitMessageForGroupConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_commit_message_for_group",
+        argNames: ["identity", "nostrGroupId", "serializedCommit"],
+      );
+
+  @override
+  Future<CreateGroupResult> crateApiMlsApiCreateGroup(
+      {required String identity,
+      required String groupName,
+      required String groupDescription,
+      required List<String> groupMembersSerializedKeyPackages,
+      required List<String> groupMembersPubkeys,
+      required String groupCreatorPublicKey,
+      required List<String> groupAdminPublicKeys,
+      required List<String> relays}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(groupName, serializer);
+        sse_encode_String(groupDescription, serializer);
+        sse_encode_list_String(groupMembersSerializedKeyPackages, serializer);
+        sse_encode_list_String(groupMembersPubkeys, serializer);
+        sse_encode_String(groupCreatorPublicKey, serializer);
+        sse_encode_list_String(groupAdminPublicKeys, serializer);
+        sse_encode_list_String(relays, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 38, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_create_group_result,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiCreateGroupConstMeta,
+      argValues: [
+        identity,
+        groupName,
+        groupDescription,
+        groupMembersSerializedKeyPackages,
+        groupMembersPubkeys,
+        groupCreatorPublicKey,
+        groupAdminPublicKeys,
+        relays
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiCreateGroupConstMeta => const TaskConstMeta(
+        debugName: "create_group",
+        argNames: [
+          "identity",
+          "groupName",
+          "groupDescription",
+          "groupMembersSerializedKeyPackages",
+          "groupMembersPubkeys",
+          "groupCreatorPublicKey",
+          "groupAdminPublicKeys",
+          "relays"
+        ],
+      );
+
+  @override
+  Future<KeyPackageResult> crateApiMlsApiCreateKeyPackageForEvent(
+      {required String identity,
+      required String publicKey,
+      List<String>? relay,
+      String? client}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(publicKey, serializer);
+        sse_encode_opt_list_String(relay, serializer);
+        sse_encode_opt_String(client, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 39, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_key_package_result,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiCreateKeyPackageForEventConstMeta,
+      argValues: [identity, publicKey, relay, client],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiCreateKeyPackageForEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_key_package_for_event",
+        argNames: ["identity", "publicKey", "relay", "client"],
+      );
+
+  @override
+  Future<EventResult> crateApiMlsApiCreateMessageForGroup(
+      {required String identity,
+      required List<int> groupId,
+      required String rumorEventString}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_String(rumorEventString, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 40, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_event_result,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiCreateMessageForGroupConstMeta,
+      argValues: [identity, groupId, rumorEventString],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiCreateMessageForGroupConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_message_for_group",
+        argNames: ["identity", "groupId", "rumorEventString"],
+      );
+
+  @override
+  Future<ExportedSecret> crateApiMlsApiExportSecret(
+      {required String identity, required List<int> groupId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 41, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_exported_secret,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiExportSecretConstMeta,
+      argValues: [identity, groupId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiExportSecretConstMeta => const TaskConstMeta(
+        debugName: "export_secret",
+        argNames: ["identity", "groupId"],
+      );
+
+  @override
+  Future<WelcomeKeyPackageMatch>
+      crateApiMlsApiFindEncodedKeypackageFromWelcomeEvent(
+          {required String identity,
+          required List<String> encodedKeypackages,
+          required List<int> wrapperEventId,
+          required String rumorEventString}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_String(encodedKeypackages, serializer);
+        sse_encode_list_prim_u_8_loose(wrapperEventId, serializer);
+        sse_encode_String(rumorEventString, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 42, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_welcome_key_package_match,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiFindEncodedKeypackageFromWelcomeEventConstMeta,
+      argValues: [
+        identity,
+        encodedKeypackages,
+        wrapperEventId,
+        rumorEventString
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiMlsApiFindEncodedKeypackageFromWelcomeEventConstMeta =>
+          const TaskConstMeta(
+            debugName: "find_encoded_keypackage_from_welcome_event",
+            argNames: [
+              "identity",
+              "encodedKeypackages",
+              "wrapperEventId",
+              "rumorEventString"
+            ],
+          );
+
+  @override
+  Future<int> crateApiMlsApiGetCiphersuite({required String identity}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 43, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_16,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiGetCiphersuiteConstMeta,
+      argValues: [identity],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiGetCiphersuiteConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_ciphersuite",
+        argNames: ["identity"],
+      );
+
+  @override
+  Future<List<String>> crateApiMlsApiGetExtensions({required String identity}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 44, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiGetExtensionsConstMeta,
+      argValues: [identity],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiGetExtensionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_extensions",
+        argNames: ["identity"],
+      );
+
+  @override
+  Future<GroupInfo> crateApiMlsApiGetGroup(
+      {required String identity, required List<int> groupId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 45, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_group_info,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiGetGroupConstMeta,
+      argValues: [identity, groupId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiGetGroupConstMeta => const TaskConstMeta(
+        debugName: "get_group",
+        argNames: ["identity", "groupId"],
+      );
+
+  @override
+  Future<KeyPackageLookup> crateApiMlsApiGetKeyPackageFromStorage(
+      {required String identity, required String serializedKeyPackage}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(serializedKeyPackage, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 46, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_key_package_lookup,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiGetKeyPackageFromStorageConstMeta,
+      argValues: [identity, serializedKeyPackage],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiGetKeyPackageFromStorageConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_key_package_from_storage",
+        argNames: ["identity", "serializedKeyPackage"],
+      );
+
+  @override
+  Future<List<String>> crateApiMlsApiGetMembers(
+      {required String identity, required List<int> groupId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 47, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiGetMembersConstMeta,
+      argValues: [identity, groupId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiGetMembersConstMeta => const TaskConstMeta(
+        debugName: "get_members",
+        argNames: ["identity", "groupId"],
+      );
+
+  @override
+  Future<int> crateApiMlsApiGetPendingMessageCount(
+      {required String identity, required List<int> groupId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 48, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiGetPendingMessageCountConstMeta,
+      argValues: [identity, groupId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiGetPendingMessageCountConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_pending_message_count",
+        argNames: ["identity", "groupId"],
+      );
+
+  @override
+  Future<void> crateApiMlsApiInitNostrMls(
+      {required String path, required String identity, String? password}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(identity, serializer);
+        sse_encode_opt_String(password, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 49, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiInitNostrMlsConstMeta,
+      argValues: [path, identity, password],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiInitNostrMlsConstMeta => const TaskConstMeta(
+        debugName: "init_nostr_mls",
+        argNames: ["path", "identity", "password"],
+      );
+
+  @override
+  Future<GroupInfo> crateApiMlsApiJoinGroupFromWelcome(
+      {required String identity,
+      required List<int> wrapperEventId,
+      required String rumorEventString}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(wrapperEventId, serializer);
+        sse_encode_String(rumorEventString, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 50, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_group_info,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiJoinGroupFromWelcomeConstMeta,
+      argValues: [identity, wrapperEventId, rumorEventString],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiJoinGroupFromWelcomeConstMeta =>
+      const TaskConstMeta(
+        debugName: "join_group_from_welcome",
+        argNames: ["identity", "wrapperEventId", "rumorEventString"],
+      );
+
+  @override
+  Future<CommitResult> crateApiMlsApiLeaveGroup(
+      {required String identity, required List<int> groupId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 51, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_commit_result,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiLeaveGroupConstMeta,
+      argValues: [identity, groupId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiLeaveGroupConstMeta => const TaskConstMeta(
+        debugName: "leave_group",
+        argNames: ["identity", "groupId"],
+      );
+
+  @override
+  Future<List<GroupSummary>> crateApiMlsApiListGroups(
+      {required String identity, required GroupStateFilter filter}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_group_state_filter(filter, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 52, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_group_summary,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiListGroupsConstMeta,
+      argValues: [identity, filter],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiListGroupsConstMeta => const TaskConstMeta(
+        debugName: "list_groups",
+        argNames: ["identity", "filter"],
+      );
+
+  @override
+  Future<List<String>> crateApiMlsApiListNostrMlsIdentities() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 53, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiListNostrMlsIdentitiesConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiListNostrMlsIdentitiesConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_nostr_mls_identities",
+        argNames: [],
+      );
+
+  @override
+  Future<List<WelcomeSummary>> crateApiMlsApiListPendingWelcomes(
+      {required String identity}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 54, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_welcome_summary,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiListPendingWelcomesConstMeta,
+      argValues: [identity],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiListPendingWelcomesConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_pending_welcomes",
+        argNames: ["identity"],
+      );
+
+  @override
+  Future<GroupInfo> crateApiMlsApiPreviewGroupFromWelcome(
+      {required String identity,
+      required List<int> wrapperEventId,
+      required String rumorEventString}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(wrapperEventId, serializer);
+        sse_encode_String(rumorEventString, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 55, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_group_info,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiPreviewGroupFromWelcomeConstMeta,
+      argValues: [identity, wrapperEventId, rumorEventString],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiPreviewGroupFromWelcomeConstMeta =>
+      const TaskConstMeta(
+        debugName: "preview_group_from_welcome",
+        argNames: ["identity", "wrapperEventId", "rumorEventString"],
+      );
+
+  @override
+  Future<ProcessedMessage> crateApiMlsApiProcessCommitMessageForGroup(
+      {required String identity,
+      required List<int> groupId,
+      required List<int> messageBytes}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_list_prim_u_8_loose(messageBytes, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 56, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_processed_message,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiProcessCommitMessageForGroupConstMeta,
+      argValues: [identity, groupId, messageBytes],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiProcessCommitMessageForGroupConstMeta =>
+      const TaskConstMeta(
+        debugName: "process_commit_message_for_group",
+        argNames: ["identity", "groupId", "messageBytes"],
+      );
+
+  @override
+  Future<ProcessedMessage> crateApiMlsApiProcessMessageForGroup(
+      {required String identity, required String eventString}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(eventString, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 57, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_processed_message,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiProcessMessageForGroupConstMeta,
+      argValues: [identity, eventString],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiProcessMessageForGroupConstMeta =>
+      const TaskConstMeta(
+        debugName: "process_message_for_group",
+        argNames: ["identity", "eventString"],
+      );
+
+  @override
+  Future<CommitResult> crateApiMlsApiRemoveMembers(
+      {required String identity,
+      required List<int> groupId,
+      required List<String> memberPubkeys}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_list_String(memberPubkeys, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 58, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_commit_result,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiMlsApiRemoveMembersConstMeta,
+      argValues: [identity, groupId, memberPubkeys],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMlsApiRemoveMembersConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_members",
+        argNames: ["identity", "groupId", "memberPubkeys"],
+      );
+
+  @override
+  Future<GroupPolicy> crateApiPolicyGroupPolicyDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 59, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_group_policy,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPolicyGroupPolicyDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPolicyGroupPolicyDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "GroupPolicy_default",
+        argNames: [],
+      );
+
+  @override
+  Future<GroupPolicy> crateApiPolicyGetGroupPolicy(
+      {required String identity, required List<int> groupId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 60, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_group_policy,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiPolicyGetGroupPolicyConstMeta,
+      argValues: [identity, groupId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPolicyGetGroupPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_group_policy",
+        argNames: ["identity", "groupId"],
+      );
+
+  @override
+  Future<List<CommitAuditEntry>> crateApiPolicyListCommitAudit(
+      {required String identity,
+      required List<int> groupId,
+      required int limit}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_u_32(limit, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 61, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_commit_audit_entry,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiPolicyListCommitAuditConstMeta,
+      argValues: [identity, groupId, limit],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPolicyListCommitAuditConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_commit_audit",
+        argNames: ["identity", "groupId", "limit"],
+      );
+
+  @override
+  Future<void> crateApiPolicySetGroupPolicy(
+      {required String identity,
+      required List<int> groupId,
+      required GroupPolicy policy}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_box_autoadd_group_policy(policy, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 62, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiPolicySetGroupPolicyConstMeta,
+      argValues: [identity, groupId, policy],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPolicySetGroupPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_group_policy",
+        argNames: ["identity", "groupId", "policy"],
+      );
+
+  @override
+  Future<bool> crateApiRecoveryCancelGroupRecovery(
+      {required String identity, required List<int> groupId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 63, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiRecoveryCancelGroupRecoveryConstMeta,
+      argValues: [identity, groupId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRecoveryCancelGroupRecoveryConstMeta =>
+      const TaskConstMeta(
+        debugName: "cancel_group_recovery",
+        argNames: ["identity", "groupId"],
+      );
+
+  @override
+  Future<GroupStateComparison> crateApiRecoveryCompareGroupState(
+      {required String identity,
+      required List<int> groupId,
+      required BigInt epoch,
+      required String treeHash}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_u_64(epoch, serializer);
+        sse_encode_String(treeHash, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 64, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_group_state_comparison,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiRecoveryCompareGroupStateConstMeta,
+      argValues: [identity, groupId, epoch, treeHash],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRecoveryCompareGroupStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "compare_group_state",
+        argNames: ["identity", "groupId", "epoch", "treeHash"],
+      );
+
+  @override
+  Future<GroupHealth> crateApiRecoveryGetGroupHealth(
+      {required String identity, required List<int> groupId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 65, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_group_health,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiRecoveryGetGroupHealthConstMeta,
+      argValues: [identity, groupId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRecoveryGetGroupHealthConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_group_health",
+        argNames: ["identity", "groupId"],
+      );
+
+  @override
+  Future<AddMembersResult> crateApiRecoveryReaddMember(
+      {required String identity,
+      required List<int> groupId,
+      required String memberPubkey,
+      required String keyPackage}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_String(memberPubkey, serializer);
+        sse_encode_String(keyPackage, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 66, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_add_members_result,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiRecoveryReaddMemberConstMeta,
+      argValues: [identity, groupId, memberPubkey, keyPackage],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRecoveryReaddMemberConstMeta =>
+      const TaskConstMeta(
+        debugName: "readd_member",
+        argNames: ["identity", "groupId", "memberPubkey", "keyPackage"],
+      );
+
+  @override
+  Future<GroupRecoveryRequest> crateApiRecoveryStartGroupRecovery(
+      {required String identity,
+      required List<int> groupId,
+      List<String>? relay,
+      String? client}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        sse_encode_opt_list_String(relay, serializer);
+        sse_encode_opt_String(client, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 67, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_group_recovery_request,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiRecoveryStartGroupRecoveryConstMeta,
+      argValues: [identity, groupId, relay, client],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRecoveryStartGroupRecoveryConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_group_recovery",
+        argNames: ["identity", "groupId", "relay", "client"],
+      );
+
+  @override
+  Future<RetentionPolicy> crateApiRetentionRetentionPolicyDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 68, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_retention_policy,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiRetentionRetentionPolicyDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRetentionRetentionPolicyDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "RetentionPolicy_default",
+        argNames: [],
+      );
+
+  @override
+  Future<RetentionReport> crateApiRetentionApplyRetentionPolicy(
+      {required String identity}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 69, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_retention_report,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiRetentionApplyRetentionPolicyConstMeta,
+      argValues: [identity],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRetentionApplyRetentionPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "apply_retention_policy",
+        argNames: ["identity"],
+      );
+
+  @override
+  Future<void> crateApiRetentionCompactStorage({required String identity}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 70, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiRetentionCompactStorageConstMeta,
+      argValues: [identity],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRetentionCompactStorageConstMeta =>
+      const TaskConstMeta(
+        debugName: "compact_storage",
+        argNames: ["identity"],
+      );
+
+  @override
+  Future<RetentionPolicy> crateApiRetentionGetRetentionPolicy(
+      {required String identity}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 71, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_retention_policy,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiRetentionGetRetentionPolicyConstMeta,
+      argValues: [identity],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRetentionGetRetentionPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_retention_policy",
+        argNames: ["identity"],
+      );
+
+  @override
+  Future<void> crateApiRetentionPurgeGroup(
+      {required String identity, required List<int> groupId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(groupId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 72, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiRetentionPurgeGroupConstMeta,
+      argValues: [identity, groupId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRetentionPurgeGroupConstMeta =>
+      const TaskConstMeta(
+        debugName: "purge_group",
+        argNames: ["identity", "groupId"],
+      );
+
+  @override
+  Future<void> crateApiRetentionSetRetentionPolicy(
+      {required String identity, required RetentionPolicy policy}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_box_autoadd_retention_policy(policy, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 73, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiRetentionSetRetentionPolicyConstMeta,
+      argValues: [identity, policy],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRetentionSetRetentionPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_retention_policy",
+        argNames: ["identity", "policy"],
+      );
+
+  @override
+  Future<RotationPolicy> crateApiRotationRotationPolicyDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 74, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_rotation_policy,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiRotationRotationPolicyDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRotationRotationPolicyDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "RotationPolicy_default",
+        argNames: [],
+      );
+
+  @override
+  Future<RotationPolicy> crateApiRotationGetRotationPolicy(
+      {required String identity}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 75, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_rotation_policy,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiRotationGetRotationPolicyConstMeta,
+      argValues: [identity],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRotationGetRotationPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_rotation_policy",
+        argNames: ["identity"],
+      );
+
+  @override
+  Future<List<RotationStatus>> crateApiRotationListRotationStatus(
+      {required String identity, required bool dueOnly}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_bool(dueOnly, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 76, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_rotation_status,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiRotationListRotationStatusConstMeta,
+      argValues: [identity, dueOnly],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRotationListRotationStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_rotation_status",
+        argNames: ["identity", "dueOnly"],
+      );
+
+  @override
+  Future<void> crateApiRotationSetRotationPolicy(
+      {required String identity, required RotationPolicy policy}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_box_autoadd_rotation_policy(policy, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 77, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiRotationSetRotationPolicyConstMeta,
+      argValues: [identity, policy],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRotationSetRotationPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_rotation_policy",
+        argNames: ["identity", "policy"],
+      );
+
+  @override
+  Future<void> crateApiSignerClearSigner({required String identity}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 78, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiSignerClearSignerConstMeta,
+      argValues: [identity],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSignerClearSignerConstMeta => const TaskConstMeta(
+        debugName: "clear_signer",
+        argNames: ["identity"],
+      );
+
+  @override
+  Future<void> crateApiSignerUseDartSigner(
+      {required String identity,
+      required SignerScope scope,
+      required String publicKey,
+      required FutureOr<String?> Function(String) signEvent,
+      required FutureOr<String?> Function(String, String) nip44Encrypt,
+      required FutureOr<String?> Function(String, String) nip44Decrypt}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_signer_scope(scope, serializer);
+        sse_encode_String(publicKey, serializer);
+        sse_encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(
+            signEvent, serializer);
+        sse_encode_DartFn_Inputs_String_String_Output_opt_String_AnyhowException(
+            nip44Encrypt, serializer);
+        sse_encode_DartFn_Inputs_String_String_Output_opt_String_AnyhowException(
+            nip44Decrypt, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 79, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiSignerUseDartSignerConstMeta,
+      argValues: [
+        identity,
+        scope,
+        publicKey,
+        signEvent,
+        nip44Encrypt,
+        nip44Decrypt
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSignerUseDartSignerConstMeta =>
+      const TaskConstMeta(
+        debugName: "use_dart_signer",
+        argNames: [
+          "identity",
+          "scope",
+          "publicKey",
+          "signEvent",
+          "nip44Encrypt",
+          "nip44Decrypt"
+        ],
+      );
+
+  @override
+  Future<void> crateApiSignerUseNip46Signer(
+      {required String identity,
+      required SignerScope scope,
+      required String publicKey,
+      required FutureOr<String?> Function(String) sendRequest}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_signer_scope(scope, serializer);
+        sse_encode_String(publicKey, serializer);
+        sse_encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(
+            sendRequest, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 80, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiSignerUseNip46SignerConstMeta,
+      argValues: [identity, scope, publicKey, sendRequest],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSignerUseNip46SignerConstMeta =>
+      const TaskConstMeta(
+        debugName: "use_nip46_signer",
+        argNames: ["identity", "scope", "publicKey", "sendRequest"],
+      );
+
+  @override
+  Future<void> crateApiStorageEncryptExistingStorage(
+      {required String path,
+      required String identity,
+      required String password}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(password, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 81, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiStorageEncryptExistingStorageConstMeta,
+      argValues: [path, identity, password],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiStorageEncryptExistingStorageConstMeta =>
+      const TaskConstMeta(
+        debugName: "encrypt_existing_storage",
+        argNames: ["path", "identity", "password"],
+      );
+
+  @override
+  Future<void> crateApiStorageInitNostrMlsWithKeySource(
+      {required String path,
+      required String identity,
+      required StorageKeySource source}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(identity, serializer);
+        sse_encode_box_autoadd_storage_key_source(source, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 82, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiStorageInitNostrMlsWithKeySourceConstMeta,
+      argValues: [path, identity, source],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiStorageInitNostrMlsWithKeySourceConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_nostr_mls_with_key_source",
+        argNames: ["path", "identity", "source"],
+      );
+
+  @override
+  Future<void> crateApiStorageInitNostrMlsWithKeystore(
+      {required String path,
+      required String identity,
+      required FutureOr<Uint8List?> Function(String, Uint8List) wrapKey,
+      required FutureOr<Uint8List?> Function(String, Uint8List) unwrapKey}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(identity, serializer);
+        sse_encode_DartFn_Inputs_String_list_prim_u_8_strict_Output_opt_list_prim_u_8_strict_AnyhowException(
+            wrapKey, serializer);
+        sse_encode_DartFn_Inputs_String_list_prim_u_8_strict_Output_opt_list_prim_u_8_strict_AnyhowException(
+            unwrapKey, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 83, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiStorageInitNostrMlsWithKeystoreConstMeta,
+      argValues: [path, identity, wrapKey, unwrapKey],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiStorageInitNostrMlsWithKeystoreConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_nostr_mls_with_keystore",
+        argNames: ["path", "identity", "wrapKey", "unwrapKey"],
+      );
+
+  @override
+  Future<void> crateApiStorageRekeyStorage(
+      {required String path,
+      required String identity,
+      required String oldPassword,
+      String? newPassword}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(oldPassword, serializer);
+        sse_encode_opt_String(newPassword, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 84, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiStorageRekeyStorageConstMeta,
+      argValues: [path, identity, oldPassword, newPassword],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiStorageRekeyStorageConstMeta =>
+      const TaskConstMeta(
+        debugName: "rekey_storage",
+        argNames: ["path", "identity", "oldPassword", "newPassword"],
+      );
+
+  @override
+  Future<GroupInfo> crateApiWelcomesAcceptWelcome(
+      {required String identity, required String id}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(id, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 85, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_group_info,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiWelcomesAcceptWelcomeConstMeta,
+      argValues: [identity, id],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWelcomesAcceptWelcomeConstMeta =>
+      const TaskConstMeta(
+        debugName: "accept_welcome",
+        argNames: ["identity", "id"],
+      );
+
+  @override
+  Future<List<GiftWrappedWelcome>> crateApiWelcomesCreateWelcomeGiftWraps(
+      {required String identity,
+      required List<int> serializedWelcomeMessage,
+      required List<WelcomeRecipient> recipients,
+      required List<String> relays}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(serializedWelcomeMessage, serializer);
+        sse_encode_list_welcome_recipient(recipients, serializer);
+        sse_encode_list_String(relays, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 86, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_gift_wrapped_welcome,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiWelcomesCreateWelcomeGiftWrapsConstMeta,
+      argValues: [identity, serializedWelcomeMessage, recipients, relays],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWelcomesCreateWelcomeGiftWrapsConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_welcome_gift_wraps",
+        argNames: [
+          "identity",
+          "serializedWelcomeMessage",
+          "recipients",
+          "relays"
+        ],
+      );
+
+  @override
+  Future<bool> crateApiWelcomesDeclineWelcome(
+      {required String identity, required String id}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(id, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 87, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiWelcomesDeclineWelcomeConstMeta,
+      argValues: [identity, id],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWelcomesDeclineWelcomeConstMeta =>
+      const TaskConstMeta(
+        debugName: "decline_welcome",
+        argNames: ["identity", "id"],
+      );
+
+  @override
+  Future<GroupInfo> crateApiWelcomesJoinGiftWrappedWelcome(
+      {required String identity, required String giftWrapEventJson}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(giftWrapEventJson, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 88, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_group_info,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiWelcomesJoinGiftWrappedWelcomeConstMeta,
+      argValues: [identity, giftWrapEventJson],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWelcomesJoinGiftWrappedWelcomeConstMeta =>
+      const TaskConstMeta(
+        debugName: "join_gift_wrapped_welcome",
+        argNames: ["identity", "giftWrapEventJson"],
+      );
+
+  @override
+  Future<WelcomeList> crateApiWelcomesListWelcomes(
+      {required String identity, required WelcomeStateFilter filter}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_welcome_state_filter(filter, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 89, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_welcome_list,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiWelcomesListWelcomesConstMeta,
+      argValues: [identity, filter],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWelcomesListWelcomesConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_welcomes",
+        argNames: ["identity", "filter"],
+      );
+
+  @override
+  Future<GroupInfo> crateApiWelcomesPreviewGiftWrappedWelcome(
+      {required String identity, required String giftWrapEventJson}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(giftWrapEventJson, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 90, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_group_info,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiWelcomesPreviewGiftWrappedWelcomeConstMeta,
+      argValues: [identity, giftWrapEventJson],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWelcomesPreviewGiftWrappedWelcomeConstMeta =>
+      const TaskConstMeta(
+        debugName: "preview_gift_wrapped_welcome",
+        argNames: ["identity", "giftWrapEventJson"],
+      );
+
+  @override
+  Future<InboxWelcome> crateApiWelcomesReceiveGiftWrappedWelcome(
+      {required String identity, required String giftWrapEventJson}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(giftWrapEventJson, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 91, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_inbox_welcome,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiWelcomesReceiveGiftWrappedWelcomeConstMeta,
+      argValues: [identity, giftWrapEventJson],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWelcomesReceiveGiftWrappedWelcomeConstMeta =>
+      const TaskConstMeta(
+        debugName: "receive_gift_wrapped_welcome",
+        argNames: ["identity", "giftWrapEventJson"],
+      );
+
+  @override
+  Future<InboxWelcome> crateApiWelcomesReceiveWelcome(
+      {required String identity,
+      required List<int> wrapperEventId,
+      required String rumorEventString}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_list_prim_u_8_loose(wrapperEventId, serializer);
+        sse_encode_String(rumorEventString, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 92, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_inbox_welcome,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiWelcomesReceiveWelcomeConstMeta,
+      argValues: [identity, wrapperEventId, rumorEventString],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWelcomesReceiveWelcomeConstMeta =>
+      const TaskConstMeta(
+        debugName: "receive_welcome",
+        argNames: ["identity", "wrapperEventId", "rumorEventString"],
+      );
+
+  @override
+  Future<UnwrappedWelcome> crateApiWelcomesUnwrapWelcome(
+      {required String identity, required String giftWrapEventJson}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(identity, serializer);
+        sse_encode_String(giftWrapEventJson, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 93, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unwrapped_welcome,
+        decodeErrorData: sse_decode_mls_bridge_error,
+      ),
+      constMeta: kCrateApiWelcomesUnwrapWelcomeConstMeta,
+      argValues: [identity, giftWrapEventJson],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWelcomesUnwrapWelcomeConstMeta =>
+      const TaskConstMeta(
+        debugName: "unwrap_welcome",
+        argNames: ["identity", "giftWrapEventJson"],
+      );
+
+  Future<void> Function(int, dynamic)
+      encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(
+          FutureOr<String?> Function(String) raw) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_String(rawArg0);
+
+      Box<String?>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_opt_String(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+      encode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+          FutureOr<Uint8List?> Function(String) raw) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_String(rawArg0);
+
+      Box<Uint8List?>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_opt_list_prim_u_8_strict(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
+  Future<void> Function(int, dynamic, dynamic)
+      encode_DartFn_Inputs_String_String_Output_opt_String_AnyhowException(
+          FutureOr<String?> Function(String, String) raw) {
+    return (callId, rawArg0, rawArg1) async {
+      final arg0 = dco_decode_String(rawArg0);
+      final arg1 = dco_decode_String(rawArg1);
+
+      Box<String?>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0, arg1));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_opt_String(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
+  Future<void> Function(int, dynamic, dynamic)
+      encode_DartFn_Inputs_String_list_prim_u_8_strict_Output_opt_list_prim_u_8_strict_AnyhowException(
+          FutureOr<Uint8List?> Function(String, Uint8List) raw) {
+    return (callId, rawArg0, rawArg1) async {
+      final arg0 = dco_decode_String(rawArg0);
+      final arg1 = dco_decode_list_prim_u_8_strict(rawArg1);
+
+      Box<Uint8List?>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0, arg1));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_opt_list_prim_u_8_strict(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
+  Future<void> Function(int, dynamic, dynamic)
+      encode_DartFn_Inputs_list_prim_u_8_strict_String_Output_opt_String_AnyhowException(
+          FutureOr<String?> Function(Uint8List, String) raw) {
+    return (callId, rawArg0, rawArg1) async {
+      final arg0 = dco_decode_list_prim_u_8_strict(rawArg0);
+      final arg1 = dco_decode_String(rawArg1);
+
+      Box<String?>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0, arg1));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_opt_String(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  FutureOr<String?> Function(String)
+      dco_decode_DartFn_Inputs_String_Output_opt_String_AnyhowException(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<Uint8List?> Function(String)
+      dco_decode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<String?> Function(String, String)
+      dco_decode_DartFn_Inputs_String_String_Output_opt_String_AnyhowException(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<Uint8List?> Function(String, Uint8List)
+      dco_decode_DartFn_Inputs_String_list_prim_u_8_strict_Output_opt_list_prim_u_8_strict_AnyhowException(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<String?> Function(Uint8List, String)
+      dco_decode_DartFn_Inputs_list_prim_u_8_strict_String_Output_opt_String_AnyhowException(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  Object dco_decode_DartOpaque(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return decodeDartOpaque(raw, generalizedFrbRustBinding);
+  }
+
+  @protected
+  RustStreamSink<GroupEvent> dco_decode_StreamSink_group_event_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AddMembersResult dco_decode_add_members_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AddMembersResult(
+      commitMessage: dco_decode_list_prim_u_8_strict(arr[0]),
+      welcomeMessage: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  BatchEventResult dco_decode_batch_event_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BatchEventResult(
+      eventId: dco_decode_opt_String(arr[0]),
+      processed: dco_decode_opt_box_autoadd_processed_message(arr[1]),
+      error: dco_decode_opt_box_autoadd_mls_bridge_error(arr[2]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  DecryptedMessage dco_decode_box_autoadd_decrypted_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_decrypted_message(raw);
+  }
+
+  @protected
+  GroupInfo dco_decode_box_autoadd_group_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_group_info(raw);
+  }
+
+  @protected
+  GroupPolicy dco_decode_box_autoadd_group_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_group_policy(raw);
+  }
+
+  @protected
+  KeyPackagePolicy dco_decode_box_autoadd_key_package_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_key_package_policy(raw);
+  }
+
+  @protected
+  KeyringPolicy dco_decode_box_autoadd_keyring_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_keyring_policy(raw);
+  }
+
+  @protected
+  MediaReference dco_decode_box_autoadd_media_reference(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_media_reference(raw);
+  }
+
+  @protected
+  MessageCursor dco_decode_box_autoadd_message_cursor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_message_cursor(raw);
+  }
+
+  @protected
+  MlsBridgeError dco_decode_box_autoadd_mls_bridge_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_mls_bridge_error(raw);
+  }
+
+  @protected
+  PendingCommit dco_decode_box_autoadd_pending_commit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pending_commit(raw);
+  }
+
+  @protected
+  ProcessedMessage dco_decode_box_autoadd_processed_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_processed_message(raw);
+  }
+
+  @protected
+  RetentionPolicy dco_decode_box_autoadd_retention_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_retention_policy(raw);
+  }
+
+  @protected
+  RotationPolicy dco_decode_box_autoadd_rotation_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_rotation_policy(raw);
+  }
+
+  @protected
+  StorageKeySource dco_decode_box_autoadd_storage_key_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_storage_key_source(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  CommitAuditEntry dco_decode_commit_audit_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CommitAuditEntry(
+      epoch: dco_decode_u_64(arr[0]),
+      sender: dco_decode_opt_String(arr[1]),
+      reason: dco_decode_String(arr[2]),
+      createdAt: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  CommitResult dco_decode_commit_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return CommitResult(
+      serializedCommit: dco_decode_list_prim_u_8_strict(arr[0]),
+    );
+  }
+
+  @protected
+  CreateGroupResult dco_decode_create_group_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CreateGroupResult(
+      group: dco_decode_group_info(arr[0]),
+      serializedWelcomeMessage: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  DecryptedMessage dco_decode_decrypted_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return DecryptedMessage(
+      eventId: dco_decode_String(arr[0]),
+      pubkey: dco_decode_String(arr[1]),
+      kind: dco_decode_u_16(arr[2]),
+      mlsGroupId: dco_decode_list_prim_u_8_strict(arr[3]),
+      createdAt: dco_decode_u_64(arr[4]),
+      content: dco_decode_String(arr[5]),
+      tags: dco_decode_list_list_String(arr[6]),
+      rumorJson: dco_decode_String(arr[7]),
+    );
+  }
+
+  @protected
+  EncryptedMedia dco_decode_encrypted_media(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return EncryptedMedia(
+      encryptedData: dco_decode_list_prim_u_8_strict(arr[0]),
+      encryptedHash: dco_decode_String(arr[1]),
+      reference: dco_decode_media_reference(arr[2]),
+    );
+  }
+
+  @protected
+  EventResult dco_decode_event_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EventResult(
+      eventId: dco_decode_String(arr[0]),
+      eventJson: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ExportedSecret dco_decode_exported_secret(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ExportedSecret(
+      secret: dco_decode_list_prim_u_8_strict(arr[0]),
+      epoch: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  GiftWrappedWelcome dco_decode_gift_wrapped_welcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GiftWrappedWelcome(
+      recipient: dco_decode_String(arr[0]),
+      event: dco_decode_event_result(arr[1]),
+    );
+  }
+
+  @protected
+  GroupEvent dco_decode_group_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return GroupEvent_MessageDecrypted(
+          message: dco_decode_box_autoadd_decrypted_message(raw[1]),
+        );
+      case 1:
+        return GroupEvent_MembersAdded(
+          mlsGroupId: dco_decode_list_prim_u_8_strict(raw[1]),
+          members: dco_decode_list_String(raw[2]),
+        );
+      case 2:
+        return GroupEvent_MembersRemoved(
+          mlsGroupId: dco_decode_list_prim_u_8_strict(raw[1]),
+          members: dco_decode_list_String(raw[2]),
+        );
+      case 3:
+        return GroupEvent_EpochAdvanced(
+          mlsGroupId: dco_decode_list_prim_u_8_strict(raw[1]),
+          epoch: dco_decode_u_64(raw[2]),
+        );
+      case 4:
+        return GroupEvent_GroupMetadataChanged(
+          group: dco_decode_box_autoadd_group_info(raw[1]),
+        );
+      case 5:
+        return GroupEvent_WelcomeReceived(
+          wrapperEventId: dco_decode_String(raw[1]),
+          group: dco_decode_box_autoadd_group_info(raw[2]),
+        );
+      case 6:
+        return GroupEvent_KeyPackageConsumed(
+          wrapperEventId: dco_decode_String(raw[1]),
+          keyPackageId: dco_decode_String(raw[2]),
+        );
+      case 7:
+        return GroupEvent_PendingCommitRolledBack(
+          mlsGroupId: dco_decode_list_prim_u_8_strict(raw[1]),
+          eventId: dco_decode_opt_String(raw[2]),
+        );
+      case 8:
+        return GroupEvent_GroupPurged(
+          mlsGroupId: dco_decode_list_prim_u_8_strict(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  GroupHealth dco_decode_group_health(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 14)
+      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+    return GroupHealth(
+      mlsGroupId: dco_decode_list_prim_u_8_strict(arr[0]),
+      epoch: dco_decode_u_64(arr[1]),
+      treeHash: dco_decode_String(arr[2]),
+      pendingMessageCount: dco_decode_u_32(arr[3]),
+      oldestPendingMessageAt: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      undecryptableEventCount: dco_decode_u_32(arr[5]),
+      lastUndecryptableEventAt: dco_decode_opt_box_autoadd_u_64(arr[6]),
+      consecutiveFailures: dco_decode_u_32(arr[7]),
+      lastFailure: dco_decode_opt_String(arr[8]),
+      lastFailureAt: dco_decode_opt_box_autoadd_u_64(arr[9]),
+      lastSuccessAt: dco_decode_opt_box_autoadd_u_64(arr[10]),
+      recoveryRequestedAt: dco_decode_opt_box_autoadd_u_64(arr[11]),
+      recoveryKeyPackageId: dco_decode_opt_String(arr[12]),
+      status: dco_decode_group_health_status(arr[13]),
+    );
+  }
+
+  @protected
+  GroupHealthStatus dco_decode_group_health_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return GroupHealthStatus.values[raw as int];
+  }
+
+  @protected
+  GroupInfo dco_decode_group_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return GroupInfo(
+      mlsGroupId: dco_decode_list_prim_u_8_strict(arr[0]),
+      members: dco_decode_list_String(arr[1]),
+      nostrGroupData: dco_decode_nostr_group_data(arr[2]),
+    );
+  }
+
+  @protected
+  GroupPolicy dco_decode_group_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return GroupPolicy(
+      addMembers: dco_decode_policy_role(arr[0]),
+      removeMembers: dco_decode_policy_role(arr[1]),
+      updateGroupData: dco_decode_policy_role(arr[2]),
+      allowSelfRemove: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  GroupRecoveryRequest dco_decode_group_recovery_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return GroupRecoveryRequest(
+      mlsGroupId: dco_decode_list_prim_u_8_strict(arr[0]),
+      keyPackage: dco_decode_managed_key_package(arr[1]),
+      admins: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  GroupStateComparison dco_decode_group_state_comparison(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return GroupStateComparison.values[raw as int];
+  }
+
+  @protected
+  GroupStateFilter dco_decode_group_state_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return GroupStateFilter.values[raw as int];
+  }
+
+  @protected
+  GroupStatus dco_decode_group_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return GroupStatus.values[raw as int];
+  }
+
+  @protected
+  GroupSummary dco_decode_group_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return GroupSummary(
+      mlsGroupId: dco_decode_list_prim_u_8_strict(arr[0]),
+      nostrGroupId: dco_decode_String(arr[1]),
+      name: dco_decode_String(arr[2]),
+      description: dco_decode_String(arr[3]),
+      epoch: dco_decode_u_64(arr[4]),
+      memberCount: dco_decode_u_32(arr[5]),
+      lastMessageId: dco_decode_opt_String(arr[6]),
+      lastMessageAt: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      status: dco_decode_group_status(arr[8]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  InboxWelcome dco_decode_inbox_welcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return InboxWelcome(
+      id: dco_decode_String(arr[0]),
+      sender: dco_decode_String(arr[1]),
+      group: dco_decode_group_info(arr[2]),
+      state: dco_decode_welcome_state(arr[3]),
+      receivedAt: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  PlatformInt64 dco_decode_isize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  KeyPackageLookup dco_decode_key_package_lookup(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return KeyPackageLookup(
+      found: dco_decode_bool(arr[0]),
+      keyPackage: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  KeyPackagePolicy dco_decode_key_package_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return KeyPackagePolicy(
+      lastResortCount: dco_decode_u_32(arr[0]),
+      oneTimeCount: dco_decode_u_32(arr[1]),
+      maxAgeSecs: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  KeyPackageResult dco_decode_key_package_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return KeyPackageResult(
+      encodedKeyPackage: dco_decode_String(arr[0]),
+      tags: dco_decode_list_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  KeyPackageRotation dco_decode_key_package_rotation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return KeyPackageRotation(
+      toPublish: dco_decode_list_managed_key_package(arr[0]),
+      toDelete: dco_decode_list_managed_key_package(arr[1]),
+    );
+  }
+
+  @protected
+  KeyPackageState dco_decode_key_package_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return KeyPackageState.values[raw as int];
+  }
+
+  @protected
+  KeyringPolicy dco_decode_keyring_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return KeyringPolicy(
+      retainedEpochs: dco_decode_u_32(arr[0]),
+      maxAgeSecs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<BatchEventResult> dco_decode_list_batch_event_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_batch_event_result).toList();
+  }
+
+  @protected
+  List<CommitAuditEntry> dco_decode_list_commit_audit_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_commit_audit_entry).toList();
+  }
+
+  @protected
+  List<DecryptedMessage> dco_decode_list_decrypted_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_decrypted_message).toList();
+  }
+
+  @protected
+  List<GiftWrappedWelcome> dco_decode_list_gift_wrapped_welcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_gift_wrapped_welcome).toList();
+  }
+
+  @protected
+  List<GroupSummary> dco_decode_list_group_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_group_summary).toList();
+  }
+
+  @protected
+  List<InboxWelcome> dco_decode_list_inbox_welcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_inbox_welcome).toList();
+  }
+
+  @protected
+  List<List<String>> dco_decode_list_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_list_String).toList();
+  }
+
+  @protected
+  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_list_prim_u_8_strict).toList();
+  }
+
+  @protected
+  List<ManagedKeyPackage> dco_decode_list_managed_key_package(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_managed_key_package).toList();
+  }
+
+  @protected
+  Uint64List dco_decode_list_prim_u_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeUint64List(raw);
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<ProposalSummary> dco_decode_list_proposal_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_proposal_summary).toList();
+  }
+
+  @protected
+  List<RotationStatus> dco_decode_list_rotation_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_rotation_status).toList();
+  }
+
+  @protected
+  List<WelcomeRecipient> dco_decode_list_welcome_recipient(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_welcome_recipient).toList();
+  }
+
+  @protected
+  List<WelcomeSummary> dco_decode_list_welcome_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_welcome_summary).toList();
+  }
+
+  @protected
+  ManagedKeyPackage dco_decode_managed_key_package(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return ManagedKeyPackage(
+      id: dco_decode_String(arr[0]),
+      encodedKeyPackage: dco_decode_String(arr[1]),
+      tags: dco_decode_list_list_String(arr[2]),
+      lastResort: dco_decode_bool(arr[3]),
+      state: dco_decode_key_package_state(arr[4]),
+      eventId: dco_decode_opt_String(arr[5]),
+      createdAt: dco_decode_u_64(arr[6]),
+      publishedAt: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      consumedAt: dco_decode_opt_box_autoadd_u_64(arr[8]),
+    );
+  }
+
+  @protected
+  MediaReference dco_decode_media_reference(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return MediaReference(
+      url: dco_decode_opt_String(arr[0]),
+      mimeType: dco_decode_String(arr[1]),
+      filename: dco_decode_String(arr[2]),
+      originalHash: dco_decode_String(arr[3]),
+      nonce: dco_decode_String(arr[4]),
+      epoch: dco_decode_u_64(arr[5]),
+      size: dco_decode_u_64(arr[6]),
+    );
+  }
+
+  @protected
+  MessageCursor dco_decode_message_cursor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MessageCursor(
+      createdAt: dco_decode_u_64(arr[0]),
+      eventId: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  MlsBridgeError dco_decode_mls_bridge_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return MlsBridgeError_NotInitialized();
+      case 1:
+        return MlsBridgeError_InvalidInput(
+          field: dco_decode_String(raw[1]),
+          message: dco_decode_String(raw[2]),
+        );
+      case 2:
+        return MlsBridgeError_GroupNotFound();
+      case 3:
+        return MlsBridgeError_StaleEpoch(
+          message: dco_decode_String(raw[1]),
+        );
+      case 4:
+        return MlsBridgeError_OwnMessage();
+      case 5:
+        return MlsBridgeError_DecryptionFailed(
+          message: dco_decode_String(raw[1]),
+        );
+      case 6:
+        return MlsBridgeError_StorageLocked(
+          message: dco_decode_String(raw[1]),
+        );
+      case 7:
+        return MlsBridgeError_KeyPackageNotFound();
+      case 8:
+        return MlsBridgeError_PermissionDenied(
+          message: dco_decode_String(raw[1]),
+        );
+      case 9:
+        return MlsBridgeError_Signer(
+          message: dco_decode_String(raw[1]),
+        );
+      case 10:
+        return MlsBridgeError_Transfer(
+          message: dco_decode_String(raw[1]),
+        );
+      case 11:
+        return MlsBridgeError_Storage(
+          message: dco_decode_String(raw[1]),
+        );
+      case 12:
+        return MlsBridgeError_Mls(
+          message: dco_decode_String(raw[1]),
+        );
+      case 13:
+        return MlsBridgeError_Internal(
+          message: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  NostrGroupData dco_decode_nostr_group_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return NostrGroupData(
+      nostrGroupId: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      description: dco_decode_String(arr[2]),
+      adminPubkeys: dco_decode_list_String(arr[3]),
+      relays: dco_decode_list_String(arr[4]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  DecryptedMessage? dco_decode_opt_box_autoadd_decrypted_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_decrypted_message(raw);
+  }
+
+  @protected
+  MessageCursor? dco_decode_opt_box_autoadd_message_cursor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_message_cursor(raw);
+  }
+
+  @protected
+  MlsBridgeError? dco_decode_opt_box_autoadd_mls_bridge_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_mls_bridge_error(raw);
+  }
+
+  @protected
+  PendingCommit? dco_decode_opt_box_autoadd_pending_commit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_pending_commit(raw);
+  }
+
+  @protected
+  ProcessedMessage? dco_decode_opt_box_autoadd_processed_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_processed_message(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_String(raw);
+  }
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
+  @protected
+  PendingCommit dco_decode_pending_commit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PendingCommit(
+      epoch: dco_decode_u_64(arr[0]),
+      kind: dco_decode_pending_commit_kind(arr[1]),
+      eventId: dco_decode_opt_String(arr[2]),
+      eventJson: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  PendingCommitKind dco_decode_pending_commit_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PendingCommitKind.values[raw as int];
+  }
+
+  @protected
+  PolicyRole dco_decode_policy_role(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PolicyRole.values[raw as int];
+  }
+
+  @protected
+  ProcessedMessage dco_decode_processed_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return ProcessedMessage(
+      message: dco_decode_opt_box_autoadd_decrypted_message(arr[0]),
+      addedMembers: dco_decode_list_String(arr[1]),
+      removedMembers: dco_decode_list_String(arr[2]),
+      commit: dco_decode_opt_list_prim_u_8_strict(arr[3]),
+      welcome: dco_decode_opt_list_prim_u_8_strict(arr[4]),
+      stagedMessageBytes: dco_decode_opt_list_prim_u_8_strict(arr[5]),
+      pending: dco_decode_bool(arr[6]),
+      releasedMessages: dco_decode_list_decrypted_message(arr[7]),
+      superseded: dco_decode_bool(arr[8]),
+      ownCommitRolledBack: dco_decode_bool(arr[9]),
+    );
+  }
+
+  @protected
+  ProposalCommitResult dco_decode_proposal_commit_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ProposalCommitResult(
+      commitEvent: dco_decode_event_result(arr[0]),
+      welcomeMessage: dco_decode_opt_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  ProposalKind dco_decode_proposal_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ProposalKind.values[raw as int];
+  }
+
+  @protected
+  ProposalSummary dco_decode_proposal_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ProposalSummary(
+      proposalRef: dco_decode_String(arr[0]),
+      kind: dco_decode_proposal_kind(arr[1]),
+      proposer: dco_decode_opt_String(arr[2]),
+      target: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  RetentionPolicy dco_decode_retention_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RetentionPolicy(
+      messageMaxAgeSecs: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      purgeInactiveGroups: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  RetentionReport dco_decode_retention_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RetentionReport(
+      purgedGroups: dco_decode_list_list_prim_u_8_strict(arr[0]),
+      expiredMessages: dco_decode_u_32(arr[1]),
+      forgottenKeyPackages: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  RotationPolicy dco_decode_rotation_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RotationPolicy(
+      maxMessages: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      maxAgeSecs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  RotationStatus dco_decode_rotation_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return RotationStatus(
+      mlsGroupId: dco_decode_list_prim_u_8_strict(arr[0]),
+      lastRotatedAt: dco_decode_u_64(arr[1]),
+      messagesSinceRotation: dco_decode_u_64(arr[2]),
+      due: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  SignerScope dco_decode_signer_scope(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SignerScope.values[raw as int];
+  }
+
+  @protected
+  StorageKeySource dco_decode_storage_key_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return StorageKeySource_Passphrase(
+          passphrase: dco_decode_String(raw[1]),
+        );
+      case 1:
+        return StorageKeySource_KeyFile(
+          path: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  UnwrappedWelcome dco_decode_unwrapped_welcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return UnwrappedWelcome(
+      wrapperEventId: dco_decode_String(arr[0]),
+      sender: dco_decode_String(arr[1]),
+      rumorJson: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  UploadedMedia dco_decode_uploaded_media(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return UploadedMedia(
+      imetaTag: dco_decode_list_String(arr[0]),
+      reference: dco_decode_media_reference(arr[1]),
+    );
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  WelcomeKeyPackageMatch dco_decode_welcome_key_package_match(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WelcomeKeyPackageMatch(
+      found: dco_decode_bool(arr[0]),
+      matchedIndex: dco_decode_opt_box_autoadd_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  WelcomeList dco_decode_welcome_list(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WelcomeList(
+      welcomes: dco_decode_list_inbox_welcome(arr[0]),
+      unreadable: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  WelcomeRecipient dco_decode_welcome_recipient(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WelcomeRecipient(
+      publicKey: dco_decode_String(arr[0]),
+      keyPackageEventId: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  WelcomeState dco_decode_welcome_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WelcomeState.values[raw as int];
+  }
+
+  @protected
+  WelcomeStateFilter dco_decode_welcome_state_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WelcomeStateFilter.values[raw as int];
+  }
+
+  @protected
+  WelcomeSummary dco_decode_welcome_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return WelcomeSummary(
+      welcomeEventId: dco_decode_String(arr[0]),
+      wrapperEventId: dco_decode_String(arr[1]),
+      mlsGroupId: dco_decode_list_prim_u_8_strict(arr[2]),
+      nostrGroupData: dco_decode_nostr_group_data(arr[3]),
+      welcomer: dco_decode_String(arr[4]),
+      memberCount: dco_decode_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  Object sse_decode_DartOpaque(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_isize(deserializer);
+    return decodeDartOpaque(inner, generalizedFrbRustBinding);
+  }
+
+  @protected
+  RustStreamSink<GroupEvent> sse_decode_StreamSink_group_event_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AddMembersResult sse_decode_add_members_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_commitMessage = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_welcomeMessage = sse_decode_list_prim_u_8_strict(deserializer);
+    return AddMembersResult(
+        commitMessage: var_commitMessage, welcomeMessage: var_welcomeMessage);
+  }
+
+  @protected
+  BatchEventResult sse_decode_batch_event_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_eventId = sse_decode_opt_String(deserializer);
+    var var_processed = sse_decode_opt_box_autoadd_processed_message(
+        deserializer);
+    var var_error = sse_decode_opt_box_autoadd_mls_bridge_error(deserializer);
+    return BatchEventResult(
+        eventId: var_eventId, processed: var_processed, error: var_error);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  DecryptedMessage sse_decode_box_autoadd_decrypted_message(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_decrypted_message(deserializer));
+  }
+
+  @protected
+  GroupInfo sse_decode_box_autoadd_group_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_group_info(deserializer));
+  }
+
+  @protected
+  GroupPolicy sse_decode_box_autoadd_group_policy(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_group_policy(deserializer));
+  }
+
+  @protected
+  KeyPackagePolicy sse_decode_box_autoadd_key_package_policy(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_key_package_policy(deserializer));
+  }
+
+  @protected
+  KeyringPolicy sse_decode_box_autoadd_keyring_policy(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_keyring_policy(deserializer));
+  }
+
+  @protected
+  MediaReference sse_decode_box_autoadd_media_reference(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_media_reference(deserializer));
+  }
+
+  @protected
+  MessageCursor sse_decode_box_autoadd_message_cursor(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_message_cursor(deserializer));
+  }
+
+  @protected
+  MlsBridgeError sse_decode_box_autoadd_mls_bridge_error(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_mls_bridge_error(deserializer));
+  }
+
+  @protected
+  PendingCommit sse_decode_box_autoadd_pending_commit(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pending_commit(deserializer));
+  }
+
+  @protected
+  ProcessedMessage sse_decode_box_autoadd_processed_message(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_processed_message(deserializer));
+  }
+
+  @protected
+  RetentionPolicy sse_decode_box_autoadd_retention_policy(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_retention_policy(deserializer));
+  }
+
+  @protected
+  RotationPolicy sse_decode_box_autoadd_rotation_policy(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_rotation_policy(deserializer));
+  }
+
+  @protected
+  StorageKeySource sse_decode_box_autoadd_storage_key_source(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_storage_key_source(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  CommitAuditEntry sse_decode_commit_audit_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_epoch = sse_decode_u_64(deserializer);
+    var var_sender = sse_decode_opt_String(deserializer);
+    var var_reason = sse_decode_String(deserializer);
+    var var_createdAt = sse_decode_u_64(deserializer);
+    return CommitAuditEntry(
+        epoch: var_epoch,
+        sender: var_sender,
+        reason: var_reason,
+        createdAt: var_createdAt);
+  }
+
+  @protected
+  CommitResult sse_decode_commit_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_serializedCommit = sse_decode_list_prim_u_8_strict(deserializer);
+    return CommitResult(serializedCommit: var_serializedCommit);
+  }
+
+  @protected
+  CreateGroupResult sse_decode_create_group_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_group = sse_decode_group_info(deserializer);
+    var var_serializedWelcomeMessage = sse_decode_list_prim_u_8_strict(
+        deserializer);
+    return CreateGroupResult(
+        group: var_group,
+        serializedWelcomeMessage: var_serializedWelcomeMessage);
+  }
+
+  @protected
+  DecryptedMessage sse_decode_decrypted_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_eventId = sse_decode_String(deserializer);
+    var var_pubkey = sse_decode_String(deserializer);
+    var var_kind = sse_decode_u_16(deserializer);
+    var var_mlsGroupId = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_createdAt = sse_decode_u_64(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_tags = sse_decode_list_list_String(deserializer);
+    var var_rumorJson = sse_decode_String(deserializer);
+    return DecryptedMessage(
+        eventId: var_eventId,
+        pubkey: var_pubkey,
+        kind: var_kind,
+        mlsGroupId: var_mlsGroupId,
+        createdAt: var_createdAt,
+        content: var_content,
+        tags: var_tags,
+        rumorJson: var_rumorJson);
+  }
+
+  @protected
+  EncryptedMedia sse_decode_encrypted_media(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_encryptedData = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_encryptedHash = sse_decode_String(deserializer);
+    var var_reference = sse_decode_media_reference(deserializer);
+    return EncryptedMedia(
+        encryptedData: var_encryptedData,
+        encryptedHash: var_encryptedHash,
+        reference: var_reference);
+  }
+
+  @protected
+  EventResult sse_decode_event_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_eventId = sse_decode_String(deserializer);
+    var var_eventJson = sse_decode_String(deserializer);
+    return EventResult(eventId: var_eventId, eventJson: var_eventJson);
+  }
+
+  @protected
+  ExportedSecret sse_decode_exported_secret(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_secret = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_epoch = sse_decode_u_64(deserializer);
+    return ExportedSecret(secret: var_secret, epoch: var_epoch);
+  }
+
+  @protected
+  GiftWrappedWelcome sse_decode_gift_wrapped_welcome(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_recipient = sse_decode_String(deserializer);
+    var var_event = sse_decode_event_result(deserializer);
+    return GiftWrappedWelcome(recipient: var_recipient, event: var_event);
+  }
+
+  @protected
+  GroupEvent sse_decode_group_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_message = sse_decode_box_autoadd_decrypted_message(
+            deserializer);
+        return GroupEvent_MessageDecrypted(message: var_message);
+      case 1:
+        var var_mlsGroupId = sse_decode_list_prim_u_8_strict(deserializer);
+        var var_members = sse_decode_list_String(deserializer);
+        return GroupEvent_MembersAdded(
+            mlsGroupId: var_mlsGroupId, members: var_members);
+      case 2:
+        var var_mlsGroupId = sse_decode_list_prim_u_8_strict(deserializer);
+        var var_members = sse_decode_list_String(deserializer);
+        return GroupEvent_MembersRemoved(
+            mlsGroupId: var_mlsGroupId, members: var_members);
+      case 3:
+        var var_mlsGroupId = sse_decode_list_prim_u_8_strict(deserializer);
+        var var_epoch = sse_decode_u_64(deserializer);
+        return GroupEvent_EpochAdvanced(
+            mlsGroupId: var_mlsGroupId, epoch: var_epoch);
+      case 4:
+        var var_group = sse_decode_box_autoadd_group_info(deserializer);
+        return GroupEvent_GroupMetadataChanged(group: var_group);
+      case 5:
+        var var_wrapperEventId = sse_decode_String(deserializer);
+        var var_group = sse_decode_box_autoadd_group_info(deserializer);
+        return GroupEvent_WelcomeReceived(
+            wrapperEventId: var_wrapperEventId, group: var_group);
+      case 6:
+        var var_wrapperEventId = sse_decode_String(deserializer);
+        var var_keyPackageId = sse_decode_String(deserializer);
+        return GroupEvent_KeyPackageConsumed(
+            wrapperEventId: var_wrapperEventId, keyPackageId: var_keyPackageId);
+      case 7:
+        var var_mlsGroupId = sse_decode_list_prim_u_8_strict(deserializer);
+        var var_eventId = sse_decode_opt_String(deserializer);
+        return GroupEvent_PendingCommitRolledBack(
+            mlsGroupId: var_mlsGroupId, eventId: var_eventId);
+      case 8:
+        var var_mlsGroupId = sse_decode_list_prim_u_8_strict(deserializer);
+        return GroupEvent_GroupPurged(mlsGroupId: var_mlsGroupId);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  GroupHealth sse_decode_group_health(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mlsGroupId = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_epoch = sse_decode_u_64(deserializer);
+    var var_treeHash = sse_decode_String(deserializer);
+    var var_pendingMessageCount = sse_decode_u_32(deserializer);
+    var var_oldestPendingMessageAt = sse_decode_opt_box_autoadd_u_64(
+        deserializer);
+    var var_undecryptableEventCount = sse_decode_u_32(deserializer);
+    var var_lastUndecryptableEventAt = sse_decode_opt_box_autoadd_u_64(
+        deserializer);
+    var var_consecutiveFailures = sse_decode_u_32(deserializer);
+    var var_lastFailure = sse_decode_opt_String(deserializer);
+    var var_lastFailureAt = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_lastSuccessAt = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_recoveryRequestedAt = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_recoveryKeyPackageId = sse_decode_opt_String(deserializer);
+    var var_status = sse_decode_group_health_status(deserializer);
+    return GroupHealth(
+        mlsGroupId: var_mlsGroupId,
+        epoch: var_epoch,
+        treeHash: var_treeHash,
+        pendingMessageCount: var_pendingMessageCount,
+        oldestPendingMessageAt: var_oldestPendingMessageAt,
+        undecryptableEventCount: var_undecryptableEventCount,
+        lastUndecryptableEventAt: var_lastUndecryptableEventAt,
+        consecutiveFailures: var_consecutiveFailures,
+        lastFailure: var_lastFailure,
+        lastFailureAt: var_lastFailureAt,
+        lastSuccessAt: var_lastSuccessAt,
+        recoveryRequestedAt: var_recoveryRequestedAt,
+        recoveryKeyPackageId: var_recoveryKeyPackageId,
+        status: var_status);
+  }
+
+  @protected
+  GroupHealthStatus sse_decode_group_health_status(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return GroupHealthStatus.values[inner];
+  }
+
+  @protected
+  GroupInfo sse_decode_group_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mlsGroupId = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_members = sse_decode_list_String(deserializer);
+    var var_nostrGroupData = sse_decode_nostr_group_data(deserializer);
+    return GroupInfo(
+        mlsGroupId: var_mlsGroupId,
+        members: var_members,
+        nostrGroupData: var_nostrGroupData);
+  }
+
+  @protected
+  GroupPolicy sse_decode_group_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_addMembers = sse_decode_policy_role(deserializer);
+    var var_removeMembers = sse_decode_policy_role(deserializer);
+    var var_updateGroupData = sse_decode_policy_role(deserializer);
+    var var_allowSelfRemove = sse_decode_bool(deserializer);
+    return GroupPolicy(
+        addMembers: var_addMembers,
+        removeMembers: var_removeMembers,
+        updateGroupData: var_updateGroupData,
+        allowSelfRemove: var_allowSelfRemove);
+  }
+
+  @protected
+  GroupRecoveryRequest sse_decode_group_recovery_request(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mlsGroupId = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_keyPackage = sse_decode_managed_key_package(deserializer);
+    var var_admins = sse_decode_list_String(deserializer);
+    return GroupRecoveryRequest(
+        mlsGroupId: var_mlsGroupId,
+        keyPackage: var_keyPackage,
+        admins: var_admins);
+  }
+
+  @protected
+  GroupStateComparison sse_decode_group_state_comparison(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return GroupStateComparison.values[inner];
+  }
+
+  @protected
+  GroupStateFilter sse_decode_group_state_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return GroupStateFilter.values[inner];
+  }
+
+  @protected
+  GroupStatus sse_decode_group_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return GroupStatus.values[inner];
+  }
+
+  @protected
+  GroupSummary sse_decode_group_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mlsGroupId = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_nostrGroupId = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_description = sse_decode_String(deserializer);
+    var var_epoch = sse_decode_u_64(deserializer);
+    var var_memberCount = sse_decode_u_32(deserializer);
+    var var_lastMessageId = sse_decode_opt_String(deserializer);
+    var var_lastMessageAt = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_status = sse_decode_group_status(deserializer);
+    return GroupSummary(
+        mlsGroupId: var_mlsGroupId,
+        nostrGroupId: var_nostrGroupId,
+        name: var_name,
+        description: var_description,
+        epoch: var_epoch,
+        memberCount: var_memberCount,
+        lastMessageId: var_lastMessageId,
+        lastMessageAt: var_lastMessageAt,
+        status: var_status);
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  InboxWelcome sse_decode_inbox_welcome(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_sender = sse_decode_String(deserializer);
+    var var_group = sse_decode_group_info(deserializer);
+    var var_state = sse_decode_welcome_state(deserializer);
+    var var_receivedAt = sse_decode_u_64(deserializer);
+    return InboxWelcome(
+        id: var_id,
+        sender: var_sender,
+        group: var_group,
+        state: var_state,
+        receivedAt: var_receivedAt);
+  }
+
+  @protected
+  PlatformInt64 sse_decode_isize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  KeyPackageLookup sse_decode_key_package_lookup(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_found = sse_decode_bool(deserializer);
+    var var_keyPackage = sse_decode_opt_String(deserializer);
+    return KeyPackageLookup(found: var_found, keyPackage: var_keyPackage);
+  }
+
+  @protected
+  KeyPackagePolicy sse_decode_key_package_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_lastResortCount = sse_decode_u_32(deserializer);
+    var var_oneTimeCount = sse_decode_u_32(deserializer);
+    var var_maxAgeSecs = sse_decode_u_64(deserializer);
+    return KeyPackagePolicy(
+        lastResortCount: var_lastResortCount,
+        oneTimeCount: var_oneTimeCount,
+        maxAgeSecs: var_maxAgeSecs);
+  }
+
+  @protected
+  KeyPackageResult sse_decode_key_package_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_encodedKeyPackage = sse_decode_String(deserializer);
+    var var_tags = sse_decode_list_list_String(deserializer);
+    return KeyPackageResult(
+        encodedKeyPackage: var_encodedKeyPackage, tags: var_tags);
+  }
+
+  @protected
+  KeyPackageRotation sse_decode_key_package_rotation(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_toPublish = sse_decode_list_managed_key_package(deserializer);
+    var var_toDelete = sse_decode_list_managed_key_package(deserializer);
+    return KeyPackageRotation(toPublish: var_toPublish, toDelete: var_toDelete);
+  }
+
+  @protected
+  KeyPackageState sse_decode_key_package_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return KeyPackageState.values[inner];
+  }
+
+  @protected
+  KeyringPolicy sse_decode_keyring_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_retainedEpochs = sse_decode_u_32(deserializer);
+    var var_maxAgeSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return KeyringPolicy(
+        retainedEpochs: var_retainedEpochs, maxAgeSecs: var_maxAgeSecs);
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BatchEventResult> sse_decode_list_batch_event_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BatchEventResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_batch_event_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CommitAuditEntry> sse_decode_list_commit_audit_entry(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CommitAuditEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_commit_audit_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DecryptedMessage> sse_decode_list_decrypted_message(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DecryptedMessage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_decrypted_message(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<GiftWrappedWelcome> sse_decode_list_gift_wrapped_welcome(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <GiftWrappedWelcome>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_gift_wrapped_welcome(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<GroupSummary> sse_decode_list_group_summary(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <GroupSummary>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_group_summary(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<InboxWelcome> sse_decode_list_inbox_welcome(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <InboxWelcome>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_inbox_welcome(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <List<String>>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_list_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Uint8List> sse_decode_list_list_prim_u_8_strict(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Uint8List>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_list_prim_u_8_strict(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ManagedKeyPackage> sse_decode_list_managed_key_package(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ManagedKeyPackage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_managed_key_package(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint64List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<ProposalSummary> sse_decode_list_proposal_summary(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ProposalSummary>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_proposal_summary(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RotationStatus> sse_decode_list_rotation_status(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RotationStatus>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_rotation_status(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WelcomeRecipient> sse_decode_list_welcome_recipient(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WelcomeRecipient>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_welcome_recipient(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WelcomeSummary> sse_decode_list_welcome_summary(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WelcomeSummary>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_welcome_summary(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  ManagedKeyPackage sse_decode_managed_key_package(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_encodedKeyPackage = sse_decode_String(deserializer);
+    var var_tags = sse_decode_list_list_String(deserializer);
+    var var_lastResort = sse_decode_bool(deserializer);
+    var var_state = sse_decode_key_package_state(deserializer);
+    var var_eventId = sse_decode_opt_String(deserializer);
+    var var_createdAt = sse_decode_u_64(deserializer);
+    var var_publishedAt = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_consumedAt = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return ManagedKeyPackage(
+        id: var_id,
+        encodedKeyPackage: var_encodedKeyPackage,
+        tags: var_tags,
+        lastResort: var_lastResort,
+        state: var_state,
+        eventId: var_eventId,
+        createdAt: var_createdAt,
+        publishedAt: var_publishedAt,
+        consumedAt: var_consumedAt);
+  }
+
+  @protected
+  MediaReference sse_decode_media_reference(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_opt_String(deserializer);
+    var var_mimeType = sse_decode_String(deserializer);
+    var var_filename = sse_decode_String(deserializer);
+    var var_originalHash = sse_decode_String(deserializer);
+    var var_nonce = sse_decode_String(deserializer);
+    var var_epoch = sse_decode_u_64(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    return MediaReference(
+        url: var_url,
+        mimeType: var_mimeType,
+        filename: var_filename,
+        originalHash: var_originalHash,
+        nonce: var_nonce,
+        epoch: var_epoch,
+        size: var_size);
+  }
+
+  @protected
+  MessageCursor sse_decode_message_cursor(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_createdAt = sse_decode_u_64(deserializer);
+    var var_eventId = sse_decode_String(deserializer);
+    return MessageCursor(createdAt: var_createdAt, eventId: var_eventId);
+  }
+
+  @protected
+  MlsBridgeError sse_decode_mls_bridge_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return MlsBridgeError_NotInitialized();
+      case 1:
+        var var_field = sse_decode_String(deserializer);
+        var var_message = sse_decode_String(deserializer);
+        return MlsBridgeError_InvalidInput(
+            field: var_field, message: var_message);
+      case 2:
+        return MlsBridgeError_GroupNotFound();
+      case 3:
+        var var_message = sse_decode_String(deserializer);
+        return MlsBridgeError_StaleEpoch(message: var_message);
+      case 4:
+        return MlsBridgeError_OwnMessage();
+      case 5:
+        var var_message = sse_decode_String(deserializer);
+        return MlsBridgeError_DecryptionFailed(message: var_message);
+      case 6:
+        var var_message = sse_decode_String(deserializer);
+        return MlsBridgeError_StorageLocked(message: var_message);
+      case 7:
+        return MlsBridgeError_KeyPackageNotFound();
+      case 8:
+        var var_message = sse_decode_String(deserializer);
+        return MlsBridgeError_PermissionDenied(message: var_message);
+      case 9:
+        var var_message = sse_decode_String(deserializer);
+        return MlsBridgeError_Signer(message: var_message);
+      case 10:
+        var var_message = sse_decode_String(deserializer);
+        return MlsBridgeError_Transfer(message: var_message);
+      case 11:
+        var var_message = sse_decode_String(deserializer);
+        return MlsBridgeError_Storage(message: var_message);
+      case 12:
+        var var_message = sse_decode_String(deserializer);
+        return MlsBridgeError_Mls(message: var_message);
+      case 13:
+        var var_message = sse_decode_String(deserializer);
+        return MlsBridgeError_Internal(message: var_message);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  NostrGroupData sse_decode_nostr_group_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_nostrGroupId = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_description = sse_decode_String(deserializer);
+    var var_adminPubkeys = sse_decode_list_String(deserializer);
+    var var_relays = sse_decode_list_String(deserializer);
+    return NostrGroupData(
+        nostrGroupId: var_nostrGroupId,
+        name: var_name,
+        description: var_description,
+        adminPubkeys: var_adminPubkeys,
+        relays: var_relays);
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DecryptedMessage? sse_decode_opt_box_autoadd_decrypted_message(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_decrypted_message(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MessageCursor? sse_decode_opt_box_autoadd_message_cursor(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_message_cursor(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MlsBridgeError? sse_decode_opt_box_autoadd_mls_bridge_error(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_mls_bridge_error(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PendingCommit? sse_decode_opt_box_autoadd_pending_commit(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_pending_commit(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ProcessedMessage? sse_decode_opt_box_autoadd_processed_message(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_processed_message(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PendingCommit sse_decode_pending_commit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_epoch = sse_decode_u_64(deserializer);
+    var var_kind = sse_decode_pending_commit_kind(deserializer);
+    var var_eventId = sse_decode_opt_String(deserializer);
+    var var_eventJson = sse_decode_opt_String(deserializer);
+    return PendingCommit(
+        epoch: var_epoch,
+        kind: var_kind,
+        eventId: var_eventId,
+        eventJson: var_eventJson);
+  }
+
+  @protected
+  PendingCommitKind sse_decode_pending_commit_kind(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PendingCommitKind.values[inner];
+  }
+
+  @protected
+  PolicyRole sse_decode_policy_role(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PolicyRole.values[inner];
+  }
+
+  @protected
+  ProcessedMessage sse_decode_processed_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_message = sse_decode_opt_box_autoadd_decrypted_message(
+        deserializer);
+    var var_addedMembers = sse_decode_list_String(deserializer);
+    var var_removedMembers = sse_decode_list_String(deserializer);
+    var var_commit = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_welcome = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_stagedMessageBytes = sse_decode_opt_list_prim_u_8_strict(
+        deserializer);
+    var var_pending = sse_decode_bool(deserializer);
+    var var_releasedMessages = sse_decode_list_decrypted_message(deserializer);
+    var var_superseded = sse_decode_bool(deserializer);
+    var var_ownCommitRolledBack = sse_decode_bool(deserializer);
+    return ProcessedMessage(
+        message: var_message,
+        addedMembers: var_addedMembers,
+        removedMembers: var_removedMembers,
+        commit: var_commit,
+        welcome: var_welcome,
+        stagedMessageBytes: var_stagedMessageBytes,
+        pending: var_pending,
+        releasedMessages: var_releasedMessages,
+        superseded: var_superseded,
+        ownCommitRolledBack: var_ownCommitRolledBack);
+  }
+
+  @protected
+  ProposalCommitResult sse_decode_proposal_commit_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_commitEvent = sse_decode_event_result(deserializer);
+    var var_welcomeMessage = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    return ProposalCommitResult(
+        commitEvent: var_commitEvent, welcomeMessage: var_welcomeMessage);
+  }
+
+  @protected
+  ProposalKind sse_decode_proposal_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ProposalKind.values[inner];
+  }
+
+  @protected
+  ProposalSummary sse_decode_proposal_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_proposalRef = sse_decode_String(deserializer);
+    var var_kind = sse_decode_proposal_kind(deserializer);
+    var var_proposer = sse_decode_opt_String(deserializer);
+    var var_target = sse_decode_opt_String(deserializer);
+    return ProposalSummary(
+        proposalRef: var_proposalRef,
+        kind: var_kind,
+        proposer: var_proposer,
+        target: var_target);
+  }
+
+  @protected
+  RetentionPolicy sse_decode_retention_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_messageMaxAgeSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_purgeInactiveGroups = sse_decode_bool(deserializer);
+    return RetentionPolicy(
+        messageMaxAgeSecs: var_messageMaxAgeSecs,
+        purgeInactiveGroups: var_purgeInactiveGroups);
+  }
+
+  @protected
+  RetentionReport sse_decode_retention_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_purgedGroups = sse_decode_list_list_prim_u_8_strict(deserializer);
+    var var_expiredMessages = sse_decode_u_32(deserializer);
+    var var_forgottenKeyPackages = sse_decode_u_32(deserializer);
+    return RetentionReport(
+        purgedGroups: var_purgedGroups,
+        expiredMessages: var_expiredMessages,
+        forgottenKeyPackages: var_forgottenKeyPackages);
+  }
+
+  @protected
+  RotationPolicy sse_decode_rotation_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxMessages = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_maxAgeSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return RotationPolicy(
+        maxMessages: var_maxMessages, maxAgeSecs: var_maxAgeSecs);
+  }
+
+  @protected
+  RotationStatus sse_decode_rotation_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mlsGroupId = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_lastRotatedAt = sse_decode_u_64(deserializer);
+    var var_messagesSinceRotation = sse_decode_u_64(deserializer);
+    var var_due = sse_decode_bool(deserializer);
+    return RotationStatus(
+        mlsGroupId: var_mlsGroupId,
+        lastRotatedAt: var_lastRotatedAt,
+        messagesSinceRotation: var_messagesSinceRotation,
+        due: var_due);
+  }
+
+  @protected
+  SignerScope sse_decode_signer_scope(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SignerScope.values[inner];
+  }
+
+  @protected
+  StorageKeySource sse_decode_storage_key_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_passphrase = sse_decode_String(deserializer);
+        return StorageKeySource_Passphrase(passphrase: var_passphrase);
+      case 1:
+        var var_path = sse_decode_String(deserializer);
+        return StorageKeySource_KeyFile(path: var_path);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  UnwrappedWelcome sse_decode_unwrapped_welcome(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_wrapperEventId = sse_decode_String(deserializer);
+    var var_sender = sse_decode_String(deserializer);
+    var var_rumorJson = sse_decode_String(deserializer);
+    return UnwrappedWelcome(
+        wrapperEventId: var_wrapperEventId,
+        sender: var_sender,
+        rumorJson: var_rumorJson);
+  }
+
+  @protected
+  UploadedMedia sse_decode_uploaded_media(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_imetaTag = sse_decode_list_String(deserializer);
+    var var_reference = sse_decode_media_reference(deserializer);
+    return UploadedMedia(imetaTag: var_imetaTag, reference: var_reference);
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  WelcomeKeyPackageMatch sse_decode_welcome_key_package_match(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_found = sse_decode_bool(deserializer);
+    var var_matchedIndex = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return WelcomeKeyPackageMatch(
+        found: var_found, matchedIndex: var_matchedIndex);
+  }
+
+  @protected
+  WelcomeList sse_decode_welcome_list(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_welcomes = sse_decode_list_inbox_welcome(deserializer);
+    var var_unreadable = sse_decode_u_32(deserializer);
+    return WelcomeList(welcomes: var_welcomes, unreadable: var_unreadable);
+  }
+
+  @protected
+  WelcomeRecipient sse_decode_welcome_recipient(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_publicKey = sse_decode_String(deserializer);
+    var var_keyPackageEventId = sse_decode_String(deserializer);
+    return WelcomeRecipient(
+        publicKey: var_publicKey, keyPackageEventId: var_keyPackageEventId);
+  }
+
+  @protected
+  WelcomeState sse_decode_welcome_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WelcomeState.values[inner];
+  }
+
+  @protected
+  WelcomeStateFilter sse_decode_welcome_state_filter(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WelcomeStateFilter.values[inner];
+  }
+
+  @protected
+  WelcomeSummary sse_decode_welcome_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_welcomeEventId = sse_decode_String(deserializer);
+    var var_wrapperEventId = sse_decode_String(deserializer);
+    var var_mlsGroupId = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_nostrGroupData = sse_decode_nostr_group_data(deserializer);
+    var var_welcomer = sse_decode_String(deserializer);
+    var var_memberCount = sse_decode_u_32(deserializer);
+    return WelcomeSummary(
+        welcomeEventId: var_welcomeEventId,
+        wrapperEventId: var_wrapperEventId,
+        mlsGroupId: var_mlsGroupId,
+        nostrGroupData: var_nostrGroupData,
+        welcomer: var_welcomer,
+        memberCount: var_memberCount);
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(
+      FutureOr<String?> Function(String) self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+        encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(self),
+        serializer);
+  }
+
+  @protected
+  void
+      sse_encode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+          FutureOr<Uint8List?> Function(String) self,
+          SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+        encode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+            self),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_DartFn_Inputs_String_String_Output_opt_String_AnyhowException(
+      FutureOr<String?> Function(String, String) self,
+      SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+        encode_DartFn_Inputs_String_String_Output_opt_String_AnyhowException(
+            self),
+        serializer);
+  }
+
+  @protected
+  void
+      sse_encode_DartFn_Inputs_String_list_prim_u_8_strict_Output_opt_list_prim_u_8_strict_AnyhowException(
+          FutureOr<Uint8List?> Function(String, Uint8List) self,
+          SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+        encode_DartFn_Inputs_String_list_prim_u_8_strict_Output_opt_list_prim_u_8_strict_AnyhowException(
+            self),
+        serializer);
+  }
+
+  @protected
+  void
+      sse_encode_DartFn_Inputs_list_prim_u_8_strict_String_Output_opt_String_AnyhowException(
+          FutureOr<String?> Function(Uint8List, String) self,
+          SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+        encode_DartFn_Inputs_list_prim_u_8_strict_String_Output_opt_String_AnyhowException(
+            self),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_DartOpaque(Object self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_isize(
+        PlatformPointerUtil.ptrToPlatformInt64(encodeDartOpaque(
+            self, portManager.dartHandlerPort, generalizedFrbRustBinding)),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_group_event_Sse(
+      RustStreamSink<GroupEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_group_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_add_members_result(
+      AddMembersResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.commitMessage, serializer);
+    sse_encode_list_prim_u_8_strict(self.welcomeMessage, serializer);
+  }
+
+  @protected
+  void sse_encode_batch_event_result(
+      BatchEventResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.eventId, serializer);
+    sse_encode_opt_box_autoadd_processed_message(self.processed, serializer);
+    sse_encode_opt_box_autoadd_mls_bridge_error(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_decrypted_message(
+      DecryptedMessage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_decrypted_message(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_group_info(
+      GroupInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_group_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_group_policy(
+      GroupPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_group_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_key_package_policy(
+      KeyPackagePolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_key_package_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_keyring_policy(
+      KeyringPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_keyring_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_media_reference(
+      MediaReference self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_media_reference(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_message_cursor(
+      MessageCursor self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_message_cursor(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_mls_bridge_error(
+      MlsBridgeError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_mls_bridge_error(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_pending_commit(
+      PendingCommit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_pending_commit(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_processed_message(
+      ProcessedMessage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_processed_message(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_retention_policy(
+      RetentionPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_retention_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_rotation_policy(
+      RotationPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_rotation_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_storage_key_source(
+      StorageKeySource self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_storage_key_source(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_commit_audit_entry(
+      CommitAuditEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.epoch, serializer);
+    sse_encode_opt_String(self.sender, serializer);
+    sse_encode_String(self.reason, serializer);
+    sse_encode_u_64(self.createdAt, serializer);
+  }
+
+  @protected
+  void sse_encode_commit_result(CommitResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.serializedCommit, serializer);
+  }
+
+  @protected
+  void sse_encode_create_group_result(
+      CreateGroupResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_group_info(self.group, serializer);
+    sse_encode_list_prim_u_8_strict(self.serializedWelcomeMessage, serializer);
+  }
+
+  @protected
+  void sse_encode_decrypted_message(
+      DecryptedMessage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.eventId, serializer);
+    sse_encode_String(self.pubkey, serializer);
+    sse_encode_u_16(self.kind, serializer);
+    sse_encode_list_prim_u_8_strict(self.mlsGroupId, serializer);
+    sse_encode_u_64(self.createdAt, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_list_list_String(self.tags, serializer);
+    sse_encode_String(self.rumorJson, serializer);
+  }
+
+  @protected
+  void sse_encode_encrypted_media(
+      EncryptedMedia self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.encryptedData, serializer);
+    sse_encode_String(self.encryptedHash, serializer);
+    sse_encode_media_reference(self.reference, serializer);
+  }
+
+  @protected
+  void sse_encode_event_result(EventResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.eventId, serializer);
+    sse_encode_String(self.eventJson, serializer);
+  }
+
+  @protected
+  void sse_encode_exported_secret(
+      ExportedSecret self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.secret, serializer);
+    sse_encode_u_64(self.epoch, serializer);
+  }
+
+  @protected
+  void sse_encode_gift_wrapped_welcome(
+      GiftWrappedWelcome self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.recipient, serializer);
+    sse_encode_event_result(self.event, serializer);
+  }
+
+  @protected
+  void sse_encode_group_event(GroupEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case GroupEvent_MessageDecrypted(message: final message):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_decrypted_message(message, serializer);
+      case GroupEvent_MembersAdded(
+          mlsGroupId: final mlsGroupId,
+          members: final members
+        ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_list_prim_u_8_strict(mlsGroupId, serializer);
+        sse_encode_list_String(members, serializer);
+      case GroupEvent_MembersRemoved(
+          mlsGroupId: final mlsGroupId,
+          members: final members
+        ):
+        sse_encode_i_32(2, serializer);
+        sse_encode_list_prim_u_8_strict(mlsGroupId, serializer);
+        sse_encode_list_String(members, serializer);
+      case GroupEvent_EpochAdvanced(
+          mlsGroupId: final mlsGroupId,
+          epoch: final epoch
+        ):
+        sse_encode_i_32(3, serializer);
+        sse_encode_list_prim_u_8_strict(mlsGroupId, serializer);
+        sse_encode_u_64(epoch, serializer);
+      case GroupEvent_GroupMetadataChanged(group: final group):
+        sse_encode_i_32(4, serializer);
+        sse_encode_box_autoadd_group_info(group, serializer);
+      case GroupEvent_WelcomeReceived(
+          wrapperEventId: final wrapperEventId,
+          group: final group
+        ):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(wrapperEventId, serializer);
+        sse_encode_box_autoadd_group_info(group, serializer);
+      case GroupEvent_KeyPackageConsumed(
+          wrapperEventId: final wrapperEventId,
+          keyPackageId: final keyPackageId
+        ):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(wrapperEventId, serializer);
+        sse_encode_String(keyPackageId, serializer);
+      case GroupEvent_PendingCommitRolledBack(
+          mlsGroupId: final mlsGroupId,
+          eventId: final eventId
+        ):
+        sse_encode_i_32(7, serializer);
+        sse_encode_list_prim_u_8_strict(mlsGroupId, serializer);
+        sse_encode_opt_String(eventId, serializer);
+      case GroupEvent_GroupPurged(mlsGroupId: final mlsGroupId):
+        sse_encode_i_32(8, serializer);
+        sse_encode_list_prim_u_8_strict(mlsGroupId, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_group_health(GroupHealth self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.mlsGroupId, serializer);
+    sse_encode_u_64(self.epoch, serializer);
+    sse_encode_String(self.treeHash, serializer);
+    sse_encode_u_32(self.pendingMessageCount, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.oldestPendingMessageAt, serializer);
+    sse_encode_u_32(self.undecryptableEventCount, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.lastUndecryptableEventAt, serializer);
+    sse_encode_u_32(self.consecutiveFailures, serializer);
+    sse_encode_opt_String(self.lastFailure, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.lastFailureAt, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.lastSuccessAt, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.recoveryRequestedAt, serializer);
+    sse_encode_opt_String(self.recoveryKeyPackageId, serializer);
+    sse_encode_group_health_status(self.status, serializer);
+  }
+
+  @protected
+  void sse_encode_group_health_status(
+      GroupHealthStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_group_info(GroupInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.mlsGroupId, serializer);
+    sse_encode_list_String(self.members, serializer);
+    sse_encode_nostr_group_data(self.nostrGroupData, serializer);
+  }
+
+  @protected
+  void sse_encode_group_policy(GroupPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_policy_role(self.addMembers, serializer);
+    sse_encode_policy_role(self.removeMembers, serializer);
+    sse_encode_policy_role(self.updateGroupData, serializer);
+    sse_encode_bool(self.allowSelfRemove, serializer);
+  }
+
+  @protected
+  void sse_encode_group_recovery_request(
+      GroupRecoveryRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.mlsGroupId, serializer);
+    sse_encode_managed_key_package(self.keyPackage, serializer);
+    sse_encode_list_String(self.admins, serializer);
+  }
+
+  @protected
+  void sse_encode_group_state_comparison(
+      GroupStateComparison self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_group_state_filter(
+      GroupStateFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_group_status(GroupStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_group_summary(GroupSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.mlsGroupId, serializer);
+    sse_encode_String(self.nostrGroupId, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_u_64(self.epoch, serializer);
+    sse_encode_u_32(self.memberCount, serializer);
+    sse_encode_opt_String(self.lastMessageId, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.lastMessageAt, serializer);
+    sse_encode_group_status(self.status, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_inbox_welcome(InboxWelcome self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.sender, serializer);
+    sse_encode_group_info(self.group, serializer);
+    sse_encode_welcome_state(self.state, serializer);
+    sse_encode_u_64(self.receivedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_key_package_lookup(
+      KeyPackageLookup self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.found, serializer);
+    sse_encode_opt_String(self.keyPackage, serializer);
+  }
+
+  @protected
+  void sse_encode_key_package_policy(
+      KeyPackagePolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.lastResortCount, serializer);
+    sse_encode_u_32(self.oneTimeCount, serializer);
+    sse_encode_u_64(self.maxAgeSecs, serializer);
+  }
+
+  @protected
+  void sse_encode_key_package_result(
+      KeyPackageResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.encodedKeyPackage, serializer);
+    sse_encode_list_list_String(self.tags, serializer);
+  }
+
+  @protected
+  void sse_encode_key_package_rotation(
+      KeyPackageRotation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_managed_key_package(self.toPublish, serializer);
+    sse_encode_list_managed_key_package(self.toDelete, serializer);
+  }
+
+  @protected
+  void sse_encode_key_package_state(
+      KeyPackageState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_keyring_policy(KeyringPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.retainedEpochs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxAgeSecs, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_batch_event_result(
+      List<BatchEventResult> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_batch_event_result(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_commit_audit_entry(
+      List<CommitAuditEntry> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_commit_audit_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_decrypted_message(
+      List<DecryptedMessage> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_decrypted_message(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_gift_wrapped_welcome(
+      List<GiftWrappedWelcome> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_gift_wrapped_welcome(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_group_summary(
+      List<GroupSummary> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_group_summary(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_inbox_welcome(
+      List<InboxWelcome> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_inbox_welcome(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_list_String(
+      List<List<String>> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_list_String(item, serializer);
+    }
+  }
+
   @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  void sse_encode_list_list_prim_u_8_strict(
+      List<Uint8List> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_list_prim_u_8_strict(item, serializer);
+    }
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  void sse_encode_list_managed_key_package(
+      List<ManagedKeyPackage> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_managed_key_package(item, serializer);
+    }
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  void sse_encode_list_prim_u_64_strict(
+      Uint64List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint64List(self);
   }
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+  void sse_encode_list_prim_u_8_loose(
+      List<int> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer
+        .putUint8List(self is Uint8List ? self : Uint8List.fromList(self));
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_list_prim_u_8_strict(
+      Uint8List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_list_proposal_summary(
+      List<ProposalSummary> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_proposal_summary(item, serializer);
+    }
   }
 
   @protected
-  List<String>? dco_decode_opt_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_String(raw);
+  void sse_encode_list_rotation_status(
+      List<RotationStatus> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_rotation_status(item, serializer);
+    }
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_welcome_recipient(
+      List<WelcomeRecipient> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_welcome_recipient(item, serializer);
+    }
   }
 
   @protected
-  U8Array32 dco_decode_u_8_array_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return U8Array32(dco_decode_list_prim_u_8_strict(raw));
+  void sse_encode_list_welcome_summary(
+      List<WelcomeSummary> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_welcome_summary(item, serializer);
+    }
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_managed_key_package(
+      ManagedKeyPackage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.encodedKeyPackage, serializer);
+    sse_encode_list_list_String(self.tags, serializer);
+    sse_encode_bool(self.lastResort, serializer);
+    sse_encode_key_package_state(self.state, serializer);
+    sse_encode_opt_String(self.eventId, serializer);
+    sse_encode_u_64(self.createdAt, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.publishedAt, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.consumedAt, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_media_reference(
+      MediaReference self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_opt_String(self.url, serializer);
+    sse_encode_String(self.mimeType, serializer);
+    sse_encode_String(self.filename, serializer);
+    sse_encode_String(self.originalHash, serializer);
+    sse_encode_String(self.nonce, serializer);
+    sse_encode_u_64(self.epoch, serializer);
+    sse_encode_u_64(self.size, serializer);
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_message_cursor(MessageCursor self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.createdAt, serializer);
+    sse_encode_String(self.eventId, serializer);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+  @protected
+  void sse_encode_mls_bridge_error(
+      MlsBridgeError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case MlsBridgeError_NotInitialized():
+        sse_encode_i_32(0, serializer);
+      case MlsBridgeError_InvalidInput(
+          field: final field,
+          message: final message
+        ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field, serializer);
+        sse_encode_String(message, serializer);
+      case MlsBridgeError_GroupNotFound():
+        sse_encode_i_32(2, serializer);
+      case MlsBridgeError_StaleEpoch(message: final message):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(message, serializer);
+      case MlsBridgeError_OwnMessage():
+        sse_encode_i_32(4, serializer);
+      case MlsBridgeError_DecryptionFailed(message: final message):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(message, serializer);
+      case MlsBridgeError_StorageLocked(message: final message):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(message, serializer);
+      case MlsBridgeError_KeyPackageNotFound():
+        sse_encode_i_32(7, serializer);
+      case MlsBridgeError_PermissionDenied(message: final message):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(message, serializer);
+      case MlsBridgeError_Signer(message: final message):
+        sse_encode_i_32(9, serializer);
+        sse_encode_String(message, serializer);
+      case MlsBridgeError_Transfer(message: final message):
+        sse_encode_i_32(10, serializer);
+        sse_encode_String(message, serializer);
+      case MlsBridgeError_Storage(message: final message):
+        sse_encode_i_32(11, serializer);
+        sse_encode_String(message, serializer);
+      case MlsBridgeError_Mls(message: final message):
+        sse_encode_i_32(12, serializer);
+        sse_encode_String(message, serializer);
+      case MlsBridgeError_Internal(message: final message):
+        sse_encode_i_32(13, serializer);
+        sse_encode_String(message, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  void sse_encode_nostr_group_data(
+      NostrGroupData self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_String(self.nostrGroupId, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_list_String(self.adminPubkeys, serializer);
+    sse_encode_list_String(self.relays, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_decrypted_message(
+      DecryptedMessage? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_decrypted_message(self, serializer);
     }
   }
 
   @protected
-  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_message_cursor(
+      MessageCursor? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_String(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_message_cursor(self, serializer);
     }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_mls_bridge_error(
+      MlsBridgeError? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_mls_bridge_error(self, serializer);
+    }
   }
 
   @protected
-  U8Array32 sse_decode_u_8_array_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_pending_commit(
+      PendingCommit? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return U8Array32(inner);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_pending_commit(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_processed_message(
+      ProcessedMessage? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_processed_message(self, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_opt_list_String(
+      List<String>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_String(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_opt_list_prim_u_8_strict(
+      Uint8List? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_strict(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_loose(
-      List<int> self, SseSerializer serializer) {
+  void sse_encode_pending_commit(PendingCommit self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer
-        .putUint8List(self is Uint8List ? self : Uint8List.fromList(self));
+    sse_encode_u_64(self.epoch, serializer);
+    sse_encode_pending_commit_kind(self.kind, serializer);
+    sse_encode_opt_String(self.eventId, serializer);
+    sse_encode_opt_String(self.eventJson, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
+  void sse_encode_pending_commit_kind(
+      PendingCommitKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_policy_role(PolicyRole self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+  @protected
+  void sse_encode_processed_message(
+      ProcessedMessage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_decrypted_message(self.message, serializer);
+    sse_encode_list_String(self.addedMembers, serializer);
+    sse_encode_list_String(self.removedMembers, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.commit, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.welcome, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.stagedMessageBytes, serializer);
+    sse_encode_bool(self.pending, serializer);
+    sse_encode_list_decrypted_message(self.releasedMessages, serializer);
+    sse_encode_bool(self.superseded, serializer);
+    sse_encode_bool(self.ownCommitRolledBack, serializer);
   }
 
   @protected
-  void sse_encode_opt_list_String(
-      List<String>? self, SseSerializer serializer) {
+  void sse_encode_proposal_commit_result(
+      ProposalCommitResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_event_result(self.commitEvent, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.welcomeMessage, serializer);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_String(self, serializer);
+  @protected
+  void sse_encode_proposal_kind(ProposalKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_proposal_summary(
+      ProposalSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.proposalRef, serializer);
+    sse_encode_proposal_kind(self.kind, serializer);
+    sse_encode_opt_String(self.proposer, serializer);
+    sse_encode_opt_String(self.target, serializer);
+  }
+
+  @protected
+  void sse_encode_retention_policy(
+      RetentionPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.messageMaxAgeSecs, serializer);
+    sse_encode_bool(self.purgeInactiveGroups, serializer);
+  }
+
+  @protected
+  void sse_encode_retention_report(
+      RetentionReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_list_prim_u_8_strict(self.purgedGroups, serializer);
+    sse_encode_u_32(self.expiredMessages, serializer);
+    sse_encode_u_32(self.forgottenKeyPackages, serializer);
+  }
+
+  @protected
+  void sse_encode_rotation_policy(
+      RotationPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.maxMessages, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxAgeSecs, serializer);
+  }
+
+  @protected
+  void sse_encode_rotation_status(
+      RotationStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.mlsGroupId, serializer);
+    sse_encode_u_64(self.lastRotatedAt, serializer);
+    sse_encode_u_64(self.messagesSinceRotation, serializer);
+    sse_encode_bool(self.due, serializer);
+  }
+
+  @protected
+  void sse_encode_signer_scope(SignerScope self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_storage_key_source(
+      StorageKeySource self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case StorageKeySource_Passphrase(passphrase: final passphrase):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(passphrase, serializer);
+      case StorageKeySource_KeyFile(path: final path):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(path, serializer);
     }
   }
 
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -917,20 +6675,77 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_u_8_array_32(U8Array32 self, SseSerializer serializer) {
+  void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.inner, serializer);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_unwrapped_welcome(
+      UnwrappedWelcome self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_String(self.wrapperEventId, serializer);
+    sse_encode_String(self.sender, serializer);
+    sse_encode_String(self.rumorJson, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_uploaded_media(UploadedMedia self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_list_String(self.imetaTag, serializer);
+    sse_encode_media_reference(self.reference, serializer);
+  }
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
+  @protected
+  void sse_encode_welcome_key_package_match(
+      WelcomeKeyPackageMatch self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.found, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.matchedIndex, serializer);
+  }
+
+  @protected
+  void sse_encode_welcome_list(WelcomeList self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_inbox_welcome(self.welcomes, serializer);
+    sse_encode_u_32(self.unreadable, serializer);
+  }
+
+  @protected
+  void sse_encode_welcome_recipient(
+      WelcomeRecipient self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.publicKey, serializer);
+    sse_encode_String(self.keyPackageEventId, serializer);
+  }
+
+  @protected
+  void sse_encode_welcome_state(WelcomeState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_welcome_state_filter(
+      WelcomeStateFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_welcome_summary(
+      WelcomeSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.welcomeEventId, serializer);
+    sse_encode_String(self.wrapperEventId, serializer);
+    sse_encode_list_prim_u_8_strict(self.mlsGroupId, serializer);
+    sse_encode_nostr_group_data(self.nostrGroupData, serializer);
+    sse_encode_String(self.welcomer, serializer);
+    sse_encode_u_32(self.memberCount, serializer);
   }
 }
@@ -3,7 +3,23 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/batch.dart';
+import 'api/commits.dart';
+import 'api/error.dart';
+import 'api/events.dart';
+import 'api/key_packages.dart';
+import 'api/keyring.dart';
+import 'api/media.dart';
+import 'api/messages.dart';
 import 'api/mls_api.dart';
+import 'api/policy.dart';
+import 'api/recovery.dart';
+import 'api/retention.dart';
+import 'api/rotation.dart';
+import 'api/signer.dart';
+import 'api/storage.dart';
+import 'api/types.dart';
+import 'api/welcomes.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
@@ -21,73 +37,964 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  FutureOr<String?> Function(String)
+      dco_decode_DartFn_Inputs_String_Output_opt_String_AnyhowException(
+          dynamic raw);
+
+  @protected
+  FutureOr<Uint8List?> Function(String)
+      dco_decode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+          dynamic raw);
+
+  @protected
+  FutureOr<String?> Function(String, String)
+      dco_decode_DartFn_Inputs_String_String_Output_opt_String_AnyhowException(
+          dynamic raw);
+
+  @protected
+  FutureOr<Uint8List?> Function(String, Uint8List)
+      dco_decode_DartFn_Inputs_String_list_prim_u_8_strict_Output_opt_list_prim_u_8_strict_AnyhowException(
+          dynamic raw);
+
+  @protected
+  FutureOr<String?> Function(Uint8List, String)
+      dco_decode_DartFn_Inputs_list_prim_u_8_strict_String_Output_opt_String_AnyhowException(
+          dynamic raw);
+
+  @protected
+  Object dco_decode_DartOpaque(dynamic raw);
+
+  @protected
+  RustStreamSink<GroupEvent> dco_decode_StreamSink_group_event_Sse(dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw);
+  AddMembersResult dco_decode_add_members_result(dynamic raw);
+
+  @protected
+  BatchEventResult dco_decode_batch_event_result(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  DecryptedMessage dco_decode_box_autoadd_decrypted_message(dynamic raw);
+
+  @protected
+  GroupInfo dco_decode_box_autoadd_group_info(dynamic raw);
+
+  @protected
+  GroupPolicy dco_decode_box_autoadd_group_policy(dynamic raw);
+
+  @protected
+  KeyPackagePolicy dco_decode_box_autoadd_key_package_policy(dynamic raw);
+
+  @protected
+  KeyringPolicy dco_decode_box_autoadd_keyring_policy(dynamic raw);
+
+  @protected
+  MediaReference dco_decode_box_autoadd_media_reference(dynamic raw);
+
+  @protected
+  MessageCursor dco_decode_box_autoadd_message_cursor(dynamic raw);
+
+  @protected
+  MlsBridgeError dco_decode_box_autoadd_mls_bridge_error(dynamic raw);
+
+  @protected
+  PendingCommit dco_decode_box_autoadd_pending_commit(dynamic raw);
+
+  @protected
+  ProcessedMessage dco_decode_box_autoadd_processed_message(dynamic raw);
+
+  @protected
+  RetentionPolicy dco_decode_box_autoadd_retention_policy(dynamic raw);
+
+  @protected
+  RotationPolicy dco_decode_box_autoadd_rotation_policy(dynamic raw);
+
+  @protected
+  StorageKeySource dco_decode_box_autoadd_storage_key_source(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  CommitAuditEntry dco_decode_commit_audit_entry(dynamic raw);
+
+  @protected
+  CommitResult dco_decode_commit_result(dynamic raw);
+
+  @protected
+  CreateGroupResult dco_decode_create_group_result(dynamic raw);
+
+  @protected
+  DecryptedMessage dco_decode_decrypted_message(dynamic raw);
+
+  @protected
+  EncryptedMedia dco_decode_encrypted_media(dynamic raw);
+
+  @protected
+  EventResult dco_decode_event_result(dynamic raw);
+
+  @protected
+  ExportedSecret dco_decode_exported_secret(dynamic raw);
+
+  @protected
+  GiftWrappedWelcome dco_decode_gift_wrapped_welcome(dynamic raw);
+
+  @protected
+  GroupEvent dco_decode_group_event(dynamic raw);
+
+  @protected
+  GroupHealth dco_decode_group_health(dynamic raw);
+
+  @protected
+  GroupHealthStatus dco_decode_group_health_status(dynamic raw);
+
+  @protected
+  GroupInfo dco_decode_group_info(dynamic raw);
+
+  @protected
+  GroupPolicy dco_decode_group_policy(dynamic raw);
+
+  @protected
+  GroupRecoveryRequest dco_decode_group_recovery_request(dynamic raw);
+
+  @protected
+  GroupStateComparison dco_decode_group_state_comparison(dynamic raw);
+
+  @protected
+  GroupStateFilter dco_decode_group_state_filter(dynamic raw);
+
+  @protected
+  GroupStatus dco_decode_group_status(dynamic raw);
+
+  @protected
+  GroupSummary dco_decode_group_summary(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  InboxWelcome dco_decode_inbox_welcome(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_isize(dynamic raw);
+
+  @protected
+  KeyPackageLookup dco_decode_key_package_lookup(dynamic raw);
+
+  @protected
+  KeyPackagePolicy dco_decode_key_package_policy(dynamic raw);
+
+  @protected
+  KeyPackageResult dco_decode_key_package_result(dynamic raw);
+
+  @protected
+  KeyPackageRotation dco_decode_key_package_rotation(dynamic raw);
+
+  @protected
+  KeyPackageState dco_decode_key_package_state(dynamic raw);
+
+  @protected
+  KeyringPolicy dco_decode_keyring_policy(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<BatchEventResult> dco_decode_list_batch_event_result(dynamic raw);
+
+  @protected
+  List<CommitAuditEntry> dco_decode_list_commit_audit_entry(dynamic raw);
+
+  @protected
+  List<DecryptedMessage> dco_decode_list_decrypted_message(dynamic raw);
+
+  @protected
+  List<GiftWrappedWelcome> dco_decode_list_gift_wrapped_welcome(dynamic raw);
+
+  @protected
+  List<GroupSummary> dco_decode_list_group_summary(dynamic raw);
+
+  @protected
+  List<InboxWelcome> dco_decode_list_inbox_welcome(dynamic raw);
+
+  @protected
+  List<List<String>> dco_decode_list_list_String(dynamic raw);
+
+  @protected
+  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<ManagedKeyPackage> dco_decode_list_managed_key_package(dynamic raw);
+
+  @protected
+  Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<ProposalSummary> dco_decode_list_proposal_summary(dynamic raw);
+
+  @protected
+  List<RotationStatus> dco_decode_list_rotation_status(dynamic raw);
+
+  @protected
+  List<WelcomeRecipient> dco_decode_list_welcome_recipient(dynamic raw);
+
+  @protected
+  List<WelcomeSummary> dco_decode_list_welcome_summary(dynamic raw);
+
+  @protected
+  ManagedKeyPackage dco_decode_managed_key_package(dynamic raw);
+
+  @protected
+  MediaReference dco_decode_media_reference(dynamic raw);
+
+  @protected
+  MessageCursor dco_decode_message_cursor(dynamic raw);
+
+  @protected
+  MlsBridgeError dco_decode_mls_bridge_error(dynamic raw);
+
+  @protected
+  NostrGroupData dco_decode_nostr_group_data(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  DecryptedMessage? dco_decode_opt_box_autoadd_decrypted_message(dynamic raw);
+
+  @protected
+  MessageCursor? dco_decode_opt_box_autoadd_message_cursor(dynamic raw);
+
+  @protected
+  MlsBridgeError? dco_decode_opt_box_autoadd_mls_bridge_error(dynamic raw);
+
+  @protected
+  PendingCommit? dco_decode_opt_box_autoadd_pending_commit(dynamic raw);
+
+  @protected
+  ProcessedMessage? dco_decode_opt_box_autoadd_processed_message(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw);
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  PendingCommit dco_decode_pending_commit(dynamic raw);
+
+  @protected
+  PendingCommitKind dco_decode_pending_commit_kind(dynamic raw);
+
+  @protected
+  PolicyRole dco_decode_policy_role(dynamic raw);
+
+  @protected
+  ProcessedMessage dco_decode_processed_message(dynamic raw);
+
+  @protected
+  ProposalCommitResult dco_decode_proposal_commit_result(dynamic raw);
+
+  @protected
+  ProposalKind dco_decode_proposal_kind(dynamic raw);
+
+  @protected
+  ProposalSummary dco_decode_proposal_summary(dynamic raw);
+
+  @protected
+  RetentionPolicy dco_decode_retention_policy(dynamic raw);
+
+  @protected
+  RetentionReport dco_decode_retention_report(dynamic raw);
+
+  @protected
+  RotationPolicy dco_decode_rotation_policy(dynamic raw);
+
+  @protected
+  RotationStatus dco_decode_rotation_status(dynamic raw);
+
+  @protected
+  SignerScope dco_decode_signer_scope(dynamic raw);
+
+  @protected
+  StorageKeySource dco_decode_storage_key_source(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  UnwrappedWelcome dco_decode_unwrapped_welcome(dynamic raw);
+
+  @protected
+  UploadedMedia dco_decode_uploaded_media(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  WelcomeKeyPackageMatch dco_decode_welcome_key_package_match(dynamic raw);
+
+  @protected
+  WelcomeList dco_decode_welcome_list(dynamic raw);
+
+  @protected
+  WelcomeRecipient dco_decode_welcome_recipient(dynamic raw);
+
+  @protected
+  WelcomeState dco_decode_welcome_state(dynamic raw);
+
+  @protected
+  WelcomeStateFilter dco_decode_welcome_state_filter(dynamic raw);
+
+  @protected
+  WelcomeSummary dco_decode_welcome_summary(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  Object sse_decode_DartOpaque(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<GroupEvent> sse_decode_StreamSink_group_event_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AddMembersResult sse_decode_add_members_result(SseDeserializer deserializer);
+
+  @protected
+  BatchEventResult sse_decode_batch_event_result(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  DecryptedMessage sse_decode_box_autoadd_decrypted_message(
+      SseDeserializer deserializer);
+
+  @protected
+  GroupInfo sse_decode_box_autoadd_group_info(SseDeserializer deserializer);
+
+  @protected
+  GroupPolicy sse_decode_box_autoadd_group_policy(SseDeserializer deserializer);
+
+  @protected
+  KeyPackagePolicy sse_decode_box_autoadd_key_package_policy(
+      SseDeserializer deserializer);
+
+  @protected
+  KeyringPolicy sse_decode_box_autoadd_keyring_policy(
+      SseDeserializer deserializer);
+
+  @protected
+  MediaReference sse_decode_box_autoadd_media_reference(
+      SseDeserializer deserializer);
+
+  @protected
+  MessageCursor sse_decode_box_autoadd_message_cursor(
+      SseDeserializer deserializer);
+
+  @protected
+  MlsBridgeError sse_decode_box_autoadd_mls_bridge_error(
+      SseDeserializer deserializer);
+
+  @protected
+  PendingCommit sse_decode_box_autoadd_pending_commit(
+      SseDeserializer deserializer);
+
+  @protected
+  ProcessedMessage sse_decode_box_autoadd_processed_message(
+      SseDeserializer deserializer);
+
+  @protected
+  RetentionPolicy sse_decode_box_autoadd_retention_policy(
+      SseDeserializer deserializer);
+
+  @protected
+  RotationPolicy sse_decode_box_autoadd_rotation_policy(
+      SseDeserializer deserializer);
+
+  @protected
+  StorageKeySource sse_decode_box_autoadd_storage_key_source(
+      SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  CommitAuditEntry sse_decode_commit_audit_entry(SseDeserializer deserializer);
+
+  @protected
+  CommitResult sse_decode_commit_result(SseDeserializer deserializer);
+
+  @protected
+  CreateGroupResult sse_decode_create_group_result(
+      SseDeserializer deserializer);
+
+  @protected
+  DecryptedMessage sse_decode_decrypted_message(SseDeserializer deserializer);
+
+  @protected
+  EncryptedMedia sse_decode_encrypted_media(SseDeserializer deserializer);
+
+  @protected
+  EventResult sse_decode_event_result(SseDeserializer deserializer);
+
+  @protected
+  ExportedSecret sse_decode_exported_secret(SseDeserializer deserializer);
+
+  @protected
+  GiftWrappedWelcome sse_decode_gift_wrapped_welcome(
+      SseDeserializer deserializer);
+
+  @protected
+  GroupEvent sse_decode_group_event(SseDeserializer deserializer);
+
+  @protected
+  GroupHealth sse_decode_group_health(SseDeserializer deserializer);
+
+  @protected
+  GroupHealthStatus sse_decode_group_health_status(
+      SseDeserializer deserializer);
+
+  @protected
+  GroupInfo sse_decode_group_info(SseDeserializer deserializer);
+
+  @protected
+  GroupPolicy sse_decode_group_policy(SseDeserializer deserializer);
+
+  @protected
+  GroupRecoveryRequest sse_decode_group_recovery_request(
+      SseDeserializer deserializer);
+
+  @protected
+  GroupStateComparison sse_decode_group_state_comparison(
+      SseDeserializer deserializer);
+
+  @protected
+  GroupStateFilter sse_decode_group_state_filter(SseDeserializer deserializer);
+
+  @protected
+  GroupStatus sse_decode_group_status(SseDeserializer deserializer);
+
+  @protected
+  GroupSummary sse_decode_group_summary(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  InboxWelcome sse_decode_inbox_welcome(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
+
+  @protected
+  KeyPackageLookup sse_decode_key_package_lookup(SseDeserializer deserializer);
+
+  @protected
+  KeyPackagePolicy sse_decode_key_package_policy(SseDeserializer deserializer);
+
+  @protected
+  KeyPackageResult sse_decode_key_package_result(SseDeserializer deserializer);
+
+  @protected
+  KeyPackageRotation sse_decode_key_package_rotation(
+      SseDeserializer deserializer);
+
+  @protected
+  KeyPackageState sse_decode_key_package_state(SseDeserializer deserializer);
+
+  @protected
+  KeyringPolicy sse_decode_keyring_policy(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<BatchEventResult> sse_decode_list_batch_event_result(
+      SseDeserializer deserializer);
+
+  @protected
+  List<CommitAuditEntry> sse_decode_list_commit_audit_entry(
+      SseDeserializer deserializer);
+
+  @protected
+  List<DecryptedMessage> sse_decode_list_decrypted_message(
+      SseDeserializer deserializer);
+
+  @protected
+  List<GiftWrappedWelcome> sse_decode_list_gift_wrapped_welcome(
+      SseDeserializer deserializer);
+
+  @protected
+  List<GroupSummary> sse_decode_list_group_summary(
+      SseDeserializer deserializer);
+
+  @protected
+  List<InboxWelcome> sse_decode_list_inbox_welcome(
+      SseDeserializer deserializer);
+
+  @protected
+  List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<Uint8List> sse_decode_list_list_prim_u_8_strict(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ManagedKeyPackage> sse_decode_list_managed_key_package(
+      SseDeserializer deserializer);
+
+  @protected
+  Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<ProposalSummary> sse_decode_list_proposal_summary(
+      SseDeserializer deserializer);
+
+  @protected
+  List<RotationStatus> sse_decode_list_rotation_status(
+      SseDeserializer deserializer);
+
+  @protected
+  List<WelcomeRecipient> sse_decode_list_welcome_recipient(
+      SseDeserializer deserializer);
+
+  @protected
+  List<WelcomeSummary> sse_decode_list_welcome_summary(
+      SseDeserializer deserializer);
+
+  @protected
+  ManagedKeyPackage sse_decode_managed_key_package(
+      SseDeserializer deserializer);
+
+  @protected
+  MediaReference sse_decode_media_reference(SseDeserializer deserializer);
+
+  @protected
+  MessageCursor sse_decode_message_cursor(SseDeserializer deserializer);
+
+  @protected
+  MlsBridgeError sse_decode_mls_bridge_error(SseDeserializer deserializer);
+
+  @protected
+  NostrGroupData sse_decode_nostr_group_data(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  DecryptedMessage? sse_decode_opt_box_autoadd_decrypted_message(
+      SseDeserializer deserializer);
+
+  @protected
+  MessageCursor? sse_decode_opt_box_autoadd_message_cursor(
+      SseDeserializer deserializer);
+
+  @protected
+  MlsBridgeError? sse_decode_opt_box_autoadd_mls_bridge_error(
+      SseDeserializer deserializer);
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+  PendingCommit? sse_decode_opt_box_autoadd_pending_commit(
+      SseDeserializer deserializer);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  ProcessedMessage? sse_decode_opt_box_autoadd_processed_message(
+      SseDeserializer deserializer);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
-  List<String>? dco_decode_opt_list_String(dynamic raw);
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
 
   @protected
-  U8Array32 dco_decode_u_8_array_32(dynamic raw);
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  PendingCommit sse_decode_pending_commit(SseDeserializer deserializer);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  PendingCommitKind sse_decode_pending_commit_kind(
+      SseDeserializer deserializer);
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  PolicyRole sse_decode_policy_role(SseDeserializer deserializer);
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+  ProcessedMessage sse_decode_processed_message(SseDeserializer deserializer);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  ProposalCommitResult sse_decode_proposal_commit_result(
+      SseDeserializer deserializer);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  ProposalKind sse_decode_proposal_kind(SseDeserializer deserializer);
 
   @protected
-  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+  ProposalSummary sse_decode_proposal_summary(SseDeserializer deserializer);
+
+  @protected
+  RetentionPolicy sse_decode_retention_policy(SseDeserializer deserializer);
+
+  @protected
+  RetentionReport sse_decode_retention_report(SseDeserializer deserializer);
+
+  @protected
+  RotationPolicy sse_decode_rotation_policy(SseDeserializer deserializer);
+
+  @protected
+  RotationStatus sse_decode_rotation_status(SseDeserializer deserializer);
+
+  @protected
+  SignerScope sse_decode_signer_scope(SseDeserializer deserializer);
+
+  @protected
+  StorageKeySource sse_decode_storage_key_source(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
 
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
   @protected
-  U8Array32 sse_decode_u_8_array_32(SseDeserializer deserializer);
+  void sse_decode_unit(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  UnwrappedWelcome sse_decode_unwrapped_welcome(SseDeserializer deserializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  UploadedMedia sse_decode_uploaded_media(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  WelcomeKeyPackageMatch sse_decode_welcome_key_package_match(
+      SseDeserializer deserializer);
+
+  @protected
+  WelcomeList sse_decode_welcome_list(SseDeserializer deserializer);
+
+  @protected
+  WelcomeRecipient sse_decode_welcome_recipient(SseDeserializer deserializer);
+
+  @protected
+  WelcomeState sse_decode_welcome_state(SseDeserializer deserializer);
+
+  @protected
+  WelcomeStateFilter sse_decode_welcome_state_filter(
+      SseDeserializer deserializer);
+
+  @protected
+  WelcomeSummary sse_decode_welcome_summary(SseDeserializer deserializer);
 
   @protected
   void sse_encode_AnyhowException(
       AnyhowException self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(
+      FutureOr<String?> Function(String) self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+          FutureOr<Uint8List?> Function(String) self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_DartFn_Inputs_String_String_Output_opt_String_AnyhowException(
+      FutureOr<String?> Function(String, String) self,
+      SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_DartFn_Inputs_String_list_prim_u_8_strict_Output_opt_list_prim_u_8_strict_AnyhowException(
+          FutureOr<Uint8List?> Function(String, Uint8List) self,
+          SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_DartFn_Inputs_list_prim_u_8_strict_String_Output_opt_String_AnyhowException(
+          FutureOr<String?> Function(Uint8List, String) self,
+          SseSerializer serializer);
+
+  @protected
+  void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_group_event_Sse(
+      RustStreamSink<GroupEvent> self, SseSerializer serializer);
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_add_members_result(
+      AddMembersResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_batch_event_result(
+      BatchEventResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_decrypted_message(
+      DecryptedMessage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_group_info(
+      GroupInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_group_policy(
+      GroupPolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_key_package_policy(
+      KeyPackagePolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_keyring_policy(
+      KeyringPolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_media_reference(
+      MediaReference self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_message_cursor(
+      MessageCursor self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_mls_bridge_error(
+      MlsBridgeError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_pending_commit(
+      PendingCommit self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_processed_message(
+      ProcessedMessage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_retention_policy(
+      RetentionPolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_rotation_policy(
+      RotationPolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_storage_key_source(
+      StorageKeySource self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_commit_audit_entry(
+      CommitAuditEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_commit_result(CommitResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_create_group_result(
+      CreateGroupResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_decrypted_message(
+      DecryptedMessage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_encrypted_media(
+      EncryptedMedia self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_event_result(EventResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_exported_secret(
+      ExportedSecret self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_gift_wrapped_welcome(
+      GiftWrappedWelcome self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_group_event(GroupEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_group_health(GroupHealth self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_group_health_status(
+      GroupHealthStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_group_info(GroupInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_group_policy(GroupPolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_group_recovery_request(
+      GroupRecoveryRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_group_state_comparison(
+      GroupStateComparison self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_group_state_filter(
+      GroupStateFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_group_status(GroupStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_group_summary(GroupSummary self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_inbox_welcome(InboxWelcome self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_key_package_lookup(
+      KeyPackageLookup self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_key_package_policy(
+      KeyPackagePolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_key_package_result(
+      KeyPackageResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_key_package_rotation(
+      KeyPackageRotation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_key_package_state(
+      KeyPackageState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_keyring_policy(KeyringPolicy self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_batch_event_result(
+      List<BatchEventResult> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_commit_audit_entry(
+      List<CommitAuditEntry> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_decrypted_message(
+      List<DecryptedMessage> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_gift_wrapped_welcome(
+      List<GiftWrappedWelcome> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_group_summary(
+      List<GroupSummary> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_inbox_welcome(
+      List<InboxWelcome> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_list_String(
+      List<List<String>> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_list_prim_u_8_strict(
+      List<Uint8List> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_managed_key_package(
+      List<ManagedKeyPackage> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_64_strict(
+      Uint64List self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
@@ -95,23 +1002,171 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_prim_u_8_strict(
       Uint8List self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_proposal_summary(
+      List<ProposalSummary> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_rotation_status(
+      List<RotationStatus> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_welcome_recipient(
+      List<WelcomeRecipient> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_welcome_summary(
+      List<WelcomeSummary> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_managed_key_package(
+      ManagedKeyPackage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_media_reference(
+      MediaReference self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_message_cursor(MessageCursor self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_mls_bridge_error(
+      MlsBridgeError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_nostr_group_data(
+      NostrGroupData self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_decrypted_message(
+      DecryptedMessage? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_message_cursor(
+      MessageCursor? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_mls_bridge_error(
+      MlsBridgeError? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_pending_commit(
+      PendingCommit? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_processed_message(
+      ProcessedMessage? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+      Uint8List? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pending_commit(PendingCommit self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pending_commit_kind(
+      PendingCommitKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_policy_role(PolicyRole self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_processed_message(
+      ProcessedMessage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_proposal_commit_result(
+      ProposalCommitResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_proposal_kind(ProposalKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_proposal_summary(
+      ProposalSummary self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_retention_policy(
+      RetentionPolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_retention_report(
+      RetentionReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_rotation_policy(
+      RotationPolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_rotation_status(
+      RotationStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_signer_scope(SignerScope self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_storage_key_source(
+      StorageKeySource self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
   @protected
-  void sse_encode_u_8_array_32(U8Array32 self, SseSerializer serializer);
+  void sse_encode_unit(void self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_unwrapped_welcome(
+      UnwrappedWelcome self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_uploaded_media(UploadedMedia self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_welcome_key_package_match(
+      WelcomeKeyPackageMatch self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_welcome_list(WelcomeList self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_welcome_recipient(
+      WelcomeRecipient self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_welcome_state(WelcomeState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_welcome_state_filter(
+      WelcomeStateFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_welcome_summary(
+      WelcomeSummary self, SseSerializer serializer);
 }
 
 // Section: wire_class
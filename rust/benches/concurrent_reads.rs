@@ -0,0 +1,101 @@
+//! Measures read throughput of `get_members` / `get_group` from several threads,
+//! with and without a writer sending messages to the same group.
+//!
+//! Run with `cargo bench --bench concurrent_reads`.
+
+use nostr_mls::prelude::*;
+use nostr_mls_package::api::mls_api::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const READS_PER_THREAD: usize = 2_000;
+const THREAD_COUNTS: [usize; 4] = [1, 2, 4, 8];
+
+fn main() {
+    let dir = std::env::temp_dir().join(format!("nostr-mls-bench-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("create bench directory");
+    let path = dir.to_string_lossy().into_owned();
+
+    let alice = Keys::generate().public_key().to_hex();
+    let bob = Keys::generate().public_key().to_hex();
+    init_nostr_mls(path.clone(), alice.clone(), None).expect("init alice");
+    init_nostr_mls(path, bob.clone(), None).expect("init bob");
+
+    let key_package =
+        create_key_package_for_event(bob.clone(), bob.clone(), None, None).expect("key package");
+    let group = create_group(
+        alice.clone(),
+        "bench".to_string(),
+        "concurrent read benchmark".to_string(),
+        vec![key_package.encoded_key_package],
+        vec![bob],
+        alice.clone(),
+        vec![alice.clone()],
+        vec!["wss://relay.example.com".to_string()],
+    )
+    .expect("create group")
+    .group;
+
+    println!("{:>8} {:>8} {:>14}", "threads", "writer", "reads/sec");
+    for with_writer in [false, true] {
+        for threads in THREAD_COUNTS {
+            let elapsed = run(&alice, &group.mls_group_id, threads, with_writer);
+            let reads = (threads * READS_PER_THREAD) as f64;
+            println!(
+                "{:>8} {:>8} {:>14.0}",
+                threads,
+                with_writer,
+                reads / elapsed.as_secs_f64()
+            );
+        }
+    }
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+fn run(identity: &str, group_id: &[u8], threads: usize, with_writer: bool) -> Duration {
+    let done = Arc::new(AtomicBool::new(false));
+    let writer = with_writer.then(|| {
+        let identity = identity.to_string();
+        let group_id = group_id.to_vec();
+        let done = done.clone();
+        thread::spawn(move || {
+            let author = PublicKey::from_hex(&identity).expect("author pubkey");
+            while !done.load(Ordering::Relaxed) {
+                let rumor = EventBuilder::new(Kind::Custom(9), "bench").build(author);
+                create_message_for_group(identity.clone(), group_id.clone(), rumor.as_json())
+                    .expect("create message");
+            }
+        })
+    });
+
+    let start = Instant::now();
+    let readers: Vec<_> = (0..threads)
+        .map(|_| {
+            let identity = identity.to_string();
+            let group_id = group_id.to_vec();
+            thread::spawn(move || {
+                for i in 0..READS_PER_THREAD {
+                    if i % 2 == 0 {
+                        get_members(identity.clone(), group_id.clone()).expect("get members");
+                    } else {
+                        get_group(identity.clone(), group_id.clone()).expect("get group");
+                    }
+                }
+            })
+        })
+        .collect();
+    for reader in readers {
+        reader.join().expect("reader thread");
+    }
+    let elapsed = start.elapsed();
+
+    done.store(true, Ordering::Relaxed);
+    if let Some(writer) = writer {
+        writer.join().expect("writer thread");
+    }
+
+    elapsed
+}
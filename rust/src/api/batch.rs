@@ -0,0 +1,359 @@
+use nostr_mls::prelude::*;
+
+use crate::api::error::MlsBridgeError;
+use crate::api::mls_api::{buffer_event, process_commit, process_event};
+use crate::api::recovery::record_outcome;
+use crate::api::types::ProcessedMessage;
+use crate::registry::{self, MlsInstance};
+
+/// Result of one event of `process_messages_batch`, in the order the events were given
+#[derive(Debug, Clone)]
+pub struct BatchEventResult {
+    /// Hex encoded event id, `None` if the event could not be parsed
+    pub event_id: Option<String>,
+    /// Set when the event was processed
+    pub processed: Option<ProcessedMessage>,
+    /// Set when the event could not be processed
+    pub error: Option<MlsBridgeError>,
+}
+
+/// Process a batch of kind-445 events fetched after being offline
+///
+/// Events are applied oldest first (created_at, then event id). Staged commits are merged
+/// right away so later-epoch messages can be decrypted, and events failing with an epoch
+/// mismatch are retried every time another event of the batch succeeded. Events still
+/// failing at the end are buffered like in `process_message_for_group`.
+/// Parameters: events - JSON strings of the events
+/// Returns: one result per event, in input order
+pub fn process_messages_batch(
+    identity: String,
+    events: Vec<String>,
+) -> Result<Vec<BatchEventResult>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let mut results: Vec<BatchEventResult> = Vec::with_capacity(events.len());
+    let mut pending: Vec<(usize, Event)> = Vec::new();
+    for (index, event_string) in events.iter().enumerate() {
+        match Event::from_json(event_string) {
+            Ok(event) => {
+                results.push(BatchEventResult {
+                    event_id: Some(event.id.to_hex()),
+                    processed: None,
+                    error: None,
+                });
+                pending.push((index, event));
+            }
+            Err(e) => results.push(BatchEventResult {
+                event_id: None,
+                processed: None,
+                error: Some(MlsBridgeError::invalid_input("events", e)),
+            }),
+        }
+    }
+
+    sort_oldest_first(&mut pending);
+
+    let pending = retry_while_progressing(pending, |(index, event)| {
+        let result = match process_and_merge(&instance, event) {
+            Err(e) if e.may_be_later_epoch() => {
+                results[*index].error = Some(e);
+                return Attempt::Deferred;
+            }
+            result => result,
+        };
+
+        if let Some(group_id) = instance.group_id_for_event(event) {
+            record_outcome(&instance, &group_id, &result);
+        }
+        match result {
+            Ok(processed) => {
+                results[*index].processed = Some(processed);
+                results[*index].error = None;
+                Attempt::Succeeded
+            }
+            Err(e) => {
+                results[*index].error = Some(e);
+                Attempt::Failed
+            }
+        }
+    });
+
+    // Keep what is still undecryptable for the commits that have not arrived yet
+    for (index, event) in pending {
+        let Some(group_id) = instance.group_id_for_event(&event) else {
+            continue;
+        };
+        let result = buffer_event(&instance, &group_id, &event);
+        record_outcome(&instance, &group_id, &result);
+        match result {
+            Ok(processed) => {
+                results[index].processed = Some(processed);
+                results[index].error = None;
+            }
+            Err(e) => results[index].error = Some(e),
+        }
+    }
+
+    Ok(results)
+}
+
+/// Outcome of one attempt at an event of a batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Attempt {
+    Succeeded,
+    Failed,
+    /// Failed with an epoch mismatch, worth retrying once another event succeeded
+    Deferred,
+}
+
+/// Order events oldest first, by created_at then event id
+fn sort_oldest_first(pending: &mut [(usize, Event)]) {
+    pending.sort_by(|(_, a), (_, b)| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+}
+
+/// Attempt every pending item, then the deferred ones again for as long as a pass succeeded
+/// with at least one item
+/// Returns: the items still deferred
+fn retry_while_progressing<T>(
+    mut pending: Vec<T>,
+    mut attempt: impl FnMut(&T) -> Attempt,
+) -> Vec<T> {
+    loop {
+        let mut progressed = false;
+        let mut deferred = Vec::new();
+
+        for item in pending {
+            match attempt(&item) {
+                Attempt::Succeeded => progressed = true,
+                Attempt::Failed => {}
+                Attempt::Deferred => deferred.push(item),
+            }
+        }
+
+        pending = deferred;
+        if !progressed || pending.is_empty() {
+            return pending;
+        }
+    }
+}
+
+fn process_and_merge(
+    instance: &MlsInstance,
+    event: &Event,
+) -> Result<ProcessedMessage, MlsBridgeError> {
+    let processed = process_event(instance, event)?;
+
+    match (&processed.commit, &processed.staged_message_bytes) {
+        (Some(_), Some(message_bytes)) => {
+            let group_id = instance
+                .group_id_for_event(event)
+                .ok_or(MlsBridgeError::GroupNotFound)?;
+            process_commit(instance, &group_id, message_bytes)
+        }
+        _ => Ok(processed),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::commits::{confirm_commit_published, self_update};
+    use crate::api::mls_api::{create_message_for_group, export_secret, get_pending_message_count};
+    use crate::api::types::EventResult;
+    use crate::test_utils::{create_test_group, TestDir, TestIdentity};
+    use std::cell::Cell;
+
+    fn event(created_at: u64) -> Event {
+        EventBuilder::new(Kind::MlsGroupMessage, "")
+            .custom_created_at(Timestamp::from(created_at))
+            .sign_with_keys(&Keys::generate())
+            .expect("sign event")
+    }
+
+    #[test]
+    fn sorts_by_created_at_then_event_id() {
+        let late = event(20);
+        let (low, high) = {
+            let a = event(10);
+            let b = event(10);
+            if a.id < b.id {
+                (a, b)
+            } else {
+                (b, a)
+            }
+        };
+        let mut pending = vec![(0, late.clone()), (1, high.clone()), (2, low.clone())];
+
+        sort_oldest_first(&mut pending);
+
+        let order: Vec<usize> = pending.iter().map(|(index, _)| *index).collect();
+        assert_eq!(order, vec![2, 1, 0]);
+    }
+
+    /// An item needing the group at `epoch`, advancing it when it is a commit
+    struct Item {
+        epoch: u64,
+        commit: bool,
+    }
+
+    fn attempt_at(group_epoch: &Cell<u64>, attempts: &Cell<u32>, item: &Item) -> Attempt {
+        attempts.set(attempts.get() + 1);
+        if item.epoch > group_epoch.get() {
+            return Attempt::Deferred;
+        }
+        if item.commit {
+            group_epoch.set(group_epoch.get() + 1);
+        }
+        Attempt::Succeeded
+    }
+
+    #[test]
+    fn retries_deferred_items_while_progress_is_made() {
+        let group_epoch = Cell::new(0);
+        let attempts = Cell::new(0);
+        // A message of epoch 2 and the commits leading to it, received out of order
+        let items = vec![
+            Item {
+                epoch: 2,
+                commit: false,
+            },
+            Item {
+                epoch: 1,
+                commit: true,
+            },
+            Item {
+                epoch: 0,
+                commit: true,
+            },
+        ];
+
+        let left = retry_while_progressing(items, |item| attempt_at(&group_epoch, &attempts, item));
+
+        assert!(left.is_empty());
+        assert_eq!(group_epoch.get(), 2);
+        // 3 items, then the 2 deferred ones, then the message alone
+        assert_eq!(attempts.get(), 6);
+    }
+
+    #[test]
+    fn stops_once_a_pass_makes_no_progress() {
+        let group_epoch = Cell::new(0);
+        let attempts = Cell::new(0);
+        let items = vec![
+            Item {
+                epoch: 0,
+                commit: false,
+            },
+            Item {
+                epoch: 5,
+                commit: false,
+            },
+        ];
+
+        let left = retry_while_progressing(items, |item| attempt_at(&group_epoch, &attempts, item));
+
+        assert_eq!(left.len(), 1);
+        assert_eq!(left[0].epoch, 5);
+        assert_eq!(attempts.get(), 3);
+    }
+
+    #[test]
+    fn failed_items_are_not_retried() {
+        let attempts = Cell::new(0);
+
+        let left = retry_while_progressing(vec![(), ()], |_| {
+            attempts.set(attempts.get() + 1);
+            Attempt::Failed
+        });
+
+        assert!(left.is_empty());
+        assert_eq!(attempts.get(), 2);
+    }
+
+    async fn commit_self_update(member: &TestIdentity, group_id: &[u8]) -> EventResult {
+        let commit = self_update(member.identity.clone(), group_id.to_vec())
+            .await
+            .expect("self-update");
+        confirm_commit_published(
+            member.identity.clone(),
+            group_id.to_vec(),
+            commit.event_id.clone(),
+        )
+        .expect("merge commit");
+        commit
+    }
+
+    fn send(member: &TestIdentity, group_id: &[u8], content: &str) -> String {
+        let rumor = EventBuilder::new(Kind::Custom(9), content).build(member.keys.public_key());
+        create_message_for_group(member.identity.clone(), group_id.to_vec(), rumor.as_json())
+            .expect("create message")
+            .event_json
+    }
+
+    #[tokio::test]
+    async fn applies_a_shuffled_batch_commit_first_and_buffers_the_rest() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+
+        let commit = commit_self_update(&alice, &group_id).await;
+        let epoch = export_secret(alice.identity.clone(), group_id.clone())
+            .unwrap()
+            .epoch;
+        let first = send(&alice, &group_id, "first");
+        let second = send(&alice, &group_id, "second");
+        // Of an epoch whose commit bob never gets
+        commit_self_update(&alice, &group_id).await;
+        let later = send(&alice, &group_id, "later");
+
+        // Published later than the messages, so they are attempted first and retried
+        let commit = Event::from_json(&commit.event_json).unwrap();
+        let commit = EventBuilder::new(commit.kind, commit.content.clone())
+            .tags(commit.tags.clone())
+            .custom_created_at(commit.created_at + 60)
+            .sign_with_keys(&Keys::generate())
+            .unwrap()
+            .as_json();
+
+        let events = vec![second, later, commit, first];
+        let results =
+            process_messages_batch(bob.identity.clone(), events.clone()).expect("process batch");
+
+        let event_ids: Vec<Option<String>> = events
+            .iter()
+            .map(|json| Some(Event::from_json(json).unwrap().id.to_hex()))
+            .collect();
+        assert_eq!(
+            results
+                .iter()
+                .map(|result| result.event_id.clone())
+                .collect::<Vec<_>>(),
+            event_ids
+        );
+        assert!(results.iter().all(|result| result.error.is_none()));
+
+        let content = |index: usize| {
+            results[index]
+                .processed
+                .as_ref()
+                .and_then(|processed| processed.message.as_ref())
+                .map(|message| message.content.clone())
+        };
+        assert_eq!(content(0).as_deref(), Some("second"));
+        assert_eq!(content(3).as_deref(), Some("first"));
+        assert_eq!(
+            export_secret(bob.identity.clone(), group_id.clone())
+                .unwrap()
+                .epoch,
+            epoch
+        );
+
+        assert!(results[1].processed.as_ref().unwrap().pending);
+        assert_eq!(
+            get_pending_message_count(bob.identity.clone(), group_id).unwrap(),
+            1
+        );
+    }
+}
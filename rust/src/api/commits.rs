@@ -0,0 +1,1192 @@
+use nostr_mls::prelude::*;
+use openmls_basic_credential::SignatureKeyPair;
+use openmls_traits::types::SignatureScheme;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::api::error::MlsBridgeError;
+use crate::api::events::GroupSnapshot;
+use crate::api::key_packages::key_package_signature_keys;
+use crate::api::keyring::record_exporter_secret;
+use crate::api::mls_api::{
+    ephemeral_group_event, event_result, parse_relays, replay_pending_messages,
+};
+use crate::api::policy::{check_own_action, check_own_proposals, GroupAction};
+use crate::api::rotation::record_rotation;
+use crate::api::signer::Signer;
+use crate::api::types::{DecryptedMessage, EventResult};
+use crate::mls;
+use crate::registry::{self, MlsInstance};
+use crate::store::{CommitRecord, StoredPendingCommit};
+
+/// Kind of a pending proposal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalKind {
+    Add,
+    Remove,
+    Update,
+    GroupContextExtensions,
+    Other,
+}
+
+/// A proposal of the current epoch waiting to be committed
+#[derive(Debug, Clone)]
+pub struct ProposalSummary {
+    /// Hex encoded proposal reference, used to select it in `commit_pending_proposals`
+    pub proposal_ref: String,
+    pub kind: ProposalKind,
+    /// Hex encoded public key of the member who proposed it, `None` for non-member senders
+    pub proposer: Option<String>,
+    /// Hex encoded public key of the member added or removed
+    pub target: Option<String>,
+}
+
+/// What a pending commit of ours does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PendingCommitKind {
+    Commit,
+    /// A proposal to remove ourselves, from `leave_group`
+    Leave,
+}
+
+/// Our commit waiting for its event to be published
+#[derive(Debug, Clone)]
+pub struct PendingCommit {
+    /// Epoch the commit was created in
+    pub epoch: u64,
+    pub kind: PendingCommitKind,
+    /// `None` until the commit is wrapped with `create_commit_message_for_group`
+    pub event_id: Option<String>,
+    pub event_json: Option<String>,
+}
+
+/// Result of `commit_pending_proposals`
+#[derive(Debug, Clone)]
+pub struct ProposalCommitResult {
+    pub commit_event: EventResult,
+    /// Welcome of the members added by the commit, to gift-wrap with `create_welcome_gift_wraps`
+    pub welcome_message: Option<Vec<u8>>,
+}
+
+/// Update the Nostr group data of a group with a GroupContextExtensions commit
+/// The group policy decides who may update it, admins by default. Fields left `None` keep
+/// their current value.
+/// Parameters: group_id - byte array of group ID, admins - hex public keys of the new admin set,
+/// relays - new relay set
+/// Returns: the signed kind-445 commit event to publish, then `confirm_commit_published`
+pub async fn update_group_data(
+    identity: String,
+    group_id: Vec<u8>,
+    name: Option<String>,
+    description: Option<String>,
+    admins: Option<Vec<String>>,
+    relays: Option<Vec<String>>,
+) -> Result<EventResult, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
+
+    let group_id = GroupId::from_slice(&group_id);
+
+    let admins = admins
+        .map(|admins| {
+            admins
+                .into_iter()
+                .map(|k| {
+                    PublicKey::from_str(&k).map_err(|e| MlsBridgeError::invalid_input("admins", e))
+                })
+                .collect::<Result<Vec<PublicKey>, MlsBridgeError>>()
+        })
+        .transpose()?;
+    let relays = relays
+        .map(|relays| parse_relays(Some(relays)))
+        .transpose()?;
+
+    let signer = instance.commit_signer();
+    let event = {
+        let group_lock = instance.group_lock(&group_id);
+        let _guard = registry::lock(&group_lock);
+
+        let mut group = mls::load_group(nostr_mls, &group_id)?;
+        ensure_no_pending_commit(&instance, &group)?;
+        let mut group_data = mls::group_data(&group)?;
+        check_own_action(&instance, &group, GroupAction::UpdateGroupData)?;
+
+        if let Some(name) = name {
+            group_data.name = name;
+        }
+        if let Some(description) = description {
+            group_data.description = description;
+        }
+        if let Some(admins) = admins {
+            if admins.is_empty() {
+                return Err(MlsBridgeError::invalid_input(
+                    "admins",
+                    "a group needs at least one admin",
+                ));
+            }
+            group_data.admins = admins.into_iter().collect();
+        }
+        if let Some(relays) = relays {
+            group_data.relays = relays.into_iter().collect();
+        }
+
+        let extensions = mls::extensions_with_group_data(&group, &group_data)?;
+        let leaf_signer = mls::load_signer(nostr_mls, &group)?;
+        let (commit, _, _) = group
+            .update_group_context_extensions(&nostr_mls.provider, extensions, &leaf_signer)
+            .map_err(MlsBridgeError::mls)?;
+
+        let event = ephemeral_group_event(
+            nostr_mls,
+            hex::encode(group_data.nostr_group_id),
+            &mls::serialize_message(&commit)?,
+        )?;
+        save_own_pending_commit(&instance, &group, signer.is_none().then_some(&event))?;
+        event
+    };
+    let event = sign_own_commit(&instance, &group_id, signer, event).await?;
+
+    Ok(event_result(&event))
+}
+
+/// Rotate our leaf in a group with a self-update commit
+/// Both the leaf's encryption key and its signing key are replaced, restoring post-compromise
+/// security; `list_rotation_status` reports the groups due for it.
+/// Parameters: group_id - byte array of group ID
+/// Returns: the signed kind-445 commit event to publish, then `confirm_commit_published`
+pub async fn self_update(
+    identity: String,
+    group_id: Vec<u8>,
+) -> Result<EventResult, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
+
+    let group_id = GroupId::from_slice(&group_id);
+
+    let signer = instance.commit_signer();
+    let event = {
+        let group_lock = instance.group_lock(&group_id);
+        let _guard = registry::lock(&group_lock);
+
+        let mut group = mls::load_group(nostr_mls, &group_id)?;
+        ensure_no_pending_commit(&instance, &group)?;
+        let credential = group
+            .own_leaf()
+            .ok_or(MlsBridgeError::GroupNotFound)?
+            .credential()
+            .clone();
+
+        let signature_algorithm = group.ciphersuite().signature_algorithm();
+        let old_signer = mls::load_signer(nostr_mls, &group)?;
+        let new_signer = SignatureKeyPair::new(signature_algorithm).map_err(MlsBridgeError::mls)?;
+        new_signer
+            .store(nostr_mls.provider.storage())
+            .map_err(MlsBridgeError::storage)?;
+
+        let bundle = match group.self_update_with_new_signer(
+            &nostr_mls.provider,
+            &old_signer,
+            NewSignerBundle {
+                signer: &new_signer,
+                credential_with_key: CredentialWithKey {
+                    credential,
+                    signature_key: new_signer.public().into(),
+                },
+            },
+            LeafNodeParameters::default(),
+        ) {
+            Ok(bundle) => bundle,
+            Err(e) => {
+                SignatureKeyPair::delete(
+                    nostr_mls.provider.storage(),
+                    new_signer.public(),
+                    signature_algorithm,
+                )
+                .map_err(MlsBridgeError::storage)?;
+                return Err(MlsBridgeError::mls(e));
+            }
+        };
+
+        let event = ephemeral_group_event(
+            nostr_mls,
+            hex::encode(mls::group_data(&group)?.nostr_group_id),
+            &mls::serialize_message(bundle.commit())?,
+        )?;
+        save_own_pending_commit(&instance, &group, signer.is_none().then_some(&event))?;
+        event
+    };
+    let event = sign_own_commit(&instance, &group_id, signer, event).await?;
+
+    Ok(event_result(&event))
+}
+
+/// Merge our pending commit of a group after the relay acknowledged its event
+/// For a leave proposal nothing is merged; the group ends once an admin commits the removal.
+/// A competing commit of the same epoch that wins but reaches us only after this merge can no
+/// longer roll ours back: it fails with `StaleEpoch` and is counted in `get_group_health`.
+/// The members who applied it are then on another branch, which `compare_group_state` reports
+/// as `Diverged` and `start_group_recovery` resolves.
+/// Parameters: group_id - byte array of group ID, event_id - hex id of the published event
+/// Returns: buffered messages the new epoch can decrypt
+pub fn confirm_commit_published(
+    identity: String,
+    group_id: Vec<u8>,
+    event_id: String,
+) -> Result<Vec<DecryptedMessage>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    merge_own_commit(&instance, &GroupId::from_slice(&group_id), &event_id)
+}
+
+/// Discard our pending commit or leave proposal of a group, e.g. when the relay rejected it
+/// Parameters: group_id - byte array of group ID
+/// Returns: whether something was discarded
+pub fn abort_commit(identity: String, group_id: Vec<u8>) -> Result<bool, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    abort_own_commit(&instance, &GroupId::from_slice(&group_id))
+}
+
+/// Merge our pending commit of a group once its event was published
+/// Same as `confirm_commit_published` with the event id recorded for the pending commit.
+/// Parameters: group_id - byte array of group ID
+/// Returns: buffered messages the new epoch can decrypt
+pub fn merge_pending_commit(
+    identity: String,
+    group_id: Vec<u8>,
+) -> Result<Vec<DecryptedMessage>, MlsBridgeError> {
+    let event_id = get_pending_commit(identity.clone(), group_id.clone())?
+        .and_then(|pending| pending.event_id)
+        .ok_or_else(|| {
+            MlsBridgeError::invalid_input("group_id", "no pending commit event for the group")
+        })?;
+
+    confirm_commit_published(identity, group_id, event_id)
+}
+
+/// Discard our pending commit of a group, e.g. when publishing its event failed
+/// Same as `abort_commit`.
+/// Parameters: group_id - byte array of group ID
+/// Returns: whether a pending commit was discarded
+pub fn clear_pending_commit(identity: String, group_id: Vec<u8>) -> Result<bool, MlsBridgeError> {
+    abort_commit(identity, group_id)
+}
+
+/// Get our commit of a group waiting for `confirm_commit_published` or `abort_commit`
+/// Pending commits survive restarts; an app can publish `event_json` again before confirming.
+/// Parameters: group_id - byte array of group ID
+pub fn get_pending_commit(
+    identity: String,
+    group_id: Vec<u8>,
+) -> Result<Option<PendingCommit>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    Ok(instance
+        .store
+        .pending_commit(&hex::encode(&group_id))?
+        .map(|pending| PendingCommit {
+            epoch: pending.epoch,
+            kind: match pending.proposal_ref {
+                Some(_) => PendingCommitKind::Leave,
+                None => PendingCommitKind::Commit,
+            },
+            event_id: pending.event_id,
+            event_json: pending.event_json,
+        }))
+}
+
+/// Propose adding a member, for an admin to commit later
+/// Parameters: group_id - byte array of group ID, key_package - serialized key package of the member
+/// Returns: the signed kind-445 proposal event to publish
+pub async fn propose_add(
+    identity: String,
+    group_id: Vec<u8>,
+    key_package: String,
+) -> Result<EventResult, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
+
+    let key_package = nostr_mls
+        .parse_serialized_key_package(&key_package)
+        .map_err(|e| MlsBridgeError::invalid_input("key_package", e))?;
+
+    propose(
+        &instance,
+        &GroupId::from_slice(&group_id),
+        |group, signer| {
+            let (proposal, _) = group
+                .propose_add_member(&nostr_mls.provider, signer, &key_package)
+                .map_err(MlsBridgeError::mls)?;
+            Ok(proposal)
+        },
+    )
+    .await
+}
+
+/// Propose removing a member, for an admin to commit later
+/// Parameters: group_id - byte array of group ID, member_pubkey - hex public key of the member
+/// Returns: the signed kind-445 proposal event to publish
+pub async fn propose_remove(
+    identity: String,
+    group_id: Vec<u8>,
+    member_pubkey: String,
+) -> Result<EventResult, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
+
+    let member_pubkey = PublicKey::from_str(&member_pubkey)
+        .map_err(|e| MlsBridgeError::invalid_input("member_pubkey", e))?;
+
+    propose(
+        &instance,
+        &GroupId::from_slice(&group_id),
+        |group, signer| {
+            let leaf_index = mls::member_leaf_index(group, &member_pubkey).ok_or_else(|| {
+                MlsBridgeError::invalid_input("member_pubkey", "not a member of the group")
+            })?;
+            let (proposal, _) = group
+                .propose_remove_member(&nostr_mls.provider, signer, leaf_index)
+                .map_err(MlsBridgeError::mls)?;
+            Ok(proposal)
+        },
+    )
+    .await
+}
+
+/// Propose updating our own leaf node, for any member to commit later
+/// Parameters: group_id - byte array of group ID
+/// Returns: the signed kind-445 proposal event to publish
+pub async fn propose_update(
+    identity: String,
+    group_id: Vec<u8>,
+) -> Result<EventResult, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
+
+    propose(
+        &instance,
+        &GroupId::from_slice(&group_id),
+        |group, signer| {
+            let (proposal, _) = group
+                .propose_self_update(&nostr_mls.provider, signer, LeafNodeParameters::default())
+                .map_err(MlsBridgeError::mls)?;
+            Ok(proposal)
+        },
+    )
+    .await
+}
+
+/// List the proposals of the current epoch received or sent but not committed yet
+/// Parameters: group_id - byte array of group ID
+pub fn list_pending_proposals(
+    identity: String,
+    group_id: Vec<u8>,
+) -> Result<Vec<ProposalSummary>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
+
+    let group = mls::load_group(nostr_mls, &GroupId::from_slice(&group_id))?;
+
+    Ok(group
+        .pending_proposals()
+        .map(|queued| {
+            let (kind, target) = match queued.proposal() {
+                Proposal::Add(add) => (
+                    ProposalKind::Add,
+                    mls::credential_pubkey(add.key_package().leaf_node().credential()),
+                ),
+                Proposal::Remove(remove) => (
+                    ProposalKind::Remove,
+                    group
+                        .member(remove.removed())
+                        .and_then(mls::credential_pubkey),
+                ),
+                Proposal::Update(_) => (ProposalKind::Update, None),
+                Proposal::GroupContextExtensions(_) => (ProposalKind::GroupContextExtensions, None),
+                _ => (ProposalKind::Other, None),
+            };
+            let proposer = match queued.sender() {
+                Sender::Member(index) => group.member(*index).and_then(mls::credential_pubkey),
+                _ => None,
+            };
+
+            ProposalSummary {
+                proposal_ref: hex::encode(queued.proposal_reference().as_slice()),
+                kind,
+                proposer: proposer.map(|pk| pk.to_hex()),
+                target: target.map(|pk| pk.to_hex()),
+            }
+        })
+        .collect())
+}
+
+/// Commit pending proposals of the current epoch
+/// Proposals left out of the selection are discarded, as a commit ends their epoch anyway.
+/// Parameters: group_id - byte array of group ID, selection - proposal refs to commit, `None` for
+/// all of them
+/// Returns: the signed kind-445 commit event and the welcome of added members, to send once
+/// `confirm_commit_published` succeeded
+pub async fn commit_pending_proposals(
+    identity: String,
+    group_id: Vec<u8>,
+    selection: Option<Vec<String>>,
+) -> Result<ProposalCommitResult, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
+
+    let group_id = GroupId::from_slice(&group_id);
+
+    let signer = instance.commit_signer();
+    let (event, welcome_message) = {
+        let group_lock = instance.group_lock(&group_id);
+        let _guard = registry::lock(&group_lock);
+
+        let mut group = mls::load_group(nostr_mls, &group_id)?;
+        ensure_no_pending_commit(&instance, &group)?;
+
+        if let Some(selection) = selection {
+            let pending: Vec<ProposalRef> = group
+                .pending_proposals()
+                .map(|queued| queued.proposal_reference())
+                .collect();
+            if let Some(unknown) = selection.iter().find(|selected| {
+                !pending
+                    .iter()
+                    .any(|proposal_ref| hex::encode(proposal_ref.as_slice()) == **selected)
+            }) {
+                return Err(MlsBridgeError::invalid_input(
+                    "selection",
+                    format!("{} is not a pending proposal", unknown),
+                ));
+            }
+
+            for proposal_ref in pending {
+                if !selection.contains(&hex::encode(proposal_ref.as_slice())) {
+                    group
+                        .remove_pending_proposal(nostr_mls.provider.storage(), &proposal_ref)
+                        .map_err(MlsBridgeError::mls)?;
+                }
+            }
+        }
+
+        if group.pending_proposals().next().is_none() {
+            return Err(MlsBridgeError::invalid_input(
+                "selection",
+                "no pending proposals to commit",
+            ));
+        }
+        check_own_proposals(&instance, &group, group.pending_proposals())?;
+
+        let leaf_signer = mls::load_signer(nostr_mls, &group)?;
+        let (commit, welcome, _) = group
+            .commit_to_pending_proposals(&nostr_mls.provider, &leaf_signer)
+            .map_err(MlsBridgeError::mls)?;
+
+        let event = ephemeral_group_event(
+            nostr_mls,
+            hex::encode(mls::group_data(&group)?.nostr_group_id),
+            &mls::serialize_message(&commit)?,
+        )?;
+        let welcome_message = welcome
+            .map(|welcome| mls::serialize_message(&welcome))
+            .transpose()?;
+        save_own_pending_commit(&instance, &group, signer.is_none().then_some(&event))?;
+        (event, welcome_message)
+    };
+    let event = sign_own_commit(&instance, &group_id, signer, event).await?;
+
+    Ok(ProposalCommitResult {
+        commit_event: event_result(&event),
+        welcome_message,
+    })
+}
+
+/// Create a proposal under the group lock and wrap it in a kind-445 event, signed by the
+/// identity's commit signer if it has one
+async fn propose(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+    create_proposal: impl FnOnce(
+        &mut MlsGroup,
+        &SignatureKeyPair,
+    ) -> Result<MlsMessageOut, MlsBridgeError>,
+) -> Result<EventResult, MlsBridgeError> {
+    let nostr_mls = &instance.nostr_mls;
+
+    let event = {
+        let group_lock = instance.group_lock(group_id);
+        let _guard = registry::lock(&group_lock);
+
+        let mut group = mls::load_group(nostr_mls, group_id)?;
+        let signer = mls::load_signer(nostr_mls, &group)?;
+        let proposal = create_proposal(&mut group, &signer)?;
+
+        ephemeral_group_event(
+            nostr_mls,
+            hex::encode(mls::group_data(&group)?.nostr_group_id),
+            &mls::serialize_message(&proposal)?,
+        )?
+    };
+    let event = match instance.commit_signer() {
+        Some(signer) => signer.resign(event).await?,
+        None => event,
+    };
+
+    Ok(event_result(&event))
+}
+
+/// Whether a commit wins the race for its epoch against another one per NIP-EE: the earliest
+/// `created_at` wins, then the lowest event id
+pub(crate) fn wins_over(commit: &CommitRecord, other: &CommitRecord) -> bool {
+    (commit.created_at, &commit.event_id) < (other.created_at, &other.event_id)
+}
+
+/// Drop our pending commit or leave proposal of a group, the caller holding the group lock
+pub(crate) fn discard_own_commit(
+    instance: &MlsInstance,
+    group: &mut MlsGroup,
+) -> Result<(), MlsBridgeError> {
+    let storage = instance.nostr_mls.provider.storage();
+    let mls_group_id = hex::encode(group.group_id().as_slice());
+
+    let leave_proposal = instance
+        .store
+        .pending_commit(&mls_group_id)?
+        .and_then(|pending| pending.proposal_ref);
+    if let Some(leave_proposal) = leave_proposal {
+        let proposal_ref = group
+            .pending_proposals()
+            .map(|queued| queued.proposal_reference())
+            .find(|proposal_ref| hex::encode(proposal_ref.as_slice()) == leave_proposal);
+        if let Some(proposal_ref) = proposal_ref {
+            group
+                .remove_pending_proposal(storage, &proposal_ref)
+                .map_err(MlsBridgeError::mls)?;
+        }
+    }
+    // A self-update stored a new signing key that nothing uses once its commit is dropped
+    let own_key = own_signature_key(group).ok();
+    let staged_signature_key = group
+        .pending_commit()
+        .and_then(|staged| staged.update_path_leaf_node())
+        .map(|leaf| leaf.signature_key().as_slice().to_vec())
+        .filter(|key| own_key.as_ref() != Some(key));
+    if group.pending_commit().is_some() {
+        group
+            .clear_pending_commit(storage)
+            .map_err(MlsBridgeError::storage)?;
+    }
+    if let Some(staged_signature_key) = staged_signature_key {
+        delete_signature_key(instance, group, &staged_signature_key)?;
+    }
+
+    instance.store.delete_pending_commit(&mls_group_id)
+}
+
+/// Attach the kind-445 event wrapping our pending commit of a group, the caller holding the
+/// group lock
+/// Fails if the commit was rolled back or the group moved to another epoch since it was staged,
+/// as its event must never be published then.
+pub(crate) fn attach_pending_commit_event(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+    event: &Event,
+) -> Result<(), MlsBridgeError> {
+    let group = mls::load_group(&instance.nostr_mls, group_id)?;
+    ensure_pending_commit_awaits_event(instance, &group)?;
+
+    instance.store.set_pending_commit_event(
+        &hex::encode(group_id.as_slice()),
+        &event.id.to_hex(),
+        event.created_at.as_u64(),
+        &event.as_json(),
+    )?;
+    Ok(())
+}
+
+/// Fail unless our pending commit of a group is staged at its current epoch and has no event yet
+pub(crate) fn ensure_pending_commit_awaits_event(
+    instance: &MlsInstance,
+    group: &MlsGroup,
+) -> Result<(), MlsBridgeError> {
+    let pending = instance
+        .store
+        .pending_commit(&hex::encode(group.group_id().as_slice()))?
+        .filter(|pending| pending.epoch == group.epoch().as_u64())
+        .filter(|pending| pending.proposal_ref.is_some() || group.pending_commit().is_some());
+    match pending {
+        Some(pending) if pending.event_id.is_none() => Ok(()),
+        Some(_) => Err(MlsBridgeError::invalid_input(
+            "serialized_commit",
+            "the group's pending commit already has its event",
+        )),
+        None => Err(MlsBridgeError::invalid_input(
+            "serialized_commit",
+            "the group has no pending commit at this epoch, it was rolled back or aborted",
+        )),
+    }
+}
+
+/// Sign the event of our pending commit of a group with the identity's commit signer and
+/// attach it, returning the event unchanged when the signer is `None`
+/// Signing runs outside the group lock, so the commit has no event meanwhile and a competing
+/// commit rolls it back; its signed event is then refused. A commit the signer failed to sign is
+/// discarded, as nothing could publish it.
+async fn sign_own_commit(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+    signer: Option<Arc<Signer>>,
+    event: Event,
+) -> Result<Event, MlsBridgeError> {
+    let Some(signer) = signer else {
+        return Ok(event);
+    };
+    let signed = signer.resign(event).await;
+
+    let group_lock = instance.group_lock(group_id);
+    let _guard = registry::lock(&group_lock);
+
+    match signed {
+        Ok(signed) => {
+            attach_pending_commit_event(instance, group_id, &signed)?;
+            Ok(signed)
+        }
+        Err(e) => {
+            let mut group = mls::load_group(&instance.nostr_mls, group_id)?;
+            if ensure_pending_commit_awaits_event(instance, &group).is_ok() {
+                discard_own_commit(instance, &mut group)?;
+            }
+            Err(e)
+        }
+    }
+}
+
+/// Fail if our previous commit or leave proposal of a group is still waiting to be published
+pub(crate) fn ensure_no_pending_commit(
+    instance: &MlsInstance,
+    group: &MlsGroup,
+) -> Result<(), MlsBridgeError> {
+    let pending_row = instance
+        .store
+        .pending_commit(&hex::encode(group.group_id().as_slice()))?;
+    if group.pending_commit().is_some() || pending_row.is_some() {
+        return Err(MlsBridgeError::invalid_input(
+            "group_id",
+            "a commit of the group is pending, confirm or abort it first",
+        ));
+    }
+    Ok(())
+}
+
+/// Remember our commit as the group's pending commit, the caller holding the group lock
+/// Its event is `None` while the identity's commit signer signs it outside the lock.
+fn save_own_pending_commit(
+    instance: &MlsInstance,
+    group: &MlsGroup,
+    event: Option<&Event>,
+) -> Result<(), MlsBridgeError> {
+    instance.store.save_pending_commit(
+        &hex::encode(group.group_id().as_slice()),
+        &StoredPendingCommit {
+            epoch: group.epoch().as_u64(),
+            event_id: event.map(|event| event.id.to_hex()),
+            created_at: event.map(|event| event.created_at.as_u64()),
+            event_json: event.map(|event| event.as_json()),
+            proposal_ref: None,
+        },
+    )
+}
+
+/// Merge our pending commit of a group, checking it is the one of the published event
+fn merge_own_commit(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+    event_id: &str,
+) -> Result<Vec<DecryptedMessage>, MlsBridgeError> {
+    let nostr_mls = &instance.nostr_mls;
+    let mls_group_id = hex::encode(group_id.as_slice());
+
+    let snapshot = GroupSnapshot::take(instance, group_id);
+    {
+        let group_lock = instance.group_lock(group_id);
+        let _guard = registry::lock(&group_lock);
+
+        let mut group = mls::load_group(nostr_mls, group_id)?;
+        let pending = instance.store.pending_commit(&mls_group_id)?;
+        let pending_event_id = pending.as_ref().and_then(|p| p.event_id.as_deref());
+        if pending_event_id != Some(event_id) {
+            return Err(MlsBridgeError::invalid_input(
+                "event_id",
+                "not the event of the group's pending commit",
+            ));
+        }
+        if pending.is_some_and(|pending| pending.proposal_ref.is_some()) {
+            instance.store.delete_pending_commit(&mls_group_id)?;
+            return Ok(vec![]);
+        }
+        if group.pending_commit().is_none() {
+            return Err(MlsBridgeError::invalid_input(
+                "group_id",
+                "the group has no pending commit",
+            ));
+        }
+        let old_signature_key = own_signature_key(&group)?;
+
+        group
+            .merge_pending_commit(&nostr_mls.provider)
+            .map_err(MlsBridgeError::mls)?;
+        // Record the exporter secret of the new epoch, as nostr-mls does after its own merges
+        record_exporter_secret(instance, group_id)?;
+        mls::sync_group_row(nostr_mls, &group)?;
+
+        instance.store.delete_pending_commit(&mls_group_id)?;
+        instance
+            .store
+            .delete_commit_candidates_before(&mls_group_id, group.epoch().as_u64())?;
+
+        // A self-update replaced our signing key, the old one may be shared with other groups
+        if own_signature_key(&group)? != old_signature_key {
+            delete_signature_key(instance, &group, &old_signature_key)?;
+            record_rotation(instance, group_id)?;
+        }
+    }
+    if let Some(snapshot) = snapshot {
+        snapshot.emit_changes(instance, group_id);
+    }
+
+    replay_pending_messages(instance, group_id)
+}
+
+/// Discard our pending commit or leave proposal of a group
+fn abort_own_commit(instance: &MlsInstance, group_id: &GroupId) -> Result<bool, MlsBridgeError> {
+    let group_lock = instance.group_lock(group_id);
+    let _guard = registry::lock(&group_lock);
+
+    let mut group = mls::load_group(&instance.nostr_mls, group_id)?;
+    let pending_row = instance
+        .store
+        .pending_commit(&hex::encode(group_id.as_slice()))?;
+    if group.pending_commit().is_none() && pending_row.is_none() {
+        return Ok(false);
+    }
+    discard_own_commit(instance, &mut group)?;
+
+    Ok(true)
+}
+
+/// Delete a signing key of ours no longer used in a group, the caller holding its group lock
+fn delete_signature_key(
+    instance: &MlsInstance,
+    group: &MlsGroup,
+    signature_key: &[u8],
+) -> Result<(), MlsBridgeError> {
+    delete_unused_signature_key(
+        instance,
+        Some(group.group_id()),
+        signature_key,
+        group.ciphersuite().signature_algorithm(),
+    )
+}
+
+/// Delete a signing key of ours
+/// Groups joined with the same key package share its signing key, so it is kept while our
+/// leaf in a group other than `except` or a key package that can still be used signs with it.
+pub(crate) fn delete_unused_signature_key(
+    instance: &MlsInstance,
+    except: Option<&GroupId>,
+    signature_key: &[u8],
+    signature_scheme: SignatureScheme,
+) -> Result<(), MlsBridgeError> {
+    let nostr_mls = &instance.nostr_mls;
+
+    for other in nostr_mls.get_groups()? {
+        if except.is_some_and(|except| other.mls_group_id == *except) {
+            continue;
+        }
+        // Read without the other group's lock: a rotation there never starts using this key
+        let in_use = mls::load_group(nostr_mls, &other.mls_group_id)
+            .ok()
+            .and_then(|other| own_signature_key(&other).ok())
+            .is_some_and(|other_key| other_key == signature_key);
+        if in_use {
+            return Ok(());
+        }
+    }
+    if key_package_signature_keys(instance)?
+        .iter()
+        .any(|key| key == signature_key)
+    {
+        return Ok(());
+    }
+
+    SignatureKeyPair::delete(
+        nostr_mls.provider.storage(),
+        signature_key,
+        signature_scheme,
+    )
+    .map_err(MlsBridgeError::storage)
+}
+
+fn own_signature_key(group: &MlsGroup) -> Result<Vec<u8>, MlsBridgeError> {
+    Ok(group
+        .own_leaf()
+        .ok_or(MlsBridgeError::GroupNotFound)?
+        .signature_key()
+        .as_slice()
+        .to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::mls_api::{
+        create_message_for_group, export_secret, get_group, process_message_for_group,
+    };
+    use crate::api::recovery::{compare_group_state, get_group_health, GroupStateComparison};
+    use crate::test_utils::{create_test_group, receive_test_commit, TestDir, TestIdentity};
+
+    fn commit(created_at: u64, event_id: &str) -> CommitRecord {
+        CommitRecord {
+            epoch: 7,
+            event_id: event_id.to_string(),
+            created_at,
+        }
+    }
+
+    #[test]
+    fn earliest_commit_wins() {
+        let early = commit(100, "ff");
+        let late = commit(101, "00");
+
+        assert!(wins_over(&early, &late));
+        assert!(!wins_over(&late, &early));
+    }
+
+    #[test]
+    fn lowest_event_id_breaks_ties() {
+        let low = commit(100, "0a");
+        let high = commit(100, "0b");
+
+        assert!(wins_over(&low, &high));
+        assert!(!wins_over(&high, &low));
+    }
+
+    #[test]
+    fn commit_does_not_win_over_itself() {
+        let record = commit(100, "0a");
+
+        assert!(!wins_over(&record, &commit(100, "0a")));
+    }
+
+    #[tokio::test]
+    async fn merged_commits_update_the_nostr_mls_group_row() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = GroupId::from_slice(&group.mls_group_id);
+
+        let event = update_group_data(
+            alice.identity.clone(),
+            group.mls_group_id.clone(),
+            Some("renamed".to_string()),
+            None,
+            Some(vec![alice.identity.clone(), bob.identity.clone()]),
+            None,
+        )
+        .await
+        .expect("update group data");
+        confirm_commit_published(
+            alice.identity.clone(),
+            group.mls_group_id.clone(),
+            event.event_id,
+        )
+        .expect("merge own commit");
+        receive_test_commit(&bob, &event.event_json).expect("apply commit");
+
+        for member in [&alice, &bob] {
+            let instance = member.instance();
+            let nostr_mls = &instance.nostr_mls;
+            let row = nostr_mls
+                .get_group(&group_id)
+                .expect("read group row")
+                .expect("group row");
+
+            let mls_group = mls::load_group(nostr_mls, &group_id).expect("load group");
+            assert_eq!(row.epoch, mls_group.epoch().as_u64());
+            assert_eq!(row.state, GroupState::Active);
+            assert_eq!(row.name, "renamed");
+            assert!(row.admin_pubkeys.contains(&bob.keys.public_key()));
+        }
+    }
+
+    #[tokio::test]
+    async fn updated_group_data_reaches_every_member() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let relay = "wss://other.example.com".to_string();
+
+        let event = update_group_data(
+            alice.identity.clone(),
+            group.mls_group_id.clone(),
+            Some("renamed".to_string()),
+            Some("described".to_string()),
+            None,
+            Some(vec![relay.clone()]),
+        )
+        .await
+        .expect("update group data");
+        confirm_commit_published(
+            alice.identity.clone(),
+            group.mls_group_id.clone(),
+            event.event_id,
+        )
+        .expect("merge own commit");
+        receive_test_commit(&bob, &event.event_json).expect("apply commit");
+
+        for member in [&alice, &bob] {
+            let data = get_group(member.identity.clone(), group.mls_group_id.clone())
+                .expect("get group")
+                .nostr_group_data;
+            assert_eq!(data.name, "renamed");
+            assert_eq!(data.description, "described");
+            assert_eq!(data.admin_pubkeys, vec![alice.identity.clone()]);
+            assert_eq!(data.relays.len(), 1);
+            assert_eq!(data.relays[0].trim_end_matches('/'), relay);
+        }
+
+        // Only admins may update it under the default policy
+        assert!(matches!(
+            update_group_data(
+                bob.identity.clone(),
+                group.mls_group_id.clone(),
+                Some("taken over".to_string()),
+                None,
+                None,
+                None,
+            )
+            .await,
+            Err(MlsBridgeError::PermissionDenied { .. })
+        ));
+    }
+
+    /// The same kind-445 event sent `offset` seconds later, re-signed by another random key
+    fn shift_created_at(event_json: &str, offset: i64) -> String {
+        let event = Event::from_json(event_json).expect("parse event");
+        let created_at = event.created_at.as_u64().saturating_add_signed(offset);
+
+        EventBuilder::new(event.kind, event.content)
+            .tags(event.tags)
+            .custom_created_at(Timestamp::from(created_at))
+            .sign_with_keys(&Keys::generate())
+            .expect("sign event")
+            .as_json()
+    }
+
+    fn assert_same_epoch(a: &TestIdentity, b: &TestIdentity, group_id: &[u8]) {
+        let a = export_secret(a.identity.clone(), group_id.to_vec()).expect("export secret");
+        let b = export_secret(b.identity.clone(), group_id.to_vec()).expect("export secret");
+        assert_eq!(a.epoch, b.epoch);
+        assert_eq!(a.secret, b.secret);
+    }
+
+    #[tokio::test]
+    async fn earlier_competing_commit_rolls_back_our_own() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+
+        let own = self_update(alice.identity.clone(), group_id.clone())
+            .await
+            .expect("alice self-update");
+        let competing = self_update(bob.identity.clone(), group_id.clone())
+            .await
+            .expect("bob self-update");
+        confirm_commit_published(bob.identity.clone(), group_id.clone(), competing.event_id)
+            .expect("merge bob's commit");
+
+        let processed = receive_test_commit(&alice, &shift_created_at(&competing.event_json, -10))
+            .expect("apply bob's commit");
+        assert!(processed.own_commit_rolled_back);
+        assert!(!processed.superseded);
+
+        assert!(get_pending_commit(alice.identity.clone(), group_id.clone())
+            .expect("read pending commit")
+            .is_none());
+        assert!(
+            confirm_commit_published(alice.identity.clone(), group_id.clone(), own.event_id)
+                .is_err()
+        );
+        assert_same_epoch(&alice, &bob, &group_id);
+    }
+
+    #[tokio::test]
+    async fn later_competing_commit_is_superseded_by_our_own() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+
+        let own = self_update(alice.identity.clone(), group_id.clone())
+            .await
+            .expect("alice self-update");
+        let competing = self_update(bob.identity.clone(), group_id.clone())
+            .await
+            .expect("bob self-update");
+
+        let processed = receive_test_commit(&alice, &shift_created_at(&competing.event_json, 10))
+            .expect("order bob's commit");
+        assert!(processed.superseded);
+        assert!(!processed.own_commit_rolled_back);
+        confirm_commit_published(alice.identity.clone(), group_id.clone(), own.event_id)
+            .expect("merge alice's commit");
+
+        // Bob's commit is still pending when alice's earlier one arrives
+        let processed = receive_test_commit(&bob, &own.event_json).expect("apply alice's commit");
+        assert!(processed.own_commit_rolled_back);
+        assert!(get_pending_commit(bob.identity.clone(), group_id.clone())
+            .expect("read pending commit")
+            .is_none());
+        assert_same_epoch(&alice, &bob, &group_id);
+    }
+
+    #[tokio::test]
+    async fn winning_commit_arriving_after_our_merge_leaves_us_diverged() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+
+        let winning = self_update(alice.identity.clone(), group_id.clone())
+            .await
+            .expect("alice self-update");
+        self_update(bob.identity.clone(), group_id.clone())
+            .await
+            .expect("bob self-update");
+        merge_pending_commit(alice.identity.clone(), group_id.clone())
+            .expect("merge alice's commit");
+        // Bob hears nothing of alice's commit before merging his own
+        merge_pending_commit(bob.identity.clone(), group_id.clone()).expect("merge bob's commit");
+
+        let late = process_message_for_group(
+            bob.identity.clone(),
+            shift_created_at(&winning.event_json, -10),
+        );
+        assert!(matches!(late, Err(MlsBridgeError::StaleEpoch { .. })));
+        assert!(get_pending_commit(bob.identity.clone(), group_id.clone())
+            .expect("read pending commit")
+            .is_none());
+
+        let alice_health =
+            get_group_health(alice.identity.clone(), group_id.clone()).expect("alice health");
+        let comparison = compare_group_state(
+            bob.identity.clone(),
+            group_id.clone(),
+            alice_health.epoch,
+            alice_health.tree_hash,
+        )
+        .expect("compare group state");
+        assert_eq!(comparison, GroupStateComparison::Diverged);
+    }
+
+    #[tokio::test]
+    async fn clear_pending_commit_discards_our_commit() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+
+        assert!(merge_pending_commit(alice.identity.clone(), group_id.clone()).is_err());
+        let before =
+            export_secret(alice.identity.clone(), group_id.clone()).expect("export secret");
+        self_update(alice.identity.clone(), group_id.clone())
+            .await
+            .expect("alice self-update");
+
+        assert!(
+            clear_pending_commit(alice.identity.clone(), group_id.clone())
+                .expect("clear pending commit")
+        );
+        assert!(
+            !clear_pending_commit(alice.identity.clone(), group_id.clone()).expect("clear nothing")
+        );
+        let after = export_secret(alice.identity.clone(), group_id.clone()).expect("export secret");
+        assert_eq!(before.epoch, after.epoch);
+    }
+
+    #[tokio::test]
+    async fn pending_commit_survives_reopening_until_confirmed() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+
+        let before =
+            export_secret(alice.identity.clone(), group_id.clone()).expect("export secret");
+        let commit = self_update(alice.identity.clone(), group_id.clone())
+            .await
+            .expect("alice self-update");
+
+        alice.reopen(&dir);
+        let pending = get_pending_commit(alice.identity.clone(), group_id.clone())
+            .expect("read pending commit")
+            .expect("pending commit kept");
+        assert_eq!(pending.kind, PendingCommitKind::Commit);
+        assert_eq!(pending.epoch, before.epoch);
+        assert_eq!(pending.event_id.as_deref(), Some(commit.event_id.as_str()));
+        let reopened =
+            export_secret(alice.identity.clone(), group_id.clone()).expect("export secret");
+        assert_eq!(reopened.epoch, before.epoch);
+
+        confirm_commit_published(alice.identity.clone(), group_id.clone(), commit.event_id)
+            .expect("merge alice's commit");
+        assert!(get_pending_commit(alice.identity.clone(), group_id.clone())
+            .expect("read pending commit")
+            .is_none());
+        receive_test_commit(&bob, &commit.event_json).expect("apply alice's commit");
+        assert_same_epoch(&alice, &bob, &group_id);
+        let merged =
+            export_secret(alice.identity.clone(), group_id.clone()).expect("export secret");
+        assert_eq!(merged.epoch, before.epoch + 1);
+    }
+
+    #[tokio::test]
+    async fn commit_releases_messages_buffered_for_its_epoch() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+
+        let commit = self_update(alice.identity.clone(), group_id.clone())
+            .await
+            .expect("alice self-update");
+        confirm_commit_published(alice.identity.clone(), group_id.clone(), commit.event_id)
+            .expect("merge alice's commit");
+        let rumor = EventBuilder::new(Kind::Custom(9), "sent in the new epoch")
+            .build(alice.keys.public_key());
+        let message =
+            create_message_for_group(alice.identity.clone(), group_id.clone(), rumor.as_json())
+                .expect("create message");
+
+        // The message overtakes the commit on its way to bob
+        let processed = process_message_for_group(bob.identity.clone(), message.event_json)
+            .expect("buffer message");
+        assert!(processed.pending);
+
+        let processed = receive_test_commit(&bob, &commit.event_json).expect("apply commit");
+        assert_eq!(processed.released_messages.len(), 1);
+        assert_eq!(
+            processed.released_messages[0].content,
+            "sent in the new epoch"
+        );
+    }
+}
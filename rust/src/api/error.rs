@@ -0,0 +1,180 @@
+use flutter_rust_bridge::frb;
+use nostr_mls::prelude::{ProcessMessageError, ValidationError};
+use std::fmt;
+
+/// Errors returned by every bridge function, surfaced to Dart as a typed exception
+#[derive(Debug, Clone)]
+pub enum MlsBridgeError {
+    /// `init_nostr_mls` has not been called
+    NotInitialized,
+    /// An argument could not be parsed (public key, relay url, event JSON, ...)
+    InvalidInput { field: String, message: String },
+    /// The group does not exist in local storage
+    GroupNotFound,
+    /// The message belongs to an epoch other than the group's current one
+    StaleEpoch { message: String },
+    /// The message was sent by ourselves and cannot be decrypted again
+    OwnMessage,
+    /// The outer NIP-44 layer or the MLS ciphertext could not be decrypted
+    DecryptionFailed { message: String },
+    /// The storage database could not be opened with the given password
+    StorageLocked { message: String },
+    /// No private key material exists for the key package
+    KeyPackageNotFound,
+    /// The group's admin policy does not allow the operation
+    PermissionDenied { message: String },
+    /// The configured signer failed or returned an invalid signature
+    Signer { message: String },
+    /// The app's upload or download callback failed
+    Transfer { message: String },
+    /// Any other storage failure
+    Storage { message: String },
+    /// Any other MLS protocol failure
+    Mls { message: String },
+    /// Internal failure of the bridge itself
+    Internal { message: String },
+}
+
+impl MlsBridgeError {
+    /// Stable machine readable code of the error
+    #[frb(sync)]
+    pub fn code(&self) -> String {
+        match self {
+            Self::NotInitialized => "not_initialized",
+            Self::InvalidInput { .. } => "invalid_input",
+            Self::GroupNotFound => "group_not_found",
+            Self::StaleEpoch { .. } => "stale_epoch",
+            Self::OwnMessage => "own_message",
+            Self::DecryptionFailed { .. } => "decryption_failed",
+            Self::StorageLocked { .. } => "storage_locked",
+            Self::KeyPackageNotFound => "key_package_not_found",
+            Self::PermissionDenied { .. } => "permission_denied",
+            Self::Signer { .. } => "signer",
+            Self::Transfer { .. } => "transfer",
+            Self::Storage { .. } => "storage",
+            Self::Mls { .. } => "mls",
+            Self::Internal { .. } => "internal",
+        }
+        .to_string()
+    }
+
+    /// Whether the event may belong to an epoch the group has not reached yet
+    /// Only an outer layer that does not open at our epoch can; an MLS `WrongEpoch` means it
+    /// opened with our exporter secret, so the event is not from a later epoch.
+    pub(crate) fn may_be_later_epoch(&self) -> bool {
+        matches!(self, Self::DecryptionFailed { .. })
+    }
+
+    pub(crate) fn invalid_input(field: &str, e: impl fmt::Display) -> Self {
+        Self::InvalidInput {
+            field: field.to_string(),
+            message: e.to_string(),
+        }
+    }
+
+    pub(crate) fn internal(e: impl fmt::Display) -> Self {
+        Self::Internal {
+            message: e.to_string(),
+        }
+    }
+
+    pub(crate) fn permission_denied(message: impl fmt::Display) -> Self {
+        Self::PermissionDenied {
+            message: message.to_string(),
+        }
+    }
+
+    /// Map an error of openmls called directly
+    /// Its debug form names the variant, which the display form may not
+    pub(crate) fn mls(e: impl fmt::Debug) -> Self {
+        Self::Mls {
+            message: format!("{:?}", e),
+        }
+    }
+
+    pub(crate) fn signer(e: impl fmt::Display) -> Self {
+        Self::Signer {
+            message: e.to_string(),
+        }
+    }
+
+    pub(crate) fn storage(e: impl fmt::Display) -> Self {
+        Self::Storage {
+            message: e.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for MlsBridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotInitialized => write!(f, "NostrMls is not initialized"),
+            Self::InvalidInput { field, message } => write!(f, "Invalid {}: {}", field, message),
+            Self::GroupNotFound => write!(f, "Group not found"),
+            Self::StaleEpoch { message } => write!(f, "Message epoch mismatch: {}", message),
+            Self::OwnMessage => write!(f, "Cannot process own message"),
+            Self::DecryptionFailed { message } => write!(f, "Failed to decrypt: {}", message),
+            Self::StorageLocked { message } => write!(f, "Storage is locked: {}", message),
+            Self::KeyPackageNotFound => write!(f, "Key package not found"),
+            Self::PermissionDenied { message } => write!(f, "Permission denied: {}", message),
+            Self::Signer { message } => write!(f, "Signer error: {}", message),
+            Self::Transfer { message } => write!(f, "Transfer error: {}", message),
+            Self::Storage { message } => write!(f, "Storage error: {}", message),
+            Self::Mls { message } => write!(f, "MLS error: {}", message),
+            Self::Internal { message } => write!(f, "Internal error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for MlsBridgeError {}
+
+impl From<nostr_mls::Error> for MlsBridgeError {
+    fn from(e: nostr_mls::Error) -> Self {
+        match e {
+            nostr_mls::Error::GroupNotFound => Self::GroupNotFound,
+            nostr_mls::Error::CannotDecryptOwnMessage => Self::OwnMessage,
+            nostr_mls::Error::Message(message) => Self::Mls { message },
+            e => Self::Mls {
+                message: e.to_string(),
+            },
+        }
+    }
+}
+
+impl From<ProcessMessageError> for MlsBridgeError {
+    fn from(e: ProcessMessageError) -> Self {
+        match e {
+            ProcessMessageError::ValidationError(ValidationError::WrongEpoch) => Self::StaleEpoch {
+                message: e.to_string(),
+            },
+            ProcessMessageError::ValidationError(ValidationError::UnableToDecrypt(_)) => {
+                Self::DecryptionFailed {
+                    message: e.to_string(),
+                }
+            }
+            e => Self::mls(e),
+        }
+    }
+}
+
+impl From<nostr_mls_sqlite_storage::error::Error> for MlsBridgeError {
+    fn from(e: nostr_mls_sqlite_storage::error::Error) -> Self {
+        Self::storage(e)
+    }
+}
+
+/// SQLCipher reports a wrong or missing password as a file that is not a database
+impl From<rusqlite::Error> for MlsBridgeError {
+    fn from(e: rusqlite::Error) -> Self {
+        match e {
+            rusqlite::Error::SqliteFailure(ref err, _)
+                if err.code == rusqlite::ErrorCode::NotADatabase =>
+            {
+                Self::StorageLocked {
+                    message: e.to_string(),
+                }
+            }
+            e => Self::storage(e),
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use nostr_mls::prelude::*;
+use std::collections::BTreeSet;
+
+use crate::api::error::MlsBridgeError;
+use crate::api::mls_api::group_info;
+use crate::api::types::{DecryptedMessage, GroupInfo};
+use crate::frb_generated::StreamSink;
+use crate::registry::{self, EventSink, MlsInstance};
+
+/// State change of an identity's groups, pushed to `subscribe_group_events` sinks
+#[derive(Debug, Clone)]
+pub enum GroupEvent {
+    MessageDecrypted {
+        message: DecryptedMessage,
+    },
+    MembersAdded {
+        mls_group_id: Vec<u8>,
+        members: Vec<String>,
+    },
+    MembersRemoved {
+        mls_group_id: Vec<u8>,
+        members: Vec<String>,
+    },
+    EpochAdvanced {
+        mls_group_id: Vec<u8>,
+        epoch: u64,
+    },
+    GroupMetadataChanged {
+        group: GroupInfo,
+    },
+    WelcomeReceived {
+        /// Hex encoded id of the gift wrap carrying the welcome
+        wrapper_event_id: String,
+        group: GroupInfo,
+    },
+    /// A welcome we joined used a key package of the key package manager
+    KeyPackageConsumed {
+        /// Hex encoded id of the gift wrap carrying the welcome that used the key package
+        wrapper_event_id: String,
+        /// Id of the `ManagedKeyPackage` used
+        key_package_id: String,
+    },
+    PendingCommitRolledBack {
+        mls_group_id: Vec<u8>,
+        /// Hex encoded id of our commit event, which must not be published anymore
+        event_id: Option<String>,
+    },
+    /// Everything stored about a group we are no longer in was deleted
+    GroupPurged {
+        mls_group_id: Vec<u8>,
+    },
+}
+
+/// Subscribe to the group events of an identity
+/// The stream stays open until Dart cancels it or the identity is closed
+pub fn subscribe_group_events(
+    identity: String,
+    sink: StreamSink<GroupEvent>,
+) -> Result<(), MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    instance.subscribe(EventSink::Dart(sink));
+    Ok(())
+}
+
+/// Group state taken before a call that may change it
+pub(crate) struct GroupSnapshot {
+    epoch: u64,
+    group: GroupInfo,
+}
+
+impl GroupSnapshot {
+    /// Take a snapshot, or `None` when nobody listens or the group is unknown
+    pub(crate) fn take(instance: &MlsInstance, group_id: &GroupId) -> Option<Self> {
+        if !instance.has_subscribers() {
+            return None;
+        }
+        Self::capture(instance, group_id)
+    }
+
+    fn capture(instance: &MlsInstance, group_id: &GroupId) -> Option<Self> {
+        Some(Self {
+            epoch: instance.nostr_mls.exporter_secret(group_id).ok()?.epoch,
+            group: group_info(&instance.nostr_mls, group_id).ok()?,
+        })
+    }
+
+    /// Emit the events describing how the group changed since the snapshot
+    pub(crate) fn emit_changes(self, instance: &MlsInstance, group_id: &GroupId) {
+        let Some(after) = Self::take(instance, group_id) else {
+            return;
+        };
+        for event in self.changes(&after, group_id) {
+            instance.emit(event);
+        }
+    }
+
+    fn changes(&self, after: &Self, group_id: &GroupId) -> Vec<GroupEvent> {
+        let mut events = Vec::new();
+        let before_members: BTreeSet<&String> = self.group.members.iter().collect();
+        let after_members: BTreeSet<&String> = after.group.members.iter().collect();
+
+        let added: Vec<String> = after_members
+            .difference(&before_members)
+            .map(|pk| pk.to_string())
+            .collect();
+        if !added.is_empty() {
+            events.push(GroupEvent::MembersAdded {
+                mls_group_id: group_id.to_vec(),
+                members: added,
+            });
+        }
+
+        let removed: Vec<String> = before_members
+            .difference(&after_members)
+            .map(|pk| pk.to_string())
+            .collect();
+        if !removed.is_empty() {
+            events.push(GroupEvent::MembersRemoved {
+                mls_group_id: group_id.to_vec(),
+                members: removed,
+            });
+        }
+
+        if after.group.nostr_group_data != self.group.nostr_group_data {
+            events.push(GroupEvent::GroupMetadataChanged {
+                group: after.group.clone(),
+            });
+        }
+
+        if after.epoch != self.epoch {
+            events.push(GroupEvent::EpochAdvanced {
+                mls_group_id: group_id.to_vec(),
+                epoch: after.epoch,
+            });
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::commits::confirm_commit_published;
+    use crate::api::key_packages::{maintain_key_packages, KeyPackagePolicy};
+    use crate::api::mls_api::{
+        add_members, create_commit_message_for_group, create_group, create_key_package_for_event,
+        create_message_for_group, process_message_for_group, remove_members,
+    };
+    use crate::test_utils::{
+        create_test_group, join_test_welcome, receive_test_commit, TestDir, TestIdentity,
+        TEST_RELAY,
+    };
+    use std::sync::mpsc::{self, Receiver};
+
+    fn subscribe(member: &TestIdentity) -> Receiver<GroupEvent> {
+        let (sender, receiver) = mpsc::channel();
+        member.instance().subscribe(EventSink::Channel(sender));
+        receiver
+    }
+
+    #[tokio::test]
+    async fn added_member_is_reported_with_the_new_epoch() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let carol = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = GroupId::from_slice(&group.mls_group_id);
+        let instance = alice.instance();
+        let before = GroupSnapshot::capture(&instance, &group_id).expect("snapshot before");
+
+        let key_package = create_key_package_for_event(
+            carol.identity.clone(),
+            carol.identity.clone(),
+            None,
+            None,
+        )
+        .expect("create key package")
+        .encoded_key_package;
+        let added = add_members(
+            alice.identity.clone(),
+            group.mls_group_id.clone(),
+            vec![key_package],
+        )
+        .expect("add carol");
+        let event = create_commit_message_for_group(
+            alice.identity.clone(),
+            group.nostr_group_data.nostr_group_id.clone(),
+            added.commit_message,
+        )
+        .await
+        .expect("wrap commit");
+        confirm_commit_published(
+            alice.identity.clone(),
+            group.mls_group_id.clone(),
+            event.event_id,
+        )
+        .expect("merge commit");
+
+        let after = GroupSnapshot::capture(&instance, &group_id).expect("snapshot after");
+        let changes = before.changes(&after, &group_id);
+        assert_eq!(changes.len(), 2);
+        assert!(matches!(
+            &changes[0],
+            GroupEvent::MembersAdded { members, .. } if members == &vec![carol.identity.clone()]
+        ));
+        assert!(matches!(
+            changes[1],
+            GroupEvent::EpochAdvanced { epoch, .. } if epoch == before.epoch + 1
+        ));
+    }
+
+    #[tokio::test]
+    async fn subscribers_receive_the_events_of_processed_welcomes_messages_and_commits() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let carol = TestIdentity::open(&dir);
+        let bob_events = subscribe(&bob);
+        let carol_events = subscribe(&carol);
+
+        // Bob's key package is managed, carol's is not
+        let managed = maintain_key_packages(
+            bob.identity.clone(),
+            bob.identity.clone(),
+            None,
+            None,
+            KeyPackagePolicy {
+                last_resort_count: 0,
+                one_time_count: 1,
+                max_age_secs: 3_600,
+            },
+        )
+        .expect("maintain key packages")
+        .to_publish
+        .remove(0);
+        let unmanaged = create_key_package_for_event(
+            carol.identity.clone(),
+            carol.identity.clone(),
+            None,
+            None,
+        )
+        .expect("create key package")
+        .encoded_key_package;
+        let created = create_group(
+            alice.identity.clone(),
+            "events".to_string(),
+            String::new(),
+            vec![managed.encoded_key_package.clone(), unmanaged],
+            vec![bob.identity.clone(), carol.identity.clone()],
+            alice.identity.clone(),
+            vec![alice.identity.clone()],
+            vec![TEST_RELAY.to_string()],
+        )
+        .expect("create group");
+        let group = created.group;
+        join_test_welcome(&bob, &alice, &created.serialized_welcome_message);
+        join_test_welcome(&carol, &alice, &created.serialized_welcome_message);
+
+        let events: Vec<GroupEvent> = bob_events.try_iter().collect();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            GroupEvent::WelcomeReceived { group: joined, .. }
+                if joined.mls_group_id == group.mls_group_id
+        )));
+        assert!(events.iter().any(|event| matches!(
+            event,
+            GroupEvent::KeyPackageConsumed { key_package_id, .. } if key_package_id == &managed.id
+        )));
+        let events: Vec<GroupEvent> = carol_events.try_iter().collect();
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, GroupEvent::WelcomeReceived { .. })));
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, GroupEvent::KeyPackageConsumed { .. })));
+
+        let rumor = EventBuilder::new(Kind::Custom(9), "hello").build(alice.keys.public_key());
+        let message = create_message_for_group(
+            alice.identity.clone(),
+            group.mls_group_id.clone(),
+            rumor.as_json(),
+        )
+        .expect("create message");
+        process_message_for_group(bob.identity.clone(), message.event_json)
+            .expect("process message");
+        let events: Vec<GroupEvent> = bob_events.try_iter().collect();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            GroupEvent::MessageDecrypted { message } if message.content == "hello"
+        )));
+
+        let removed = remove_members(
+            alice.identity.clone(),
+            group.mls_group_id.clone(),
+            vec![carol.identity.clone()],
+        )
+        .expect("remove carol");
+        let commit = create_commit_message_for_group(
+            alice.identity.clone(),
+            group.nostr_group_data.nostr_group_id.clone(),
+            removed.serialized_commit,
+        )
+        .await
+        .expect("wrap commit");
+        confirm_commit_published(
+            alice.identity.clone(),
+            group.mls_group_id.clone(),
+            commit.event_id.clone(),
+        )
+        .expect("merge commit");
+        receive_test_commit(&bob, &commit.event_json).expect("apply commit");
+        let events: Vec<GroupEvent> = bob_events.try_iter().collect();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            GroupEvent::MembersRemoved { members, .. } if members == &vec![carol.identity.clone()]
+        )));
+    }
+}
@@ -0,0 +1,643 @@
+use nostr_mls::prelude::*;
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+
+use crate::api::commits::delete_unused_signature_key;
+use crate::api::error::MlsBridgeError;
+use crate::api::mls_api::parse_relays;
+use crate::registry::{self, MlsInstance};
+use crate::store::StoredKeyPackage;
+
+const STATE_UNPUBLISHED: &str = "unpublished";
+const STATE_PUBLISHED: &str = "published";
+const STATE_CONSUMED: &str = "consumed";
+const STATE_DECLINED: &str = "declined";
+const STATE_RETIRED: &str = "retired";
+const STATE_DELETED: &str = "deleted";
+
+/// How many unused key packages `maintain_key_packages` keeps available
+#[derive(Debug, Clone)]
+pub struct KeyPackagePolicy {
+    /// Key packages that stay usable by several welcomes until they are rotated
+    pub last_resort_count: u32,
+    /// Key packages whose private material is deleted by the first welcome using them
+    ///
+    /// nostr-mls marks every key package last resort in MLS, so single use is enforced here.
+    pub one_time_count: u32,
+    /// Key packages older than this are retired and replaced
+    pub max_age_secs: u64,
+}
+
+/// Lifecycle state of a managed key package
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyPackageState {
+    /// Created but its kind-443 event is not confirmed published yet
+    Unpublished,
+    Published,
+    /// Used by a welcome we joined
+    Consumed,
+    /// A last resort one a declined welcome was sent to, usable by other welcomes until the next
+    /// `maintain_key_packages` rotates it
+    Declined,
+    /// Rotated out, its private material is deleted; its kind-443 event is still to delete
+    Retired,
+    /// Retired and its kind-443 event deleted, forgotten by `apply_retention_policy`
+    Deleted,
+}
+
+/// A key package tracked by the key package manager
+#[derive(Debug, Clone)]
+pub struct ManagedKeyPackage {
+    /// Hex encoded sha256 of the encoded key package
+    pub id: String,
+    pub encoded_key_package: String,
+    /// Tags of its kind-443 event
+    pub tags: Vec<Vec<String>>,
+    pub last_resort: bool,
+    pub state: KeyPackageState,
+    /// Hex encoded id of the published kind-443 event
+    pub event_id: Option<String>,
+    pub created_at: u64,
+    pub published_at: Option<u64>,
+    pub consumed_at: Option<u64>,
+}
+
+/// Work left to the app after `maintain_key_packages`
+#[derive(Debug, Clone)]
+pub struct KeyPackageRotation {
+    /// Key packages to publish as kind-443 events, each confirmed with `mark_key_package_published`
+    pub to_publish: Vec<ManagedKeyPackage>,
+    /// Retired key packages whose kind-443 event is to delete with a kind-5 event, each confirmed
+    /// with `mark_key_package_deleted`; listed again until then
+    pub to_delete: Vec<ManagedKeyPackage>,
+}
+
+impl Default for KeyPackagePolicy {
+    fn default() -> Self {
+        Self {
+            last_resort_count: 1,
+            one_time_count: 5,
+            max_age_secs: 30 * 24 * 60 * 60,
+        }
+    }
+}
+
+/// Bring the key package pool of an identity to the target of a policy
+///
+/// Expired key packages and consumed or declined last resort ones are retired and their private
+/// material deleted, then new key packages are created until the policy's counts of unused ones
+/// are met.
+/// Parameters: public_key - hex public key of the identity, relay - relays of the kind-443 events
+/// Returns: the key packages to publish and the kind-443 events to delete
+pub fn maintain_key_packages(
+    identity: String,
+    public_key: String,
+    relay: Option<Vec<String>>,
+    client: Option<String>,
+    policy: KeyPackagePolicy,
+) -> Result<KeyPackageRotation, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let public_key = PublicKey::from_str(&public_key)
+        .map_err(|e| MlsBridgeError::invalid_input("public_key", e))?;
+    let relays = parse_relays(relay)?;
+
+    let now = Timestamp::now().as_u64();
+    let mut to_delete = Vec::new();
+    let mut last_resort_unused = 0;
+    let mut one_time_unused = 0;
+
+    for mut key_package in instance.store.key_packages()? {
+        let expired = now.saturating_sub(key_package.created_at) >= policy.max_age_secs;
+        let unused = key_package.state == STATE_UNPUBLISHED || key_package.state == STATE_PUBLISHED;
+        let used_last_resort = (key_package.state == STATE_CONSUMED && key_package.last_resort)
+            || key_package.state == STATE_DECLINED;
+
+        if (unused && expired) || used_last_resort {
+            retire(&instance, &mut key_package)?;
+        } else if unused && key_package.last_resort {
+            last_resort_unused += 1;
+        } else if unused {
+            one_time_unused += 1;
+        }
+
+        // Consumed one-time key packages were retired when their welcome was joined
+        if key_package.state == STATE_RETIRED {
+            to_delete.push(ManagedKeyPackage::try_from(key_package)?);
+        }
+    }
+
+    let missing = [
+        (
+            true,
+            policy.last_resort_count.saturating_sub(last_resort_unused),
+        ),
+        (false, policy.one_time_count.saturating_sub(one_time_unused)),
+    ];
+    for (last_resort, count) in missing {
+        for _ in 0..count {
+            create_managed_key_package(
+                &instance,
+                &public_key,
+                relays.clone(),
+                client.as_deref().unwrap_or(""),
+                last_resort,
+            )?;
+        }
+    }
+
+    let to_publish = instance
+        .store
+        .key_packages()?
+        .into_iter()
+        .filter(|key_package| key_package.state == STATE_UNPUBLISHED)
+        .map(ManagedKeyPackage::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(KeyPackageRotation {
+        to_publish,
+        to_delete,
+    })
+}
+
+/// Record that the kind-443 event of a key package was published
+/// Parameters: key_package_id - id from `ManagedKeyPackage`, event_id - hex encoded id of the event
+/// Returns: whether the key package is managed
+pub fn mark_key_package_published(
+    identity: String,
+    key_package_id: String,
+    event_id: String,
+) -> Result<bool, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let event_id =
+        EventId::from_hex(&event_id).map_err(|e| MlsBridgeError::invalid_input("event_id", e))?;
+
+    let Some(mut key_package) = instance.store.key_package(&key_package_id)? else {
+        return Ok(false);
+    };
+    if key_package.state == STATE_UNPUBLISHED {
+        key_package.state = STATE_PUBLISHED.to_string();
+    }
+    key_package.event_id = Some(event_id.to_hex());
+    key_package.published_at = Some(Timestamp::now().as_u64());
+    instance.store.save_key_package(&key_package)?;
+
+    Ok(true)
+}
+
+/// Record that the kind-443 event of a retired key package was deleted
+/// Parameters: key_package_id - id from `ManagedKeyPackage`
+/// Returns: whether the key package is managed and retired
+pub fn mark_key_package_deleted(
+    identity: String,
+    key_package_id: String,
+) -> Result<bool, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let Some(mut key_package) = instance.store.key_package(&key_package_id)? else {
+        return Ok(false);
+    };
+    if key_package.state != STATE_RETIRED {
+        return Ok(false);
+    }
+    key_package.state = STATE_DELETED.to_string();
+    instance.store.save_key_package(&key_package)?;
+
+    Ok(true)
+}
+
+/// List the key packages tracked by the key package manager, oldest first
+pub fn list_key_packages(identity: String) -> Result<Vec<ManagedKeyPackage>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    instance
+        .store
+        .key_packages()?
+        .into_iter()
+        .map(ManagedKeyPackage::try_from)
+        .collect()
+}
+
+/// Create a key package tracked by the key package manager, waiting to be published
+pub(crate) fn create_managed_key_package(
+    instance: &MlsInstance,
+    public_key: &PublicKey,
+    relays: Vec<RelayUrl>,
+    client: &str,
+    last_resort: bool,
+) -> Result<ManagedKeyPackage, MlsBridgeError> {
+    let (encoded_key_package, tags) = instance
+        .nostr_mls
+        .create_key_package_for_event(public_key, relays, client)?;
+    let tags: Vec<Vec<String>> = tags.into_iter().map(|tag| tag.to_vec()).collect();
+
+    let key_package = StoredKeyPackage {
+        id: hex::encode(Sha256::digest(encoded_key_package.as_bytes())),
+        encoded_key_package,
+        tags_json: serde_json::to_string(&tags).map_err(MlsBridgeError::internal)?,
+        last_resort,
+        state: STATE_UNPUBLISHED.to_string(),
+        event_id: None,
+        created_at: Timestamp::now().as_u64(),
+        published_at: None,
+        consumed_at: None,
+    };
+    instance.store.save_key_package(&key_package)?;
+
+    ManagedKeyPackage::try_from(key_package)
+}
+
+/// Forget the retired key packages whose kind-443 event is deleted
+/// Retired ones are kept until `mark_key_package_deleted`, so their event ids stay reported.
+/// Returns: the number of key packages forgotten
+pub(crate) fn forget_retired_key_packages(instance: &MlsInstance) -> Result<u32, MlsBridgeError> {
+    let deleted = instance.store.delete_key_packages_in_state(STATE_DELETED)?;
+    Ok(deleted as u32)
+}
+
+/// Signing keys of the managed key packages a welcome can still use
+pub(crate) fn key_package_signature_keys(
+    instance: &MlsInstance,
+) -> Result<Vec<Vec<u8>>, MlsBridgeError> {
+    instance
+        .store
+        .key_packages()?
+        .into_iter()
+        .filter(|key_package| {
+            key_package.state != STATE_RETIRED && key_package.state != STATE_DELETED
+        })
+        .map(|key_package| {
+            let parsed = instance
+                .nostr_mls
+                .parse_serialized_key_package(&key_package.encoded_key_package)?;
+            Ok(parsed.leaf_node().signature_key().as_slice().to_vec())
+        })
+        .collect()
+}
+
+/// Find the managed key package a welcome was encrypted to
+pub(crate) fn key_package_for_welcome(
+    instance: &MlsInstance,
+    wrapper_event_id: &EventId,
+    rumor_event: &UnsignedEvent,
+) -> Result<Option<StoredKeyPackage>, MlsBridgeError> {
+    let mut candidates: Vec<StoredKeyPackage> = instance
+        .store
+        .key_packages()?
+        .into_iter()
+        .filter(|key_package| {
+            key_package.state != STATE_RETIRED && key_package.state != STATE_DELETED
+        })
+        .collect();
+    if candidates.is_empty() {
+        return Ok(None);
+    }
+
+    let encoded: Vec<String> = candidates
+        .iter()
+        .map(|key_package| key_package.encoded_key_package.clone())
+        .collect();
+    let (matched_index, _) = instance
+        .nostr_mls
+        .find_encoded_keypackage_from_welcome_event(&encoded, wrapper_event_id, rumor_event)?;
+
+    Ok(matched_index
+        .filter(|index| *index < candidates.len())
+        .map(|index| candidates.swap_remove(index)))
+}
+
+/// Mark a key package used by a joined welcome
+/// One-time key packages lose their private material right away, last resort ones when the next
+/// `maintain_key_packages` rotates them.
+pub(crate) fn consume_key_package(
+    instance: &MlsInstance,
+    mut key_package: StoredKeyPackage,
+) -> Result<(), MlsBridgeError> {
+    if key_package.consumed_at.is_none() {
+        key_package.consumed_at = Some(Timestamp::now().as_u64());
+    }
+
+    if key_package.last_resort {
+        key_package.state = STATE_CONSUMED.to_string();
+        instance.store.save_key_package(&key_package)
+    } else {
+        retire(instance, &mut key_package)
+    }
+}
+
+/// Retire a managed key package no welcome used, e.g. the one of a cancelled group recovery
+/// Returns: whether it was retired
+pub(crate) fn retire_unused_key_package(
+    instance: &MlsInstance,
+    id: &str,
+) -> Result<bool, MlsBridgeError> {
+    let Some(mut key_package) = instance.store.key_package(id)? else {
+        return Ok(false);
+    };
+    if key_package.state != STATE_UNPUBLISHED && key_package.state != STATE_PUBLISHED {
+        return Ok(false);
+    }
+    retire(instance, &mut key_package)?;
+
+    Ok(true)
+}
+
+/// Mark an unused last resort key package a declined welcome was sent to as declined, so the
+/// next `maintain_key_packages` rotates it; its private material stays for other welcomes until
+/// then
+pub(crate) fn rotate_declined_key_package(
+    instance: &MlsInstance,
+    mut key_package: StoredKeyPackage,
+) -> Result<(), MlsBridgeError> {
+    if key_package.state != STATE_UNPUBLISHED && key_package.state != STATE_PUBLISHED {
+        return Ok(());
+    }
+    key_package.state = STATE_DECLINED.to_string();
+    instance.store.save_key_package(&key_package)
+}
+
+/// Delete the private material of a key package and mark it retired
+/// Its signing key is deleted too, unless a group joined with it still signs with it.
+/// A key package never published has no event to delete and is marked deleted right away.
+fn retire(
+    instance: &MlsInstance,
+    key_package: &mut StoredKeyPackage,
+) -> Result<(), MlsBridgeError> {
+    let parsed = instance
+        .nostr_mls
+        .parse_serialized_key_package(&key_package.encoded_key_package)?;
+    instance
+        .nostr_mls
+        .delete_key_package_from_storage(&parsed)?;
+
+    key_package.state = match key_package.event_id {
+        Some(_) => STATE_RETIRED.to_string(),
+        None => STATE_DELETED.to_string(),
+    };
+    // Saved first, so the key package no longer counts among those using the signing key
+    instance.store.save_key_package(key_package)?;
+
+    delete_unused_signature_key(
+        instance,
+        None,
+        parsed.leaf_node().signature_key().as_slice(),
+        parsed.ciphersuite().signature_algorithm(),
+    )
+}
+
+impl TryFrom<StoredKeyPackage> for ManagedKeyPackage {
+    type Error = MlsBridgeError;
+
+    fn try_from(key_package: StoredKeyPackage) -> Result<Self, Self::Error> {
+        let state = match key_package.state.as_str() {
+            STATE_UNPUBLISHED => KeyPackageState::Unpublished,
+            STATE_PUBLISHED => KeyPackageState::Published,
+            STATE_CONSUMED => KeyPackageState::Consumed,
+            STATE_DECLINED => KeyPackageState::Declined,
+            STATE_RETIRED => KeyPackageState::Retired,
+            STATE_DELETED => KeyPackageState::Deleted,
+            state => {
+                return Err(MlsBridgeError::internal(format!(
+                    "unknown key package state {state}"
+                )))
+            }
+        };
+
+        Ok(Self {
+            id: key_package.id,
+            tags: serde_json::from_str(&key_package.tags_json).map_err(MlsBridgeError::internal)?,
+            encoded_key_package: key_package.encoded_key_package,
+            last_resort: key_package.last_resort,
+            state,
+            event_id: key_package.event_id,
+            created_at: key_package.created_at,
+            published_at: key_package.published_at,
+            consumed_at: key_package.consumed_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::mls_api::{create_group, get_key_package_from_storage};
+    use crate::test_utils::{join_test_welcome, TestDir, TestIdentity, TEST_RELAY};
+    use openmls_basic_credential::SignatureKeyPair;
+
+    fn policy(last_resort_count: u32, one_time_count: u32, max_age_secs: u64) -> KeyPackagePolicy {
+        KeyPackagePolicy {
+            last_resort_count,
+            one_time_count,
+            max_age_secs,
+        }
+    }
+
+    fn maintain(identity: &TestIdentity, policy: KeyPackagePolicy) -> KeyPackageRotation {
+        maintain_key_packages(
+            identity.identity.clone(),
+            identity.identity.clone(),
+            None,
+            None,
+            policy,
+        )
+        .unwrap()
+    }
+
+    /// Publish every key package waiting for it, with a made up event id
+    fn publish_all(identity: &TestIdentity, rotation: &KeyPackageRotation) {
+        for key_package in &rotation.to_publish {
+            let event_id = EventId::from_byte_array(rand::random()).to_hex();
+            assert!(mark_key_package_published(
+                identity.identity.clone(),
+                key_package.id.clone(),
+                event_id
+            )
+            .unwrap());
+        }
+    }
+
+    fn has_private_material(identity: &TestIdentity, key_package: &ManagedKeyPackage) -> bool {
+        get_key_package_from_storage(
+            identity.identity.clone(),
+            key_package.encoded_key_package.clone(),
+        )
+        .unwrap()
+        .found
+    }
+
+    fn has_signing_key(identity: &TestIdentity, key_package: &ManagedKeyPackage) -> bool {
+        let instance = identity.instance();
+        let parsed = instance
+            .nostr_mls
+            .parse_serialized_key_package(&key_package.encoded_key_package)
+            .unwrap();
+        SignatureKeyPair::read(
+            instance.nostr_mls.provider.storage(),
+            parsed.leaf_node().signature_key().as_slice(),
+            parsed.ciphersuite().signature_algorithm(),
+        )
+        .is_some()
+    }
+
+    #[test]
+    fn refills_the_pool_to_the_policy() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+
+        let rotation = maintain(&alice, policy(1, 2, 3_600));
+        assert_eq!(rotation.to_publish.len(), 3);
+        assert_eq!(
+            rotation
+                .to_publish
+                .iter()
+                .filter(|key_package| key_package.last_resort)
+                .count(),
+            1
+        );
+        assert!(rotation.to_delete.is_empty());
+
+        // Unpublished key packages count towards the pool and are listed again
+        assert_eq!(maintain(&alice, policy(1, 2, 3_600)).to_publish.len(), 3);
+
+        publish_all(&alice, &rotation);
+        let rotation = maintain(&alice, policy(1, 2, 3_600));
+        assert!(rotation.to_publish.is_empty());
+        assert!(list_key_packages(alice.identity.clone())
+            .unwrap()
+            .iter()
+            .all(|key_package| key_package.state == KeyPackageState::Published));
+    }
+
+    #[test]
+    fn retires_expired_key_packages_until_their_events_are_deleted() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let published = maintain(&alice, policy(1, 1, 3_600));
+        publish_all(&alice, &published);
+
+        // Every key package is expired at an age limit of 0
+        let rotation = maintain(&alice, policy(1, 1, 0));
+
+        let mut retired: Vec<(String, Option<String>)> = rotation
+            .to_delete
+            .iter()
+            .map(|key_package| (key_package.id.clone(), key_package.event_id.clone()))
+            .collect();
+        let mut expected: Vec<(String, Option<String>)> = list_key_packages(alice.identity.clone())
+            .unwrap()
+            .into_iter()
+            .filter(|key_package| published.to_publish.iter().any(|p| p.id == key_package.id))
+            .map(|key_package| (key_package.id, key_package.event_id))
+            .collect();
+        retired.sort();
+        expected.sort();
+        assert_eq!(retired, expected);
+        assert!(retired.iter().all(|(_, event_id)| event_id.is_some()));
+        assert!(rotation
+            .to_delete
+            .iter()
+            .all(|key_package| key_package.state == KeyPackageState::Retired
+                && !has_private_material(&alice, key_package)
+                && !has_signing_key(&alice, key_package)));
+        // Replacements were created
+        assert_eq!(rotation.to_publish.len(), 2);
+
+        // Listed again until their deletion is confirmed
+        let again = maintain(&alice, policy(1, 1, 3_600));
+        assert_eq!(again.to_delete.len(), 2);
+        for key_package in &again.to_delete {
+            assert!(
+                mark_key_package_deleted(alice.identity.clone(), key_package.id.clone()).unwrap()
+            );
+        }
+        assert!(maintain(&alice, policy(1, 1, 3_600)).to_delete.is_empty());
+    }
+
+    #[test]
+    fn never_published_key_packages_are_deleted_right_away() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        maintain(&alice, policy(0, 1, 3_600));
+
+        let rotation = maintain(&alice, policy(0, 1, 0));
+
+        assert!(rotation.to_delete.is_empty());
+        assert_eq!(
+            list_key_packages(alice.identity.clone())
+                .unwrap()
+                .iter()
+                .filter(|key_package| key_package.state == KeyPackageState::Deleted)
+                .count(),
+            1
+        );
+    }
+
+    /// Have `admin` add `member` to a new group with the member's only unused managed key
+    /// package
+    fn consume_key_package_of(member: &TestIdentity, admin: &TestIdentity, last_resort: bool) {
+        let rotation = if last_resort {
+            maintain(member, policy(1, 0, 3_600))
+        } else {
+            maintain(member, policy(0, 1, 3_600))
+        };
+        publish_all(member, &rotation);
+        let key_package = &rotation.to_publish[0];
+
+        let created = create_group(
+            admin.identity.clone(),
+            "key packages".to_string(),
+            String::new(),
+            vec![key_package.encoded_key_package.clone()],
+            vec![member.identity.clone()],
+            admin.identity.clone(),
+            vec![admin.identity.clone()],
+            vec![TEST_RELAY.to_string()],
+        )
+        .unwrap();
+        join_test_welcome(member, admin, &created.serialized_welcome_message);
+    }
+
+    #[test]
+    fn joining_retires_a_one_time_key_package() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+
+        consume_key_package_of(&bob, &alice, false);
+
+        let key_packages = list_key_packages(bob.identity.clone()).unwrap();
+        assert_eq!(key_packages.len(), 1);
+        assert_eq!(key_packages[0].state, KeyPackageState::Retired);
+        assert!(key_packages[0].consumed_at.is_some());
+        assert!(!has_private_material(&bob, &key_packages[0]));
+        // Our leaf in the joined group still signs with it
+        assert!(has_signing_key(&bob, &key_packages[0]));
+
+        let rotation = maintain(&bob, policy(0, 1, 3_600));
+        assert_eq!(rotation.to_delete.len(), 1);
+        assert_eq!(rotation.to_delete[0].id, key_packages[0].id);
+        assert_eq!(rotation.to_publish.len(), 1);
+    }
+
+    #[test]
+    fn a_consumed_last_resort_key_package_is_rotated_by_the_next_maintenance() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+
+        consume_key_package_of(&bob, &alice, true);
+
+        let consumed = list_key_packages(bob.identity.clone()).unwrap();
+        assert_eq!(consumed.len(), 1);
+        assert_eq!(consumed[0].state, KeyPackageState::Consumed);
+        assert!(has_private_material(&bob, &consumed[0]));
+
+        let rotation = maintain(&bob, policy(1, 0, 3_600));
+        assert_eq!(rotation.to_delete.len(), 1);
+        assert_eq!(rotation.to_delete[0].id, consumed[0].id);
+        assert!(!has_private_material(&bob, &rotation.to_delete[0]));
+        assert_eq!(rotation.to_publish.len(), 1);
+        assert!(rotation.to_publish[0].last_resort);
+    }
+}
@@ -0,0 +1,292 @@
+use nostr_mls::prelude::*;
+
+use crate::api::error::MlsBridgeError;
+use crate::api::mls_api::group_status;
+use crate::api::types::{ExportedSecret, GroupStatus};
+use crate::registry::{self, MlsInstance};
+use crate::store::StoredKeyringPolicy;
+
+/// How long the exporter secrets of past epochs are kept
+/// A past secret is purged once either limit is reached; `None` disables the age limit.
+/// The current epoch's secret is always kept. nostr-mls's own copy of a past secret is purged
+/// with the keyring's, so a purged epoch cannot be decrypted from this device anymore.
+#[derive(Debug, Clone)]
+pub struct KeyringPolicy {
+    /// Past epochs kept before the current one, 0 keeping only the current epoch
+    pub retained_epochs: u32,
+    /// Seconds since the secret of an epoch was first seen
+    pub max_age_secs: Option<u64>,
+}
+
+impl Default for KeyringPolicy {
+    fn default() -> Self {
+        Self {
+            retained_epochs: 5,
+            max_age_secs: Some(30 * 24 * 60 * 60),
+        }
+    }
+}
+
+/// Set the keyring policy of an identity and purge the secrets its active groups no longer retain
+pub fn set_keyring_policy(identity: String, policy: KeyringPolicy) -> Result<(), MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    if policy.max_age_secs == Some(0) {
+        return Err(MlsBridgeError::invalid_input(
+            "max_age_secs",
+            "must be at least 1",
+        ));
+    }
+
+    instance.store.save_keyring_policy(&StoredKeyringPolicy {
+        retained_epochs: policy.retained_epochs,
+        max_age_secs: policy.max_age_secs,
+    })?;
+
+    for group in instance.nostr_mls.get_groups()? {
+        if group_status(&instance.nostr_mls, &group) != GroupStatus::Active {
+            continue;
+        }
+        record_exporter_secret(&instance, &group.mls_group_id)?;
+    }
+
+    Ok(())
+}
+
+/// Get the keyring policy of an identity, 5 epochs up to 30 days by default
+pub fn get_keyring_policy(identity: String) -> Result<KeyringPolicy, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    keyring_policy(&instance)
+}
+
+/// Export the group secret of the current or a retained past epoch
+/// Parameters: group_id - byte array of group ID, epoch - epoch of the secret
+/// Returns: secret key and epoch, `StaleEpoch` if the epoch is not retained
+pub fn export_secret_for_epoch(
+    identity: String,
+    group_id: Vec<u8>,
+    epoch: u64,
+) -> Result<ExportedSecret, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    exporter_secret_for_epoch(&instance, &GroupId::from_slice(&group_id), epoch)
+}
+
+/// List the epochs of a group whose secret is retained, current epoch included
+/// Parameters: group_id - byte array of group ID
+/// Returns: epochs in ascending order
+pub fn list_retained_epochs(
+    identity: String,
+    group_id: Vec<u8>,
+) -> Result<Vec<u64>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let group_id = GroupId::from_slice(&group_id);
+    let current = current_exporter_secret(&instance, &group_id)?;
+
+    let mut epochs = instance
+        .store
+        .exporter_secret_epochs(&hex::encode(group_id.as_slice()))?;
+    if !epochs.contains(&current.epoch) {
+        epochs.push(current.epoch);
+        epochs.sort_unstable();
+    }
+    Ok(epochs)
+}
+
+pub(crate) fn keyring_policy(instance: &MlsInstance) -> Result<KeyringPolicy, MlsBridgeError> {
+    Ok(match instance.store.keyring_policy()? {
+        Some(policy) => KeyringPolicy {
+            retained_epochs: policy.retained_epochs,
+            max_age_secs: policy.max_age_secs,
+        },
+        None => KeyringPolicy::default(),
+    })
+}
+
+/// Get the secret of the group's current epoch
+/// Read only as far as the keyring goes, `record_exporter_secret` keeps it.
+pub(crate) fn current_exporter_secret(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+) -> Result<ExportedSecret, MlsBridgeError> {
+    let secret = instance.nostr_mls.exporter_secret(group_id)?;
+    Ok(ExportedSecret {
+        secret: secret.secret.to_vec(),
+        epoch: secret.epoch,
+    })
+}
+
+/// Keep the secret of the group's current epoch in the keyring and purge the secrets the
+/// policy no longer retains
+/// Called where the epoch changes: group creation, joins and merges.
+pub(crate) fn record_exporter_secret(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+) -> Result<ExportedSecret, MlsBridgeError> {
+    let secret = current_exporter_secret(instance, group_id)?;
+    let hex_group_id = hex::encode(group_id.as_slice());
+
+    let now = Timestamp::now().as_u64();
+    instance
+        .store
+        .save_exporter_secret(&hex_group_id, secret.epoch, &secret.secret, now)?;
+
+    let policy = keyring_policy(instance)?;
+    instance.store.delete_exporter_secrets(
+        group_id.as_slice(),
+        secret.epoch,
+        secret
+            .epoch
+            .saturating_sub(u64::from(policy.retained_epochs)),
+        policy.max_age_secs.map(|max| now.saturating_sub(max)),
+    )?;
+
+    Ok(secret)
+}
+
+/// Get the secret of the current or a retained past epoch of a group
+pub(crate) fn exporter_secret_for_epoch(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+    epoch: u64,
+) -> Result<ExportedSecret, MlsBridgeError> {
+    let current = current_exporter_secret(instance, group_id)?;
+    if current.epoch == epoch {
+        return Ok(current);
+    }
+
+    match instance
+        .store
+        .exporter_secret(&hex::encode(group_id.as_slice()), epoch)?
+    {
+        Some(secret) => Ok(ExportedSecret { secret, epoch }),
+        None => Err(MlsBridgeError::StaleEpoch {
+            message: format!(
+                "secret of epoch {} is not retained, group at epoch {}",
+                epoch, current.epoch
+            ),
+        }),
+    }
+}
+
+/// Find the retained past epoch whose secret opens the outer layer of an event, if any
+/// Read only: the keyring is not touched, so an event anyone can post changes nothing.
+pub(crate) fn past_epoch_of_event(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+    event: &Event,
+) -> Result<Option<u64>, MlsBridgeError> {
+    let current_epoch = instance.nostr_mls.exporter_secret(group_id)?.epoch;
+    let hex_group_id = hex::encode(group_id.as_slice());
+
+    for epoch in instance.store.exporter_secret_epochs(&hex_group_id)? {
+        if epoch >= current_epoch {
+            continue;
+        }
+        let Some(secret) = instance.store.exporter_secret(&hex_group_id, epoch)? else {
+            continue;
+        };
+        if opens_outer_layer(&secret, event) {
+            return Ok(Some(epoch));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Whether the secret of the group's current epoch opens the outer layer of an event
+pub(crate) fn opens_at_current_epoch(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+    event: &Event,
+) -> Result<bool, MlsBridgeError> {
+    let current = current_exporter_secret(instance, group_id)?;
+    Ok(opens_outer_layer(&current.secret, event))
+}
+
+fn opens_outer_layer(secret: &[u8], event: &Event) -> bool {
+    let Ok(secret_key) = SecretKey::from_slice(secret) else {
+        return false;
+    };
+    let keys = Keys::new(secret_key);
+    nip44::decrypt_to_bytes(keys.secret_key(), &keys.public_key(), &event.content).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::commits::{confirm_commit_published, self_update};
+    use crate::api::mls_api::export_secret;
+    use crate::api::storage::db_path;
+    use crate::test_utils::{create_test_group, TestDir, TestIdentity};
+    use nostr_mls_sqlite_storage::NostrMlsSqliteStorage;
+    use nostr_mls_storage::groups::GroupStorage;
+
+    #[tokio::test]
+    async fn past_secrets_are_exported_until_the_policy_drops_them() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+
+        set_keyring_policy(
+            alice.identity.clone(),
+            KeyringPolicy {
+                retained_epochs: 2,
+                max_age_secs: None,
+            },
+        )
+        .expect("set keyring policy");
+
+        let mut secrets =
+            vec![export_secret(alice.identity.clone(), group_id.clone()).expect("export secret")];
+        for _ in 0..3 {
+            let commit = self_update(alice.identity.clone(), group_id.clone())
+                .await
+                .expect("self-update");
+            confirm_commit_published(alice.identity.clone(), group_id.clone(), commit.event_id)
+                .expect("merge self-update");
+            secrets.push(
+                export_secret(alice.identity.clone(), group_id.clone()).expect("export secret"),
+            );
+        }
+
+        // The current epoch and the 2 before it are retained
+        for secret in &secrets[1..] {
+            let exported =
+                export_secret_for_epoch(alice.identity.clone(), group_id.clone(), secret.epoch)
+                    .expect("retained secret");
+            assert_eq!(exported.epoch, secret.epoch);
+            assert_eq!(exported.secret, secret.secret);
+        }
+        assert!(matches!(
+            export_secret_for_epoch(alice.identity.clone(), group_id.clone(), secrets[0].epoch),
+            Err(MlsBridgeError::StaleEpoch { .. })
+        ));
+        let retained = secrets[1..]
+            .iter()
+            .map(|secret| secret.epoch)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            list_retained_epochs(alice.identity.clone(), group_id.clone()).expect("list epochs"),
+            retained
+        );
+
+        // nostr-mls's own copy of the dropped secret is gone too, those retained are kept
+        let storage =
+            NostrMlsSqliteStorage::new_with_password(db_path(&dir.path(), &alice.identity), None)
+                .expect("open nostr-mls storage");
+        let mls_group_id = GroupId::from_slice(&group_id);
+        let stored = |epoch| {
+            storage
+                .get_group_exporter_secret(&mls_group_id, epoch)
+                .expect("read nostr-mls secret")
+                .is_some()
+        };
+        assert!(!stored(secrets[0].epoch));
+        assert!(retained.into_iter().all(stored));
+    }
+}
@@ -0,0 +1,673 @@
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use flutter_rust_bridge::DartFnFuture;
+use hkdf::Hkdf;
+use nostr_mls::prelude::*;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+use crate::api::error::MlsBridgeError;
+use crate::api::keyring::{current_exporter_secret, exporter_secret_for_epoch};
+use crate::api::types::ExportedSecret;
+use crate::registry::{self, MlsInstance};
+
+/// Encryption scheme of media attachments, the `v` field of their imeta tags
+const MEDIA_VERSION: &str = "mip04-v1";
+/// Largest attachment accepted for encryption or decryption
+const MAX_MEDIA_SIZE: usize = 100 * 1024 * 1024;
+const NONCE_LEN: usize = 12;
+
+/// What a receiver needs to fetch, decrypt and check an attachment, as carried by its imeta tag
+#[derive(Debug, Clone)]
+pub struct MediaReference {
+    /// Where the encrypted blob was uploaded, `None` before `upload_media`
+    pub url: Option<String>,
+    pub mime_type: String,
+    pub filename: String,
+    /// Hex encoded sha256 of the plaintext, binding the file key to the content
+    pub original_hash: String,
+    /// Hex encoded ChaCha20-Poly1305 nonce
+    pub nonce: String,
+    /// Epoch of the exporter secret the file key was derived from
+    pub epoch: u64,
+    /// Size of the plaintext in bytes
+    pub size: u64,
+}
+
+/// Result of `encrypt_media`
+#[derive(Debug, Clone)]
+pub struct EncryptedMedia {
+    pub encrypted_data: Vec<u8>,
+    /// Hex encoded sha256 of `encrypted_data`, the blob address on Blossom-style servers
+    pub encrypted_hash: String,
+    pub reference: MediaReference,
+}
+
+/// Result of `upload_media`
+#[derive(Debug, Clone)]
+pub struct UploadedMedia {
+    /// imeta tag to add to the message rumor
+    pub imeta_tag: Vec<String>,
+    pub reference: MediaReference,
+}
+
+/// Encrypt an attachment for a group with a key derived from the group's exporter secret
+/// Parameters: group_id - byte array of group ID, data - file content, mime_type - e.g. image/png
+/// Returns: the encrypted blob to upload and the reference for its imeta tag
+pub fn encrypt_media(
+    identity: String,
+    group_id: Vec<u8>,
+    data: Vec<u8>,
+    mime_type: String,
+    filename: String,
+) -> Result<EncryptedMedia, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    encrypt(
+        &instance,
+        &GroupId::from_slice(&group_id),
+        &data,
+        mime_type,
+        filename,
+    )
+}
+
+/// Decrypt an attachment of a group message and check it against its imeta tag
+/// Attachments of past epochs decrypt as long as the keyring retains the epoch's secret.
+/// Parameters: group_id - byte array of group ID, imeta_tag - the tag from the rumor,
+/// encrypted_data - the downloaded blob
+/// Returns: the file content
+pub fn decrypt_media(
+    identity: String,
+    group_id: Vec<u8>,
+    imeta_tag: Vec<String>,
+    encrypted_data: Vec<u8>,
+) -> Result<Vec<u8>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let reference = parse_imeta_tag(imeta_tag)?;
+
+    decrypt(
+        &instance,
+        &GroupId::from_slice(&group_id),
+        &reference,
+        &encrypted_data,
+    )
+}
+
+/// Encrypt an attachment and upload it with the app's Blossom-style client
+/// Parameters: group_id - byte array of group ID, upload - takes the encrypted blob and its hex
+/// sha256 and returns the URL it is served at, or `null` on failure
+/// Returns: the imeta tag to add to the message rumor
+pub async fn upload_media(
+    identity: String,
+    group_id: Vec<u8>,
+    data: Vec<u8>,
+    mime_type: String,
+    filename: String,
+    upload: impl Fn(Vec<u8>, String) -> DartFnFuture<Option<String>> + Send + Sync + 'static,
+) -> Result<UploadedMedia, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let encrypted = encrypt(
+        &instance,
+        &GroupId::from_slice(&group_id),
+        &data,
+        mime_type,
+        filename,
+    )?;
+
+    let url = upload(encrypted.encrypted_data, encrypted.encrypted_hash)
+        .await
+        .ok_or_else(|| MlsBridgeError::Transfer {
+            message: "upload failed".to_string(),
+        })?;
+    let url = Url::parse(&url).map_err(|e| MlsBridgeError::Transfer {
+        message: format!("upload returned an invalid url: {}", e),
+    })?;
+
+    let mut reference = encrypted.reference;
+    reference.url = Some(url.to_string());
+
+    Ok(UploadedMedia {
+        imeta_tag: imeta_tag(&reference),
+        reference,
+    })
+}
+
+/// Download an attachment with the app's client, then decrypt and check it
+/// Parameters: group_id - byte array of group ID, imeta_tag - the tag from the rumor, download -
+/// takes the URL and returns the blob, or `null` on failure
+/// Returns: the file content
+pub async fn download_media(
+    identity: String,
+    group_id: Vec<u8>,
+    imeta_tag: Vec<String>,
+    download: impl Fn(String) -> DartFnFuture<Option<Vec<u8>>> + Send + Sync + 'static,
+) -> Result<Vec<u8>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let reference = parse_imeta_tag(imeta_tag)?;
+    let url = reference
+        .url
+        .clone()
+        .ok_or_else(|| MlsBridgeError::invalid_input("imeta_tag", "missing url"))?;
+
+    let encrypted_data = download(url)
+        .await
+        .ok_or_else(|| MlsBridgeError::Transfer {
+            message: "download failed".to_string(),
+        })?;
+
+    decrypt(
+        &instance,
+        &GroupId::from_slice(&group_id),
+        &reference,
+        &encrypted_data,
+    )
+}
+
+/// Build the imeta tag of an uploaded attachment
+/// Parameters: url - where the encrypted blob is served
+pub fn build_media_imeta_tag(
+    reference: MediaReference,
+    url: String,
+) -> Result<Vec<String>, MlsBridgeError> {
+    let url = Url::parse(&url).map_err(|e| MlsBridgeError::invalid_input("url", e))?;
+
+    Ok(imeta_tag(&MediaReference {
+        url: Some(url.to_string()),
+        ..reference
+    }))
+}
+
+/// Parse and validate the imeta tag of an attachment
+pub fn parse_media_imeta_tag(imeta_tag: Vec<String>) -> Result<MediaReference, MlsBridgeError> {
+    parse_imeta_tag(imeta_tag)
+}
+
+fn encrypt(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+    data: &[u8],
+    mime_type: String,
+    filename: String,
+) -> Result<EncryptedMedia, MlsBridgeError> {
+    if data.len() > MAX_MEDIA_SIZE {
+        return Err(MlsBridgeError::invalid_input(
+            "data",
+            format!("larger than {} bytes", MAX_MEDIA_SIZE),
+        ));
+    }
+    let mime_type = validate_mime_type(&mime_type)?;
+    validate_filename(&filename)?;
+
+    let secret = current_exporter_secret(instance, group_id)?;
+    seal_media(&secret, data, mime_type, filename)
+}
+
+/// Encrypt an attachment under an exporter secret, the mime type being already normalized
+fn seal_media(
+    secret: &ExportedSecret,
+    data: &[u8],
+    mime_type: String,
+    filename: String,
+) -> Result<EncryptedMedia, MlsBridgeError> {
+    let original_hash: [u8; 32] = Sha256::digest(data).into();
+
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    let context = media_context(&original_hash, &mime_type, &filename);
+    let cipher = ChaCha20Poly1305::new(&file_key(&secret.secret, &context)?);
+    let encrypted_data = cipher
+        .encrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: data,
+                aad: &context,
+            },
+        )
+        .map_err(MlsBridgeError::internal)?;
+
+    Ok(EncryptedMedia {
+        encrypted_hash: hex::encode(Sha256::digest(&encrypted_data)),
+        encrypted_data,
+        reference: MediaReference {
+            url: None,
+            mime_type,
+            filename,
+            original_hash: hex::encode(original_hash),
+            nonce: hex::encode(nonce),
+            epoch: secret.epoch,
+            size: data.len() as u64,
+        },
+    })
+}
+
+fn decrypt(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+    reference: &MediaReference,
+    encrypted_data: &[u8],
+) -> Result<Vec<u8>, MlsBridgeError> {
+    if encrypted_data.len() > MAX_MEDIA_SIZE + 16 {
+        return Err(MlsBridgeError::invalid_input(
+            "encrypted_data",
+            format!("larger than {} bytes", MAX_MEDIA_SIZE),
+        ));
+    }
+
+    let secret = exporter_secret_for_epoch(instance, group_id, reference.epoch)?;
+    open_media(&secret.secret, reference, encrypted_data)
+}
+
+/// Decrypt an attachment with the exporter secret of its epoch and check it against its reference
+fn open_media(
+    exporter_secret: &[u8],
+    reference: &MediaReference,
+    encrypted_data: &[u8],
+) -> Result<Vec<u8>, MlsBridgeError> {
+    let original_hash = decode_hex::<32>("x", &reference.original_hash)?;
+    let nonce = decode_hex::<NONCE_LEN>("n", &reference.nonce)?;
+
+    let context = media_context(&original_hash, &reference.mime_type, &reference.filename);
+    let cipher = ChaCha20Poly1305::new(&file_key(exporter_secret, &context)?);
+    let data = cipher
+        .decrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: encrypted_data,
+                aad: &context,
+            },
+        )
+        .map_err(|_| MlsBridgeError::DecryptionFailed {
+            message: "attachment authentication failed".to_string(),
+        })?;
+
+    if Sha256::digest(&data).as_slice() != original_hash {
+        return Err(MlsBridgeError::invalid_input(
+            "imeta_tag",
+            "attachment does not match its hash",
+        ));
+    }
+    if data.len() as u64 != reference.size {
+        return Err(MlsBridgeError::invalid_input(
+            "imeta_tag",
+            "attachment does not match its size",
+        ));
+    }
+
+    Ok(data)
+}
+
+/// Bytes binding a file key and its ciphertext to the attachment's metadata
+fn media_context(original_hash: &[u8], mime_type: &str, filename: &str) -> Vec<u8> {
+    let mut context = Vec::new();
+    context.extend_from_slice(MEDIA_VERSION.as_bytes());
+    context.push(0);
+    context.extend_from_slice(original_hash);
+    context.push(0);
+    context.extend_from_slice(mime_type.as_bytes());
+    context.push(0);
+    context.extend_from_slice(filename.as_bytes());
+    context
+}
+
+/// Derive the key of one file from an exporter secret
+fn file_key(exporter_secret: &[u8], context: &[u8]) -> Result<Key, MlsBridgeError> {
+    let mut key = Key::default();
+    Hkdf::<Sha256>::new(None, exporter_secret)
+        .expand(context, &mut key)
+        .map_err(MlsBridgeError::internal)?;
+    Ok(key)
+}
+
+fn imeta_tag(reference: &MediaReference) -> Vec<String> {
+    let mut tag = vec!["imeta".to_string()];
+    tag.extend(reference.url.as_ref().map(|url| format!("url {url}")));
+    tag.extend([
+        format!("m {}", reference.mime_type),
+        format!("filename {}", reference.filename),
+        format!("x {}", reference.original_hash),
+        format!("n {}", reference.nonce),
+        format!("size {}", reference.size),
+        format!("epoch {}", reference.epoch),
+        format!("v {MEDIA_VERSION}"),
+    ]);
+    tag
+}
+
+fn parse_imeta_tag(imeta_tag: Vec<String>) -> Result<MediaReference, MlsBridgeError> {
+    let invalid = |message: &str| MlsBridgeError::invalid_input("imeta_tag", message);
+
+    let mut entries = imeta_tag.iter();
+    if entries.next().map(String::as_str) != Some("imeta") {
+        return Err(invalid("not an imeta tag"));
+    }
+
+    let mut url = None;
+    let mut mime_type = None;
+    let mut filename = None;
+    let mut original_hash = None;
+    let mut nonce = None;
+    let mut size = None;
+    let mut epoch = None;
+    let mut version = None;
+    for entry in entries {
+        let Some((key, value)) = entry.split_once(' ') else {
+            continue;
+        };
+        let value = value.to_string();
+        match key {
+            "url" => url = Some(value),
+            "m" => mime_type = Some(value),
+            "filename" => filename = Some(value),
+            "x" => original_hash = Some(value),
+            "n" => nonce = Some(value),
+            "size" => size = Some(value.parse::<u64>().map_err(|_| invalid("invalid size"))?),
+            "epoch" => epoch = Some(value.parse::<u64>().map_err(|_| invalid("invalid epoch"))?),
+            "v" => version = Some(value),
+            // Other NIP-92 fields such as dim or blurhash are not needed to decrypt
+            _ => {}
+        }
+    }
+
+    if version.as_deref() != Some(MEDIA_VERSION) {
+        return Err(invalid("unsupported encryption version"));
+    }
+    if let Some(url) = &url {
+        Url::parse(url).map_err(|e| MlsBridgeError::invalid_input("imeta_tag", e))?;
+    }
+    let mime_type = validate_mime_type(&mime_type.ok_or_else(|| invalid("missing m"))?)?;
+    let filename = filename.ok_or_else(|| invalid("missing filename"))?;
+    validate_filename(&filename)?;
+    let original_hash = original_hash.ok_or_else(|| invalid("missing x"))?;
+    decode_hex::<32>("x", &original_hash)?;
+    let nonce = nonce.ok_or_else(|| invalid("missing n"))?;
+    decode_hex::<NONCE_LEN>("n", &nonce)?;
+    let size = size.ok_or_else(|| invalid("missing size"))?;
+    if size > MAX_MEDIA_SIZE as u64 {
+        return Err(invalid("attachment too large"));
+    }
+
+    Ok(MediaReference {
+        url,
+        mime_type,
+        filename,
+        original_hash: original_hash.to_lowercase(),
+        nonce: nonce.to_lowercase(),
+        epoch: epoch.ok_or_else(|| invalid("missing epoch"))?,
+        size,
+    })
+}
+
+/// Normalize a MIME type to the lowercase form the file key is bound to
+fn validate_mime_type(mime_type: &str) -> Result<String, MlsBridgeError> {
+    let mime_type = mime_type.trim().to_lowercase();
+    let valid = mime_type
+        .split_once('/')
+        .is_some_and(|(kind, subtype)| !kind.is_empty() && !subtype.is_empty())
+        && !mime_type
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control());
+    if !valid {
+        return Err(MlsBridgeError::invalid_input(
+            "mime_type",
+            "expected type/subtype",
+        ));
+    }
+    Ok(mime_type)
+}
+
+fn validate_filename(filename: &str) -> Result<(), MlsBridgeError> {
+    if filename.is_empty() || filename.chars().any(char::is_control) {
+        return Err(MlsBridgeError::invalid_input(
+            "filename",
+            "must be non-empty without control characters",
+        ));
+    }
+    Ok(())
+}
+
+fn decode_hex<const N: usize>(field: &str, value: &str) -> Result<[u8; N], MlsBridgeError> {
+    hex::decode(value)
+        .ok()
+        .and_then(|bytes| <[u8; N]>::try_from(bytes).ok())
+        .ok_or_else(|| {
+            MlsBridgeError::invalid_input("imeta_tag", format!("{field} is not {N} hex bytes"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::commits::{confirm_commit_published, self_update};
+    use crate::api::keyring::{set_keyring_policy, KeyringPolicy};
+    use crate::test_utils::{create_test_group, receive_test_commit, TestDir, TestIdentity};
+
+    fn secret(epoch: u64) -> ExportedSecret {
+        ExportedSecret {
+            secret: vec![epoch as u8; 32],
+            epoch,
+        }
+    }
+
+    fn sealed(data: &[u8]) -> EncryptedMedia {
+        seal_media(
+            &secret(3),
+            data,
+            "image/png".to_string(),
+            "cat.png".to_string(),
+        )
+        .unwrap()
+    }
+
+    fn uploaded_tag(reference: &MediaReference) -> Vec<String> {
+        build_media_imeta_tag(reference.clone(), "https://blossom.example/abc".to_string()).unwrap()
+    }
+
+    #[test]
+    fn attachment_round_trips_through_its_imeta_tag() {
+        let encrypted = sealed(b"attachment");
+        assert_eq!(
+            encrypted.encrypted_hash,
+            hex::encode(Sha256::digest(&encrypted.encrypted_data))
+        );
+
+        let reference = parse_imeta_tag(uploaded_tag(&encrypted.reference)).unwrap();
+        assert_eq!(
+            reference.url.as_deref(),
+            Some("https://blossom.example/abc")
+        );
+        assert_eq!(reference.epoch, 3);
+        assert_eq!(reference.size, 10);
+
+        let data = open_media(&secret(3).secret, &reference, &encrypted.encrypted_data).unwrap();
+        assert_eq!(data, b"attachment");
+    }
+
+    #[test]
+    fn tampered_ciphertext_fails_authentication() {
+        let mut encrypted = sealed(b"attachment");
+        encrypted.encrypted_data[0] ^= 1;
+
+        let result = open_media(
+            &secret(3).secret,
+            &encrypted.reference,
+            &encrypted.encrypted_data,
+        );
+        assert!(matches!(
+            result,
+            Err(MlsBridgeError::DecryptionFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn secret_of_another_epoch_fails_authentication() {
+        let encrypted = sealed(b"attachment");
+
+        let result = open_media(
+            &secret(4).secret,
+            &encrypted.reference,
+            &encrypted.encrypted_data,
+        );
+        assert!(matches!(
+            result,
+            Err(MlsBridgeError::DecryptionFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn metadata_is_bound_to_the_file_key() {
+        let encrypted = sealed(b"attachment");
+
+        let renamed = MediaReference {
+            filename: "dog.png".to_string(),
+            ..encrypted.reference.clone()
+        };
+        let rehashed = MediaReference {
+            original_hash: hex::encode(Sha256::digest(b"other")),
+            ..encrypted.reference.clone()
+        };
+        for reference in [renamed, rehashed] {
+            let result = open_media(&secret(3).secret, &reference, &encrypted.encrypted_data);
+            assert!(matches!(
+                result,
+                Err(MlsBridgeError::DecryptionFailed { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn size_mismatch_is_rejected() {
+        let encrypted = sealed(b"attachment");
+        let reference = MediaReference {
+            size: 11,
+            ..encrypted.reference
+        };
+
+        let result = open_media(&secret(3).secret, &reference, &encrypted.encrypted_data);
+        assert!(matches!(result, Err(MlsBridgeError::InvalidInput { .. })));
+    }
+
+    #[test]
+    fn file_key_is_deterministic_per_context() {
+        let context = media_context(&[7u8; 32], "image/png", "cat.png");
+
+        assert_eq!(
+            file_key(b"secret", &context).unwrap(),
+            file_key(b"secret", &context).unwrap()
+        );
+        assert_ne!(
+            file_key(b"secret", &context).unwrap(),
+            file_key(b"other secret", &context).unwrap()
+        );
+        assert_ne!(
+            file_key(b"secret", &context).unwrap(),
+            file_key(
+                b"secret",
+                &media_context(&[7u8; 32], "image/png", "dog.png")
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn malformed_imeta_tags_are_rejected() {
+        let tag = uploaded_tag(&sealed(b"attachment").reference);
+        let replaced = |prefix: &str, entry: Option<&str>| -> Vec<String> {
+            tag.iter()
+                .filter(|e| !e.starts_with(prefix))
+                .cloned()
+                .chain(entry.map(str::to_string))
+                .collect()
+        };
+
+        let malformed = [
+            replaced("imeta", Some("other")),
+            replaced("v ", Some("v mip04-v0")),
+            replaced("v ", None),
+            replaced("m ", Some("m png")),
+            replaced("filename ", None),
+            replaced("x ", Some("x 00")),
+            replaced("n ", Some("n not-hex")),
+            replaced("size ", Some("size -1")),
+            replaced("size ", Some(&format!("size {}", MAX_MEDIA_SIZE + 1))),
+            replaced("epoch ", None),
+            replaced("url ", Some("url not a url")),
+        ];
+        for malformed_tag in malformed {
+            assert!(
+                matches!(
+                    parse_imeta_tag(malformed_tag.clone()),
+                    Err(MlsBridgeError::InvalidInput { .. })
+                ),
+                "accepted {:?}",
+                malformed_tag
+            );
+        }
+    }
+
+    /// Commit a self-update of `admin` and apply it for `member`
+    async fn advance_epoch(admin: &TestIdentity, member: &TestIdentity, group_id: &[u8]) {
+        let commit = self_update(admin.identity.clone(), group_id.to_vec())
+            .await
+            .expect("self-update");
+        confirm_commit_published(
+            admin.identity.clone(),
+            group_id.to_vec(),
+            commit.event_id.clone(),
+        )
+        .expect("merge self-update");
+        receive_test_commit(member, &commit.event_json).expect("apply self-update");
+    }
+
+    #[tokio::test]
+    async fn attachment_of_a_past_epoch_decrypts_until_the_keyring_drops_it() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+        set_keyring_policy(
+            bob.identity.clone(),
+            KeyringPolicy {
+                retained_epochs: 1,
+                max_age_secs: None,
+            },
+        )
+        .expect("set keyring policy");
+
+        let encrypted = encrypt_media(
+            alice.identity.clone(),
+            group_id.clone(),
+            b"attachment".to_vec(),
+            "image/png".to_string(),
+            "cat.png".to_string(),
+        )
+        .expect("encrypt media");
+        let tag = uploaded_tag(&encrypted.reference);
+
+        advance_epoch(&alice, &bob, &group_id).await;
+        let decrypted = decrypt_media(
+            bob.identity.clone(),
+            group_id.clone(),
+            tag.clone(),
+            encrypted.encrypted_data.clone(),
+        )
+        .expect("decrypt media of the previous epoch");
+        assert_eq!(decrypted, b"attachment");
+
+        advance_epoch(&alice, &bob, &group_id).await;
+        assert!(matches!(
+            decrypt_media(
+                bob.identity.clone(),
+                group_id,
+                tag,
+                encrypted.encrypted_data
+            ),
+            Err(MlsBridgeError::StaleEpoch { .. })
+        ));
+    }
+}
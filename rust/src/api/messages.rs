@@ -0,0 +1,277 @@
+use nostr_mls::prelude::*;
+
+use crate::api::error::MlsBridgeError;
+use crate::api::types::DecryptedMessage;
+use crate::registry::{self, MlsInstance};
+use crate::store::IndexedMessage;
+
+/// Position of a message in the pages of `get_messages`
+#[derive(Debug, Clone)]
+pub struct MessageCursor {
+    pub created_at: u64,
+    /// Hex encoded rumor id
+    pub event_id: String,
+}
+
+/// Get the stored messages of a group, newest first
+/// Parameters: group_id - byte array of group ID, before - cursor of the last message of the
+/// previous page, `None` for the newest page, limit - maximum number of messages
+/// Returns: one page of decrypted messages
+pub fn get_messages(
+    identity: String,
+    group_id: Vec<u8>,
+    before: Option<MessageCursor>,
+    limit: u32,
+) -> Result<Vec<DecryptedMessage>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let group_id = GroupId::from_slice(&group_id);
+    let mls_group_id = hex::encode(group_id.as_slice());
+    ensure_group_indexed(&instance, &group_id)?;
+
+    let before = before
+        .map(|cursor| {
+            EventId::from_hex(&cursor.event_id)
+                .map(|event_id| (cursor.created_at, event_id.to_hex()))
+                .map_err(|e| MlsBridgeError::invalid_input("before", e))
+        })
+        .transpose()?;
+    let event_ids = instance.store.page_messages(
+        &mls_group_id,
+        before
+            .as_ref()
+            .map(|(created_at, event_id)| (*created_at, event_id.as_str())),
+        limit,
+    )?;
+    load_messages(&instance, event_ids)
+}
+
+/// Get a stored message by the id of its rumor, `None` once expired or purged
+/// Parameters: event_id - hex encoded rumor id
+pub fn get_message(
+    identity: String,
+    event_id: String,
+) -> Result<Option<DecryptedMessage>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
+
+    let event_id =
+        EventId::from_hex(&event_id).map_err(|e| MlsBridgeError::invalid_input("event_id", e))?;
+
+    let Some(message) = nostr_mls.get_message(&event_id)? else {
+        return Ok(None);
+    };
+    // nostr-mls keeps the messages the retention policy dropped from the indexes
+    ensure_group_indexed(&instance, &message.mls_group_id)?;
+    if !instance.store.is_message_indexed(&event_id.to_hex())? {
+        return Ok(None);
+    }
+    Ok(Some(DecryptedMessage::from(message)))
+}
+
+/// Full-text search over the stored messages of a group
+/// Parameters: group_id - byte array of group ID, query - space separated terms that must all match
+/// Returns: matching messages, best match first
+pub fn search_messages(
+    identity: String,
+    group_id: Vec<u8>,
+    query: String,
+    limit: u32,
+) -> Result<Vec<DecryptedMessage>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let group_id = GroupId::from_slice(&group_id);
+    let mls_group_id = hex::encode(group_id.as_slice());
+    ensure_group_indexed(&instance, &group_id)?;
+
+    let event_ids = instance
+        .store
+        .search_messages(&mls_group_id, &query, limit)?;
+    load_messages(&instance, event_ids)
+}
+
+/// Index the messages a group stored before the indexes existed, once per group
+/// Messages are indexed when they are stored from then on. Their receive time is not known, so
+/// they count as received now.
+pub(crate) fn ensure_group_indexed(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+) -> Result<(), MlsBridgeError> {
+    let mls_group_id = hex::encode(group_id.as_slice());
+    if instance.store.is_group_indexed(&mls_group_id)? {
+        return Ok(());
+    }
+
+    let received_at = Timestamp::now().as_u64();
+    let messages: Vec<IndexedMessage> = instance
+        .nostr_mls
+        .get_messages(group_id)?
+        .into_iter()
+        .map(|message| IndexedMessage {
+            event_id: message.id.to_hex(),
+            created_at: message.created_at.as_u64(),
+            content: message.content,
+            received_at,
+        })
+        .collect();
+    instance
+        .store
+        .index_group_messages(&mls_group_id, &messages)
+}
+
+/// Load messages by their hex encoded rumor ids, keeping their order
+fn load_messages(
+    instance: &MlsInstance,
+    event_ids: Vec<String>,
+) -> Result<Vec<DecryptedMessage>, MlsBridgeError> {
+    let mut messages = Vec::new();
+    for event_id in event_ids {
+        let event_id = EventId::from_hex(&event_id).map_err(MlsBridgeError::internal)?;
+        if let Some(message) = instance.nostr_mls.get_message(&event_id)? {
+            messages.push(DecryptedMessage::from(message));
+        }
+    }
+    Ok(messages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::mls_api::{create_message_for_group, process_message_for_group};
+    use crate::test_utils::{create_test_group, TestDir, TestIdentity};
+
+    fn rumor(member: &TestIdentity, content: &str, created_at: u64) -> UnsignedEvent {
+        let mut rumor = EventBuilder::new(Kind::Custom(9), content)
+            .custom_created_at(Timestamp::from(created_at))
+            .build(member.keys.public_key());
+        rumor.ensure_id();
+        rumor
+    }
+
+    /// Every page of the messages of a group, `limit` messages at a time
+    fn all_pages(member: &TestIdentity, group_id: &[u8], limit: u32) -> Vec<Vec<DecryptedMessage>> {
+        let mut pages = Vec::new();
+        let mut before = None;
+        loop {
+            let page = get_messages(member.identity.clone(), group_id.to_vec(), before, limit)
+                .expect("get messages");
+            let Some(last) = page.last() else {
+                return pages;
+            };
+            before = Some(MessageCursor {
+                created_at: last.created_at,
+                event_id: last.event_id.clone(),
+            });
+            pages.push(page);
+        }
+    }
+
+    #[test]
+    fn pages_fetches_and_searches_received_messages() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+
+        let mut rumors = vec![rumor(&alice, "an older hello", 100)];
+        for i in 0..4 {
+            rumors.push(rumor(&alice, &format!("tied message {i}"), 200));
+        }
+        for rumor in &rumors {
+            let event =
+                create_message_for_group(alice.identity.clone(), group_id.clone(), rumor.as_json())
+                    .expect("create message");
+            process_message_for_group(bob.identity.clone(), event.event_json)
+                .expect("process message");
+        }
+
+        let pages = all_pages(&bob, &group_id, 2);
+        assert_eq!(
+            pages.iter().map(|page| page.len()).collect::<Vec<_>>(),
+            vec![2, 2, 1]
+        );
+        let paged: Vec<(u64, String)> = pages
+            .into_iter()
+            .flatten()
+            .map(|message| (message.created_at, message.event_id))
+            .collect();
+        let mut expected: Vec<(u64, String)> = rumors
+            .iter()
+            .map(|rumor| (rumor.created_at.as_u64(), rumor.id.unwrap().to_hex()))
+            .collect();
+        expected.sort();
+        expected.reverse();
+        assert_eq!(paged, expected);
+
+        let oldest = &paged[4].1;
+        let message = get_message(bob.identity.clone(), oldest.clone())
+            .expect("get message")
+            .expect("message is stored");
+        assert_eq!(message.content, "an older hello");
+        assert!(get_message(
+            bob.identity.clone(),
+            EventId::from_byte_array(rand::random()).to_hex()
+        )
+        .expect("get unknown message")
+        .is_none());
+
+        let found = search_messages(
+            bob.identity.clone(),
+            group_id.clone(),
+            "hello".to_string(),
+            10,
+        )
+        .expect("search messages");
+        assert_eq!(found.len(), 1);
+        assert_eq!(&found[0].event_id, oldest);
+        let found = search_messages(bob.identity.clone(), group_id, "tied".to_string(), 10)
+            .expect("search messages");
+        assert_eq!(found.len(), 4);
+    }
+
+    #[test]
+    fn indexes_messages_stored_before_the_indexes_existed() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = GroupId::from_slice(&group.mls_group_id);
+        let instance = alice.instance();
+
+        // Stored by nostr-mls alone, as before the bridge indexed messages
+        for (content, created_at) in [("stored early", 100), ("stored later", 200)] {
+            instance
+                .nostr_mls
+                .create_message(&group_id, rumor(&alice, content, created_at))
+                .expect("create message");
+        }
+        assert!(!instance
+            .store
+            .is_group_indexed(&hex::encode(group_id.as_slice()))
+            .unwrap());
+
+        let pages = all_pages(&alice, &group.mls_group_id, 10);
+        assert_eq!(pages.len(), 1);
+        assert_eq!(
+            pages[0]
+                .iter()
+                .map(|message| message.content.as_str())
+                .collect::<Vec<_>>(),
+            vec!["stored later", "stored early"]
+        );
+        let found = search_messages(
+            alice.identity.clone(),
+            group.mls_group_id.clone(),
+            "early".to_string(),
+            10,
+        )
+        .expect("search messages");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].content, "stored early");
+        assert!(instance
+            .store
+            .is_group_indexed(&hex::encode(group_id.as_slice()))
+            .unwrap());
+    }
+}
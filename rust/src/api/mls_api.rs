@@ -1,132 +1,132 @@
-use anyhow::{anyhow, Result};
-use lazy_static::lazy_static;
 use nostr_mls::prelude::*;
 use nostr_mls::NostrMls;
 use nostr_mls_sqlite_storage::NostrMlsSqliteStorage;
-use serde_json::json;
-use std::path::PathBuf;
+use sha2::{Digest, Sha256};
+use std::path::Path;
 use std::str::FromStr;
-use std::sync::Mutex;
 
-lazy_static! {
-    static ref NOSTR_MLS: Mutex<Option<NostrMls<NostrMlsSqliteStorage>>> = Mutex::new(None);
+use crate::api::commits::{
+    attach_pending_commit_event, discard_own_commit, ensure_no_pending_commit,
+    ensure_pending_commit_awaits_event, wins_over,
+};
+use crate::api::error::MlsBridgeError;
+use crate::api::events::{GroupEvent, GroupSnapshot};
+use crate::api::key_packages::{consume_key_package, key_package_for_welcome};
+use crate::api::keyring::{
+    self, current_exporter_secret, past_epoch_of_event, record_exporter_secret,
+};
+use crate::api::policy::{check_commit, check_new_proposals, check_own_action, GroupAction};
+use crate::api::recovery::{count_undecryptable_event, join_recovery_welcome, record_outcome};
+use crate::api::rotation::count_message;
+use crate::api::storage::db_path;
+use crate::api::types::*;
+use crate::api::welcomes::WELCOME_ACCEPTED;
+use crate::mls;
+use crate::registry::{self, MlsInstance};
+use crate::store::{self, BridgeStore, CommitRecord, IndexedMessage, StoredPendingCommit};
+
+/// Buffered events are dropped after failing this many replays
+const MAX_PENDING_MESSAGE_ATTEMPTS: u32 = 16;
+/// Anyone can post an event with a group's `h` tag, so a group buffers at most this many
+pub(crate) const MAX_PENDING_MESSAGES_PER_GROUP: u32 = 256;
+
+/// Initialize the NostrMls instance of an identity
+/// Other identities stay open; re-initializing an identity replaces only its own instance
+/// A database that does not open with the password fails with `StorageLocked`, telling a wrong
+/// password apart from a missing or unneeded one
+/// Parameters: path - directory of the database, identity - key used by every other call
+pub fn init_nostr_mls(
+    path: String,
+    identity: String,
+    password: Option<String>,
+) -> Result<(), MlsBridgeError> {
+    open_identity(&db_path(&path, &identity), identity, password.as_deref())
 }
 
-/// Initialize the NostrMls instance
-/// Returns: JSON {"status": "success"} on success, or error message on failure
-pub fn init_nostr_mls(path: String, identity: Option<String>, password: Option<String>) -> Result<String> {
-    let mut mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-
-    if let Some(old_mls) = mls.take() {
-        drop(old_mls);
-    }
-
-    let db_path =
-        PathBuf::from(path).join(identity.as_deref().unwrap_or("default").to_owned() + "-mls.db");
+/// Open the database of an identity and register its instance
+pub(crate) fn open_identity(
+    db_path: &Path,
+    identity: String,
+    password: Option<&str>,
+) -> Result<(), MlsBridgeError> {
+    store::check_password(db_path, password)?;
 
     let nostr_mls = NostrMls::new(
-        NostrMlsSqliteStorage::new_with_password(db_path, password.as_deref())
-            .map_err(|e| anyhow!("Failed to initialize storage: {}", e))?,
+        NostrMlsSqliteStorage::new_with_password(db_path, password)
+            .map_err(MlsBridgeError::from)?,
     );
+    let store = BridgeStore::open(db_path, password)?;
+
+    registry::insert(identity, MlsInstance::new(nostr_mls, store));
 
-    *mls = Some(nostr_mls);
+    Ok(())
+}
+
+/// Close the NostrMls instance of an identity
+/// Returns: whether an instance was open
+pub fn close_nostr_mls(identity: String) -> Result<bool, MlsBridgeError> {
+    Ok(registry::remove(&identity).is_some())
+}
 
-    Ok(json!({"status": "success"}).to_string())
+/// List the identities with an open NostrMls instance
+pub fn list_nostr_mls_identities() -> Result<Vec<String>, MlsBridgeError> {
+    Ok(registry::identities())
 }
 
 /// Get the current ciphersuite
-/// Returns: JSON formatted ciphersuite information
-pub fn get_ciphersuite() -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+/// Returns: the ciphersuite identifier
+pub fn get_ciphersuite(identity: String) -> Result<u16, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
 
-    let ciphersuite = format!("{:?}", nostr_mls.ciphersuite as u16);
-    Ok(json!({"ciphersuite": ciphersuite}).to_string())
+    Ok(nostr_mls.ciphersuite as u16)
 }
 
 /// Get the list of enabled extensions
-/// Returns: JSON formatted list of extensions
-pub fn get_extensions() -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+/// Returns: list of extension names
+pub fn get_extensions(identity: String) -> Result<Vec<String>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
 
-    let extensions: String = nostr_mls
+    Ok(nostr_mls
         .extensions
         .iter()
         .map(|e| format!("{:?}", e))
-        .collect::<Vec<String>>()
-        .join(",");
-
-    Ok(json!({"extensions": extensions}).to_string())
+        .collect())
 }
 
 /// Create a key package for an event
-/// Returns: JSON formatted key package information, including encoded key package and tags
+/// Returns: encoded key package and the tags of its kind-443 event
 pub fn create_key_package_for_event(
+    identity: String,
     public_key: String,
     relay: Option<Vec<String>>,
     client: Option<String>,
-) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+) -> Result<KeyPackageResult, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
 
-    let public_key =
-        PublicKey::from_str(&public_key).map_err(|e| anyhow!("Invalid public key: {}", e))?;
-
-    let relay = relay
-        .map(|relays| {
-            relays
-                .into_iter()
-                .map(|r| RelayUrl::from_str(&r).map_err(|_| anyhow!("Invalid relay url: {}", r)))
-                .collect::<Result<Vec<RelayUrl>>>()
-        })
-        .unwrap_or(Ok(vec![]))?;
+    let public_key = PublicKey::from_str(&public_key)
+        .map_err(|e| MlsBridgeError::invalid_input("public_key", e))?;
 
-    let (encoded_key_package, tags) = nostr_mls
-        .create_key_package_for_event(&public_key, relay, client.as_deref().unwrap_or(""))
-        .map_err(|e| anyhow!("Failed to create key package: {}", e))?;
+    let relay = parse_relays(relay)?;
 
-    let tags_str: Vec<String> = tags.iter().map(|tag| format!("{:?}", tag)).collect();
+    let (encoded_key_package, tags) = nostr_mls.create_key_package_for_event(
+        &public_key,
+        relay,
+        client.as_deref().unwrap_or(""),
+    )?;
 
-    Ok(json!({
-        "encoded_key_package": encoded_key_package,
-        "tags": tags_str
+    Ok(KeyPackageResult {
+        encoded_key_package,
+        tags: tags.into_iter().map(|tag| tag.to_vec()).collect(),
     })
-    .to_string())
 }
 
-// /// Parse a key package from serialized key package
-// /// Returns: JSON formatted key package information
-// pub fn parse_serialized_key_package(serialized_key_package: String) -> Result<String> {
-//     let mls = NOSTR_MLS.lock().map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-//     let nostr_mls = mls.as_ref().ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
-
-//     let key_package = nostr_mls
-//         .parse_serialized_key_package(&serialized_key_package)
-//         .map_err(|e| anyhow!("Failed to parse key package: {}", e))?;
-
-//     Ok(json!({
-//         "key_package": format!("{:?}", key_package)
-//     }).to_string())
-// }
-
 /// Create a group
-/// Returns: JSON formatted group information
+/// Returns: group information and the serialized welcome message for the invited members
 pub fn create_group(
+    identity: String,
     group_name: String,
     group_description: String,
     group_members_serialized_key_packages: Vec<String>,
@@ -134,613 +134,1253 @@ pub fn create_group(
     group_creator_public_key: String,
     group_admin_public_keys: Vec<String>,
     relays: Vec<String>,
-) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+) -> Result<CreateGroupResult, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
 
-    let member_pubkeys: Result<Vec<PublicKey>> = group_members_pubkeys
+    let member_pubkeys: Result<Vec<PublicKey>, MlsBridgeError> = group_members_pubkeys
         .into_iter()
-        .map(|k| PublicKey::from_str(&k).map_err(|e| anyhow!("Invalid member pubkey: {}", e)))
+        .map(|k| {
+            PublicKey::from_str(&k)
+                .map_err(|e| MlsBridgeError::invalid_input("group_members_pubkeys", e))
+        })
         .collect();
     let member_pubkeys = member_pubkeys?;
 
     let mut member_key_packages = Vec::new();
     for serialized_key_package in &group_members_serialized_key_packages {
         let key_package = nostr_mls
-            .parse_serialized_key_package(&serialized_key_package)
-            .map_err(|e| anyhow!("Failed to parse key package: {}", e))?;
+            .parse_serialized_key_package(serialized_key_package)
+            .map_err(|e| MlsBridgeError::invalid_input("key_package", e))?;
         member_key_packages.push(key_package);
     }
 
-    let group_admin_public_keys: Result<Vec<PublicKey>> = group_admin_public_keys
+    let group_admin_public_keys: Result<Vec<PublicKey>, MlsBridgeError> = group_admin_public_keys
         .into_iter()
-        .map(|k| PublicKey::from_str(&k).map_err(|e| anyhow!("Invalid admin pubkey: {}", e)))
+        .map(|k| {
+            PublicKey::from_str(&k)
+                .map_err(|e| MlsBridgeError::invalid_input("group_admin_public_keys", e))
+        })
         .collect();
     let group_admin_public_keys = group_admin_public_keys?;
 
     let group_creator_public_key = PublicKey::from_str(&group_creator_public_key)
-        .map_err(|e| anyhow!("Invalid creator pubkey: {}", e))?;
+        .map_err(|e| MlsBridgeError::invalid_input("group_creator_public_key", e))?;
 
-    let relays: Result<Vec<RelayUrl>> = relays
+    let relays: Result<Vec<RelayUrl>, MlsBridgeError> = relays
         .into_iter()
-        .map(|r| RelayUrl::from_str(&r).map_err(|e| anyhow!("Invalid relay url: {}", e)))
+        .map(|r| RelayUrl::from_str(&r).map_err(|e| MlsBridgeError::invalid_input("relays", e)))
         .collect();
     let relays = relays?;
 
-    let group_create_result = nostr_mls
-        .create_group(
-            group_name,
-            group_description,
-            &group_creator_public_key,
-            &member_pubkeys,
-            &member_key_packages,
-            group_admin_public_keys,
-            relays,
-        )
-        .map_err(|e| anyhow!("Failed to create group: {}", e))?;
-
-    let mls_group = group_create_result.group;
-    let group_id = mls_group.mls_group_id;
-
-    let members: Vec<String> = match nostr_mls.get_members(&group_id) {
-        Ok(members) => members.iter().map(|pk| pk.to_string()).collect(),
-        Err(e) => return Err(anyhow!("Failed to get members: {}", e)),
-    };
-
-    let serialized_welcome_message = group_create_result.serialized_welcome_message;
-    let nostr_group_id = mls_group.nostr_group_id;
-    let name = mls_group.name;
-    let description = mls_group.description;
-    let admin_pubkeys = mls_group.admin_pubkeys;
-
-    let output = json!({
-        "mls_group_id": group_id,
-        "members": members,
-        "serialized_welcome_message": serialized_welcome_message,
-        "nostr_group_data": {
-            "nostr_group_id": nostr_group_id,
-            "name": name,
-            "description": description,
-            "admin_pubkeys": admin_pubkeys,
-        }
-    });
-
-    Ok(output.to_string())
+    let group_create_result = nostr_mls.create_group(
+        group_name,
+        group_description,
+        &group_creator_public_key,
+        &member_pubkeys,
+        &member_key_packages,
+        group_admin_public_keys,
+        relays,
+    )?;
+
+    let group = group_info(nostr_mls, &group_create_result.group.mls_group_id)?;
+    record_exporter_secret(&instance, &group_create_result.group.mls_group_id)?;
+
+    Ok(CreateGroupResult {
+        group,
+        serialized_welcome_message: group_create_result.serialized_welcome_message,
+    })
 }
 
 /// Create a message for a group
 /// Parameters: group_id - byte array of group ID, rumor_event_string - JSON string of the event
-/// Returns: JSON formatted message information
-pub fn create_message_for_group(group_id: Vec<u8>, rumor_event_string: String) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
-
-    let rumor_event = UnsignedEvent::from_json(rumor_event_string)
-        .map_err(|e| anyhow!("Failed to parse event: {}", e))?;
+/// Returns: the signed kind-445 event
+pub fn create_message_for_group(
+    identity: String,
+    group_id: Vec<u8>,
+    rumor_event_string: String,
+) -> Result<EventResult, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
+
+    let mut rumor_event = UnsignedEvent::from_json(rumor_event_string)
+        .map_err(|e| MlsBridgeError::invalid_input("rumor_event_string", e))?;
+    rumor_event.ensure_id();
+    let indexed = rumor_event.id.map(|event_id| IndexedMessage {
+        event_id: event_id.to_hex(),
+        created_at: rumor_event.created_at.as_u64(),
+        content: rumor_event.content.clone(),
+        received_at: Timestamp::now().as_u64(),
+    });
 
     let group_id = GroupId::from_slice(&group_id);
+    let group_lock = instance.group_lock(&group_id);
+    let _guard = registry::lock(&group_lock);
+
+    let event = nostr_mls.create_message(&group_id, rumor_event)?;
+    if let Some(indexed) = indexed {
+        instance
+            .store
+            .index_message(&hex::encode(group_id.as_slice()), &indexed)?;
+    }
+    count_message(&instance, &group_id)?;
 
-    let event = nostr_mls
-        .create_message(&group_id, rumor_event)
-        .map_err(|e| anyhow!("Failed to create message: {}", e))?;
-
-    let event_json =
-        serde_json::to_value(&event).map_err(|e| anyhow!("Failed to serialize event: {}", e))?;
-
-    Ok(json!({
-        "event": event_json
-    })
-    .to_string())
+    Ok(event_result(&event))
 }
 
 /// Create a commit message for a group
-/// Parameters: group_id - byte array of group ID, serialized_commit - serialized commit
-/// Returns: JSON formatted message information
-pub fn create_commit_message_for_group(
+/// The event is signed with a new random key, or by the identity's signer when its scope covers
+/// commits. It becomes the event of the group's pending commit, which fails if that commit was
+/// rolled back by a competing one meanwhile.
+/// Parameters: nostr_group_id - hex encoded nostr group ID, serialized_commit - serialized commit
+/// from `add_members`, `remove_members`, `leave_group` or `readd_member`
+/// Returns: the signed kind-445 event, to confirm with `confirm_commit_published`
+pub async fn create_commit_message_for_group(
+    identity: String,
     nostr_group_id: String,
     serialized_commit: Vec<u8>,
-    secret_key: &[u8; 32],
-) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+) -> Result<EventResult, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
+
+    let group_id = nostr_mls
+        .get_groups()?
+        .into_iter()
+        .find(|group| hex::encode(group.nostr_group_id) == nostr_group_id)
+        .map(|group| group.mls_group_id)
+        .ok_or(MlsBridgeError::GroupNotFound)?;
+
+    // Wrapped under the group lock, so the event matches the epoch of the pending commit
+    let signer = instance.commit_signer();
+    let event = {
+        let group_lock = instance.group_lock(&group_id);
+        let _guard = registry::lock(&group_lock);
+
+        let group = mls::load_group(nostr_mls, &group_id)?;
+        ensure_pending_commit_awaits_event(&instance, &group)?;
+        let event = ephemeral_group_event(nostr_mls, nostr_group_id, &serialized_commit)?;
+        if signer.is_none() {
+            attach_pending_commit_event(&instance, &group_id, &event)?;
+        }
+        event
+    };
 
-    let event = nostr_mls
-        .create_commit_proposal_message(nostr_group_id, &serialized_commit, secret_key)
-        .map_err(|e| anyhow!("Failed to create message: {}", e))?;
+    let Some(signer) = signer else {
+        return Ok(event_result(&event));
+    };
+    let event = signer.resign(event).await?;
 
-    let event_json =
-        serde_json::to_value(&event).map_err(|e| anyhow!("Failed to serialize event: {}", e))?;
+    let group_lock = instance.group_lock(&group_id);
+    let _guard = registry::lock(&group_lock);
+    attach_pending_commit_event(&instance, &group_id, &event)?;
 
-    Ok(json!({
-        "event": event_json
-    })
-    .to_string())
+    Ok(event_result(&event))
 }
 
 /// Export group secret
+/// Past epochs' secrets are available through `export_secret_for_epoch`
 /// Parameters: group_id - byte array of group ID
-/// Returns: JSON formatted secret information, including secret key and epoch
-pub fn export_secret(group_id: Vec<u8>) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+/// Returns: secret key and epoch
+pub fn export_secret(
+    identity: String,
+    group_id: Vec<u8>,
+) -> Result<ExportedSecret, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
 
     let group_id = GroupId::from_slice(&group_id);
 
-    let export_secret = nostr_mls
-        .exporter_secret(&group_id)
-        .map_err(|e| anyhow!("Failed to export secret: {}", e))?;
+    current_exporter_secret(&instance, &group_id)
+}
 
-    Ok(json!({
-        "secret": export_secret.secret,
-        "epoch": export_secret.epoch
-    })
-    .to_string())
+/// Get the number of buffered messages waiting for a later epoch of a group
+/// Parameters: group_id - byte array of group ID
+pub fn get_pending_message_count(
+    identity: String,
+    group_id: Vec<u8>,
+) -> Result<u32, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let group_id = GroupId::from_slice(&group_id);
+    let group = instance
+        .nostr_mls
+        .get_group(&group_id)?
+        .ok_or(MlsBridgeError::GroupNotFound)?;
+
+    instance
+        .store
+        .count_pending_messages(&hex::encode(group.nostr_group_id))
 }
 
 /// Process a message for a group
-/// Parameters: group_id - byte array of group ID, serialized_message - serialized message
-/// Returns: JSON formatted processing result
-pub fn process_message_for_group(event_string: String) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+/// Messages for an epoch the group has not reached yet are buffered in storage and
+/// replayed once a commit advances the group
+/// Parameters: event_string - JSON string of the kind-445 event
+/// Returns: the decrypted message and/or staged commit information
+pub fn process_message_for_group(
+    identity: String,
+    event_string: String,
+) -> Result<ProcessedMessage, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
 
     let event: Event = serde_json::from_str(&event_string)
-        .map_err(|e| anyhow!("Failed to deserialize event: {}", e))?;
-
-    let result = nostr_mls.process_message(&event).map_err(|e| anyhow!("Failed to process message: {}", e))?;
+        .map_err(|e| MlsBridgeError::invalid_input("event_string", e))?;
 
-    // Handle message
-    let message_json = match result.message {
-        Some(message) => {
-            serde_json::to_value(&message)
-                .map_err(|e| anyhow!("Failed to serialize message: {}", e))?
+    let group_id = instance.group_id_for_event(&event);
+    let result = match (process_event(&instance, &event), &group_id) {
+        (Err(e), Some(group_id)) if e.may_be_later_epoch() => {
+            buffer_event(&instance, group_id, &event)
         }
-        None => serde_json::Value::Null,
-    };
-
-    // Handle member_changes
-    let (added_members_json, removed_members_json) = match result.member_changes {
-        Some(member_changes) => {
-            let added_members: Vec<String> = member_changes.added_members;
-            let removed_members: Vec<String> = member_changes.removed_members;
-            (
-                serde_json::to_value(added_members)
-                    .map_err(|e| anyhow!("Failed to serialize added_members: {}", e))?,
-                serde_json::to_value(removed_members)
-                    .map_err(|e| anyhow!("Failed to serialize removed_members: {}", e))?,
-            )
-        }
-        None => (serde_json::Value::Null, serde_json::Value::Null),
+        (result, _) => result,
     };
+    if let Some(group_id) = &group_id {
+        record_outcome(&instance, group_id, &result);
+    }
 
-    Ok(json!({
-        "message": message_json,
-        "added_members": added_members_json,
-        "removed_members": removed_members_json,
-        "commit": result.commit,
-        "welcome": result.welcome,
-        "staged_message_bytes": result.message_bytes
-    }).to_string())
+    result
 }
 
 /// Process a commit message for a specific group
+/// Commits of the same epoch that pass the group policy are ordered by their kind-445 events as
+/// seen by `process_message_for_group`, earliest `created_at` then lowest event id. A losing
+/// commit is reported as `superseded`; a winning one replaces our own pending commit, which is
+/// rolled back. A commit arriving after we merged another one of its epoch cannot be applied
+/// anymore: it fails with `StaleEpoch` and is counted in `GroupHealth`. If the other members
+/// applied it, `compare_group_state` reports `Diverged` and `start_group_recovery` gets us back.
+/// Buffered messages the new epoch can decrypt are returned in `released_messages`
 /// Parameters: group_id - byte array of group ID, message_bytes - serialized message bytes
-/// Returns: JSON formatted processing result
-pub fn process_commit_message_for_group(group_id: Vec<u8>, message_bytes: Vec<u8>) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+/// Returns: the processing result
+pub fn process_commit_message_for_group(
+    identity: String,
+    group_id: Vec<u8>,
+    message_bytes: Vec<u8>,
+) -> Result<ProcessedMessage, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
 
     let group_id = GroupId::from_slice(&group_id);
 
-    let result = nostr_mls
-        .process_commit_message_for_group(&group_id, &message_bytes)
-        .map_err(|e| anyhow!("Failed to process commit message: {}", e))?;
+    let result = process_commit(&instance, &group_id, &message_bytes);
+    record_outcome(&instance, &group_id, &result);
 
-    // Handle message
-    let message_json = match result.message {
-        Some(message) => {
-            serde_json::to_value(&message)
-                .map_err(|e| anyhow!("Failed to serialize message: {}", e))?
-        }
-        None => serde_json::Value::Null,
+    result
+}
+
+pub(crate) fn process_event(
+    instance: &MlsInstance,
+    event: &Event,
+) -> Result<ProcessedMessage, MlsBridgeError> {
+    let group_id = instance.group_id_for_event(event);
+    let group_lock = group_id
+        .as_ref()
+        .map(|group_id| instance.group_lock(group_id));
+    let _guard = group_lock.as_deref().map(registry::lock);
+
+    // nostr-mls queues incoming proposals; the ones it adds are checked against the policy
+    let known_proposals: Vec<ProposalRef> = group_id
+        .as_ref()
+        .and_then(|group_id| mls::load_group(&instance.nostr_mls, group_id).ok())
+        .map(|group| {
+            group
+                .pending_proposals()
+                .map(|queued| queued.proposal_reference())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let result = instance
+        .nostr_mls
+        .process_message(event)
+        .map_err(|e| message_error(instance, group_id.as_ref(), event, e))?;
+    if let Some(group_id) = &group_id {
+        check_new_proposals(instance, group_id, &known_proposals)?;
+    }
+
+    // Keep the event of a commit so it can be ordered once `process_commit` validated it
+    if let (Some(group_id), Some(_), Some(message_bytes)) =
+        (&group_id, &result.commit, &result.message_bytes)
+    {
+        let group = mls::load_group(&instance.nostr_mls, group_id)?;
+        instance.store.save_commit_event(
+            &hex::encode(Sha256::digest(message_bytes)),
+            &hex::encode(group_id.as_slice()),
+            &CommitRecord {
+                epoch: group.epoch().as_u64(),
+                event_id: event.id.to_hex(),
+                created_at: event.created_at.as_u64(),
+            },
+        )?;
+    }
+
+    let (added_members, removed_members) = match result.member_changes {
+        Some(member_changes) => (member_changes.added_members, member_changes.removed_members),
+        None => (vec![], vec![]),
     };
 
-    // Handle member_changes
-    let (added_members_json, removed_members_json) = match result.member_changes {
-        Some(member_changes) => {
-            let added_members: Vec<String> = member_changes.added_members;
-            let removed_members: Vec<String> = member_changes.removed_members;
-            (
-                serde_json::to_value(added_members)
-                    .map_err(|e| anyhow!("Failed to serialize added_members: {}", e))?,
-                serde_json::to_value(removed_members)
-                    .map_err(|e| anyhow!("Failed to serialize removed_members: {}", e))?,
-            )
+    let processed = ProcessedMessage {
+        message: result.message.map(DecryptedMessage::from),
+        added_members,
+        removed_members,
+        commit: result.commit,
+        welcome: result.welcome,
+        staged_message_bytes: result.message_bytes,
+        ..Default::default()
+    };
+
+    if let Some(message) = &processed.message {
+        instance.store.index_message(
+            &hex::encode(&message.mls_group_id),
+            &IndexedMessage {
+                event_id: message.event_id.clone(),
+                created_at: message.created_at,
+                content: message.content.clone(),
+                received_at: Timestamp::now().as_u64(),
+            },
+        )?;
+        count_message(instance, &GroupId::from_slice(&message.mls_group_id))?;
+        instance.emit(GroupEvent::MessageDecrypted {
+            message: message.clone(),
+        });
+    }
+
+    Ok(processed)
+}
+
+pub(crate) fn process_commit(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+    message_bytes: &[u8],
+) -> Result<ProcessedMessage, MlsBridgeError> {
+    let snapshot = GroupSnapshot::take(instance, group_id);
+
+    let (mut processed, removed) = {
+        let group_lock = instance.group_lock(group_id);
+        let _guard = registry::lock(&group_lock);
+
+        // Staged through openmls directly so the policy is checked before anything is merged
+        let nostr_mls = &instance.nostr_mls;
+        let mls_group_id = hex::encode(group_id.as_slice());
+        let message_hash = hex::encode(Sha256::digest(message_bytes));
+        let mut group = mls::load_group(nostr_mls, group_id)?;
+        let (sender, staged_commit) = match mls::stage_commit(nostr_mls, &mut group, message_bytes)
+        {
+            Ok(staged) => staged,
+            Err(e) => {
+                instance.store.delete_commit(&message_hash)?;
+                return Err(e);
+            }
+        };
+        // A rejected commit is forgotten, so it never takes part in the ordering of its epoch:
+        // its event's created_at is chosen by its sender and could otherwise supersede every
+        // valid commit
+        if let Err(e) = check_commit(instance, &group, &sender, &staged_commit) {
+            instance.store.delete_commit(&message_hash)?;
+            return Err(e);
+        }
+
+        // Competing commits of an epoch are ordered by their events: a commit only applies if
+        // no earlier valid one is known, ours included
+        let epoch = group.epoch().as_u64();
+        let incoming = instance.store.commit_event(&message_hash)?;
+        if let Some(incoming) = &incoming {
+            instance
+                .store
+                .save_commit_candidate(&message_hash, &mls_group_id, incoming)?;
+            let winner = instance
+                .store
+                .winning_commit_candidate(&mls_group_id, epoch)?;
+            if winner.is_some_and(|winner| wins_over(&winner, incoming)) {
+                return Ok(ProcessedMessage {
+                    superseded: true,
+                    ..Default::default()
+                });
+            }
         }
-        None => (serde_json::Value::Null, serde_json::Value::Null),
+        let own_commit = match group.pending_commit() {
+            Some(_) => Some(instance.store.pending_commit(&mls_group_id)?),
+            None => None,
+        };
+        let own_event = own_commit.iter().flatten().find_map(|own| {
+            Some(CommitRecord {
+                epoch: own.epoch,
+                event_id: own.event_id.clone()?,
+                created_at: own.created_at?,
+            })
+        });
+        if let (Some(own), Some(incoming)) = (&own_event, &incoming) {
+            if own.epoch == epoch && wins_over(own, incoming) {
+                return Ok(ProcessedMessage {
+                    superseded: true,
+                    ..Default::default()
+                });
+            }
+        }
+
+        let added_members = staged_commit
+            .add_proposals()
+            .filter_map(|add| {
+                mls::credential_pubkey(add.add_proposal().key_package().leaf_node().credential())
+            })
+            .map(|pk| pk.to_string())
+            .collect();
+        let removed_members = staged_commit
+            .remove_proposals()
+            .filter_map(|remove| group.member(remove.remove_proposal().removed()))
+            .filter_map(mls::credential_pubkey)
+            .map(|pk| pk.to_string())
+            .collect();
+
+        // Our pending commit lost, or its event is unknown and cannot be ordered
+        let own_commit_rolled_back = own_commit.is_some();
+        if let Some(own) = own_commit {
+            discard_own_commit(instance, &mut group)?;
+            instance.emit(GroupEvent::PendingCommitRolledBack {
+                mls_group_id: group_id.to_vec(),
+                event_id: own.and_then(|own| own.event_id),
+            });
+        }
+
+        if let Err(e) = group.merge_staged_commit(&nostr_mls.provider, staged_commit) {
+            instance.store.delete_commit(&message_hash)?;
+            return Err(MlsBridgeError::mls(e));
+        }
+        // Record the exporter secret of the new epoch, as nostr-mls does after its own merges;
+        // a group we were removed from has none
+        if group.is_active() {
+            record_exporter_secret(instance, group_id)?;
+        }
+        mls::sync_group_row(nostr_mls, &group)?;
+        instance
+            .store
+            .delete_commit_candidates_before(&mls_group_id, group.epoch().as_u64())?;
+        // A leave proposal of ours does not survive the epoch change
+        instance.store.delete_pending_commit(&mls_group_id)?;
+
+        let processed = ProcessedMessage {
+            added_members,
+            removed_members,
+            own_commit_rolled_back,
+            ..Default::default()
+        };
+        // We were removed, or our leave proposal was committed
+        (processed, !group.is_active())
     };
 
-    Ok(json!({
-        "message": message_json,
-        "added_members": added_members_json,
-        "removed_members": removed_members_json,
-        "commit": result.commit,
-        "welcome": result.welcome,
-        "staged_message_bytes": result.message_bytes
-    }).to_string())
+    if let Some(snapshot) = snapshot {
+        snapshot.emit_changes(instance, group_id);
+    }
+
+    // A group we are no longer in decrypts nothing anymore
+    if removed {
+        return Ok(processed);
+    }
+
+    processed.released_messages = replay_pending_messages(instance, group_id)?;
+
+    Ok(processed)
+}
+
+/// Map a failure of nostr-mls to process a group event
+/// An outer layer that does not open with the current exporter secret is `DecryptionFailed`, as
+/// the event may be of a later epoch.
+fn message_error(
+    instance: &MlsInstance,
+    group_id: Option<&GroupId>,
+    event: &Event,
+    e: nostr_mls::Error,
+) -> MlsBridgeError {
+    let e = MlsBridgeError::from(e);
+    if !matches!(e, MlsBridgeError::Mls { .. }) {
+        return e;
+    }
+    match group_id.map(|group_id| keyring::opens_at_current_epoch(instance, group_id, event)) {
+        Some(Ok(false)) => MlsBridgeError::DecryptionFailed {
+            message: "the event does not open with the exporter secret of the group's epoch"
+                .to_string(),
+        },
+        _ => e,
+    }
+}
+
+/// Map a failure of nostr-mls to process a welcome, `KeyPackageNotFound` if none of our key
+/// packages was its recipient
+pub(crate) fn welcome_error(
+    nostr_mls: &NostrMls<NostrMlsSqliteStorage>,
+    rumor_event: &UnsignedEvent,
+    e: nostr_mls::Error,
+) -> MlsBridgeError {
+    match mls::has_welcome_key_package(nostr_mls, rumor_event) {
+        Ok(false) => MlsBridgeError::KeyPackageNotFound,
+        _ => MlsBridgeError::from(e),
+    }
+}
+
+/// Store an event that cannot be decrypted at the group's current epoch
+/// Events of a past epoch, recognised with the keyring, are dropped with `StaleEpoch`; the
+/// others may be of a later epoch or garbage, and are buffered until the group's buffer is full.
+/// Dropped events are counted in the group's health.
+pub(crate) fn buffer_event(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+    event: &Event,
+) -> Result<ProcessedMessage, MlsBridgeError> {
+    let group = instance
+        .nostr_mls
+        .get_group(group_id)?
+        .ok_or(MlsBridgeError::GroupNotFound)?;
+    let nostr_group_id = hex::encode(group.nostr_group_id);
+    let epoch = instance.nostr_mls.exporter_secret(group_id)?.epoch;
+
+    if let Some(past_epoch) = past_epoch_of_event(instance, group_id, event)? {
+        let e = MlsBridgeError::StaleEpoch {
+            message: format!("event of epoch {}, group at epoch {}", past_epoch, epoch),
+        };
+        count_undecryptable_event(instance, group_id, &e)?;
+        return Err(e);
+    }
+    if instance.store.count_pending_messages(&nostr_group_id)? >= MAX_PENDING_MESSAGES_PER_GROUP {
+        let e = MlsBridgeError::DecryptionFailed {
+            message: "undecryptable at the group's epoch and its buffer is full".to_string(),
+        };
+        count_undecryptable_event(instance, group_id, &e)?;
+        return Err(e);
+    }
+
+    instance.store.save_pending_message(
+        &event.id.to_hex(),
+        &nostr_group_id,
+        epoch,
+        event.created_at.as_u64(),
+        &event.as_json(),
+    )?;
+
+    Ok(ProcessedMessage {
+        pending: true,
+        ..Default::default()
+    })
+}
+
+/// Retry the buffered events of a group received before its current epoch
+/// Returns: the messages decrypted, including those released by buffered commits
+pub(crate) fn replay_pending_messages(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+) -> Result<Vec<DecryptedMessage>, MlsBridgeError> {
+    let group = instance
+        .nostr_mls
+        .get_group(group_id)?
+        .ok_or(MlsBridgeError::GroupNotFound)?;
+    let epoch = instance.nostr_mls.exporter_secret(group_id)?.epoch;
+
+    let mut released = Vec::new();
+    let pending_messages = instance
+        .store
+        .pending_messages_before(&hex::encode(group.nostr_group_id), epoch)?;
+    for pending in pending_messages {
+        let result = Event::from_json(&pending.event_json)
+            .map_err(MlsBridgeError::internal)
+            .and_then(|event| process_event(instance, &event));
+
+        match result {
+            Ok(processed) => {
+                instance.store.delete_pending_message(&pending.event_id)?;
+                released.extend(processed.message);
+
+                // A buffered commit is merged like a received one, which replays the events
+                // still buffered at the epoch it advances to
+                if let (Some(_), Some(message_bytes)) =
+                    (&processed.commit, &processed.staged_message_bytes)
+                {
+                    let committed = process_commit(instance, group_id, message_bytes);
+                    record_outcome(instance, group_id, &committed);
+                    if let Ok(committed) = committed {
+                        if !committed.superseded {
+                            released.extend(committed.released_messages);
+                            break;
+                        }
+                    }
+                }
+            }
+            Err(e) if e.may_be_later_epoch() && pending.attempts < MAX_PENDING_MESSAGE_ATTEMPTS => {
+                instance.store.touch_pending_message(&pending.event_id)?;
+            }
+            Err(e) => {
+                instance.store.delete_pending_message(&pending.event_id)?;
+                count_undecryptable_event(instance, group_id, &e)?;
+            }
+        }
+    }
+
+    Ok(released)
 }
 
 /// Preview a group from a welcome message without joining it
 /// Parameters: wrapper_event_id - byte array of event ID, rumor_event_string - JSON string of the event
-/// Returns: JSON formatted group preview information
+/// Returns: group preview information
 pub fn preview_group_from_welcome(
+    identity: String,
     wrapper_event_id: Vec<u8>,
     rumor_event_string: String,
-) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+) -> Result<GroupInfo, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
 
     let rumor_event = UnsignedEvent::from_json(rumor_event_string)
-        .map_err(|e| anyhow!("Failed to parse event: {}", e))?;
+        .map_err(|e| MlsBridgeError::invalid_input("rumor_event_string", e))?;
 
-    let event_id =
-        EventId::from_slice(&wrapper_event_id).map_err(|e| anyhow!("Invalid event ID: {}", e))?;
+    let event_id = EventId::from_slice(&wrapper_event_id)
+        .map_err(|e| MlsBridgeError::invalid_input("wrapper_event_id", e))?;
 
     let welcome_preview = nostr_mls
         .preview_welcome(&event_id, &rumor_event)
-        .map_err(|e| anyhow!("Failed to process welcome: {}", e))?;
-
-    let nostr_group_id = welcome_preview.nostr_group_data.nostr_group_id;
-    let name = welcome_preview.nostr_group_data.name;
-    let description = welcome_preview.nostr_group_data.description;
-    let admin_pubkeys: Vec<String> = welcome_preview
-        .nostr_group_data
-        .admins
-        .iter()
-        .map(|pk| pk.to_string())
-        .collect();
-    let members: Vec<String> = welcome_preview.staged_welcome.members()
-        .filter_map(|m| {
-            let credentials = BasicCredential::try_from(m.credential).ok()?;
-            let hex_bytes = credentials.identity();
-            let hex_str = std::str::from_utf8(hex_bytes).ok()?;
-            let public_key = PublicKey::from_hex(hex_str).ok()?;
-            Some(public_key.to_string())
-        })
-        .collect();
-    let output = json!({
-        "mls_group_id": welcome_preview.staged_welcome.group_context().group_id(),
-        "members": members,
-        "nostr_group_data": {
-            "nostr_group_id": nostr_group_id,
-            "name": name,
-            "description": description,
-            "admin_pubkeys": admin_pubkeys,
-        }
-    });
+        .map_err(|e| welcome_error(nostr_mls, &rumor_event, e))?;
 
-    Ok(output.to_string())
+    let members: Vec<String> = welcome_preview
+        .staged_welcome
+        .members()
+        .filter_map(|m| mls::credential_pubkey(&m.credential))
+        .map(|public_key| public_key.to_string())
+        .collect();
+    let group_data = welcome_preview.nostr_group_data;
+
+    Ok(GroupInfo {
+        mls_group_id: welcome_preview
+            .staged_welcome
+            .group_context()
+            .group_id()
+            .to_vec(),
+        members,
+        nostr_group_data: NostrGroupData::new(
+            &group_data.nostr_group_id,
+            group_data.name,
+            group_data.description,
+            &group_data.admins,
+            &group_data.relays,
+        ),
+    })
 }
 
 /// Join a group from a welcome message
 /// Parameters: wrapper_event_id - byte array of event ID, rumor_event_string - JSON string of the event
-/// Returns: JSON formatted join result
+/// Returns: information about the joined group
 pub fn join_group_from_welcome(
+    identity: String,
     wrapper_event_id: Vec<u8>,
     rumor_event_string: String,
-) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+) -> Result<GroupInfo, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
 
     let rumor_event = UnsignedEvent::from_json(rumor_event_string)
-        .map_err(|e| anyhow!("Failed to parse event: {}", e))?;
+        .map_err(|e| MlsBridgeError::invalid_input("rumor_event_string", e))?;
 
-    let event_id =
-        EventId::from_slice(&wrapper_event_id).map_err(|e| anyhow!("Invalid event ID: {}", e))?;
+    let event_id = EventId::from_slice(&wrapper_event_id)
+        .map_err(|e| MlsBridgeError::invalid_input("wrapper_event_id", e))?;
 
-    let welcome = nostr_mls
-        .process_welcome(&event_id, &rumor_event)
-        .map_err(|e| anyhow!("Failed to process welcome: {}", e))?;
+    // Key package bookkeeping must never prevent joining
+    let key_package = key_package_for_welcome(&instance, &event_id, &rumor_event)
+        .ok()
+        .flatten();
+
+    let welcome = match join_recovery_welcome(&instance, &event_id, &rumor_event)? {
+        Some(welcome) => welcome,
+        None => nostr_mls
+            .process_welcome(&event_id, &rumor_event)
+            .map_err(|e| welcome_error(nostr_mls, &rumor_event, e))?,
+    };
 
     let mls_group_id = GroupId::from_slice(welcome.mls_group_id.as_slice());
+    instance
+        .store
+        .delete_group_recovery(&hex::encode(mls_group_id.as_slice()))?;
+    record_exporter_secret(&instance, &mls_group_id)?;
 
-    let members: Vec<String> = match nostr_mls.get_members(&mls_group_id) {
-        Ok(members) => members.iter().map(|pk| pk.to_string()).collect(),
-        Err(e) => return Err(anyhow!("Failed to get members: {}", e)),
-    };
+    let group = group_info(nostr_mls, &mls_group_id)?;
 
-    let nostr_group_id = welcome.nostr_group_id;
-    let name = welcome.group_name;
-    let description = welcome.group_description;
-    let admin_pubkeys = welcome.group_admin_pubkeys;
-
-    let output = json!({
-        "mls_group_id": mls_group_id,
-        "members": members,
-        "nostr_group_data": {
-            "nostr_group_id": nostr_group_id,
-            "name": name,
-            "description": description,
-            "admin_pubkeys": admin_pubkeys,
-        }
+    instance
+        .store
+        .set_welcome_state(&event_id.to_hex(), WELCOME_ACCEPTED)?;
+
+    let key_package_id = key_package
+        .as_ref()
+        .map(|key_package| key_package.id.clone());
+    if let Some(key_package) = key_package {
+        consume_key_package(&instance, key_package)?;
+    }
+
+    instance.emit(GroupEvent::WelcomeReceived {
+        wrapper_event_id: event_id.to_hex(),
+        group: group.clone(),
     });
+    if let Some(key_package_id) = key_package_id {
+        instance.emit(GroupEvent::KeyPackageConsumed {
+            wrapper_event_id: event_id.to_hex(),
+            key_package_id,
+        });
+    }
 
-    Ok(output.to_string())
+    Ok(group)
 }
 
-pub fn get_members(group_id: Vec<u8>) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+/// Get the members of a group
+/// Parameters: group_id - byte array of group ID
+/// Returns: hex encoded member public keys
+pub fn get_members(identity: String, group_id: Vec<u8>) -> Result<Vec<String>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
 
     let group_id = GroupId::from_slice(&group_id);
 
-    let members = nostr_mls
-        .get_members(&group_id)
-        .map_err(|e| anyhow!("Failed to get members: {}", e))?;
-
-    let members_str: Vec<String> = members.iter().map(|pk| pk.to_string()).collect();
+    let members = nostr_mls.get_members(&group_id)?;
 
-    Ok(json!({
-        "members": members_str
-    })
-    .to_string())
+    Ok(members.iter().map(|pk| pk.to_string()).collect())
 }
 
 /// Get group information by group ID
 /// Parameters: group_id - byte array of group ID
-/// Returns: JSON formatted group information including group ID, members, and nostr group data
-pub fn get_group(group_id: Vec<u8>) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+/// Returns: group information including group ID, members, and nostr group data
+pub fn get_group(identity: String, group_id: Vec<u8>) -> Result<GroupInfo, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
 
     let group_id = GroupId::from_slice(&group_id);
 
-    // Get the group information
-    let group = nostr_mls
-        .get_group(&group_id)
-        .map_err(|e| anyhow!("Failed to get group: {}", e))?
-        .ok_or_else(|| anyhow!("Group not found"))?;
-
-    // Get the members
-    let members = nostr_mls
-        .get_members(&group_id)
-        .map_err(|e| anyhow!("Failed to get members: {}", e))?;
-
-    let members_str: Vec<String> = members.iter().map(|pk| pk.to_string()).collect();
-
-    let output = json!({
-        "mls_group_id": group_id,
-        "members": members_str,
-        "nostr_group_data": {
-            "nostr_group_id": group.nostr_group_id,
-            "name": group.name,
-            "description": group.description,
-            "admin_pubkeys": group.admin_pubkeys,
-        }
-    });
+    group_info(nostr_mls, &group_id)
+}
 
-    Ok(output.to_string())
+/// List the groups stored locally
+/// Parameters: filter - which group states to include
+/// Returns: group summaries, most recently active first
+pub fn list_groups(
+    identity: String,
+    filter: GroupStateFilter,
+) -> Result<Vec<GroupSummary>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
+
+    let mut summaries: Vec<GroupSummary> = nostr_mls
+        .get_groups()?
+        .into_iter()
+        .filter_map(|group| {
+            let mls_group = mls::load_group(nostr_mls, &group.mls_group_id);
+            // Skip purged groups, of which nostr-mls keeps a record
+            if !matches!(group.state, GroupState::Pending)
+                && matches!(mls_group, Err(MlsBridgeError::GroupNotFound))
+            {
+                return None;
+            }
+            let member_count = nostr_mls
+                .get_members(&group.mls_group_id)
+                .map(|members| members.len() as u32)
+                .unwrap_or_default();
+            let status = group_status(nostr_mls, &group);
+            let mut summary = GroupSummary::new(group, member_count);
+            summary.status = status;
+            if let Ok(mls_group) = mls_group {
+                summary.epoch = mls_group.epoch().as_u64();
+                if let Ok(group_data) = mls::group_data(&mls_group) {
+                    summary.name = group_data.name;
+                    summary.description = group_data.description;
+                }
+            }
+            Some(summary)
+        })
+        .filter(|summary| filter.matches(summary.status))
+        .collect();
+
+    summaries.sort_by(|a, b| b.last_message_at.cmp(&a.last_message_at));
+    Ok(summaries)
+}
+
+/// List the welcomes stored locally that have not been accepted or declined
+/// Returns: welcome summaries
+pub fn list_pending_welcomes(identity: String) -> Result<Vec<WelcomeSummary>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
+
+    Ok(nostr_mls
+        .get_pending_welcomes()?
+        .into_iter()
+        .map(WelcomeSummary::from)
+        .collect())
 }
 
 /// Add members to an existing group
+/// The commit stays pending until `confirm_commit_published` merges it or `abort_commit`
+/// discards it; send the welcome only after it was merged
 /// Parameters: group_id - byte array of group ID, serialized_key_packages - array of serialized key packages
-/// Returns: JSON formatted result containing serialized commit and welcome messages
-pub fn add_members(group_id: Vec<u8>, serialized_key_packages: Vec<String>) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+/// Returns: serialized commit and welcome messages
+pub fn add_members(
+    identity: String,
+    group_id: Vec<u8>,
+    serialized_key_packages: Vec<String>,
+) -> Result<AddMembersResult, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
 
     let group_id = GroupId::from_slice(&group_id);
+    let group_lock = instance.group_lock(&group_id);
+    let _guard = registry::lock(&group_lock);
 
     let mut key_packages = Vec::new();
     for serialized_key_package in &serialized_key_packages {
         let key_package = nostr_mls
-            .parse_serialized_key_package(&serialized_key_package)
-            .map_err(|e| anyhow!("Failed to parse key package: {}", e))?;
+            .parse_serialized_key_package(serialized_key_package)
+            .map_err(|e| MlsBridgeError::invalid_input("key_package", e))?;
         key_packages.push(key_package);
     }
 
-    let result = nostr_mls
-        .add_members(&group_id, &key_packages)
-        .map_err(|e| anyhow!("Failed to add members: {}", e))?;
+    let mut group = mls::load_group(nostr_mls, &group_id)?;
+    ensure_no_pending_commit(&instance, &group)?;
+    check_own_action(&instance, &group, GroupAction::AddMembers)?;
 
-    Ok(json!({
-        "commit_message": result.commit_message,
-        "welcome_message": result.welcome_message
+    let signer = mls::load_signer(nostr_mls, &group)?;
+    let (commit, welcome, _) = group
+        .add_members(&nostr_mls.provider, &signer, &key_packages)
+        .map_err(MlsBridgeError::mls)?;
+    save_staged_commit(&instance, &group, None)?;
+
+    Ok(AddMembersResult {
+        commit_message: mls::serialize_message(&commit)?,
+        welcome_message: mls::serialize_message(&welcome)?,
     })
-    .to_string())
 }
 
 /// Remove members from a group
+/// The commit stays pending until `confirm_commit_published` merges it or `abort_commit`
+/// discards it
 /// Parameters: group_id - byte array of group ID, member_pubkeys - array of member public keys to remove
-/// Returns: JSON formatted result containing serialized commit message
-pub fn remove_members(group_id: Vec<u8>, member_pubkeys: Vec<String>) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+/// Returns: serialized commit message
+pub fn remove_members(
+    identity: String,
+    group_id: Vec<u8>,
+    member_pubkeys: Vec<String>,
+) -> Result<CommitResult, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
 
     let group_id = GroupId::from_slice(&group_id);
+    let group_lock = instance.group_lock(&group_id);
+    let _guard = registry::lock(&group_lock);
+
+    let mut group = mls::load_group(nostr_mls, &group_id)?;
+    ensure_no_pending_commit(&instance, &group)?;
+    check_own_action(&instance, &group, GroupAction::RemoveMembers)?;
+
+    let mut leaf_indices = Vec::new();
+    for member_pubkey in &member_pubkeys {
+        let member_pubkey = PublicKey::from_str(member_pubkey)
+            .map_err(|e| MlsBridgeError::invalid_input("member_pubkeys", e))?;
+        let leaf_index = mls::member_leaf_index(&group, &member_pubkey).ok_or_else(|| {
+            MlsBridgeError::invalid_input("member_pubkeys", "not a member of the group")
+        })?;
+        leaf_indices.push(leaf_index);
+    }
 
-    let result = nostr_mls
-        .remove_members(&group_id, &member_pubkeys)
-        .map_err(|e| anyhow!("Failed to remove members: {}", e))?;
-
-    Ok(json!({
-        "serialized_commit": result.serialized
-    })
-    .to_string())
-}
-
-/// Commit a proposal
-/// Parameters: group_id - byte array of group ID, proposal - serialized proposal
-/// Returns: JSON formatted result containing commit and welcome messages
-pub fn commit_proposal(group_id: Vec<u8>, proposal: String) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
-
-    let group_id = GroupId::from_slice(&group_id);
-
-    // Parse the proposal
-    let proposal: QueuedProposal = serde_json::from_str(&proposal)
-        .map_err(|e| anyhow!("Failed to deserialize proposal: {}", e))?;
-
-    let result = nostr_mls
-        .commit_proposal(&group_id, proposal)
-        .map_err(|e| anyhow!("Failed to commit proposal: {}", e))?;
+    let signer = mls::load_signer(nostr_mls, &group)?;
+    let (commit, _, _) = group
+        .remove_members(&nostr_mls.provider, &signer, &leaf_indices)
+        .map_err(MlsBridgeError::mls)?;
+    save_staged_commit(&instance, &group, None)?;
 
-    Ok(json!({
-        "commit_message": result.commit_message,
-        "welcome_message": result.welcome_message
+    Ok(CommitResult {
+        serialized_commit: mls::serialize_message(&commit)?,
     })
-    .to_string())
 }
 
 /// Leave a group
+/// The proposal to remove ourselves stays pending until `confirm_commit_published` or
+/// `abort_commit`; the group ends once an admin commits it
 /// Parameters: group_id - byte array of group ID
-/// Returns: JSON formatted result containing serialized leave message
-pub fn leave_group(group_id: Vec<u8>) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+/// Returns: serialized leave message
+pub fn leave_group(identity: String, group_id: Vec<u8>) -> Result<CommitResult, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
 
     let group_id = GroupId::from_slice(&group_id);
+    let group_lock = instance.group_lock(&group_id);
+    let _guard = registry::lock(&group_lock);
+
+    let mut group = mls::load_group(nostr_mls, &group_id)?;
+    ensure_no_pending_commit(&instance, &group)?;
+
+    let signer = mls::load_signer(nostr_mls, &group)?;
+    let proposal = group
+        .leave_group(&nostr_mls.provider, &signer)
+        .map_err(MlsBridgeError::mls)?;
+
+    let own_index = group.own_leaf_index();
+    let proposal_ref = group
+        .pending_proposals()
+        .find(|queued| {
+            let own_sender = matches!(queued.sender(), Sender::Member(index) if *index == own_index);
+            let removes_us =
+                matches!(queued.proposal(), Proposal::Remove(remove) if remove.removed() == own_index);
+            own_sender && removes_us
+        })
+        .map(|queued| hex::encode(queued.proposal_reference().as_slice()))
+        .ok_or_else(|| MlsBridgeError::internal("leave proposal not stored"))?;
+    save_staged_commit(&instance, &group, Some(proposal_ref))?;
 
-    let result = nostr_mls
-        .leave_group(&group_id)
-        .map_err(|e| anyhow!("Failed to leave group: {}", e))?;
-
-    Ok(json!({
-        "serialized_leave": result.serialized
+    Ok(CommitResult {
+        serialized_commit: mls::serialize_message(&proposal)?,
     })
-    .to_string())
 }
 
 /// Get key package from storage
 /// Parameters: serialized_key_package - serialized key package string
-/// Returns: JSON formatted key package information
-pub fn get_key_package_from_storage(serialized_key_package: String) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+/// Returns: whether the key package was found, with its debug representation
+pub fn get_key_package_from_storage(
+    identity: String,
+    serialized_key_package: String,
+) -> Result<KeyPackageLookup, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
 
     // First parse the serialized key package
     let key_package = nostr_mls
         .parse_serialized_key_package(&serialized_key_package)
-        .map_err(|e| anyhow!("Failed to parse key package: {}", e))?;
+        .map_err(|e| MlsBridgeError::invalid_input("key_package", e))?;
 
     // Then try to get it from storage
-    let key_package_bundle = nostr_mls
-        .get_key_package_from_storage(&key_package)
-        .map_err(|e| anyhow!("Failed to get key package from storage: {}", e))?;
-
-    // Return the result
-    let result = match key_package_bundle {
-        Some(bundle) => json!({
-            "found": true,
-            "key_package": format!("{:?}", bundle.key_package()),
-        }),
-        None => json!({
-            "found": false,
-            "key_package": null,
-        })
-    };
+    let key_package_bundle = nostr_mls.get_key_package_from_storage(&key_package)?;
 
-    Ok(result.to_string())
+    Ok(KeyPackageLookup {
+        found: key_package_bundle.is_some(),
+        key_package: key_package_bundle.map(|bundle| format!("{:?}", bundle.key_package())),
+    })
 }
 
 /// Find encoded key package from welcome event
 /// Parameters: encoded_keypackages - array of encoded key package strings, wrapper_event_id - byte array of event ID, rumor_event_string - JSON string of the event
-/// Returns: JSON formatted result containing the matched key package index and info if found
+/// Returns: the matched key package index if found
 pub fn find_encoded_keypackage_from_welcome_event(
+    identity: String,
     encoded_keypackages: Vec<String>,
     wrapper_event_id: Vec<u8>,
     rumor_event_string: String,
-) -> Result<String> {
-    let mls = NOSTR_MLS
-        .lock()
-        .map_err(|_| anyhow!("Failed to acquire NOSTR_MLS lock"))?;
-    let nostr_mls = mls
-        .as_ref()
-        .ok_or_else(|| anyhow!("NostrMls is not initialized"))?;
+) -> Result<WelcomeKeyPackageMatch, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
 
     let rumor_event = UnsignedEvent::from_json(rumor_event_string)
-        .map_err(|e| anyhow!("Failed to parse event: {}", e))?;
+        .map_err(|e| MlsBridgeError::invalid_input("rumor_event_string", e))?;
 
-    let event_id =
-        EventId::from_slice(&wrapper_event_id).map_err(|e| anyhow!("Invalid event ID: {}", e))?;
+    let event_id = EventId::from_slice(&wrapper_event_id)
+        .map_err(|e| MlsBridgeError::invalid_input("wrapper_event_id", e))?;
 
-    let (matched_index, keypackage_info) = nostr_mls
-        .find_encoded_keypackage_from_welcome_event(&encoded_keypackages, &event_id, &rumor_event)
-        .map_err(|e| anyhow!("Failed to find encoded key package: {}", e))?;
+    let (matched_index, keypackage_info) = nostr_mls.find_encoded_keypackage_from_welcome_event(
+        &encoded_keypackages,
+        &event_id,
+        &rumor_event,
+    )?;
 
     let result = match (matched_index, keypackage_info) {
-        (Some(index), Some(info)) => json!({
-            "found": true,
-            "matched_index": index,
-            "keypackage_info": null
-        }),
-        _ => json!({
-            "found": false,
-            "matched_index": null,
-            "keypackage_info": null,
-        })
+        (Some(index), Some(_)) => WelcomeKeyPackageMatch {
+            found: true,
+            matched_index: Some(index as u32),
+        },
+        _ => WelcomeKeyPackageMatch {
+            found: false,
+            matched_index: None,
+        },
+    };
+
+    Ok(result)
+}
+
+/// Remember a commit or leave proposal we staged, until its event is attached and confirmed
+pub(crate) fn save_staged_commit(
+    instance: &MlsInstance,
+    group: &MlsGroup,
+    proposal_ref: Option<String>,
+) -> Result<(), MlsBridgeError> {
+    instance.store.save_pending_commit(
+        &hex::encode(group.group_id().as_slice()),
+        &StoredPendingCommit {
+            epoch: group.epoch().as_u64(),
+            event_id: None,
+            created_at: None,
+            event_json: None,
+            proposal_ref,
+        },
+    )
+}
+
+pub(crate) fn parse_relays(relay: Option<Vec<String>>) -> Result<Vec<RelayUrl>, MlsBridgeError> {
+    relay
+        .unwrap_or_default()
+        .into_iter()
+        .map(|r| RelayUrl::from_str(&r).map_err(|e| MlsBridgeError::invalid_input("relay", e)))
+        .collect()
+}
+
+/// Wrap a commit or proposal in a kind-445 event signed by a new random key
+/// A commit must be wrapped before it is merged, while the group's exporter secret is still the
+/// one of the commit's epoch.
+pub(crate) fn ephemeral_group_event(
+    nostr_mls: &NostrMls<NostrMlsSqliteStorage>,
+    nostr_group_id: String,
+    serialized_commit: &[u8],
+) -> Result<Event, MlsBridgeError> {
+    let ephemeral_keys = Keys::generate();
+
+    Ok(nostr_mls.create_commit_proposal_message(
+        nostr_group_id,
+        serialized_commit,
+        &ephemeral_keys.secret_key().to_secret_bytes(),
+    )?)
+}
+
+pub(crate) fn event_result(event: &Event) -> EventResult {
+    EventResult {
+        event_id: event.id.to_hex(),
+        event_json: event.as_json(),
+    }
+}
+
+/// State of a group, read from its MLS group when nostr-mls still reports it active
+/// The nostr-mls row is synced after each merge, but a crash in between leaves it behind; the
+/// MLS group is the source of truth.
+pub(crate) fn group_status(
+    nostr_mls: &NostrMls<NostrMlsSqliteStorage>,
+    group: &Group,
+) -> GroupStatus {
+    let status = GroupStatus::from(&group.state);
+    if status != GroupStatus::Active {
+        return status;
+    }
+    match mls::load_group(nostr_mls, &group.mls_group_id) {
+        Ok(mls_group) if !mls_group.is_active() => GroupStatus::Left,
+        _ => status,
+    }
+}
+
+pub(crate) fn group_info(
+    nostr_mls: &NostrMls<NostrMlsSqliteStorage>,
+    group_id: &GroupId,
+) -> Result<GroupInfo, MlsBridgeError> {
+    // The group context is the source of truth, it follows update_group_data commits
+    let group = mls::load_group(nostr_mls, group_id)?;
+    let group_data = mls::group_data(&group)?;
+
+    let members = nostr_mls.get_members(group_id)?;
+
+    Ok(GroupInfo {
+        mls_group_id: group_id.to_vec(),
+        members: members.iter().map(|pk| pk.to_string()).collect(),
+        nostr_group_data: NostrGroupData::new(
+            &group_data.nostr_group_id,
+            group_data.name,
+            group_data.description,
+            &group_data.admins,
+            &group_data.relays,
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::commits::{confirm_commit_published, self_update};
+    use crate::api::retention::{set_retention_policy, RetentionPolicy};
+    use crate::test_utils::{
+        create_test_group, receive_test_commit, test_group_event, TestDir, TestIdentity,
     };
 
-    Ok(result.to_string())
+    #[test]
+    fn buffer_holds_a_bounded_number_of_events() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let instance = alice.instance();
+        let group_id = GroupId::from_slice(&group.mls_group_id);
+        let nostr_group_id = &group.nostr_group_data.nostr_group_id;
+
+        for i in 0..MAX_PENDING_MESSAGES_PER_GROUP {
+            let event = test_group_event(nostr_group_id, "of a later epoch", 1_000 + u64::from(i));
+            let processed = buffer_event(&instance, &group_id, &event).expect("buffer event");
+            assert!(processed.pending);
+        }
+        let overflow = test_group_event(nostr_group_id, "of a later epoch", 5_000);
+
+        assert!(matches!(
+            buffer_event(&instance, &group_id, &overflow),
+            Err(MlsBridgeError::DecryptionFailed { .. })
+        ));
+        assert_eq!(
+            instance
+                .store
+                .count_pending_messages(nostr_group_id)
+                .unwrap(),
+            MAX_PENDING_MESSAGES_PER_GROUP
+        );
+        let dropped = instance
+            .store
+            .undecryptable_events(&hex::encode(group_id.as_slice()))
+            .unwrap();
+        assert_eq!(dropped.map(|(count, _)| count), Some(1));
+    }
+
+    #[test]
+    fn replay_drops_an_event_after_its_last_attempt() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let instance = alice.instance();
+        let group_id = GroupId::from_slice(&group.mls_group_id);
+        let nostr_group_id = &group.nostr_group_data.nostr_group_id;
+        let epoch = instance.nostr_mls.exporter_secret(&group_id).unwrap().epoch;
+        assert!(epoch > 0);
+
+        // Buffered at the previous epoch, it is replayed but never opens
+        let event = test_group_event(nostr_group_id, "never decryptable", 1_000);
+        instance
+            .store
+            .save_pending_message(
+                &event.id.to_hex(),
+                nostr_group_id,
+                epoch - 1,
+                1_000,
+                &event.as_json(),
+            )
+            .unwrap();
+        for _ in 1..MAX_PENDING_MESSAGE_ATTEMPTS {
+            instance
+                .store
+                .touch_pending_message(&event.id.to_hex())
+                .unwrap();
+        }
+
+        assert!(replay_pending_messages(&instance, &group_id)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            instance
+                .store
+                .count_pending_messages(nostr_group_id)
+                .unwrap(),
+            1
+        );
+
+        assert!(replay_pending_messages(&instance, &group_id)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            instance
+                .store
+                .count_pending_messages(nostr_group_id)
+                .unwrap(),
+            0
+        );
+        let dropped = instance
+            .store
+            .undecryptable_events(&hex::encode(group_id.as_slice()))
+            .unwrap();
+        assert_eq!(dropped.map(|(count, _)| count), Some(1));
+    }
+
+    #[tokio::test]
+    async fn buffered_commit_of_a_later_epoch_is_merged_on_replay() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+
+        let mut commits = Vec::new();
+        for _ in 0..2 {
+            let commit = self_update(alice.identity.clone(), group_id.clone())
+                .await
+                .expect("alice self-update");
+            confirm_commit_published(
+                alice.identity.clone(),
+                group_id.clone(),
+                commit.event_id.clone(),
+            )
+            .expect("merge alice's commit");
+            commits.push(commit);
+        }
+        let rumor = EventBuilder::new(Kind::Custom(9), "sent after both commits")
+            .build(alice.keys.public_key());
+        let message =
+            create_message_for_group(alice.identity.clone(), group_id.clone(), rumor.as_json())
+                .expect("create message");
+
+        // The second commit and the message overtake the first commit on their way to bob
+        for event_json in [&commits[1].event_json, &message.event_json] {
+            let processed = process_message_for_group(bob.identity.clone(), event_json.clone())
+                .expect("buffer event");
+            assert!(processed.pending);
+        }
+
+        let processed = receive_test_commit(&bob, &commits[0].event_json).expect("apply commit");
+        assert_eq!(processed.released_messages.len(), 1);
+        assert_eq!(
+            processed.released_messages[0].content,
+            "sent after both commits"
+        );
+
+        let alice_secret = export_secret(alice.identity.clone(), group_id.clone()).unwrap();
+        let bob_secret = export_secret(bob.identity.clone(), group_id.clone()).unwrap();
+        assert_eq!(alice_secret.epoch, bob_secret.epoch);
+        assert_eq!(alice_secret.secret, bob_secret.secret);
+        assert_eq!(
+            get_pending_message_count(bob.identity.clone(), group_id).unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn list_groups_filters_by_status() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let kept = create_test_group(&alice, &[&bob]);
+        let left = create_test_group(&alice, &[&bob]);
+        set_retention_policy(
+            bob.identity.clone(),
+            RetentionPolicy {
+                message_max_age_secs: None,
+                purge_inactive_groups: false,
+            },
+        )
+        .expect("keep left groups");
+
+        let removed = remove_members(
+            alice.identity.clone(),
+            left.mls_group_id.clone(),
+            vec![bob.identity.clone()],
+        )
+        .expect("remove bob");
+        let commit = create_commit_message_for_group(
+            alice.identity.clone(),
+            left.nostr_group_data.nostr_group_id.clone(),
+            removed.serialized_commit,
+        )
+        .await
+        .expect("wrap commit");
+        confirm_commit_published(
+            alice.identity.clone(),
+            left.mls_group_id.clone(),
+            commit.event_id,
+        )
+        .expect("merge commit");
+        receive_test_commit(&bob, &commit.event_json).expect("apply commit");
+
+        let ids = |filter| {
+            list_groups(bob.identity.clone(), filter)
+                .expect("list groups")
+                .into_iter()
+                .map(|summary| summary.mls_group_id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            ids(GroupStateFilter::Active),
+            vec![kept.mls_group_id.clone()]
+        );
+        assert_eq!(ids(GroupStateFilter::Left), vec![left.mls_group_id.clone()]);
+        assert!(ids(GroupStateFilter::Pending).is_empty());
+        assert_eq!(ids(GroupStateFilter::All).len(), 2);
+    }
 }
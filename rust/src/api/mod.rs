@@ -1 +1,17 @@
+pub mod batch;
+pub mod commits;
+pub mod error;
+pub mod events;
+pub mod key_packages;
+pub mod keyring;
+pub mod media;
+pub mod messages;
 pub mod mls_api;
+pub mod policy;
+pub mod recovery;
+pub mod retention;
+pub mod rotation;
+pub mod signer;
+pub mod storage;
+pub mod types;
+pub mod welcomes;
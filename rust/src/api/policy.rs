@@ -0,0 +1,535 @@
+use nostr_mls::prelude::*;
+use std::collections::BTreeSet;
+
+use crate::api::error::MlsBridgeError;
+use crate::mls;
+use crate::registry::{self, MlsInstance};
+use crate::store::{CommitAuditRecord, StoredGroupPolicy};
+
+const ROLE_ADMINS: &str = "admins";
+const ROLE_MEMBERS: &str = "members";
+
+/// Who may perform an operation in a group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyRole {
+    /// Only the admins listed in the group data
+    Admins,
+    /// Any member
+    Members,
+}
+
+/// Rules every incoming commit of a group is checked against before it is merged
+/// Roles apply to the committer: any member may propose, and an allowed member commits the
+/// proposals it accepts. Proposals from non-members are dropped when they arrive.
+///
+/// The policy is local: each member enforces its own copy, and a rejected commit leaves this
+/// member at the previous epoch.
+#[derive(Debug, Clone)]
+pub struct GroupPolicy {
+    pub add_members: PolicyRole,
+    pub remove_members: PolicyRole,
+    pub update_group_data: PolicyRole,
+    /// Whether a member's proposal to remove itself may be committed even by members who are
+    /// not allowed to remove others
+    pub allow_self_remove: bool,
+}
+
+/// A commit or proposal rejected by the group policy
+#[derive(Debug, Clone)]
+pub struct CommitAuditEntry {
+    /// Epoch the commit was sent in
+    pub epoch: u64,
+    /// Hex encoded public key of the committer or proposer, `None` for non-member senders
+    pub sender: Option<String>,
+    pub reason: String,
+    pub created_at: u64,
+}
+
+/// Operation of a commit checked against the group policy
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum GroupAction {
+    AddMembers,
+    RemoveMembers,
+    UpdateGroupData,
+}
+
+impl Default for GroupPolicy {
+    fn default() -> Self {
+        Self {
+            add_members: PolicyRole::Admins,
+            remove_members: PolicyRole::Admins,
+            update_group_data: PolicyRole::Admins,
+            allow_self_remove: true,
+        }
+    }
+}
+
+/// Set the policy of a group
+/// Parameters: group_id - byte array of group ID
+pub fn set_group_policy(
+    identity: String,
+    group_id: Vec<u8>,
+    policy: GroupPolicy,
+) -> Result<(), MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    instance.store.save_group_policy(
+        &hex::encode(&group_id),
+        &StoredGroupPolicy {
+            add_members: role_to_str(policy.add_members).to_string(),
+            remove_members: role_to_str(policy.remove_members).to_string(),
+            update_group_data: role_to_str(policy.update_group_data).to_string(),
+            allow_self_remove: policy.allow_self_remove,
+        },
+    )
+}
+
+/// Get the policy of a group, the admin-only default if none was set
+/// Parameters: group_id - byte array of group ID
+pub fn get_group_policy(
+    identity: String,
+    group_id: Vec<u8>,
+) -> Result<GroupPolicy, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    group_policy(&instance, &GroupId::from_slice(&group_id))
+}
+
+/// List the commits and proposals of a group rejected by its policy, newest first
+/// Parameters: group_id - byte array of group ID, limit - maximum number of entries
+pub fn list_commit_audit(
+    identity: String,
+    group_id: Vec<u8>,
+    limit: u32,
+) -> Result<Vec<CommitAuditEntry>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    Ok(instance
+        .store
+        .commit_audit(&hex::encode(&group_id), limit)?
+        .into_iter()
+        .map(|record| CommitAuditEntry {
+            epoch: record.epoch,
+            sender: record.sender,
+            reason: record.reason,
+            created_at: record.created_at,
+        })
+        .collect())
+}
+
+pub(crate) fn group_policy(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+) -> Result<GroupPolicy, MlsBridgeError> {
+    let Some(policy) = instance
+        .store
+        .group_policy(&hex::encode(group_id.as_slice()))?
+    else {
+        return Ok(GroupPolicy::default());
+    };
+
+    Ok(GroupPolicy {
+        add_members: role_from_str(&policy.add_members)?,
+        remove_members: role_from_str(&policy.remove_members)?,
+        update_group_data: role_from_str(&policy.update_group_data)?,
+        allow_self_remove: policy.allow_self_remove,
+    })
+}
+
+/// Check that the policy lets us perform an action before creating its commit
+pub(crate) fn check_own_action(
+    instance: &MlsInstance,
+    group: &MlsGroup,
+    action: GroupAction,
+) -> Result<(), MlsBridgeError> {
+    let policy = group_policy(instance, group.group_id())?;
+    let admins = mls::group_data(group)?.admins;
+
+    if policy.role(action) == PolicyRole::Admins && !admins.contains(&mls::own_pubkey(group)?) {
+        return Err(MlsBridgeError::permission_denied(format!(
+            "only admins may {}",
+            action
+        )));
+    }
+
+    Ok(())
+}
+
+/// Check an incoming staged commit against the group policy
+/// A rejected commit is recorded in the audit log and reported as `PermissionDenied`.
+pub(crate) fn check_commit(
+    instance: &MlsInstance,
+    group: &MlsGroup,
+    sender: &Sender,
+    staged_commit: &StagedCommit,
+) -> Result<(), MlsBridgeError> {
+    let policy = group_policy(instance, group.group_id())?;
+    let admins = mls::group_data(group)?.admins;
+
+    let committer = sender_pubkey(group, sender);
+    let Some(action) = violation(
+        &policy,
+        &admins,
+        committer.as_ref(),
+        staged_commit.queued_proposals(),
+    ) else {
+        return Ok(());
+    };
+
+    let error = reject(instance, group, committer, action.to_string())?;
+    Err(error)
+}
+
+/// Check the proposals a group queued besides `known`, the caller holding the group lock
+/// A proposal from a non-member that the policy reserves to a role is dropped from the queue,
+/// so it can never be committed, recorded in the audit log and reported as `PermissionDenied`.
+/// Member proposals are queued whatever their kind; the committer's role is checked instead.
+pub(crate) fn check_new_proposals(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+    known: &[ProposalRef],
+) -> Result<(), MlsBridgeError> {
+    let mut group = mls::load_group(&instance.nostr_mls, group_id)?;
+    let new_proposals: Vec<QueuedProposal> = group
+        .pending_proposals()
+        .filter(|queued| !known.contains(&queued.proposal_reference()))
+        .cloned()
+        .collect();
+    if new_proposals.is_empty() {
+        return Ok(());
+    }
+
+    let policy = group_policy(instance, group_id)?;
+    let admins = mls::group_data(&group)?.admins;
+
+    let mut rejected = None;
+    for queued in &new_proposals {
+        if sender_pubkey(&group, queued.sender()).is_some() {
+            continue;
+        }
+        let Some(action) = violation(&policy, &admins, None, [queued]) else {
+            continue;
+        };
+
+        group
+            .remove_pending_proposal(
+                instance.nostr_mls.provider.storage(),
+                &queued.proposal_reference(),
+            )
+            .map_err(MlsBridgeError::mls)?;
+        let error = reject(instance, &group, None, format!("propose to {}", action))?;
+        rejected.get_or_insert(error);
+    }
+
+    match rejected {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Check that the policy lets us commit pending proposals
+pub(crate) fn check_own_proposals<'a>(
+    instance: &MlsInstance,
+    group: &MlsGroup,
+    proposals: impl IntoIterator<Item = &'a QueuedProposal>,
+) -> Result<(), MlsBridgeError> {
+    let policy = group_policy(instance, group.group_id())?;
+    let admins = mls::group_data(group)?.admins;
+    let own_pubkey = mls::own_pubkey(group)?;
+
+    match violation(&policy, &admins, Some(&own_pubkey), proposals) {
+        Some(action) => Err(MlsBridgeError::permission_denied(format!(
+            "only admins may {}",
+            action
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// First proposal a committer is not allowed to commit
+fn violation<'a>(
+    policy: &GroupPolicy,
+    admins: &BTreeSet<PublicKey>,
+    committer: Option<&PublicKey>,
+    proposals: impl IntoIterator<Item = &'a QueuedProposal>,
+) -> Option<GroupAction> {
+    let allowed = |role: PolicyRole| match (role, committer) {
+        (_, None) => false,
+        (PolicyRole::Members, Some(_)) => true,
+        (PolicyRole::Admins, Some(committer)) => admins.contains(committer),
+    };
+
+    proposals.into_iter().find_map(|queued| {
+        let action = match queued.proposal() {
+            Proposal::Add(_) => GroupAction::AddMembers,
+            Proposal::Remove(remove) => {
+                let self_remove =
+                    matches!(queued.sender(), Sender::Member(index) if *index == remove.removed());
+                if self_remove && policy.allow_self_remove {
+                    return None;
+                }
+                GroupAction::RemoveMembers
+            }
+            Proposal::GroupContextExtensions(_) => GroupAction::UpdateGroupData,
+            _ => return None,
+        };
+        (!allowed(policy.role(action))).then_some(action)
+    })
+}
+
+/// Record a commit or proposal the policy rejects in the audit log
+/// Returns: the `PermissionDenied` error reporting it
+fn reject(
+    instance: &MlsInstance,
+    group: &MlsGroup,
+    sender: Option<PublicKey>,
+    what: String,
+) -> Result<MlsBridgeError, MlsBridgeError> {
+    let reason = match sender {
+        Some(sender) => format!("{} may not {}", sender.to_hex(), what),
+        None => format!("a non-member may not {}", what),
+    };
+    instance.store.add_commit_audit(&CommitAuditRecord {
+        mls_group_id: hex::encode(group.group_id().as_slice()),
+        epoch: group.epoch().as_u64(),
+        sender: sender.map(|sender| sender.to_hex()),
+        reason: reason.clone(),
+        created_at: Timestamp::now().as_u64(),
+    })?;
+
+    Ok(MlsBridgeError::permission_denied(reason))
+}
+
+fn sender_pubkey(group: &MlsGroup, sender: &Sender) -> Option<PublicKey> {
+    match sender {
+        Sender::Member(index) => group.member(*index).and_then(mls::credential_pubkey),
+        _ => None,
+    }
+}
+
+impl GroupPolicy {
+    fn role(&self, action: GroupAction) -> PolicyRole {
+        match action {
+            GroupAction::AddMembers => self.add_members,
+            GroupAction::RemoveMembers => self.remove_members,
+            GroupAction::UpdateGroupData => self.update_group_data,
+        }
+    }
+}
+
+impl std::fmt::Display for GroupAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AddMembers => write!(f, "add members"),
+            Self::RemoveMembers => write!(f, "remove members"),
+            Self::UpdateGroupData => write!(f, "update the group data"),
+        }
+    }
+}
+
+fn role_to_str(role: PolicyRole) -> &'static str {
+    match role {
+        PolicyRole::Admins => ROLE_ADMINS,
+        PolicyRole::Members => ROLE_MEMBERS,
+    }
+}
+
+fn role_from_str(role: &str) -> Result<PolicyRole, MlsBridgeError> {
+    match role {
+        ROLE_ADMINS => Ok(PolicyRole::Admins),
+        ROLE_MEMBERS => Ok(PolicyRole::Members),
+        role => Err(MlsBridgeError::internal(format!(
+            "unknown policy role {role}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::commits::{
+        commit_pending_proposals, confirm_commit_published, list_pending_proposals, propose_add,
+        propose_remove, propose_update,
+    };
+    use crate::api::mls_api::{
+        create_commit_message_for_group, create_key_package_for_event, ephemeral_group_event,
+        process_message_for_group, remove_members,
+    };
+    use crate::api::types::GroupInfo;
+    use crate::test_utils::{create_test_group, receive_test_commit, TestDir, TestIdentity};
+
+    /// Let any member of a group do anything in `member`'s own copy of the policy
+    fn allow_members(member: &TestIdentity, group: &GroupInfo) {
+        set_group_policy(
+            member.identity.clone(),
+            group.mls_group_id.clone(),
+            GroupPolicy {
+                add_members: PolicyRole::Members,
+                remove_members: PolicyRole::Members,
+                update_group_data: PolicyRole::Members,
+                allow_self_remove: true,
+            },
+        )
+        .expect("set policy");
+    }
+
+    /// Receive a proposal and check it was queued without an audit entry
+    fn assert_proposal_queued(
+        admin: &TestIdentity,
+        group: &GroupInfo,
+        proposer: &TestIdentity,
+        event_json: String,
+    ) {
+        process_message_for_group(admin.identity.clone(), event_json).expect("process proposal");
+
+        let pending = list_pending_proposals(admin.identity.clone(), group.mls_group_id.clone())
+            .expect("list proposals");
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].proposer, Some(proposer.identity.clone()));
+        let audit = list_commit_audit(admin.identity.clone(), group.mls_group_id.clone(), 10)
+            .expect("list audit");
+        assert!(audit.is_empty());
+    }
+
+    #[tokio::test]
+    async fn admin_commits_a_member_add_proposal() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let carol = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+
+        let key_package = create_key_package_for_event(
+            carol.identity.clone(),
+            carol.identity.clone(),
+            None,
+            None,
+        )
+        .expect("create key package")
+        .encoded_key_package;
+        let event = propose_add(
+            bob.identity.clone(),
+            group.mls_group_id.clone(),
+            key_package,
+        )
+        .await
+        .expect("propose add");
+        assert_proposal_queued(&alice, &group, &bob, event.event_json);
+
+        let committed =
+            commit_pending_proposals(alice.identity.clone(), group.mls_group_id.clone(), None)
+                .await
+                .expect("admin commits the proposal");
+        assert!(committed.welcome_message.is_some());
+        confirm_commit_published(
+            alice.identity.clone(),
+            group.mls_group_id.clone(),
+            committed.commit_event.event_id,
+        )
+        .expect("merge commit");
+
+        let processed =
+            receive_test_commit(&bob, &committed.commit_event.event_json).expect("apply commit");
+        assert_eq!(processed.added_members, vec![carol.identity.clone()]);
+    }
+
+    #[tokio::test]
+    async fn non_admin_may_not_commit_a_remove_proposal() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let carol = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob, &carol]);
+
+        let event = propose_remove(
+            bob.identity.clone(),
+            group.mls_group_id.clone(),
+            carol.identity.clone(),
+        )
+        .await
+        .expect("propose remove");
+        assert_proposal_queued(&alice, &group, &bob, event.event_json);
+
+        assert!(matches!(
+            commit_pending_proposals(bob.identity.clone(), group.mls_group_id.clone(), None).await,
+            Err(MlsBridgeError::PermissionDenied { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn commit_by_non_admin_is_rejected_and_audited() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let carol = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob, &carol]);
+        // Bob's own copy of the policy lets him commit, alice's does not
+        allow_members(&bob, &group);
+
+        let removed = remove_members(
+            bob.identity.clone(),
+            group.mls_group_id.clone(),
+            vec![carol.identity.clone()],
+        )
+        .expect("remove carol");
+        let event = create_commit_message_for_group(
+            bob.identity.clone(),
+            group.nostr_group_data.nostr_group_id.clone(),
+            removed.serialized_commit,
+        )
+        .await
+        .expect("wrap commit");
+
+        assert!(matches!(
+            receive_test_commit(&alice, &event.event_json),
+            Err(MlsBridgeError::PermissionDenied { .. })
+        ));
+        let audit = list_commit_audit(alice.identity.clone(), group.mls_group_id.clone(), 10)
+            .expect("list audit");
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].sender, Some(bob.identity.clone()));
+    }
+
+    #[test]
+    fn member_group_context_extensions_proposal_is_queued() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+
+        let instance = bob.instance();
+        let nostr_mls = &instance.nostr_mls;
+        let mut mls_group = mls::load_group(nostr_mls, &GroupId::from_slice(&group.mls_group_id))
+            .expect("load group");
+        let mut group_data = mls::group_data(&mls_group).expect("group data");
+        group_data.name = "renamed".to_string();
+        let extensions =
+            mls::extensions_with_group_data(&mls_group, &group_data).expect("extensions");
+        let signer = mls::load_signer(nostr_mls, &mls_group).expect("load signer");
+        let (proposal, _) = mls_group
+            .propose_group_context_extensions(&nostr_mls.provider, extensions, &signer)
+            .expect("propose group context extensions");
+        let event = ephemeral_group_event(
+            nostr_mls,
+            hex::encode(group_data.nostr_group_id),
+            &mls::serialize_message(&proposal).expect("serialize proposal"),
+        )
+        .expect("wrap proposal");
+
+        assert_proposal_queued(&alice, &group, &bob, event.as_json());
+    }
+
+    #[tokio::test]
+    async fn member_update_proposal_is_queued() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+
+        let event = propose_update(bob.identity.clone(), group.mls_group_id.clone())
+            .await
+            .expect("propose update");
+
+        assert_proposal_queued(&alice, &group, &bob, event.event_json);
+    }
+}
@@ -0,0 +1,610 @@
+use nostr_mls::prelude::*;
+use openmls_traits::storage::StorageProvider;
+use std::str::FromStr;
+
+use crate::api::commits::ensure_no_pending_commit;
+use crate::api::error::MlsBridgeError;
+use crate::api::key_packages::{
+    create_managed_key_package, retire_unused_key_package, ManagedKeyPackage,
+};
+use crate::api::mls_api::{parse_relays, save_staged_commit, welcome_error};
+use crate::api::policy::{check_own_action, check_own_proposals, GroupAction};
+use crate::api::types::{AddMembersResult, ProcessedMessage};
+use crate::mls;
+use crate::registry::{self, MlsInstance};
+use crate::store::StoredGroupRecovery;
+
+/// Consecutive processing failures, dropped events included, after which a group is considered
+/// forked
+const FORK_FAILURE_THRESHOLD: u32 = 3;
+
+/// How well our copy of a group keeps up with the other members
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupHealthStatus {
+    Healthy,
+    /// Recent failures or buffered events that may still resolve
+    Lagging,
+    /// We most likely missed a commit; `start_group_recovery` gets us re-added
+    Forked,
+}
+
+/// Report of `get_group_health`
+#[derive(Debug, Clone)]
+pub struct GroupHealth {
+    pub mls_group_id: Vec<u8>,
+    pub epoch: u64,
+    /// Hex encoded tree hash of our epoch, equal for every member in sync
+    pub tree_hash: String,
+    /// Events buffered because they could not be decrypted at our epoch
+    /// Anyone can post an event to a group, so buffered events alone never mean a fork;
+    /// confirm with `compare_group_state` against another member before recovering.
+    pub pending_message_count: u32,
+    pub oldest_pending_message_at: Option<u64>,
+    /// Events dropped because they were of a past epoch, never decrypted or overflowed the buffer
+    pub undecryptable_event_count: u32,
+    pub last_undecryptable_event_at: Option<u64>,
+    /// Failures and dropped events since the last event processed; a member who missed a
+    /// commit drops every later event once they no longer fit the buffer
+    pub consecutive_failures: u32,
+    pub last_failure: Option<String>,
+    pub last_failure_at: Option<u64>,
+    pub last_success_at: Option<u64>,
+    /// When `start_group_recovery` was called, `None` if no recovery is in progress
+    pub recovery_requested_at: Option<u64>,
+    /// Id of the managed key package waiting for the re-add
+    pub recovery_key_package_id: Option<String>,
+    pub status: GroupHealthStatus,
+}
+
+/// Our group state compared to the epoch and tree hash reported by another member
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupStateComparison {
+    InSync,
+    /// The other member is at a later epoch
+    Behind,
+    /// The other member is at an earlier epoch
+    Ahead,
+    /// Same epoch with a different tree: one of us forked
+    Diverged,
+}
+
+/// Result of `start_group_recovery`, to hand to an admin of the group
+#[derive(Debug, Clone)]
+pub struct GroupRecoveryRequest {
+    pub mls_group_id: Vec<u8>,
+    /// Fresh key package to publish, confirmed with `mark_key_package_published`, for an admin
+    /// to pass to `readd_member`
+    pub key_package: ManagedKeyPackage,
+    /// Hex public keys of the admins who can re-add us
+    pub admins: Vec<String>,
+}
+
+/// Report whether our copy of a group still follows the other members
+/// Parameters: group_id - byte array of group ID
+pub fn get_group_health(
+    identity: String,
+    group_id: Vec<u8>,
+) -> Result<GroupHealth, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
+
+    let group_id = GroupId::from_slice(&group_id);
+    let mls_group_id = hex::encode(group_id.as_slice());
+
+    let group = mls::load_group(nostr_mls, &group_id)?;
+    let nostr_group_id = hex::encode(mls::group_data(&group)?.nostr_group_id);
+
+    let pending_message_count = instance.store.count_pending_messages(&nostr_group_id)?;
+    let oldest_pending_message_at = instance.store.oldest_pending_message(&nostr_group_id)?;
+    let health = instance.store.group_health(&mls_group_id)?;
+    let recovery = instance.store.group_recovery(&mls_group_id)?;
+    let undecryptable = instance.store.undecryptable_events(&mls_group_id)?;
+    let consecutive_failures = health
+        .as_ref()
+        .map(|health| health.consecutive_failures)
+        .unwrap_or_default();
+
+    let status = if consecutive_failures >= FORK_FAILURE_THRESHOLD {
+        GroupHealthStatus::Forked
+    } else if consecutive_failures > 0 || pending_message_count > 0 {
+        GroupHealthStatus::Lagging
+    } else {
+        GroupHealthStatus::Healthy
+    };
+
+    Ok(GroupHealth {
+        mls_group_id: group_id.to_vec(),
+        epoch: group.epoch().as_u64(),
+        tree_hash: hex::encode(group.export_group_context().tree_hash()),
+        pending_message_count,
+        oldest_pending_message_at,
+        undecryptable_event_count: undecryptable.map(|(count, _)| count).unwrap_or_default(),
+        last_undecryptable_event_at: undecryptable.map(|(_, last_at)| last_at),
+        consecutive_failures,
+        last_failure: health
+            .as_ref()
+            .and_then(|health| health.last_failure.clone()),
+        last_failure_at: health.as_ref().and_then(|health| health.last_failure_at),
+        last_success_at: health.as_ref().and_then(|health| health.last_success_at),
+        recovery_requested_at: recovery.as_ref().map(|recovery| recovery.requested_at),
+        recovery_key_package_id: recovery.map(|recovery| recovery.key_package_id),
+        status,
+    })
+}
+
+/// Compare our group state with the epoch and tree hash of another member's `get_group_health`
+/// Parameters: group_id - byte array of group ID, tree_hash - hex encoded tree hash of the member
+pub fn compare_group_state(
+    identity: String,
+    group_id: Vec<u8>,
+    epoch: u64,
+    tree_hash: String,
+) -> Result<GroupStateComparison, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let group = mls::load_group(&instance.nostr_mls, &GroupId::from_slice(&group_id))?;
+    let tree_hash =
+        hex::decode(&tree_hash).map_err(|e| MlsBridgeError::invalid_input("tree_hash", e))?;
+
+    Ok(match epoch.cmp(&group.epoch().as_u64()) {
+        std::cmp::Ordering::Greater => GroupStateComparison::Behind,
+        std::cmp::Ordering::Less => GroupStateComparison::Ahead,
+        std::cmp::Ordering::Equal
+            if tree_hash.as_slice() == group.export_group_context().tree_hash() =>
+        {
+            GroupStateComparison::InSync
+        }
+        std::cmp::Ordering::Equal => GroupStateComparison::Diverged,
+    })
+}
+
+/// Ask to be re-added to a group we fell out of sync with
+///
+/// NIP-EE groups publish no GroupInfo to join by external commit, so an admin removes our stale
+/// leaf and adds the fresh key package in one commit with `readd_member`. The welcome is then
+/// joined as usual, replacing our stale copy of the group.
+/// Parameters: group_id - byte array of group ID, relay - relays of the kind-443 event
+/// Returns: the key package to publish and the admins to ask
+pub fn start_group_recovery(
+    identity: String,
+    group_id: Vec<u8>,
+    relay: Option<Vec<String>>,
+    client: Option<String>,
+) -> Result<GroupRecoveryRequest, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let group_id = GroupId::from_slice(&group_id);
+    let relays = parse_relays(relay)?;
+
+    let group = mls::load_group(&instance.nostr_mls, &group_id)?;
+    let own_pubkey = mls::own_pubkey(&group)?;
+    let admins = mls::group_data(&group)?.admins;
+
+    let key_package = create_managed_key_package(
+        &instance,
+        &own_pubkey,
+        relays,
+        client.as_deref().unwrap_or(""),
+        false,
+    )?;
+    instance.store.save_group_recovery(
+        &hex::encode(group_id.as_slice()),
+        &StoredGroupRecovery {
+            key_package_id: key_package.id.clone(),
+            requested_at: Timestamp::now().as_u64(),
+        },
+    )?;
+
+    Ok(GroupRecoveryRequest {
+        mls_group_id: group_id.to_vec(),
+        key_package,
+        admins: admins.iter().map(|pk| pk.to_hex()).collect(),
+    })
+}
+
+/// Stop waiting to be re-added to a group
+/// The key package of the recovery is retired; `maintain_key_packages` reports its kind-443
+/// event to delete.
+/// Parameters: group_id - byte array of group ID
+/// Returns: whether a recovery was in progress
+pub fn cancel_group_recovery(identity: String, group_id: Vec<u8>) -> Result<bool, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let mls_group_id = hex::encode(&group_id);
+    let Some(recovery) = instance.store.group_recovery(&mls_group_id)? else {
+        return Ok(false);
+    };
+    retire_unused_key_package(&instance, &recovery.key_package_id)?;
+
+    instance.store.delete_group_recovery(&mls_group_id)
+}
+
+/// Re-add a member who fell out of sync, removing its stale leaf in the same commit
+/// The commit stays pending until `confirm_commit_published`; send the welcome after it.
+/// Parameters: group_id - byte array of group ID, member_pubkey - hex public key of the member,
+/// key_package - serialized key package from the member's `start_group_recovery`
+/// Returns: serialized commit and welcome messages
+pub fn readd_member(
+    identity: String,
+    group_id: Vec<u8>,
+    member_pubkey: String,
+    key_package: String,
+) -> Result<AddMembersResult, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let nostr_mls = &instance.nostr_mls;
+
+    let group_id = GroupId::from_slice(&group_id);
+    let member_pubkey = PublicKey::from_str(&member_pubkey)
+        .map_err(|e| MlsBridgeError::invalid_input("member_pubkey", e))?;
+    let key_package = nostr_mls
+        .parse_serialized_key_package(&key_package)
+        .map_err(|e| MlsBridgeError::invalid_input("key_package", e))?;
+    if mls::credential_pubkey(key_package.leaf_node().credential()) != Some(member_pubkey) {
+        return Err(MlsBridgeError::invalid_input(
+            "key_package",
+            "the key package belongs to another member",
+        ));
+    }
+
+    let group_lock = instance.group_lock(&group_id);
+    let _guard = registry::lock(&group_lock);
+
+    let mut group = mls::load_group(nostr_mls, &group_id)?;
+    ensure_no_pending_commit(&instance, &group)?;
+    check_own_action(&instance, &group, GroupAction::AddMembers)?;
+    // Proposals already queued are committed along with the re-add
+    check_own_proposals(&instance, &group, group.pending_proposals())?;
+
+    let signer = mls::load_signer(nostr_mls, &group)?;
+    let stale_leaf = mls::member_leaf_index(&group, &member_pubkey);
+    if stale_leaf.is_some() {
+        check_own_action(&instance, &group, GroupAction::RemoveMembers)?;
+    }
+
+    let mut own_proposals = Vec::new();
+    let mut commit_readd = || -> Result<_, MlsBridgeError> {
+        if let Some(leaf_index) = stale_leaf {
+            let (_, proposal_ref) = group
+                .propose_remove_member(&nostr_mls.provider, &signer, leaf_index)
+                .map_err(MlsBridgeError::mls)?;
+            own_proposals.push(proposal_ref);
+        }
+        let (_, proposal_ref) = group
+            .propose_add_member(&nostr_mls.provider, &signer, &key_package)
+            .map_err(MlsBridgeError::mls)?;
+        own_proposals.push(proposal_ref);
+
+        group
+            .commit_to_pending_proposals(&nostr_mls.provider, &signer)
+            .map_err(MlsBridgeError::mls)
+    };
+    let (commit, welcome, _) = match commit_readd() {
+        Ok(committed) => committed,
+        Err(e) => {
+            // Left queued, our proposals would be committed along with the next commit
+            for proposal_ref in &own_proposals {
+                group
+                    .remove_pending_proposal(nostr_mls.provider.storage(), proposal_ref)
+                    .map_err(MlsBridgeError::mls)?;
+            }
+            return Err(e);
+        }
+    };
+    let welcome =
+        welcome.ok_or_else(|| MlsBridgeError::internal("re-add commit has no welcome"))?;
+    save_staged_commit(&instance, &group, None)?;
+
+    Ok(AddMembersResult {
+        commit_message: mls::serialize_message(&commit)?,
+        welcome_message: mls::serialize_message(&welcome)?,
+    })
+}
+
+/// Record how processing an event or commit of a group went, for `get_group_health`
+pub(crate) fn record_outcome(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+    result: &Result<ProcessedMessage, MlsBridgeError>,
+) {
+    let mls_group_id = hex::encode(group_id.as_slice());
+    let now = Timestamp::now().as_u64();
+
+    let recorded = match result {
+        Ok(processed) if processed.pending || processed.superseded => Ok(()),
+        Ok(_) => instance.store.record_group_success(&mls_group_id, now),
+        // Anyone can post undecryptable events to a group, they are counted when dropped
+        Err(
+            MlsBridgeError::OwnMessage
+            | MlsBridgeError::InvalidInput { .. }
+            | MlsBridgeError::DecryptionFailed { .. }
+            | MlsBridgeError::StaleEpoch { .. },
+        ) => Ok(()),
+        Err(e) => instance
+            .store
+            .record_group_failure(&mls_group_id, &e.to_string(), now),
+    };
+    // Health bookkeeping must never change the outcome of processing
+    let _ = recorded;
+}
+
+/// Count an event of a group dropped without being decrypted, for `get_group_health`
+/// Dropped events of a past epoch or that never decrypted are failures too: after a missed
+/// commit nothing the group sends can be processed anymore.
+pub(crate) fn count_undecryptable_event(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+    e: &MlsBridgeError,
+) -> Result<(), MlsBridgeError> {
+    let mls_group_id = hex::encode(group_id.as_slice());
+    let now = Timestamp::now().as_u64();
+
+    instance
+        .store
+        .record_undecryptable_event(&mls_group_id, now)?;
+    instance
+        .store
+        .record_group_failure(&mls_group_id, &e.to_string(), now)
+}
+
+/// Join the welcome re-adding us to a group we are recovering, replacing our stale copy
+/// The welcome is processed first, under the group lock, and overwrites the stale MLS state;
+/// what it leaves behind is deleted only once it succeeded, so a failing welcome keeps our
+/// stale copy of the group.
+/// Returns: `None` if the welcome is not for a group we are recovering
+pub(crate) fn join_recovery_welcome(
+    instance: &MlsInstance,
+    wrapper_event_id: &EventId,
+    rumor_event: &UnsignedEvent,
+) -> Result<Option<Welcome>, MlsBridgeError> {
+    if !instance.store.has_group_recoveries()? {
+        return Ok(None);
+    }
+
+    let nostr_mls = &instance.nostr_mls;
+    let preview = nostr_mls
+        .preview_welcome(wrapper_event_id, rumor_event)
+        .map_err(|e| welcome_error(nostr_mls, rumor_event, e))?;
+    let group_id = preview.staged_welcome.group_context().group_id().clone();
+    let mls_group_id = hex::encode(group_id.as_slice());
+    if instance.store.group_recovery(&mls_group_id)?.is_none() {
+        return Ok(None);
+    }
+
+    let group_lock = instance.group_lock(&group_id);
+    let _guard = registry::lock(&group_lock);
+
+    let stale = mls::load_group(nostr_mls, &group_id).ok();
+    let welcome = nostr_mls
+        .process_welcome(wrapper_event_id, rumor_event)
+        .map_err(|e| welcome_error(nostr_mls, rumor_event, e))?;
+
+    // Stale proposals and leaf nodes are stored apart from the group state and survive it
+    let storage = nostr_mls.provider.storage();
+    let mut group = mls::load_group(nostr_mls, &group_id)?;
+    group
+        .clear_pending_proposals(storage)
+        .map_err(MlsBridgeError::storage)?;
+    storage
+        .delete_own_leaf_nodes(&group_id)
+        .map_err(MlsBridgeError::storage)?;
+    if let Some(stale) = stale {
+        if (stale.epoch(), stale.own_leaf_index()) != (group.epoch(), group.own_leaf_index()) {
+            storage
+                .delete_encryption_epoch_key_pairs(
+                    &group_id,
+                    &stale.epoch(),
+                    stale.own_leaf_index().u32(),
+                )
+                .map_err(MlsBridgeError::storage)?;
+        }
+    }
+    instance.store.delete_pending_commit(&mls_group_id)?;
+    instance.store.delete_group_health(&mls_group_id)?;
+    instance
+        .store
+        .delete_group_exporter_secrets(&mls_group_id)?;
+
+    Ok(Some(welcome))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::commits::{confirm_commit_published, self_update};
+    use crate::api::key_packages::{list_key_packages, KeyPackageState};
+    use crate::api::mls_api::{
+        create_commit_message_for_group, create_message_for_group, process_message_for_group,
+        MAX_PENDING_MESSAGES_PER_GROUP,
+    };
+    use crate::test_utils::{create_test_group, join_test_welcome, TestDir, TestIdentity};
+
+    /// Compare the group state of `member` with the one of `other`
+    fn compare(
+        member: &TestIdentity,
+        other: &TestIdentity,
+        group_id: &[u8],
+    ) -> GroupStateComparison {
+        let health = get_group_health(other.identity.clone(), group_id.to_vec()).expect("health");
+        compare_group_state(
+            member.identity.clone(),
+            group_id.to_vec(),
+            health.epoch,
+            health.tree_hash,
+        )
+        .expect("compare group state")
+    }
+
+    /// Merge a self-update of `member` that no other member receives
+    async fn advance_epoch(member: &TestIdentity, group_id: &[u8]) {
+        let commit = self_update(member.identity.clone(), group_id.to_vec())
+            .await
+            .expect("self-update");
+        confirm_commit_published(member.identity.clone(), group_id.to_vec(), commit.event_id)
+            .expect("merge self-update");
+    }
+
+    #[tokio::test]
+    async fn member_who_missed_a_commit_turns_lagging_then_forked() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+        let health = |member: &TestIdentity| {
+            get_group_health(member.identity.clone(), group_id.clone()).expect("health")
+        };
+        let send = |content: &str| {
+            let rumor = EventBuilder::new(Kind::Custom(9), content).build(alice.keys.public_key());
+            let message =
+                create_message_for_group(alice.identity.clone(), group_id.clone(), rumor.as_json())
+                    .expect("create message");
+            process_message_for_group(bob.identity.clone(), message.event_json)
+        };
+
+        assert_eq!(health(&bob).status, GroupHealthStatus::Healthy);
+        advance_epoch(&alice, &group_id).await;
+
+        // Everything alice sends after the commit bob missed is buffered
+        assert!(send("after the commit").expect("buffer message").pending);
+        let lagging = health(&bob);
+        assert_eq!(lagging.status, GroupHealthStatus::Lagging);
+        assert_eq!(lagging.pending_message_count, 1);
+        assert_eq!(lagging.consecutive_failures, 0);
+
+        for _ in 1..MAX_PENDING_MESSAGES_PER_GROUP {
+            send("buffered").expect("buffer message");
+        }
+        assert_eq!(health(&bob).status, GroupHealthStatus::Lagging);
+
+        // Then dropped
+        for _ in 0..FORK_FAILURE_THRESHOLD {
+            assert!(matches!(
+                send("dropped"),
+                Err(MlsBridgeError::DecryptionFailed { .. })
+            ));
+        }
+        let forked = health(&bob);
+        assert_eq!(forked.status, GroupHealthStatus::Forked);
+        assert_eq!(forked.undecryptable_event_count, FORK_FAILURE_THRESHOLD);
+        assert_eq!(forked.consecutive_failures, FORK_FAILURE_THRESHOLD);
+        assert_eq!(health(&alice).status, GroupHealthStatus::Healthy);
+    }
+
+    #[tokio::test]
+    async fn group_states_are_compared_by_epoch_then_tree_hash() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+
+        assert_eq!(
+            compare(&bob, &alice, &group_id),
+            GroupStateComparison::InSync
+        );
+
+        advance_epoch(&alice, &group_id).await;
+        assert_eq!(
+            compare(&bob, &alice, &group_id),
+            GroupStateComparison::Behind
+        );
+        assert_eq!(
+            compare(&alice, &bob, &group_id),
+            GroupStateComparison::Ahead
+        );
+
+        let health = get_group_health(alice.identity.clone(), group_id.clone()).expect("health");
+        let other_tree = compare_group_state(
+            alice.identity.clone(),
+            group_id.clone(),
+            health.epoch,
+            hex::encode([0u8; 32]),
+        )
+        .expect("compare group state");
+        assert_eq!(other_tree, GroupStateComparison::Diverged);
+    }
+
+    #[tokio::test]
+    async fn member_who_missed_a_commit_is_readded() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+
+        advance_epoch(&alice, &group_id).await;
+        assert_eq!(
+            compare(&bob, &alice, &group_id),
+            GroupStateComparison::Behind
+        );
+
+        let request = start_group_recovery(bob.identity.clone(), group_id.clone(), None, None)
+            .expect("start recovery");
+        assert_eq!(request.admins, vec![alice.identity.clone()]);
+        let health = get_group_health(bob.identity.clone(), group_id.clone()).expect("health");
+        assert_eq!(
+            health.recovery_key_package_id.as_deref(),
+            Some(request.key_package.id.as_str())
+        );
+
+        let readd = readd_member(
+            alice.identity.clone(),
+            group_id.clone(),
+            bob.identity.clone(),
+            request.key_package.encoded_key_package,
+        )
+        .expect("re-add bob");
+        let event = create_commit_message_for_group(
+            alice.identity.clone(),
+            group.nostr_group_data.nostr_group_id.clone(),
+            readd.commit_message,
+        )
+        .await
+        .expect("wrap commit");
+        confirm_commit_published(alice.identity.clone(), group_id.clone(), event.event_id)
+            .expect("merge re-add");
+        join_test_welcome(&bob, &alice, &readd.welcome_message);
+
+        assert_eq!(
+            compare(&bob, &alice, &group_id),
+            GroupStateComparison::InSync
+        );
+        let health = get_group_health(bob.identity.clone(), group_id.clone()).expect("health");
+        assert!(health.recovery_requested_at.is_none());
+
+        let rumor =
+            EventBuilder::new(Kind::Custom(9), "welcome back").build(alice.keys.public_key());
+        let message = create_message_for_group(alice.identity.clone(), group_id, rumor.as_json())
+            .expect("create message");
+        let processed =
+            process_message_for_group(bob.identity.clone(), message.event_json).expect("decrypt");
+        assert_eq!(
+            processed.message.expect("decrypted message").content,
+            "welcome back"
+        );
+    }
+
+    #[test]
+    fn cancelled_recovery_retires_its_key_package() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+
+        let request = start_group_recovery(bob.identity.clone(), group_id.clone(), None, None)
+            .expect("start recovery");
+        assert!(cancel_group_recovery(bob.identity.clone(), group_id.clone()).expect("cancel"));
+        assert!(!cancel_group_recovery(bob.identity.clone(), group_id.clone()).expect("cancel"));
+
+        let key_package = list_key_packages(bob.identity.clone())
+            .expect("list key packages")
+            .into_iter()
+            .find(|key_package| key_package.id == request.key_package.id)
+            .expect("recovery key package");
+        // Never published, it has no kind-443 event left to delete
+        assert_eq!(key_package.state, KeyPackageState::Deleted);
+        let health = get_group_health(bob.identity.clone(), group_id).expect("health");
+        assert!(health.recovery_requested_at.is_none());
+    }
+}
@@ -0,0 +1,399 @@
+use nostr_mls::prelude::*;
+
+use crate::api::error::MlsBridgeError;
+use crate::api::events::GroupEvent;
+use crate::api::key_packages::forget_retired_key_packages;
+use crate::api::messages::ensure_group_indexed;
+use crate::mls;
+use crate::registry::{self, MlsInstance};
+use crate::store::StoredRetentionPolicy;
+
+/// What an identity keeps of old messages and of the groups it is no longer in
+/// Nothing is expired or purged until the app calls `apply_retention_policy`: processing the
+/// commit that removed us keeps the group, so the app must schedule that call itself.
+#[derive(Debug, Clone)]
+pub struct RetentionPolicy {
+    /// Seconds the bridge returns a decrypted message after we received it, `None` keeping
+    /// messages forever
+    pub message_max_age_secs: Option<u64>,
+    /// Purge the groups we left or were removed from when the policy is applied, on by default
+    pub purge_inactive_groups: bool,
+}
+
+/// What `apply_retention_policy` deleted
+#[derive(Debug, Clone)]
+pub struct RetentionReport {
+    pub purged_groups: Vec<Vec<u8>>,
+    pub expired_messages: u32,
+    pub forgotten_key_packages: u32,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            message_max_age_secs: None,
+            purge_inactive_groups: true,
+        }
+    }
+}
+
+/// Set the retention policy of an identity, applied by `apply_retention_policy`
+pub fn set_retention_policy(
+    identity: String,
+    policy: RetentionPolicy,
+) -> Result<(), MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    if policy.message_max_age_secs == Some(0) {
+        return Err(MlsBridgeError::invalid_input(
+            "message_max_age_secs",
+            "must be at least 1",
+        ));
+    }
+
+    instance
+        .store
+        .save_retention_policy(&StoredRetentionPolicy {
+            message_max_age_secs: policy.message_max_age_secs,
+            purge_inactive_groups: policy.purge_inactive_groups,
+        })
+}
+
+/// Get the retention policy of an identity, keeping messages and purging left groups by default
+pub fn get_retention_policy(identity: String) -> Result<RetentionPolicy, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    retention_policy(&instance)
+}
+
+/// Delete everything stored about a group we are no longer in
+/// Its MLS state, the exporter secrets the keyring recorded and the bridge's bookkeeping and
+/// message indexes are deleted, and the database is compacted so none of it can be recovered
+/// from the file. nostr-mls has no API to delete its record of the group and its messages:
+/// those stay in its tables, but the bridge no longer returns them.
+/// Parameters: group_id - byte array of group ID of a group we left or were removed from
+pub fn purge_group(identity: String, group_id: Vec<u8>) -> Result<(), MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let group_id = GroupId::from_slice(&group_id);
+    let group = instance
+        .nostr_mls
+        .get_group(&group_id)?
+        .ok_or(MlsBridgeError::GroupNotFound)?;
+    // GroupNotFound once purged
+    mls::load_group(&instance.nostr_mls, &group_id)?;
+    if !has_left(&instance, &group)? {
+        return Err(MlsBridgeError::invalid_input(
+            "group_id",
+            "still a member, leave the group before purging it",
+        ));
+    }
+
+    purge_group_state(&instance, &group_id)?;
+    instance.store.compact()
+}
+
+/// Purge the groups we are no longer in if the policy asks to, expire old messages and forget
+/// retired key packages, then compact the database
+/// Nothing else applies the policy: the app must call this periodically, e.g. at startup and
+/// then daily like `maintain_key_packages`. Expired messages are dropped from the bridge's
+/// indexes and no longer returned; nostr-mls keeps its copy, having no API to delete it.
+pub fn apply_retention_policy(identity: String) -> Result<RetentionReport, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let policy = retention_policy(&instance)?;
+    let now = Timestamp::now().as_u64();
+
+    let mut report = RetentionReport {
+        purged_groups: Vec::new(),
+        expired_messages: 0,
+        forgotten_key_packages: forget_retired_key_packages(&instance)?,
+    };
+
+    for group in instance.nostr_mls.get_groups()? {
+        if policy.purge_inactive_groups && has_left(&instance, &group)? {
+            purge_group_state(&instance, &group.mls_group_id)?;
+            report.purged_groups.push(group.mls_group_id.to_vec());
+            continue;
+        }
+
+        let Some(max_age) = policy.message_max_age_secs else {
+            continue;
+        };
+        // By when we received them: a message's created_at is its sender's to choose
+        ensure_group_indexed(&instance, &group.mls_group_id)?;
+        let expired = instance.store.messages_received_before(
+            &hex::encode(group.mls_group_id.as_slice()),
+            now.saturating_sub(max_age),
+        )?;
+        if expired.is_empty() {
+            continue;
+        }
+
+        let deleted = instance.store.delete_messages(&expired)?;
+        report.expired_messages += deleted as u32;
+    }
+
+    instance.store.compact()?;
+
+    Ok(report)
+}
+
+/// Rewrite the database of an identity so deleted state cannot be recovered from the file
+pub fn compact_storage(identity: String) -> Result<(), MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    instance.store.compact()
+}
+
+pub(crate) fn retention_policy(instance: &MlsInstance) -> Result<RetentionPolicy, MlsBridgeError> {
+    Ok(match instance.store.retention_policy()? {
+        Some(policy) => RetentionPolicy {
+            message_max_age_secs: policy.message_max_age_secs,
+            purge_inactive_groups: policy.purge_inactive_groups,
+        },
+        None => RetentionPolicy::default(),
+    })
+}
+
+/// Delete the MLS state of a group, the exporter secrets the keyring recorded and the bridge's
+/// state of it
+/// The caller compacts the database afterwards, once for every group purged.
+pub(crate) fn purge_group_state(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+) -> Result<(), MlsBridgeError> {
+    {
+        let group_lock = instance.group_lock(group_id);
+        let _guard = registry::lock(&group_lock);
+
+        let group = instance
+            .nostr_mls
+            .get_group(group_id)?
+            .ok_or(MlsBridgeError::GroupNotFound)?;
+
+        if let Ok(mut mls_group) = mls::load_group(&instance.nostr_mls, group_id) {
+            mls_group
+                .delete(instance.nostr_mls.provider.storage())
+                .map_err(MlsBridgeError::storage)?;
+        }
+        // Every epoch the keyring recorded, the current one included
+        instance
+            .store
+            .delete_exporter_secrets(group_id.as_slice(), u64::MAX, u64::MAX, None)?;
+        instance
+            .store
+            .purge_group(group_id.as_slice(), &hex::encode(group.nostr_group_id))?;
+    }
+
+    instance.emit(GroupEvent::GroupPurged {
+        mls_group_id: group_id.to_vec(),
+    });
+
+    Ok(())
+}
+
+/// Whether we left or were removed from a group not purged yet, in nostr-mls or in the MLS
+/// state
+fn has_left(instance: &MlsInstance, group: &Group) -> Result<bool, MlsBridgeError> {
+    if matches!(group.state, GroupState::Pending) {
+        return Ok(false);
+    }
+    match mls::load_group(&instance.nostr_mls, &group.mls_group_id) {
+        Ok(mls_group) => Ok(matches!(group.state, GroupState::Inactive) || !mls_group.is_active()),
+        // Purged: only nostr-mls's record of the group is left
+        Err(MlsBridgeError::GroupNotFound) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::commits::confirm_commit_published;
+    use crate::api::messages::get_messages;
+    use crate::api::mls_api::{
+        create_commit_message_for_group, create_message_for_group, get_group, list_groups,
+        process_message_for_group, remove_members,
+    };
+    use crate::api::types::{GroupInfo, GroupStateFilter};
+    use crate::store::IndexedMessage;
+    use crate::test_utils::{create_test_group, receive_test_commit, TestDir, TestIdentity};
+
+    fn keep_left_groups(member: &TestIdentity) {
+        set_retention_policy(
+            member.identity.clone(),
+            RetentionPolicy {
+                message_max_age_secs: None,
+                purge_inactive_groups: false,
+            },
+        )
+        .expect("keep left groups");
+    }
+
+    /// Remove `member` from a group of `admin` and deliver the commit to `member`
+    async fn remove(admin: &TestIdentity, member: &TestIdentity, group: &GroupInfo) {
+        let removed = remove_members(
+            admin.identity.clone(),
+            group.mls_group_id.clone(),
+            vec![member.identity.clone()],
+        )
+        .expect("remove member");
+        let commit = create_commit_message_for_group(
+            admin.identity.clone(),
+            group.nostr_group_data.nostr_group_id.clone(),
+            removed.serialized_commit,
+        )
+        .await
+        .expect("wrap commit");
+        confirm_commit_published(
+            admin.identity.clone(),
+            group.mls_group_id.clone(),
+            commit.event_id,
+        )
+        .expect("merge commit");
+        receive_test_commit(member, &commit.event_json).expect("apply commit");
+    }
+
+    fn stored_group(member: &TestIdentity, group: &GroupInfo) -> Group {
+        member
+            .instance()
+            .nostr_mls
+            .get_group(&GroupId::from_slice(&group.mls_group_id))
+            .expect("read group")
+            .expect("group stored")
+    }
+
+    #[tokio::test]
+    async fn left_groups_are_purged_when_the_policy_is_applied_by_default() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+
+        assert!(
+            get_retention_policy(bob.identity.clone())
+                .expect("retention policy")
+                .purge_inactive_groups
+        );
+        remove(&alice, &bob, &group).await;
+
+        // Processing the commit that removed us purges nothing
+        assert!(has_left(&bob.instance(), &stored_group(&bob, &group)).expect("has left"));
+
+        let report = apply_retention_policy(bob.identity.clone()).expect("apply retention");
+        assert_eq!(report.purged_groups, vec![group.mls_group_id.clone()]);
+        assert!(matches!(
+            get_group(bob.identity.clone(), group.mls_group_id.clone()),
+            Err(MlsBridgeError::GroupNotFound)
+        ));
+        // nostr-mls keeps its record of the group, which is neither listed nor purged again
+        assert!(list_groups(bob.identity.clone(), GroupStateFilter::All)
+            .expect("list groups")
+            .is_empty());
+        let report = apply_retention_policy(bob.identity.clone()).expect("apply retention");
+        assert!(report.purged_groups.is_empty());
+    }
+
+    #[tokio::test]
+    async fn only_a_group_we_left_can_be_purged() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+
+        assert!(!has_left(&bob.instance(), &stored_group(&bob, &group)).expect("has left"));
+        assert!(matches!(
+            purge_group(bob.identity.clone(), group.mls_group_id.clone()),
+            Err(MlsBridgeError::InvalidInput { .. })
+        ));
+
+        remove(&alice, &bob, &group).await;
+        assert!(has_left(&bob.instance(), &stored_group(&bob, &group)).expect("has left"));
+
+        purge_group(bob.identity.clone(), group.mls_group_id.clone()).expect("purge group");
+        assert!(matches!(
+            get_group(bob.identity.clone(), group.mls_group_id.clone()),
+            Err(MlsBridgeError::GroupNotFound)
+        ));
+        // Alice is still in the group
+        assert!(matches!(
+            purge_group(alice.identity.clone(), group.mls_group_id.clone()),
+            Err(MlsBridgeError::InvalidInput { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn retention_expires_old_messages_and_purges_left_groups() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let kept = create_test_group(&alice, &[&bob]);
+        let left = create_test_group(&alice, &[&bob]);
+        keep_left_groups(&bob);
+        remove(&alice, &bob, &left).await;
+
+        let now = Timestamp::now().as_u64();
+        let year = 365 * 24 * 60 * 60;
+        // Dated by their sender, who cannot make them expire early or never
+        let messages = [
+            ("received long ago", now + 10 * year, Some(1_000)),
+            ("back-dated", 1_000, None),
+            ("new", now, None),
+        ];
+        for (content, created_at, received_at) in messages {
+            let mut rumor = EventBuilder::new(Kind::Custom(9), content)
+                .custom_created_at(Timestamp::from(created_at))
+                .build(alice.keys.public_key());
+            rumor.ensure_id();
+            if let Some(received_at) = received_at {
+                // Indexed first as if it had been received then
+                bob.instance()
+                    .store
+                    .index_message(
+                        &hex::encode(&kept.mls_group_id),
+                        &IndexedMessage {
+                            event_id: rumor.id.unwrap().to_hex(),
+                            created_at,
+                            content: content.to_string(),
+                            received_at,
+                        },
+                    )
+                    .expect("index message");
+            }
+            let message = create_message_for_group(
+                alice.identity.clone(),
+                kept.mls_group_id.clone(),
+                rumor.as_json(),
+            )
+            .expect("create message");
+            process_message_for_group(bob.identity.clone(), message.event_json)
+                .expect("receive message");
+        }
+
+        set_retention_policy(
+            bob.identity.clone(),
+            RetentionPolicy {
+                message_max_age_secs: Some(24 * 60 * 60),
+                purge_inactive_groups: true,
+            },
+        )
+        .expect("set retention policy");
+        let report = apply_retention_policy(bob.identity.clone()).expect("apply retention");
+
+        assert_eq!(report.purged_groups, vec![left.mls_group_id.clone()]);
+        assert_eq!(report.expired_messages, 1);
+        let mut remaining: Vec<String> =
+            get_messages(bob.identity.clone(), kept.mls_group_id.clone(), None, 10)
+                .expect("read messages")
+                .into_iter()
+                .map(|message| message.content)
+                .collect();
+        remaining.sort();
+        assert_eq!(remaining, vec!["back-dated".to_string(), "new".to_string()]);
+
+        let report = apply_retention_policy(bob.identity.clone()).expect("apply retention");
+        assert!(report.purged_groups.is_empty());
+        assert_eq!(report.expired_messages, 0);
+    }
+}
@@ -0,0 +1,205 @@
+use nostr_mls::prelude::*;
+
+use crate::api::error::MlsBridgeError;
+use crate::api::mls_api::group_status;
+use crate::api::types::GroupStatus;
+use crate::registry::{self, MlsInstance};
+use crate::store::StoredRotationPolicy;
+
+/// When our leaf in each group is due for a self-update
+/// A group is due once either limit is reached; `None` disables a limit.
+#[derive(Debug, Clone)]
+pub struct RotationPolicy {
+    /// Messages sent and received in the group since the last rotation
+    pub max_messages: Option<u64>,
+    /// Seconds since the last rotation
+    pub max_age_secs: Option<u64>,
+}
+
+/// Rotation state of our leaf in a group
+#[derive(Debug, Clone)]
+pub struct RotationStatus {
+    pub mls_group_id: Vec<u8>,
+    /// Last self-update, or when the bridge started tracking the group
+    pub last_rotated_at: u64,
+    pub messages_since_rotation: u64,
+    pub due: bool,
+}
+
+impl Default for RotationPolicy {
+    fn default() -> Self {
+        Self {
+            max_messages: Some(1000),
+            max_age_secs: Some(7 * 24 * 60 * 60),
+        }
+    }
+}
+
+/// Set the rotation policy applying to every group of an identity
+pub fn set_rotation_policy(identity: String, policy: RotationPolicy) -> Result<(), MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    if policy.max_messages == Some(0) {
+        return Err(MlsBridgeError::invalid_input(
+            "max_messages",
+            "must be at least 1",
+        ));
+    }
+    if policy.max_age_secs == Some(0) {
+        return Err(MlsBridgeError::invalid_input(
+            "max_age_secs",
+            "must be at least 1",
+        ));
+    }
+
+    instance.store.save_rotation_policy(&StoredRotationPolicy {
+        max_messages: policy.max_messages,
+        max_age_secs: policy.max_age_secs,
+    })
+}
+
+/// Get the rotation policy of an identity, a weekly or every 1000 messages default if none was set
+pub fn get_rotation_policy(identity: String) -> Result<RotationPolicy, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    rotation_policy(&instance)
+}
+
+/// Get the rotation state of our leaf in every active group
+/// Parameters: due_only - only return the groups due for `self_update`
+pub fn list_rotation_status(
+    identity: String,
+    due_only: bool,
+) -> Result<Vec<RotationStatus>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let policy = rotation_policy(&instance)?;
+    let now = Timestamp::now().as_u64();
+
+    let mut statuses = Vec::new();
+    for group in instance.nostr_mls.get_groups()? {
+        if group_status(&instance.nostr_mls, &group) != GroupStatus::Active {
+            continue;
+        }
+
+        let record = instance
+            .store
+            .leaf_rotation(&hex::encode(group.mls_group_id.as_slice()), now)?;
+        let due = policy
+            .max_messages
+            .is_some_and(|max| record.messages_since >= max)
+            || policy
+                .max_age_secs
+                .is_some_and(|max| now.saturating_sub(record.rotated_at) >= max);
+
+        if due || !due_only {
+            statuses.push(RotationStatus {
+                mls_group_id: group.mls_group_id.to_vec(),
+                last_rotated_at: record.rotated_at,
+                messages_since_rotation: record.messages_since,
+                due,
+            });
+        }
+    }
+
+    Ok(statuses)
+}
+
+pub(crate) fn rotation_policy(instance: &MlsInstance) -> Result<RotationPolicy, MlsBridgeError> {
+    Ok(match instance.store.rotation_policy()? {
+        Some(policy) => RotationPolicy {
+            max_messages: policy.max_messages,
+            max_age_secs: policy.max_age_secs,
+        },
+        None => RotationPolicy::default(),
+    })
+}
+
+/// Count a message sent or received in a group towards its next rotation
+pub(crate) fn count_message(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+) -> Result<(), MlsBridgeError> {
+    instance
+        .store
+        .count_group_message(&hex::encode(group_id.as_slice()), Timestamp::now().as_u64())
+}
+
+/// Restart the rotation counters of a group after a self-update
+pub(crate) fn record_rotation(
+    instance: &MlsInstance,
+    group_id: &GroupId,
+) -> Result<(), MlsBridgeError> {
+    instance
+        .store
+        .reset_leaf_rotation(&hex::encode(group_id.as_slice()), Timestamp::now().as_u64())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::commits::{confirm_commit_published, self_update};
+    use crate::api::mls_api::{create_message_for_group, get_members};
+    use crate::mls;
+    use crate::test_utils::{create_test_group, receive_test_commit, TestDir, TestIdentity};
+
+    fn own_signature_key(member: &TestIdentity, group_id: &[u8]) -> Vec<u8> {
+        let instance = member.instance();
+        let group = mls::load_group(&instance.nostr_mls, &GroupId::from_slice(group_id))
+            .expect("load group");
+        group
+            .own_leaf()
+            .expect("own leaf")
+            .signature_key()
+            .as_slice()
+            .to_vec()
+    }
+
+    #[tokio::test]
+    async fn self_update_rotates_the_signing_key_of_a_due_group() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        let group_id = group.mls_group_id.clone();
+        set_rotation_policy(
+            alice.identity.clone(),
+            RotationPolicy {
+                max_messages: Some(2),
+                max_age_secs: None,
+            },
+        )
+        .expect("set rotation policy");
+
+        let due = || list_rotation_status(alice.identity.clone(), true).expect("list due groups");
+        assert!(due().is_empty());
+        for content in ["first", "second"] {
+            let rumor = EventBuilder::new(Kind::Custom(9), content).build(alice.keys.public_key());
+            create_message_for_group(alice.identity.clone(), group_id.clone(), rumor.as_json())
+                .expect("create message");
+        }
+        let statuses = due();
+        assert_eq!(statuses.len(), 1);
+        assert_eq!(statuses[0].mls_group_id, group_id);
+        assert_eq!(statuses[0].messages_since_rotation, 2);
+
+        let signature_key = own_signature_key(&alice, &group_id);
+        let commit = self_update(alice.identity.clone(), group_id.clone())
+            .await
+            .expect("self-update");
+        confirm_commit_published(alice.identity.clone(), group_id.clone(), commit.event_id)
+            .expect("merge self-update");
+        receive_test_commit(&bob, &commit.event_json).expect("apply self-update");
+
+        assert_ne!(own_signature_key(&alice, &group_id), signature_key);
+        assert!(due().is_empty());
+        let statuses = list_rotation_status(alice.identity.clone(), false).expect("list groups");
+        assert_eq!(statuses[0].messages_since_rotation, 0);
+        assert_eq!(
+            get_members(bob.identity.clone(), group_id)
+                .expect("bob reads members")
+                .len(),
+            2
+        );
+    }
+}
@@ -0,0 +1,353 @@
+use flutter_rust_bridge::DartFnFuture;
+use nostr_mls::prelude::*;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::api::error::MlsBridgeError;
+use crate::registry;
+
+pub(crate) type DartCallback = Box<dyn Fn(String) -> DartFnFuture<Option<String>> + Send + Sync>;
+pub(crate) type DartCallback2 =
+    Box<dyn Fn(String, String) -> DartFnFuture<Option<String>> + Send + Sync>;
+
+/// Ids of NIP-46 requests without an event id of their own
+static NIP46_REQUEST_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Events signed by an identity's signer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignerScope {
+    /// Only the kind-13 seals of NIP-59 gift wraps; kind-445 events keep a new random key each,
+    /// so they never link the identity to a group as NIP-EE recommends
+    Seals,
+    /// Seals and the kind-445 events of our own commits and proposals, e.g. for relays that only
+    /// accept group events from known keys
+    SealsAndCommits,
+}
+
+/// Signer holding an identity's key
+/// Application messages are always signed with a new random key, whatever the scope.
+pub(crate) enum Signer {
+    /// No identity key, so gift wraps cannot be sealed (the default)
+    Unset,
+    /// Dart holds the identity's key and answers each call
+    Dart {
+        public_key: PublicKey,
+        sign_event: DartCallback,
+        nip44_encrypt: DartCallback2,
+        nip44_decrypt: DartCallback2,
+    },
+    /// Dart relays a NIP-46 request JSON to the remote signer and returns its response JSON
+    Nip46 {
+        public_key: PublicKey,
+        send_request: DartCallback,
+    },
+}
+
+/// Forget the identity's signer set by `use_dart_signer` or `use_nip46_signer`
+/// Gift-wrapped welcomes cannot be created or unwrapped until another one is set, and kind-445
+/// commit events are signed with a new random key again.
+pub fn clear_signer(identity: String) -> Result<(), MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    instance.set_signer(Signer::Unset, SignerScope::Seals);
+    Ok(())
+}
+
+/// Sign with the identity's key held in Dart, e.g. in a platform keystore
+/// Each callback returns `None` when the key holder refuses or fails, failing the call using it.
+/// Parameters: scope - events signed with the key, public_key - hex public key of the identity,
+/// sign_event - takes the unsigned event JSON and returns the signed event JSON, nip44_encrypt /
+/// nip44_decrypt - take the hex public key of the other party and the plaintext / payload
+pub fn use_dart_signer(
+    identity: String,
+    scope: SignerScope,
+    public_key: String,
+    sign_event: impl Fn(String) -> DartFnFuture<Option<String>> + Send + Sync + 'static,
+    nip44_encrypt: impl Fn(String, String) -> DartFnFuture<Option<String>> + Send + Sync + 'static,
+    nip44_decrypt: impl Fn(String, String) -> DartFnFuture<Option<String>> + Send + Sync + 'static,
+) -> Result<(), MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let public_key = PublicKey::from_str(&public_key)
+        .map_err(|e| MlsBridgeError::invalid_input("public_key", e))?;
+
+    instance.set_signer(
+        Signer::Dart {
+            public_key,
+            sign_event: Box::new(sign_event),
+            nip44_encrypt: Box::new(nip44_encrypt),
+            nip44_decrypt: Box::new(nip44_decrypt),
+        },
+        scope,
+    );
+    Ok(())
+}
+
+/// Sign with the identity's key held by a NIP-46 remote signer
+/// Parameters: scope - events signed with the key, public_key - hex public key of the user at
+/// the remote signer, send_request - delivers the NIP-46 request JSON to the remote signer and
+/// returns its response JSON, or `None` when it did not answer
+pub fn use_nip46_signer(
+    identity: String,
+    scope: SignerScope,
+    public_key: String,
+    send_request: impl Fn(String) -> DartFnFuture<Option<String>> + Send + Sync + 'static,
+) -> Result<(), MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let public_key = PublicKey::from_str(&public_key)
+        .map_err(|e| MlsBridgeError::invalid_input("public_key", e))?;
+
+    instance.set_signer(
+        Signer::Nip46 {
+            public_key,
+            send_request: Box::new(send_request),
+        },
+        scope,
+    );
+    Ok(())
+}
+
+impl Signer {
+    /// Public key of the identity's key
+    pub(crate) fn public_key(&self) -> Result<PublicKey, MlsBridgeError> {
+        match self {
+            Self::Unset => Err(no_identity_key()),
+            Self::Dart { public_key, .. } | Self::Nip46 { public_key, .. } => Ok(*public_key),
+        }
+    }
+
+    /// Sign an event with the identity's key
+    /// The returned event must carry the requested id, a valid signature of the identity's key
+    /// and the requested fields.
+    pub(crate) async fn sign_event(
+        &self,
+        mut unsigned: UnsignedEvent,
+    ) -> Result<Event, MlsBridgeError> {
+        let public_key = self.public_key()?;
+        if unsigned.pubkey != public_key {
+            return Err(MlsBridgeError::signer(
+                "the event's author is not the signer's key",
+            ));
+        }
+        unsigned.ensure_id();
+
+        let signed_json = match self {
+            Self::Unset => return Err(no_identity_key()),
+            Self::Dart { sign_event, .. } => sign_event(unsigned.as_json())
+                .await
+                .ok_or_else(|| declined("sign_event"))?,
+            Self::Nip46 { send_request, .. } => {
+                let request_id = unsigned.id.map(|id| id.to_hex()).unwrap_or_default();
+                nip46_request(
+                    send_request,
+                    request_id,
+                    "sign_event",
+                    vec![unsigned.as_json()],
+                )
+                .await?
+            }
+        };
+
+        let signed = Event::from_json(&signed_json).map_err(MlsBridgeError::signer)?;
+        // Checks the id is the hash of the event and the signature is the author's
+        signed.verify().map_err(MlsBridgeError::signer)?;
+        if Some(signed.id) != unsigned.id || signed.pubkey != public_key {
+            return Err(MlsBridgeError::signer(
+                "signed event has another id or author than the requested one",
+            ));
+        }
+        if signed.kind != unsigned.kind
+            || signed.created_at != unsigned.created_at
+            || signed.content != unsigned.content
+            || signed.tags != unsigned.tags
+        {
+            return Err(MlsBridgeError::signer(
+                "signed event does not match the requested one",
+            ));
+        }
+
+        Ok(signed)
+    }
+
+    /// Sign a kind-445 event built with a new random key again with the identity's key
+    pub(crate) async fn resign(&self, event: Event) -> Result<Event, MlsBridgeError> {
+        let unsigned = EventBuilder::new(event.kind, event.content)
+            .tags(event.tags)
+            .custom_created_at(event.created_at)
+            .build(self.public_key()?);
+
+        self.sign_event(unsigned).await
+    }
+
+    /// NIP-44 encrypt a plaintext to another party with the identity's key
+    pub(crate) async fn nip44_encrypt(
+        &self,
+        public_key: &PublicKey,
+        plaintext: String,
+    ) -> Result<String, MlsBridgeError> {
+        match self {
+            Self::Unset => Err(no_identity_key()),
+            Self::Dart { nip44_encrypt, .. } => nip44_encrypt(public_key.to_hex(), plaintext)
+                .await
+                .ok_or_else(|| declined("nip44_encrypt")),
+            Self::Nip46 { send_request, .. } => {
+                let params = vec![public_key.to_hex(), plaintext];
+                nip46_request(send_request, next_request_id(), "nip44_encrypt", params).await
+            }
+        }
+    }
+
+    /// NIP-44 decrypt a payload from another party with the identity's key
+    pub(crate) async fn nip44_decrypt(
+        &self,
+        public_key: &PublicKey,
+        payload: String,
+    ) -> Result<String, MlsBridgeError> {
+        match self {
+            Self::Unset => Err(no_identity_key()),
+            Self::Dart { nip44_decrypt, .. } => nip44_decrypt(public_key.to_hex(), payload)
+                .await
+                .ok_or_else(|| declined("nip44_decrypt")),
+            Self::Nip46 { send_request, .. } => {
+                let params = vec![public_key.to_hex(), payload];
+                nip46_request(send_request, next_request_id(), "nip44_decrypt", params).await
+            }
+        }
+    }
+}
+
+fn no_identity_key() -> MlsBridgeError {
+    MlsBridgeError::signer("no identity signer is set, use a Dart or NIP-46 signer")
+}
+
+fn declined(method: &str) -> MlsBridgeError {
+    MlsBridgeError::signer(format!("the signer returned nothing for {}", method))
+}
+
+fn next_request_id() -> String {
+    format!(
+        "{:x}-{:x}",
+        Timestamp::now().as_u64(),
+        NIP46_REQUEST_COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Send a NIP-46 request and read the result of its response
+async fn nip46_request(
+    send_request: &DartCallback,
+    request_id: String,
+    method: &str,
+    params: Vec<String>,
+) -> Result<String, MlsBridgeError> {
+    let request = serde_json::json!({
+        "id": request_id,
+        "method": method,
+        "params": params,
+    });
+    let response = send_request(request.to_string())
+        .await
+        .ok_or_else(|| MlsBridgeError::signer("the NIP-46 remote signer did not answer"))?;
+
+    let response: serde_json::Value =
+        serde_json::from_str(&response).map_err(MlsBridgeError::signer)?;
+
+    if response["id"].as_str() != Some(request_id.as_str()) {
+        return Err(MlsBridgeError::signer("NIP-46 response id mismatch"));
+    }
+    if let Some(error) = response["error"].as_str().filter(|error| !error.is_empty()) {
+        return Err(MlsBridgeError::signer(error));
+    }
+
+    response["result"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| MlsBridgeError::signer("NIP-46 response has no result"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A Dart signer answering `sign_event` with the event `sign` returns
+    fn dart_signer(
+        public_key: PublicKey,
+        sign: impl Fn(UnsignedEvent) -> Event + Send + Sync + 'static,
+    ) -> Signer {
+        Signer::Dart {
+            public_key,
+            sign_event: Box::new(move |json: String| -> DartFnFuture<Option<String>> {
+                let signed = sign(UnsignedEvent::from_json(json).expect("parse unsigned event"));
+                Box::pin(async move { Some(signed.as_json()) })
+            }),
+            nip44_encrypt: Box::new(no_answer),
+            nip44_decrypt: Box::new(no_answer),
+        }
+    }
+
+    fn no_answer(_: String, _: String) -> DartFnFuture<Option<String>> {
+        Box::pin(async { None })
+    }
+
+    fn unsigned(keys: &Keys) -> UnsignedEvent {
+        EventBuilder::new(Kind::TextNote, "to sign").build(keys.public_key())
+    }
+
+    #[tokio::test]
+    async fn signs_the_requested_event() {
+        let keys = Keys::generate();
+        let signing_keys = keys.clone();
+        let signer = dart_signer(keys.public_key(), move |unsigned| {
+            unsigned.sign_with_keys(&signing_keys).expect("sign")
+        });
+
+        let signed = signer
+            .sign_event(unsigned(&keys))
+            .await
+            .expect("sign event");
+        assert_eq!(signed.pubkey, keys.public_key());
+        assert_eq!(signed.content, "to sign");
+    }
+
+    #[tokio::test]
+    async fn rejects_an_event_signed_by_another_key() {
+        let keys = Keys::generate();
+        let signer = dart_signer(keys.public_key(), |unsigned| {
+            let other = Keys::generate();
+            EventBuilder::new(unsigned.kind, unsigned.content)
+                .sign_with_keys(&other)
+                .expect("sign")
+        });
+
+        assert!(matches!(
+            signer.sign_event(unsigned(&keys)).await,
+            Err(MlsBridgeError::Signer { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejects_a_signed_event_with_another_id() {
+        let keys = Keys::generate();
+        let signing_keys = keys.clone();
+        let signer = dart_signer(keys.public_key(), move |unsigned| {
+            EventBuilder::new(unsigned.kind, "something else")
+                .sign_with_keys(&signing_keys)
+                .expect("sign")
+        });
+
+        assert!(matches!(
+            signer.sign_event(unsigned(&keys)).await,
+            Err(MlsBridgeError::Signer { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejects_an_event_of_another_author_before_signing() {
+        let keys = Keys::generate();
+        let signer = dart_signer(keys.public_key(), |_| panic!("must not be asked to sign"));
+
+        assert!(matches!(
+            signer.sign_event(unsigned(&Keys::generate())).await,
+            Err(MlsBridgeError::Signer { .. })
+        ));
+    }
+}
@@ -0,0 +1,301 @@
+use flutter_rust_bridge::DartFnFuture;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+use zeroize::Zeroizing;
+
+use crate::api::error::MlsBridgeError;
+use crate::api::mls_api::open_identity;
+use crate::key_provider::{
+    init_nostr_mls_with_key_provider, install_database_key, new_database_key, read_wrapped_key,
+    unwrapped_key_password, FileKeyProvider, KeyProvider, PassphraseKeyProvider,
+};
+use crate::registry;
+use crate::store;
+
+/// How long storage changes wait for the calls still running on a closed identity
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Where the key wrapping an identity's database key comes from
+/// The database key itself is random and never leaves Rust. A platform keystore wraps it
+/// through `init_nostr_mls_with_keystore` instead.
+/// No `Debug`, so a passphrase never ends up in a log.
+#[derive(Clone)]
+pub enum StorageKeySource {
+    /// Derived from a passphrase with Argon2id, for platforms without a keystore
+    Passphrase { passphrase: String },
+    /// Read from a file, created on first use; for tests and development only
+    KeyFile { path: String },
+}
+
+/// Initialize the NostrMls instance of an identity with a database key unwrapped in Rust
+/// On first use a random key is created, and an existing unencrypted database is encrypted
+/// with it. A database encrypted with a password must be decrypted with `rekey_storage` first.
+/// Parameters: path - directory of the database, identity - key used by every other call,
+/// source - what unwraps the database key
+pub fn init_nostr_mls_with_key_source(
+    path: String,
+    identity: String,
+    source: StorageKeySource,
+) -> Result<(), MlsBridgeError> {
+    let provider: Box<dyn KeyProvider> = match source {
+        StorageKeySource::Passphrase { passphrase } => {
+            if passphrase.is_empty() {
+                return Err(MlsBridgeError::invalid_input(
+                    "passphrase",
+                    "must not be empty",
+                ));
+            }
+            Box::new(PassphraseKeyProvider::new(passphrase))
+        }
+        StorageKeySource::KeyFile { path } => Box::new(FileKeyProvider::new(path)),
+    };
+
+    init_nostr_mls_with_key_provider(path, identity, provider.as_ref())
+}
+
+/// Initialize the NostrMls instance of an identity with a database key wrapped by the platform
+/// keystore (Android Keystore, iOS Keychain), so the key-encryption key never leaves it
+/// On first use a random key is created and wrapped, and an existing unencrypted database is
+/// encrypted with it. A database encrypted with a password must be decrypted with
+/// `rekey_storage` first.
+/// Parameters: path - directory of the database, identity - key used by every other call,
+/// wrap_key - takes the identity and the database key and returns the blob to store, or `null`
+/// on failure, unwrap_key - takes the identity and the stored blob and returns the database
+/// key, or `null` if the keystore cannot unwrap it
+pub async fn init_nostr_mls_with_keystore(
+    path: String,
+    identity: String,
+    wrap_key: impl Fn(String, Vec<u8>) -> DartFnFuture<Option<Vec<u8>>> + Send + Sync + 'static,
+    unwrap_key: impl Fn(String, Vec<u8>) -> DartFnFuture<Option<Vec<u8>>> + Send + Sync + 'static,
+) -> Result<(), MlsBridgeError> {
+    let db_path = db_path(&path, &identity);
+
+    let password = match read_wrapped_key(&db_path)? {
+        Some(wrapped) => {
+            let key = unwrap_key(identity.clone(), wrapped)
+                .await
+                .map(Zeroizing::new)
+                .ok_or_else(|| MlsBridgeError::StorageLocked {
+                    message: "the keystore does not unwrap the database key".to_string(),
+                })?;
+            unwrapped_key_password(&key)?
+        }
+        None => {
+            // An existing unencrypted database is migrated, which needs it closed
+            ensure_closed(&identity)?;
+            let key = new_database_key(&db_path)?;
+            let wrapped = wrap_key(identity.clone(), key.to_vec())
+                .await
+                .ok_or_else(|| MlsBridgeError::Storage {
+                    message: "the keystore did not wrap the database key".to_string(),
+                })?;
+            install_database_key(&db_path, &key, &wrapped)?
+        }
+    };
+
+    open_identity(&db_path, identity, Some(password.as_str()))
+}
+
+/// Change or remove the password of an identity's database
+/// The identity must be closed, and is opened again with `init_nostr_mls` and the new password.
+/// Parameters: path - directory of the database, old_password - the current password,
+/// new_password - `None` to store the database unencrypted
+pub fn rekey_storage(
+    path: String,
+    identity: String,
+    old_password: String,
+    new_password: Option<String>,
+) -> Result<(), MlsBridgeError> {
+    ensure_closed(&identity)?;
+    if new_password.as_deref() == Some("") {
+        return Err(MlsBridgeError::invalid_input(
+            "new_password",
+            "must not be empty",
+        ));
+    }
+
+    store::reencrypt(
+        &db_path(&path, &identity),
+        Some(&old_password),
+        new_password.as_deref(),
+    )
+}
+
+/// Encrypt the unencrypted database of an identity with a password
+/// The identity must be closed, and is opened again with `init_nostr_mls` and the password.
+/// Parameters: path - directory of the database, password - the new password
+pub fn encrypt_existing_storage(
+    path: String,
+    identity: String,
+    password: String,
+) -> Result<(), MlsBridgeError> {
+    ensure_closed(&identity)?;
+    if password.is_empty() {
+        return Err(MlsBridgeError::invalid_input(
+            "password",
+            "must not be empty",
+        ));
+    }
+
+    store::reencrypt(&db_path(&path, &identity), None, Some(&password))
+}
+
+/// Path of the database of an identity, shared by nostr-mls and the bridge
+pub(crate) fn db_path(path: &str, identity: &str) -> PathBuf {
+    PathBuf::from(path).join(format!("{identity}-mls.db"))
+}
+
+/// nostr-mls keeps the database open with its password as long as the identity is open
+/// Calls started before `close_nostr_mls` still hold the instance, and are waited for.
+pub(crate) fn ensure_closed(identity: &str) -> Result<(), MlsBridgeError> {
+    if registry::get(identity).is_ok() {
+        return Err(MlsBridgeError::invalid_input(
+            "identity",
+            "close the identity with close_nostr_mls first",
+        ));
+    }
+
+    let deadline = Instant::now() + CLOSE_TIMEOUT;
+    while !registry::is_closed(identity) {
+        if Instant::now() >= deadline {
+            return Err(MlsBridgeError::invalid_input(
+                "identity",
+                "calls started before close_nostr_mls are still running",
+            ));
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::mls_api::{close_nostr_mls, get_group, init_nostr_mls};
+    use crate::test_utils::{create_test_group, TestDir, TestIdentity};
+    use nostr_mls::prelude::Keys;
+    use std::sync::mpsc;
+
+    fn open(dir: &TestDir, identity: &str, password: Option<&str>) -> Result<(), MlsBridgeError> {
+        init_nostr_mls(
+            dir.path(),
+            identity.to_string(),
+            password.map(str::to_string),
+        )?;
+        close_nostr_mls(identity.to_string()).expect("close identity");
+        Ok(())
+    }
+
+    #[test]
+    fn rekeyed_storage_opens_only_with_the_new_password() {
+        let dir = TestDir::new();
+        let identity = Keys::generate().public_key().to_hex();
+        open(&dir, &identity, Some("old password")).expect("create encrypted database");
+
+        rekey_storage(
+            dir.path(),
+            identity.clone(),
+            "old password".to_string(),
+            Some("new password".to_string()),
+        )
+        .expect("rekey storage");
+        assert!(matches!(
+            open(&dir, &identity, Some("old password")),
+            Err(MlsBridgeError::StorageLocked { .. })
+        ));
+        open(&dir, &identity, Some("new password")).expect("open with the new password");
+
+        assert!(matches!(
+            rekey_storage(
+                dir.path(),
+                identity.clone(),
+                "old password".to_string(),
+                None,
+            ),
+            Err(MlsBridgeError::StorageLocked { .. })
+        ));
+        rekey_storage(
+            dir.path(),
+            identity.clone(),
+            "new password".to_string(),
+            None,
+        )
+        .expect("decrypt storage");
+        open(&dir, &identity, None).expect("open unencrypted");
+    }
+
+    #[test]
+    fn existing_storage_is_encrypted_with_its_data() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let group = create_test_group(&alice, &[&bob]);
+        close_nostr_mls(bob.identity.clone()).expect("close bob");
+
+        encrypt_existing_storage(dir.path(), bob.identity.clone(), "password".to_string())
+            .expect("encrypt storage");
+        assert!(matches!(
+            init_nostr_mls(dir.path(), bob.identity.clone(), None),
+            Err(MlsBridgeError::StorageLocked { .. })
+        ));
+        assert!(matches!(
+            init_nostr_mls(dir.path(), bob.identity.clone(), Some("wrong".to_string())),
+            Err(MlsBridgeError::StorageLocked { .. })
+        ));
+
+        init_nostr_mls(
+            dir.path(),
+            bob.identity.clone(),
+            Some("password".to_string()),
+        )
+        .expect("open encrypted storage");
+        let joined =
+            get_group(bob.identity.clone(), group.mls_group_id.clone()).expect("group kept");
+        assert_eq!(joined.members.len(), 2);
+    }
+
+    #[test]
+    fn open_identity_is_not_rekeyed() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+
+        assert!(matches!(
+            encrypt_existing_storage(dir.path(), alice.identity.clone(), "password".to_string()),
+            Err(MlsBridgeError::InvalidInput { .. })
+        ));
+    }
+
+    #[test]
+    fn rekey_waits_for_calls_holding_the_closed_identity() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        // Stands in for a call started before the identity was closed
+        let running = alice.instance();
+        close_nostr_mls(alice.identity.clone()).expect("close identity");
+
+        let (done, finished) = mpsc::channel();
+        let path = dir.path();
+        let identity = alice.identity.clone();
+        thread::spawn(move || {
+            let _ = done.send(encrypt_existing_storage(
+                path,
+                identity,
+                "password".to_string(),
+            ));
+        });
+
+        assert!(finished.recv_timeout(Duration::from_millis(200)).is_err());
+        drop(running);
+        finished
+            .recv_timeout(Duration::from_secs(10))
+            .expect("rekey finishes once the call is done")
+            .expect("encrypt storage");
+        init_nostr_mls(
+            dir.path(),
+            alice.identity.clone(),
+            Some("password".to_string()),
+        )
+        .expect("open encrypted storage");
+    }
+}
@@ -0,0 +1,252 @@
+use nostr_mls::prelude::*;
+
+/// Nostr specific group data carried in the NostrGroupData extension
+#[derive(Debug, Clone, PartialEq)]
+pub struct NostrGroupData {
+    /// Hex encoded nostr group id, used in the `h` tag of kind-445 events
+    pub nostr_group_id: String,
+    pub name: String,
+    pub description: String,
+    /// Hex encoded admin public keys
+    pub admin_pubkeys: Vec<String>,
+    pub relays: Vec<String>,
+}
+
+/// Group information returned by group creation, lookup and join calls
+#[derive(Debug, Clone)]
+pub struct GroupInfo {
+    pub mls_group_id: Vec<u8>,
+    /// Hex encoded member public keys
+    pub members: Vec<String>,
+    pub nostr_group_data: NostrGroupData,
+}
+
+/// Result of `create_group`
+#[derive(Debug, Clone)]
+pub struct CreateGroupResult {
+    pub group: GroupInfo,
+    pub serialized_welcome_message: Vec<u8>,
+}
+
+/// Encoded key package and the tags to publish with its kind-443 event
+#[derive(Debug, Clone)]
+pub struct KeyPackageResult {
+    pub encoded_key_package: String,
+    pub tags: Vec<Vec<String>>,
+}
+
+/// A signed nostr event ready to be published
+#[derive(Debug, Clone)]
+pub struct EventResult {
+    /// Hex encoded event id
+    pub event_id: String,
+    /// JSON serialized event
+    pub event_json: String,
+}
+
+/// Exporter secret of a group at a given epoch
+#[derive(Debug, Clone)]
+pub struct ExportedSecret {
+    pub secret: Vec<u8>,
+    pub epoch: u64,
+}
+
+/// A decrypted application message (the inner rumor of a kind-445 event)
+#[derive(Debug, Clone)]
+pub struct DecryptedMessage {
+    /// Hex encoded rumor id
+    pub event_id: String,
+    /// Hex encoded author public key
+    pub pubkey: String,
+    pub kind: u16,
+    pub mls_group_id: Vec<u8>,
+    pub created_at: u64,
+    pub content: String,
+    pub tags: Vec<Vec<String>>,
+    /// JSON serialized rumor
+    pub rumor_json: String,
+}
+
+/// Result of processing a kind-445 group event or a staged commit
+#[derive(Debug, Clone, Default)]
+pub struct ProcessedMessage {
+    pub message: Option<DecryptedMessage>,
+    pub added_members: Vec<String>,
+    pub removed_members: Vec<String>,
+    pub commit: Option<Vec<u8>>,
+    pub welcome: Option<Vec<u8>>,
+    pub staged_message_bytes: Option<Vec<u8>>,
+    /// The event is for an epoch not reached yet and was buffered for a later retry
+    pub pending: bool,
+    /// Previously buffered messages decrypted after this commit advanced the group
+    pub released_messages: Vec<DecryptedMessage>,
+    /// The commit lost the race for its epoch to an earlier one and was not applied
+    pub superseded: bool,
+    /// Our own pending commit of the same epoch lost to this commit and was discarded
+    pub own_commit_rolled_back: bool,
+}
+
+/// Which groups `list_groups` returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupStateFilter {
+    All,
+    Active,
+    /// Groups we left or were removed from
+    Left,
+    Pending,
+}
+
+/// State of a locally stored group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupStatus {
+    Active,
+    Left,
+    Pending,
+}
+
+/// Summary of a locally stored group
+#[derive(Debug, Clone)]
+pub struct GroupSummary {
+    pub mls_group_id: Vec<u8>,
+    /// Hex encoded nostr group id
+    pub nostr_group_id: String,
+    pub name: String,
+    pub description: String,
+    pub epoch: u64,
+    pub member_count: u32,
+    /// Hex encoded id of the last message
+    pub last_message_id: Option<String>,
+    pub last_message_at: Option<u64>,
+    pub status: GroupStatus,
+}
+
+/// Summary of a welcome stored locally
+#[derive(Debug, Clone)]
+pub struct WelcomeSummary {
+    /// Hex encoded id of the kind-444 welcome rumor
+    pub welcome_event_id: String,
+    /// Hex encoded id of the gift wrap carrying the welcome
+    pub wrapper_event_id: String,
+    pub mls_group_id: Vec<u8>,
+    pub nostr_group_data: NostrGroupData,
+    /// Hex encoded public key of the member who sent the welcome
+    pub welcomer: String,
+    pub member_count: u32,
+}
+
+/// Result of `add_members` and `readd_member`
+/// Committing received proposals with `commit_pending_proposals` returns a `ProposalCommitResult`.
+#[derive(Debug, Clone)]
+pub struct AddMembersResult {
+    pub commit_message: Vec<u8>,
+    pub welcome_message: Vec<u8>,
+}
+
+/// Result of `remove_members` and `leave_group`
+#[derive(Debug, Clone)]
+pub struct CommitResult {
+    pub serialized_commit: Vec<u8>,
+}
+
+/// Result of `get_key_package_from_storage`
+#[derive(Debug, Clone)]
+pub struct KeyPackageLookup {
+    pub found: bool,
+    pub key_package: Option<String>,
+}
+
+/// Result of `find_encoded_keypackage_from_welcome_event`
+#[derive(Debug, Clone)]
+pub struct WelcomeKeyPackageMatch {
+    pub found: bool,
+    pub matched_index: Option<u32>,
+}
+
+impl NostrGroupData {
+    pub(crate) fn new<'a>(
+        nostr_group_id: &[u8],
+        name: String,
+        description: String,
+        admin_pubkeys: impl IntoIterator<Item = &'a PublicKey>,
+        relays: impl IntoIterator<Item = &'a RelayUrl>,
+    ) -> Self {
+        Self {
+            nostr_group_id: hex::encode(nostr_group_id),
+            name,
+            description,
+            admin_pubkeys: admin_pubkeys.into_iter().map(|pk| pk.to_string()).collect(),
+            relays: relays.into_iter().map(|r| r.to_string()).collect(),
+        }
+    }
+}
+
+impl From<Message> for DecryptedMessage {
+    fn from(message: Message) -> Self {
+        Self {
+            event_id: message.id.to_hex(),
+            pubkey: message.pubkey.to_string(),
+            kind: message.kind.as_u16(),
+            mls_group_id: message.mls_group_id.to_vec(),
+            created_at: message.created_at.as_u64(),
+            content: message.content,
+            tags: message.tags.into_iter().map(|tag| tag.to_vec()).collect(),
+            rumor_json: message.event.as_json(),
+        }
+    }
+}
+
+impl GroupStateFilter {
+    pub(crate) fn matches(&self, status: GroupStatus) -> bool {
+        match self {
+            Self::All => true,
+            Self::Active => status == GroupStatus::Active,
+            Self::Left => status == GroupStatus::Left,
+            Self::Pending => status == GroupStatus::Pending,
+        }
+    }
+}
+
+impl From<&GroupState> for GroupStatus {
+    fn from(state: &GroupState) -> Self {
+        match state {
+            GroupState::Active => Self::Active,
+            GroupState::Inactive => Self::Left,
+            GroupState::Pending => Self::Pending,
+        }
+    }
+}
+
+impl GroupSummary {
+    pub(crate) fn new(group: Group, member_count: u32) -> Self {
+        Self {
+            mls_group_id: group.mls_group_id.to_vec(),
+            nostr_group_id: hex::encode(group.nostr_group_id),
+            status: GroupStatus::from(&group.state),
+            name: group.name,
+            description: group.description,
+            epoch: group.epoch,
+            member_count,
+            last_message_id: group.last_message_id.map(|id| id.to_hex()),
+            last_message_at: group.last_message_at.map(|at| at.as_u64()),
+        }
+    }
+}
+
+impl From<Welcome> for WelcomeSummary {
+    fn from(welcome: Welcome) -> Self {
+        Self {
+            welcome_event_id: welcome.id.to_hex(),
+            wrapper_event_id: welcome.wrapper_event_id.to_hex(),
+            mls_group_id: welcome.mls_group_id.to_vec(),
+            nostr_group_data: NostrGroupData::new(
+                &welcome.nostr_group_id,
+                welcome.group_name,
+                welcome.group_description,
+                &welcome.group_admin_pubkeys,
+                &welcome.group_relays,
+            ),
+            welcomer: welcome.welcomer.to_string(),
+            member_count: welcome.member_count,
+        }
+    }
+}
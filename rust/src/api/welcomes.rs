@@ -0,0 +1,941 @@
+use nostr_mls::prelude::*;
+use rand::Rng;
+use std::str::FromStr;
+
+use crate::api::commits::delete_unused_signature_key;
+use crate::api::error::MlsBridgeError;
+use crate::api::key_packages::{
+    key_package_for_welcome, retire_unused_key_package, rotate_declined_key_package,
+};
+use crate::api::mls_api::{
+    group_info, join_group_from_welcome, parse_relays, preview_group_from_welcome,
+};
+use crate::api::types::{EventResult, GroupInfo, NostrGroupData};
+use crate::mls;
+use crate::registry::{self, MlsInstance};
+use crate::store::StoredWelcome;
+
+/// Seals and gift wraps are backdated by up to two days so their time does not leak the rumor's
+const MAX_TIMESTAMP_TWEAK_SECS: u64 = 2 * 24 * 60 * 60;
+
+const WELCOME_PENDING: &str = "pending";
+pub(crate) const WELCOME_ACCEPTED: &str = "accepted";
+const WELCOME_DECLINED: &str = "declined";
+
+/// A member invited by `create_group` or `add_members`
+#[derive(Debug, Clone)]
+pub struct WelcomeRecipient {
+    /// Hex encoded public key of the member
+    pub public_key: String,
+    /// Hex encoded id of the kind-443 event of the key package the member was added with
+    pub key_package_event_id: String,
+}
+
+/// A kind-1059 gift wrap carrying a welcome to one member
+#[derive(Debug, Clone)]
+pub struct GiftWrappedWelcome {
+    /// Hex encoded public key of the member
+    pub recipient: String,
+    pub event: EventResult,
+}
+
+/// The kind-444 welcome rumor taken out of a gift wrap
+#[derive(Debug, Clone)]
+pub struct UnwrappedWelcome {
+    /// Hex encoded id of the gift wrap
+    pub wrapper_event_id: String,
+    /// Hex encoded public key of the member who sent the welcome
+    pub sender: String,
+    /// JSON serialized kind-444 rumor
+    pub rumor_json: String,
+}
+
+/// State of a welcome in the inbox
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WelcomeState {
+    Pending,
+    Accepted,
+    Declined,
+}
+
+/// Which welcomes `list_welcomes` returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WelcomeStateFilter {
+    All,
+    Pending,
+    Accepted,
+    Declined,
+}
+
+/// A welcome kept in the inbox
+#[derive(Debug, Clone)]
+pub struct InboxWelcome {
+    /// Hex encoded id of the gift wrap carrying the welcome
+    pub id: String,
+    /// Hex encoded public key of the member who sent the welcome
+    pub sender: String,
+    /// Group as previewed when the welcome was received
+    pub group: GroupInfo,
+    pub state: WelcomeState,
+    pub received_at: u64,
+}
+
+/// Result of `list_welcomes`
+#[derive(Debug, Clone)]
+pub struct WelcomeList {
+    pub welcomes: Vec<InboxWelcome>,
+    /// Welcomes left out because their stored row cannot be read
+    pub unreadable: u32,
+}
+
+/// Build one NIP-59 gift-wrapped welcome per invited member
+///
+/// The kind-444 rumor is sealed (kind 13) by the identity's signer and wrapped (kind 1059)
+/// with a new random key for each member.
+/// Parameters: serialized_welcome_message - welcome returned by `create_group` or `add_members`,
+/// relays - relays the members should read group messages from
+/// Returns: the gift wraps to publish, in recipient order
+pub async fn create_welcome_gift_wraps(
+    identity: String,
+    serialized_welcome_message: Vec<u8>,
+    recipients: Vec<WelcomeRecipient>,
+    relays: Vec<String>,
+) -> Result<Vec<GiftWrappedWelcome>, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let signer = instance.signer();
+    let sender = signer.public_key()?;
+
+    let relays = parse_relays(Some(relays))?;
+
+    let mut gift_wraps = Vec::with_capacity(recipients.len());
+    for recipient in recipients {
+        let receiver = PublicKey::from_str(&recipient.public_key)
+            .map_err(|e| MlsBridgeError::invalid_input("recipients", e))?;
+        let key_package_event_id = EventId::from_hex(&recipient.key_package_event_id)
+            .map_err(|e| MlsBridgeError::invalid_input("recipients", e))?;
+
+        let mut rumor =
+            EventBuilder::new(Kind::MlsWelcome, hex::encode(&serialized_welcome_message))
+                .tags([
+                    Tag::event(key_package_event_id),
+                    Tag::custom(TagKind::Relays, relays.iter().map(|r| r.to_string())),
+                ])
+                .build(sender);
+        rumor.ensure_id();
+
+        let sealed = signer.nip44_encrypt(&receiver, rumor.as_json()).await?;
+        let seal = EventBuilder::new(Kind::Seal, sealed)
+            .custom_created_at(tweaked_timestamp())
+            .build(sender);
+        let seal = signer.sign_event(seal).await?;
+
+        let wrap_keys = Keys::generate();
+        let wrapped = nip44::encrypt(
+            wrap_keys.secret_key(),
+            &receiver,
+            seal.as_json(),
+            nip44::Version::V2,
+        )
+        .map_err(MlsBridgeError::internal)?;
+        let gift_wrap = EventBuilder::new(Kind::GiftWrap, wrapped)
+            .tag(Tag::public_key(receiver))
+            .custom_created_at(tweaked_timestamp())
+            .sign_with_keys(&wrap_keys)
+            .map_err(MlsBridgeError::internal)?;
+
+        gift_wraps.push(GiftWrappedWelcome {
+            recipient: receiver.to_hex(),
+            event: EventResult {
+                event_id: gift_wrap.id.to_hex(),
+                event_json: gift_wrap.as_json(),
+            },
+        });
+    }
+
+    Ok(gift_wraps)
+}
+
+/// Unwrap an incoming kind-1059 gift wrap into its kind-444 welcome rumor
+/// Parameters: gift_wrap_event_json - JSON string of the gift wrap
+pub async fn unwrap_welcome(
+    identity: String,
+    gift_wrap_event_json: String,
+) -> Result<UnwrappedWelcome, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+    let signer = instance.signer();
+
+    let gift_wrap = Event::from_json(&gift_wrap_event_json)
+        .map_err(|e| MlsBridgeError::invalid_input("gift_wrap_event_json", e))?;
+    gift_wrap
+        .verify()
+        .map_err(|e| MlsBridgeError::invalid_input("gift_wrap_event_json", e))?;
+    if gift_wrap.kind != Kind::GiftWrap {
+        return Err(MlsBridgeError::invalid_input(
+            "gift_wrap_event_json",
+            "not a kind-1059 gift wrap",
+        ));
+    }
+
+    let seal_json = signer
+        .nip44_decrypt(&gift_wrap.pubkey, gift_wrap.content.clone())
+        .await?;
+    let seal =
+        Event::from_json(&seal_json).map_err(|e| MlsBridgeError::invalid_input("seal", e))?;
+    seal.verify()
+        .map_err(|e| MlsBridgeError::invalid_input("seal", e))?;
+    if seal.kind != Kind::Seal {
+        return Err(MlsBridgeError::invalid_input("seal", "not a kind-13 seal"));
+    }
+
+    let rumor_json = signer
+        .nip44_decrypt(&seal.pubkey, seal.content.clone())
+        .await?;
+    let rumor = UnsignedEvent::from_json(&rumor_json)
+        .map_err(|e| MlsBridgeError::invalid_input("rumor", e))?;
+    if rumor.pubkey != seal.pubkey {
+        return Err(MlsBridgeError::invalid_input(
+            "rumor",
+            "author differs from the seal's signer",
+        ));
+    }
+    if rumor.kind != Kind::MlsWelcome {
+        return Err(MlsBridgeError::invalid_input(
+            "rumor",
+            "not a kind-444 welcome",
+        ));
+    }
+
+    Ok(UnwrappedWelcome {
+        wrapper_event_id: gift_wrap.id.to_hex(),
+        sender: seal.pubkey.to_hex(),
+        rumor_json: rumor.as_json(),
+    })
+}
+
+/// Preview the group of a gift-wrapped welcome without joining it
+/// Parameters: gift_wrap_event_json - JSON string of the kind-1059 gift wrap
+pub async fn preview_gift_wrapped_welcome(
+    identity: String,
+    gift_wrap_event_json: String,
+) -> Result<GroupInfo, MlsBridgeError> {
+    let welcome = unwrap_welcome(identity.clone(), gift_wrap_event_json).await?;
+    let wrapper_event_id =
+        hex::decode(&welcome.wrapper_event_id).map_err(MlsBridgeError::internal)?;
+
+    preview_group_from_welcome(identity, wrapper_event_id, welcome.rumor_json)
+}
+
+/// Join the group of a gift-wrapped welcome
+/// Parameters: gift_wrap_event_json - JSON string of the kind-1059 gift wrap
+pub async fn join_gift_wrapped_welcome(
+    identity: String,
+    gift_wrap_event_json: String,
+) -> Result<GroupInfo, MlsBridgeError> {
+    let welcome = unwrap_welcome(identity.clone(), gift_wrap_event_json).await?;
+    let wrapper_event_id =
+        hex::decode(&welcome.wrapper_event_id).map_err(MlsBridgeError::internal)?;
+
+    join_group_from_welcome(identity, wrapper_event_id, welcome.rumor_json)
+}
+
+/// Put a welcome in the inbox with a preview of its group
+/// Receiving a welcome already in the inbox returns it unchanged
+/// Parameters: wrapper_event_id - byte array of event ID, rumor_event_string - JSON string of the event
+pub fn receive_welcome(
+    identity: String,
+    wrapper_event_id: Vec<u8>,
+    rumor_event_string: String,
+) -> Result<InboxWelcome, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let event_id = EventId::from_slice(&wrapper_event_id)
+        .map_err(|e| MlsBridgeError::invalid_input("wrapper_event_id", e))?;
+    if let Some(welcome) = instance.store.welcome(&event_id.to_hex())? {
+        return InboxWelcome::try_from(welcome);
+    }
+
+    let rumor_event = UnsignedEvent::from_json(&rumor_event_string)
+        .map_err(|e| MlsBridgeError::invalid_input("rumor_event_string", e))?;
+
+    let group = preview_group_from_welcome(identity, wrapper_event_id, rumor_event_string)?;
+    let group_data = &group.nostr_group_data;
+
+    instance.store.save_welcome(&StoredWelcome {
+        id: event_id.to_hex(),
+        rumor_json: rumor_event.as_json(),
+        sender: rumor_event.pubkey.to_hex(),
+        mls_group_id: group.mls_group_id.clone(),
+        nostr_group_id: group_data.nostr_group_id.clone(),
+        group_name: group_data.name.clone(),
+        group_description: group_data.description.clone(),
+        admin_pubkeys_json: to_json(&group_data.admin_pubkeys)?,
+        relays_json: to_json(&group_data.relays)?,
+        members_json: to_json(&group.members)?,
+        state: WELCOME_PENDING.to_string(),
+        received_at: Timestamp::now().as_u64(),
+    })?;
+
+    let welcome = instance
+        .store
+        .welcome(&event_id.to_hex())?
+        .ok_or_else(|| MlsBridgeError::internal("welcome missing after insert"))?;
+    InboxWelcome::try_from(welcome)
+}
+
+/// Unwrap a kind-1059 gift wrap and put its welcome in the inbox
+/// Parameters: gift_wrap_event_json - JSON string of the gift wrap
+pub async fn receive_gift_wrapped_welcome(
+    identity: String,
+    gift_wrap_event_json: String,
+) -> Result<InboxWelcome, MlsBridgeError> {
+    let welcome = unwrap_welcome(identity.clone(), gift_wrap_event_json).await?;
+    let wrapper_event_id =
+        hex::decode(&welcome.wrapper_event_id).map_err(MlsBridgeError::internal)?;
+
+    receive_welcome(identity, wrapper_event_id, welcome.rumor_json)
+}
+
+/// Join the group of a welcome in the inbox
+/// Parameters: id - id of the `InboxWelcome`
+/// Returns: information about the joined group
+pub fn accept_welcome(identity: String, id: String) -> Result<GroupInfo, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let welcome = instance
+        .store
+        .welcome(&id)?
+        .ok_or_else(|| MlsBridgeError::invalid_input("id", "welcome is not in the inbox"))?;
+
+    match welcome.state.as_str() {
+        WELCOME_ACCEPTED => group_info(
+            &instance.nostr_mls,
+            &GroupId::from_slice(&welcome.mls_group_id),
+        ),
+        WELCOME_DECLINED => Err(MlsBridgeError::invalid_input("id", "welcome was declined")),
+        _ => {
+            let wrapper_event_id = hex::decode(&welcome.id).map_err(MlsBridgeError::internal)?;
+            join_group_from_welcome(identity, wrapper_event_id, welcome.rumor_json)
+        }
+    }
+}
+
+/// Decline a welcome in the inbox
+/// A one-time managed key package it was sent to, or one made with
+/// `create_key_package_for_event`, loses its private material unless another pending welcome was
+/// sent to it too; a last resort one is rotated by the next `maintain_key_packages`.
+/// Parameters: id - id of the `InboxWelcome`
+/// Returns: whether the welcome is in the inbox
+pub fn decline_welcome(identity: String, id: String) -> Result<bool, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let Some(welcome) = instance.store.welcome(&id)? else {
+        return Ok(false);
+    };
+    if welcome.state == WELCOME_ACCEPTED {
+        return Err(MlsBridgeError::invalid_input(
+            "id",
+            "welcome was already accepted",
+        ));
+    }
+
+    let event_id = EventId::from_hex(&welcome.id).map_err(MlsBridgeError::internal)?;
+    let rumor_event =
+        UnsignedEvent::from_json(&welcome.rumor_json).map_err(MlsBridgeError::internal)?;
+    match key_package_for_welcome(&instance, &event_id, &rumor_event)? {
+        Some(key_package) if key_package.last_resort => {
+            rotate_declined_key_package(&instance, key_package)?;
+        }
+        Some(key_package) => {
+            if !pending_welcome_uses_key_package(&instance, &id, &key_package.id)? {
+                retire_unused_key_package(&instance, &key_package.id)?;
+            }
+        }
+        None => delete_unmanaged_key_packages(&instance, &id, &rumor_event)?,
+    }
+
+    instance.store.set_welcome_state(&id, WELCOME_DECLINED)
+}
+
+/// Whether a pending welcome of the inbox other than `declined_id` was sent to a managed key
+/// package
+fn pending_welcome_uses_key_package(
+    instance: &MlsInstance,
+    declined_id: &str,
+    key_package_id: &str,
+) -> Result<bool, MlsBridgeError> {
+    for welcome in instance.store.welcomes()? {
+        // Unreadable welcomes cannot be accepted either
+        let Ok(welcome) = welcome else {
+            continue;
+        };
+        if welcome.id == declined_id || welcome.state != WELCOME_PENDING {
+            continue;
+        }
+        let (Ok(event_id), Ok(rumor_event)) = (
+            EventId::from_hex(&welcome.id),
+            UnsignedEvent::from_json(&welcome.rumor_json),
+        ) else {
+            continue;
+        };
+        let used = key_package_for_welcome(instance, &event_id, &rumor_event)
+            .ok()
+            .flatten()
+            .is_some_and(|key_package| key_package.id == key_package_id);
+        if used {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Delete the private material of the unmanaged key packages a declined welcome was sent to
+/// that no other pending welcome of the inbox was sent to, with their signing keys unless still
+/// used
+fn delete_unmanaged_key_packages(
+    instance: &MlsInstance,
+    declined_id: &str,
+    rumor_event: &UnsignedEvent,
+) -> Result<(), MlsBridgeError> {
+    let mut pending_refs = Vec::new();
+    for welcome in instance.store.welcomes()? {
+        // Unreadable welcomes cannot be accepted either
+        let Ok(welcome) = welcome else {
+            continue;
+        };
+        if welcome.id == declined_id || welcome.state != WELCOME_PENDING {
+            continue;
+        }
+        let refs = UnsignedEvent::from_json(&welcome.rumor_json)
+            .ok()
+            .and_then(|rumor_event| mls::welcome_key_package_refs(&rumor_event).ok());
+        pending_refs.extend(refs.unwrap_or_default());
+    }
+
+    let nostr_mls = &instance.nostr_mls;
+    for key_package_ref in mls::welcome_key_package_refs(rumor_event)? {
+        if pending_refs.contains(&key_package_ref) {
+            continue;
+        }
+        let Some(bundle) = mls::key_package_bundle(nostr_mls, &key_package_ref)? else {
+            continue;
+        };
+        let key_package = bundle.key_package();
+        nostr_mls.delete_key_package_from_storage(key_package)?;
+        delete_unused_signature_key(
+            instance,
+            None,
+            key_package.leaf_node().signature_key().as_slice(),
+            key_package.ciphersuite().signature_algorithm(),
+        )?;
+    }
+    Ok(())
+}
+
+/// List the welcomes of the inbox, newest first
+/// Welcomes whose stored row cannot be read are skipped and counted in `unreadable`
+pub fn list_welcomes(
+    identity: String,
+    filter: WelcomeStateFilter,
+) -> Result<WelcomeList, MlsBridgeError> {
+    let instance = registry::get(&identity)?;
+
+    let mut list = WelcomeList {
+        welcomes: Vec::new(),
+        unreadable: 0,
+    };
+    for welcome in instance.store.welcomes()? {
+        match welcome.and_then(InboxWelcome::try_from) {
+            Ok(welcome) if filter.matches(welcome.state) => list.welcomes.push(welcome),
+            Ok(_) => {}
+            Err(_) => list.unreadable += 1,
+        }
+    }
+
+    Ok(list)
+}
+
+impl WelcomeStateFilter {
+    fn matches(&self, state: WelcomeState) -> bool {
+        match self {
+            Self::All => true,
+            Self::Pending => state == WelcomeState::Pending,
+            Self::Accepted => state == WelcomeState::Accepted,
+            Self::Declined => state == WelcomeState::Declined,
+        }
+    }
+}
+
+impl TryFrom<StoredWelcome> for InboxWelcome {
+    type Error = MlsBridgeError;
+
+    fn try_from(welcome: StoredWelcome) -> Result<Self, Self::Error> {
+        let state = match welcome.state.as_str() {
+            WELCOME_PENDING => WelcomeState::Pending,
+            WELCOME_ACCEPTED => WelcomeState::Accepted,
+            WELCOME_DECLINED => WelcomeState::Declined,
+            state => {
+                return Err(MlsBridgeError::internal(format!(
+                    "unknown welcome state {state}"
+                )))
+            }
+        };
+
+        Ok(Self {
+            id: welcome.id,
+            sender: welcome.sender,
+            group: GroupInfo {
+                mls_group_id: welcome.mls_group_id,
+                members: from_json(&welcome.members_json)?,
+                nostr_group_data: NostrGroupData {
+                    nostr_group_id: welcome.nostr_group_id,
+                    name: welcome.group_name,
+                    description: welcome.group_description,
+                    admin_pubkeys: from_json(&welcome.admin_pubkeys_json)?,
+                    relays: from_json(&welcome.relays_json)?,
+                },
+            },
+            state,
+            received_at: welcome.received_at,
+        })
+    }
+}
+
+fn to_json(values: &[String]) -> Result<String, MlsBridgeError> {
+    serde_json::to_string(values).map_err(MlsBridgeError::internal)
+}
+
+fn from_json(json: &str) -> Result<Vec<String>, MlsBridgeError> {
+    serde_json::from_str(json).map_err(MlsBridgeError::internal)
+}
+
+fn tweaked_timestamp() -> Timestamp {
+    let tweak = rand::thread_rng().gen_range(0..MAX_TIMESTAMP_TWEAK_SECS);
+    Timestamp::from(Timestamp::now().as_u64().saturating_sub(tweak))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::key_packages::{
+        list_key_packages, maintain_key_packages, mark_key_package_published, KeyPackagePolicy,
+        KeyPackageState, ManagedKeyPackage,
+    };
+    use crate::api::mls_api::{
+        create_group, create_key_package_for_event, get_key_package_from_storage,
+    };
+    use crate::api::signer::SignerScope;
+    use crate::test_utils::{use_test_signer, TestDir, TestIdentity, TEST_RELAY};
+
+    fn key_package(member: &TestIdentity) -> String {
+        create_key_package_for_event(member.identity.clone(), member.identity.clone(), None, None)
+            .expect("create key package")
+            .encoded_key_package
+    }
+
+    /// Create a group of `admin` inviting `member` with a new key package
+    /// Returns: the group id and the serialized welcome
+    fn invite(admin: &TestIdentity, member: &TestIdentity) -> (Vec<u8>, Vec<u8>) {
+        invite_with(admin, member, key_package(member))
+    }
+
+    /// Create a group of `admin` inviting `member` with one of its key packages
+    fn invite_with(
+        admin: &TestIdentity,
+        member: &TestIdentity,
+        key_package: String,
+    ) -> (Vec<u8>, Vec<u8>) {
+        let created = create_group(
+            admin.identity.clone(),
+            "test group".to_string(),
+            "group of a test".to_string(),
+            vec![key_package],
+            vec![member.identity.clone()],
+            admin.identity.clone(),
+            vec![admin.identity.clone()],
+            vec![TEST_RELAY.to_string()],
+        )
+        .expect("create group");
+        (
+            created.group.mls_group_id,
+            created.serialized_welcome_message,
+        )
+    }
+
+    async fn gift_wrap(
+        sender: &TestIdentity,
+        recipient: &TestIdentity,
+        welcome: Vec<u8>,
+    ) -> String {
+        let gift_wraps = create_welcome_gift_wraps(
+            sender.identity.clone(),
+            welcome,
+            vec![WelcomeRecipient {
+                public_key: recipient.identity.clone(),
+                key_package_event_id: EventId::from_byte_array(rand::random()).to_hex(),
+            }],
+            vec![TEST_RELAY.to_string()],
+        )
+        .await
+        .expect("create gift wraps");
+        assert_eq!(gift_wraps.len(), 1);
+        assert_eq!(gift_wraps[0].recipient, recipient.identity);
+        gift_wraps[0].event.event_json.clone()
+    }
+
+    #[tokio::test]
+    async fn gift_wrapped_welcome_round_trips() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        use_test_signer(&alice, SignerScope::Seals);
+        use_test_signer(&bob, SignerScope::Seals);
+        let (group_id, welcome) = invite(&alice, &bob);
+
+        let gift_wrap_json = gift_wrap(&alice, &bob, welcome.clone()).await;
+        let gift_wrap = Event::from_json(&gift_wrap_json).expect("parse gift wrap");
+        assert_eq!(gift_wrap.kind, Kind::GiftWrap);
+        assert_ne!(gift_wrap.pubkey, alice.keys.public_key());
+
+        let unwrapped = unwrap_welcome(bob.identity.clone(), gift_wrap_json.clone())
+            .await
+            .expect("unwrap welcome");
+        assert_eq!(unwrapped.wrapper_event_id, gift_wrap.id.to_hex());
+        assert_eq!(unwrapped.sender, alice.identity);
+        let rumor = UnsignedEvent::from_json(&unwrapped.rumor_json).expect("parse rumor");
+        assert_eq!(rumor.content, hex::encode(&welcome));
+
+        let group = join_gift_wrapped_welcome(bob.identity.clone(), gift_wrap_json)
+            .await
+            .expect("join group");
+        assert_eq!(group.mls_group_id, group_id);
+    }
+
+    #[tokio::test]
+    async fn rumor_of_another_author_than_the_seal_is_rejected() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        use_test_signer(&bob, SignerScope::Seals);
+        let (_, welcome) = invite(&alice, &bob);
+
+        // Alice seals a rumor claiming to be from someone else
+        let rumor = EventBuilder::new(Kind::MlsWelcome, hex::encode(&welcome))
+            .build(Keys::generate().public_key());
+        let sealed = nip44::encrypt(
+            alice.keys.secret_key(),
+            &bob.keys.public_key(),
+            rumor.as_json(),
+            nip44::Version::V2,
+        )
+        .unwrap();
+        let seal = EventBuilder::new(Kind::Seal, sealed)
+            .sign_with_keys(&alice.keys)
+            .unwrap();
+        let wrap_keys = Keys::generate();
+        let wrapped = nip44::encrypt(
+            wrap_keys.secret_key(),
+            &bob.keys.public_key(),
+            seal.as_json(),
+            nip44::Version::V2,
+        )
+        .unwrap();
+        let gift_wrap = EventBuilder::new(Kind::GiftWrap, wrapped)
+            .tag(Tag::public_key(bob.keys.public_key()))
+            .sign_with_keys(&wrap_keys)
+            .unwrap();
+
+        assert!(matches!(
+            unwrap_welcome(bob.identity.clone(), gift_wrap.as_json()).await,
+            Err(MlsBridgeError::InvalidInput { field, .. }) if field == "rumor"
+        ));
+    }
+
+    fn welcome_rumor(sender: &TestIdentity, welcome: &[u8]) -> UnsignedEvent {
+        let mut rumor = EventBuilder::new(Kind::MlsWelcome, hex::encode(welcome))
+            .tags([
+                Tag::event(EventId::from_byte_array(rand::random())),
+                Tag::custom(TagKind::Relays, [TEST_RELAY]),
+            ])
+            .build(sender.keys.public_key());
+        rumor.ensure_id();
+        rumor
+    }
+
+    fn listed_ids(member: &TestIdentity, filter: WelcomeStateFilter) -> Vec<String> {
+        list_welcomes(member.identity.clone(), filter)
+            .expect("list welcomes")
+            .welcomes
+            .into_iter()
+            .map(|welcome| welcome.id)
+            .collect()
+    }
+
+    #[test]
+    fn inbox_welcomes_are_kept_across_reopening() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let (accepted_group_id, accepted) = invite(&alice, &bob);
+        let (_, declined) = invite(&alice, &bob);
+
+        let mut ids = Vec::new();
+        for welcome in [&accepted, &declined] {
+            let wrapper_event_id = rand::random::<[u8; 32]>().to_vec();
+            let rumor = welcome_rumor(&alice, welcome);
+            let received = receive_welcome(
+                bob.identity.clone(),
+                wrapper_event_id.clone(),
+                rumor.as_json(),
+            )
+            .expect("receive welcome");
+            assert_eq!(received.state, WelcomeState::Pending);
+            assert_eq!(received.sender, alice.identity);
+
+            // Receiving it again leaves it as it is
+            let again = receive_welcome(bob.identity.clone(), wrapper_event_id, rumor.as_json())
+                .expect("receive welcome again");
+            assert_eq!(again.id, received.id);
+            ids.push(received.id);
+        }
+
+        bob.reopen(&dir);
+        assert_eq!(listed_ids(&bob, WelcomeStateFilter::Pending).len(), 2);
+
+        let group = accept_welcome(bob.identity.clone(), ids[0].clone()).expect("accept welcome");
+        assert_eq!(group.mls_group_id, accepted_group_id);
+        assert!(decline_welcome(bob.identity.clone(), ids[1].clone()).expect("decline welcome"));
+
+        bob.reopen(&dir);
+        assert_eq!(
+            listed_ids(&bob, WelcomeStateFilter::Accepted),
+            vec![ids[0].clone()]
+        );
+        assert_eq!(
+            listed_ids(&bob, WelcomeStateFilter::Declined),
+            vec![ids[1].clone()]
+        );
+        assert!(listed_ids(&bob, WelcomeStateFilter::Pending).is_empty());
+        assert!(matches!(
+            accept_welcome(bob.identity.clone(), ids[1].clone()),
+            Err(MlsBridgeError::InvalidInput { .. })
+        ));
+        assert!(matches!(
+            decline_welcome(bob.identity.clone(), ids[0].clone()),
+            Err(MlsBridgeError::InvalidInput { .. })
+        ));
+    }
+
+    #[test]
+    fn declined_welcome_leaves_its_key_package_to_other_welcomes() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let key_package = key_package(&bob);
+        let (_, declined) = invite_with(&alice, &bob, key_package.clone());
+        let (accepted_group_id, accepted) = invite_with(&alice, &bob, key_package);
+
+        let ids: Vec<String> = [&declined, &accepted]
+            .into_iter()
+            .map(|welcome| {
+                receive_welcome(
+                    bob.identity.clone(),
+                    rand::random::<[u8; 32]>().to_vec(),
+                    welcome_rumor(&alice, welcome).as_json(),
+                )
+                .expect("receive welcome")
+                .id
+            })
+            .collect();
+
+        assert!(decline_welcome(bob.identity.clone(), ids[0].clone()).expect("decline welcome"));
+        let group = accept_welcome(bob.identity.clone(), ids[1].clone())
+            .expect("accept the other welcome to the key package");
+        assert_eq!(group.mls_group_id, accepted_group_id);
+    }
+
+    /// Create and publish one managed key package of `member`
+    fn managed_key_package(member: &TestIdentity, last_resort: bool) -> ManagedKeyPackage {
+        let rotation = maintain_key_packages(
+            member.identity.clone(),
+            member.identity.clone(),
+            None,
+            None,
+            KeyPackagePolicy {
+                last_resort_count: last_resort as u32,
+                one_time_count: !last_resort as u32,
+                max_age_secs: 3_600,
+            },
+        )
+        .expect("maintain key packages");
+        let key_package = rotation.to_publish[0].clone();
+        mark_key_package_published(
+            member.identity.clone(),
+            key_package.id.clone(),
+            EventId::from_byte_array(rand::random()).to_hex(),
+        )
+        .expect("mark key package published");
+        key_package
+    }
+
+    fn managed_state(member: &TestIdentity, key_package: &ManagedKeyPackage) -> KeyPackageState {
+        list_key_packages(member.identity.clone())
+            .expect("list key packages")
+            .into_iter()
+            .find(|managed| managed.id == key_package.id)
+            .expect("key package is managed")
+            .state
+    }
+
+    fn has_private_material(member: &TestIdentity, key_package: &ManagedKeyPackage) -> bool {
+        get_key_package_from_storage(
+            member.identity.clone(),
+            key_package.encoded_key_package.clone(),
+        )
+        .expect("get key package")
+        .found
+    }
+
+    /// Invite `member` to `count` groups of `admin` with the same key package and put the
+    /// welcomes in the member's inbox
+    fn receive_invites(
+        admin: &TestIdentity,
+        member: &TestIdentity,
+        key_package: &ManagedKeyPackage,
+        count: usize,
+    ) -> Vec<String> {
+        (0..count)
+            .map(|_| {
+                let (_, welcome) =
+                    invite_with(admin, member, key_package.encoded_key_package.clone());
+                receive_welcome(
+                    member.identity.clone(),
+                    rand::random::<[u8; 32]>().to_vec(),
+                    welcome_rumor(admin, &welcome).as_json(),
+                )
+                .expect("receive welcome")
+                .id
+            })
+            .collect()
+    }
+
+    #[test]
+    fn declining_retires_a_one_time_key_package_no_pending_welcome_uses() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let key_package = managed_key_package(&bob, false);
+        let ids = receive_invites(&alice, &bob, &key_package, 2);
+
+        // The other pending welcome still needs the key package
+        assert!(decline_welcome(bob.identity.clone(), ids[0].clone()).expect("decline welcome"));
+        assert_eq!(
+            managed_state(&bob, &key_package),
+            KeyPackageState::Published
+        );
+        assert!(has_private_material(&bob, &key_package));
+
+        assert!(decline_welcome(bob.identity.clone(), ids[1].clone()).expect("decline welcome"));
+        assert_eq!(managed_state(&bob, &key_package), KeyPackageState::Retired);
+        assert!(!has_private_material(&bob, &key_package));
+    }
+
+    #[test]
+    fn declining_marks_a_last_resort_key_package_for_rotation() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let key_package = managed_key_package(&bob, true);
+        let ids = receive_invites(&alice, &bob, &key_package, 1);
+
+        assert!(decline_welcome(bob.identity.clone(), ids[0].clone()).expect("decline welcome"));
+        assert_eq!(managed_state(&bob, &key_package), KeyPackageState::Declined);
+        assert!(has_private_material(&bob, &key_package));
+
+        let rotation = maintain_key_packages(
+            bob.identity.clone(),
+            bob.identity.clone(),
+            None,
+            None,
+            KeyPackagePolicy {
+                last_resort_count: 1,
+                one_time_count: 0,
+                max_age_secs: 3_600,
+            },
+        )
+        .expect("maintain key packages");
+        assert_eq!(rotation.to_delete.len(), 1);
+        assert_eq!(rotation.to_delete[0].id, key_package.id);
+        assert!(!has_private_material(&bob, &key_package));
+    }
+
+    #[test]
+    fn declining_deletes_an_unmanaged_key_package_no_pending_welcome_uses() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let key_package = key_package(&bob);
+        let ids: Vec<String> = (0..2)
+            .map(|_| {
+                let (_, welcome) = invite_with(&alice, &bob, key_package.clone());
+                receive_welcome(
+                    bob.identity.clone(),
+                    rand::random::<[u8; 32]>().to_vec(),
+                    welcome_rumor(&alice, &welcome).as_json(),
+                )
+                .expect("receive welcome")
+                .id
+            })
+            .collect();
+        let stored = || {
+            get_key_package_from_storage(bob.identity.clone(), key_package.clone())
+                .expect("get key package")
+                .found
+        };
+
+        // The other pending welcome still needs the key package
+        assert!(decline_welcome(bob.identity.clone(), ids[0].clone()).expect("decline welcome"));
+        assert!(stored());
+
+        assert!(decline_welcome(bob.identity.clone(), ids[1].clone()).expect("decline welcome"));
+        assert!(!stored());
+    }
+
+    #[test]
+    fn unreadable_welcomes_are_counted_and_skipped() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let (_, welcome) = invite(&alice, &bob);
+        let rumor = welcome_rumor(&alice, &welcome);
+        let received = receive_welcome(
+            bob.identity.clone(),
+            rand::random::<[u8; 32]>().to_vec(),
+            rumor.as_json(),
+        )
+        .expect("receive welcome");
+
+        bob.instance()
+            .store
+            .save_welcome(&StoredWelcome {
+                id: EventId::from_byte_array(rand::random()).to_hex(),
+                rumor_json: rumor.as_json(),
+                sender: alice.identity.clone(),
+                mls_group_id: vec![1; 32],
+                nostr_group_id: hex::encode([2; 32]),
+                group_name: String::new(),
+                group_description: String::new(),
+                admin_pubkeys_json: "not json".to_string(),
+                relays_json: "[]".to_string(),
+                members_json: "[]".to_string(),
+                state: WELCOME_PENDING.to_string(),
+                received_at: Timestamp::now().as_u64(),
+            })
+            .expect("save broken welcome");
+
+        let list =
+            list_welcomes(bob.identity.clone(), WelcomeStateFilter::All).expect("list welcomes");
+        assert_eq!(list.unreadable, 1);
+        assert_eq!(list.welcomes.len(), 1);
+        assert_eq!(list.welcomes[0].id, received.id);
+    }
+}
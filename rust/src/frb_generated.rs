@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.7.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -2106810556;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 848021627;
 
 // Section: executor
 
@@ -45,7 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__mls_api__add_members_impl(
+fn wire__crate__api__batch__process_messages_batch_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +53,7 @@ fn wire__crate__api__mls_api__add_members_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "add_members",
+            debug_name: "process_messages_batch",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -67,24 +67,20 @@ fn wire__crate__api__mls_api__add_members_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_serialized_key_packages = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_events = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::add_members(
-                            api_group_id,
-                            api_serialized_key_packages,
-                        )?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::batch::process_messages_batch(api_identity, api_events)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__mls_api__commit_proposal_impl(
+fn wire__crate__api__commits__abort_commit_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -92,7 +88,7 @@ fn wire__crate__api__mls_api__commit_proposal_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "commit_proposal",
+            debug_name: "abort_commit",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -106,22 +102,19 @@ fn wire__crate__api__mls_api__commit_proposal_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
             let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_proposal = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::mls_api::commit_proposal(api_group_id, api_proposal)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::commits::abort_commit(api_identity, api_group_id)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__mls_api__create_commit_message_for_group_impl(
+fn wire__crate__api__commits__clear_pending_commit_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -129,7 +122,7 @@ fn wire__crate__api__mls_api__create_commit_message_for_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_commit_message_for_group",
+            debug_name: "clear_pending_commit",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -143,26 +136,63 @@ fn wire__crate__api__mls_api__create_commit_message_for_group_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_nostr_group_id = <String>::sse_decode(&mut deserializer);
-            let api_serialized_commit = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_secret_key = <[u8; 32]>::sse_decode(&mut deserializer);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::create_commit_message_for_group(
-                            api_nostr_group_id,
-                            api_serialized_commit,
-                            &api_secret_key,
-                        )?;
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::commits::clear_pending_commit(api_identity, api_group_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__commits__commit_pending_proposals_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "commit_pending_proposals",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_selection = <Option<Vec<String>>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::commits::commit_pending_proposals(
+                            api_identity,
+                            api_group_id,
+                            api_selection,
+                        )
+                        .await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__mls_api__create_group_impl(
+fn wire__crate__api__commits__confirm_commit_published_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -170,7 +200,7 @@ fn wire__crate__api__mls_api__create_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_group",
+            debug_name: "confirm_commit_published",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -184,35 +214,24 @@ fn wire__crate__api__mls_api__create_group_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_name = <String>::sse_decode(&mut deserializer);
-            let api_group_description = <String>::sse_decode(&mut deserializer);
-            let api_group_members_serialized_key_packages =
-                <Vec<String>>::sse_decode(&mut deserializer);
-            let api_group_members_pubkeys = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_group_creator_public_key = <String>::sse_decode(&mut deserializer);
-            let api_group_admin_public_keys = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_relays = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_event_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::create_group(
-                            api_group_name,
-                            api_group_description,
-                            api_group_members_serialized_key_packages,
-                            api_group_members_pubkeys,
-                            api_group_creator_public_key,
-                            api_group_admin_public_keys,
-                            api_relays,
-                        )?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::commits::confirm_commit_published(
+                        api_identity,
+                        api_group_id,
+                        api_event_id,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__mls_api__create_key_package_for_event_impl(
+fn wire__crate__api__commits__get_pending_commit_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -220,7 +239,7 @@ fn wire__crate__api__mls_api__create_key_package_for_event_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_key_package_for_event",
+            debug_name: "get_pending_commit",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -234,26 +253,55 @@ fn wire__crate__api__mls_api__create_key_package_for_event_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_public_key = <String>::sse_decode(&mut deserializer);
-            let api_relay = <Option<Vec<String>>>::sse_decode(&mut deserializer);
-            let api_client = <Option<String>>::sse_decode(&mut deserializer);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::create_key_package_for_event(
-                            api_public_key,
-                            api_relay,
-                            api_client,
-                        )?;
-                        Ok(output_ok)
-                    })(),
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::commits::get_pending_commit(api_identity, api_group_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__commits__list_pending_proposals_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_pending_proposals",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::commits::list_pending_proposals(api_identity, api_group_id)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__mls_api__create_message_for_group_impl(
+fn wire__crate__api__commits__merge_pending_commit_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -261,7 +309,7 @@ fn wire__crate__api__mls_api__create_message_for_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_message_for_group",
+            debug_name: "merge_pending_commit",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -275,32 +323,71 @@ fn wire__crate__api__mls_api__create_message_for_group_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
             let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_rumor_event_string = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::create_message_for_group(
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::commits::merge_pending_commit(api_identity, api_group_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__commits__propose_add_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "propose_add",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_key_package = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::commits::propose_add(
+                            api_identity,
                             api_group_id,
-                            api_rumor_event_string,
-                        )?;
+                            api_key_package,
+                        )
+                        .await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__mls_api__export_secret_impl(
+fn wire__crate__api__commits__propose_remove_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "export_secret",
+            debug_name: "propose_remove",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -314,28 +401,36 @@ fn wire__crate__api__mls_api__export_secret_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
             let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_member_pubkey = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::export_secret(api_group_id)?;
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::commits::propose_remove(
+                            api_identity,
+                            api_group_id,
+                            api_member_pubkey,
+                        )
+                        .await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__mls_api__find_encoded_keypackage_from_welcome_event_impl(
+fn wire__crate__api__commits__propose_update_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "find_encoded_keypackage_from_welcome_event",
+            debug_name: "propose_update",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -349,35 +444,31 @@ fn wire__crate__api__mls_api__find_encoded_keypackage_from_welcome_event_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_encoded_keypackages = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_wrapper_event_id = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_rumor_event_string = <String>::sse_decode(&mut deserializer);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>(
+                    (move || async move {
                         let output_ok =
-                            crate::api::mls_api::find_encoded_keypackage_from_welcome_event(
-                                api_encoded_keypackages,
-                                api_wrapper_event_id,
-                                api_rumor_event_string,
-                            )?;
+                            crate::api::commits::propose_update(api_identity, api_group_id).await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__mls_api__get_ciphersuite_impl(
+fn wire__crate__api__commits__self_update_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_ciphersuite",
+            debug_name: "self_update",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -391,27 +482,31 @@ fn wire__crate__api__mls_api__get_ciphersuite_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::get_ciphersuite()?;
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::commits::self_update(api_identity, api_group_id).await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__mls_api__get_extensions_impl(
+fn wire__crate__api__commits__update_group_data_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_extensions",
+            debug_name: "update_group_data",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -425,19 +520,65 @@ fn wire__crate__api__mls_api__get_extensions_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_description = <Option<String>>::sse_decode(&mut deserializer);
+            let api_admins = <Option<Vec<String>>>::sse_decode(&mut deserializer);
+            let api_relays = <Option<Vec<String>>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::get_extensions()?;
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::commits::update_group_data(
+                            api_identity,
+                            api_group_id,
+                            api_name,
+                            api_description,
+                            api_admins,
+                            api_relays,
+                        )
+                        .await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__mls_api__get_group_impl(
+fn wire__crate__api__error__MlsBridgeError_code_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "MlsBridgeError_code",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::error::MlsBridgeError>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::error::MlsBridgeError::code(&api_that))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__events__subscribe_group_events_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -445,7 +586,7 @@ fn wire__crate__api__mls_api__get_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_group",
+            debug_name: "subscribe_group_events",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -459,20 +600,23 @@ fn wire__crate__api__mls_api__get_group_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::events::GroupEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::get_group(api_group_id)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::events::subscribe_group_events(api_identity, api_sink)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__mls_api__get_key_package_from_storage_impl(
+fn wire__crate__api__key_packages__KeyPackagePolicy_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -480,7 +624,7 @@ fn wire__crate__api__mls_api__get_key_package_from_storage_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_key_package_from_storage",
+            debug_name: "KeyPackagePolicy_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -494,22 +638,18 @@ fn wire__crate__api__mls_api__get_key_package_from_storage_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_serialized_key_package = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::get_key_package_from_storage(
-                            api_serialized_key_package,
-                        )?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::key_packages::KeyPackagePolicy::default())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__mls_api__get_members_impl(
+fn wire__crate__api__key_packages__list_key_packages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -517,7 +657,7 @@ fn wire__crate__api__mls_api__get_members_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_members",
+            debug_name: "list_key_packages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -531,20 +671,18 @@ fn wire__crate__api__mls_api__get_members_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_identity = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::get_members(api_group_id)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::key_packages::list_key_packages(api_identity)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__mls_api__init_nostr_mls_impl(
+fn wire__crate__api__key_packages__maintain_key_packages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -552,7 +690,7 @@ fn wire__crate__api__mls_api__init_nostr_mls_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_nostr_mls",
+            debug_name: "maintain_key_packages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -566,26 +704,29 @@ fn wire__crate__api__mls_api__init_nostr_mls_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
-            let api_identity = <Option<String>>::sse_decode(&mut deserializer);
-            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_public_key = <String>::sse_decode(&mut deserializer);
+            let api_relay = <Option<Vec<String>>>::sse_decode(&mut deserializer);
+            let api_client = <Option<String>>::sse_decode(&mut deserializer);
+            let api_policy =
+                <crate::api::key_packages::KeyPackagePolicy>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::init_nostr_mls(
-                            api_path,
-                            api_identity,
-                            api_password,
-                        )?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::key_packages::maintain_key_packages(
+                        api_identity,
+                        api_public_key,
+                        api_relay,
+                        api_client,
+                        api_policy,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__mls_api__join_group_from_welcome_impl(
+fn wire__crate__api__key_packages__mark_key_package_deleted_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -593,7 +734,7 @@ fn wire__crate__api__mls_api__join_group_from_welcome_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "join_group_from_welcome",
+            debug_name: "mark_key_package_deleted",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -607,24 +748,22 @@ fn wire__crate__api__mls_api__join_group_from_welcome_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_wrapper_event_id = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_rumor_event_string = <String>::sse_decode(&mut deserializer);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_key_package_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::join_group_from_welcome(
-                            api_wrapper_event_id,
-                            api_rumor_event_string,
-                        )?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::key_packages::mark_key_package_deleted(
+                        api_identity,
+                        api_key_package_id,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__mls_api__leave_group_impl(
+fn wire__crate__api__key_packages__mark_key_package_published_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -632,7 +771,7 @@ fn wire__crate__api__mls_api__leave_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "leave_group",
+            debug_name: "mark_key_package_published",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -646,20 +785,24 @@ fn wire__crate__api__mls_api__leave_group_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_key_package_id = <String>::sse_decode(&mut deserializer);
+            let api_event_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::leave_group(api_group_id)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::key_packages::mark_key_package_published(
+                        api_identity,
+                        api_key_package_id,
+                        api_event_id,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__mls_api__preview_group_from_welcome_impl(
+fn wire__crate__api__keyring__KeyringPolicy_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -667,7 +810,7 @@ fn wire__crate__api__mls_api__preview_group_from_welcome_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "preview_group_from_welcome",
+            debug_name: "KeyringPolicy_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -681,24 +824,18 @@ fn wire__crate__api__mls_api__preview_group_from_welcome_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_wrapper_event_id = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_rumor_event_string = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::preview_group_from_welcome(
-                            api_wrapper_event_id,
-                            api_rumor_event_string,
-                        )?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::keyring::KeyringPolicy::default())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__mls_api__process_commit_message_for_group_impl(
+fn wire__crate__api__keyring__export_secret_for_epoch_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -706,7 +843,7 @@ fn wire__crate__api__mls_api__process_commit_message_for_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "process_commit_message_for_group",
+            debug_name: "export_secret_for_epoch",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -720,24 +857,24 @@ fn wire__crate__api__mls_api__process_commit_message_for_group_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
             let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_epoch = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::mls_api::process_commit_message_for_group(
-                            api_group_id,
-                            api_message_bytes,
-                        )?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::keyring::export_secret_for_epoch(
+                        api_identity,
+                        api_group_id,
+                        api_epoch,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__mls_api__process_message_for_group_impl(
+fn wire__crate__api__keyring__get_keyring_policy_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -745,7 +882,7 @@ fn wire__crate__api__mls_api__process_message_for_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "process_message_for_group",
+            debug_name: "get_keyring_policy",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -759,21 +896,18 @@ fn wire__crate__api__mls_api__process_message_for_group_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_event_string = <String>::sse_decode(&mut deserializer);
+            let api_identity = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::mls_api::process_message_for_group(api_event_string)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::keyring::get_keyring_policy(api_identity)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__mls_api__remove_members_impl(
+fn wire__crate__api__keyring__list_retained_epochs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -781,7 +915,7 @@ fn wire__crate__api__mls_api__remove_members_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_members",
+            debug_name: "list_retained_epochs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -795,261 +929,6765 @@ fn wire__crate__api__mls_api__remove_members_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
             let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_member_pubkeys = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::mls_api::remove_members(api_group_id, api_member_pubkeys)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::keyring::list_retained_epochs(api_identity, api_group_id)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode for String {
+fn wire__crate__api__keyring__set_keyring_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_keyring_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_policy = <crate::api::keyring::KeyringPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::keyring::set_keyring_policy(api_identity, api_policy)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__media__build_media_imeta_tag_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "build_media_imeta_tag",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_reference = <crate::api::media::MediaReference>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::media::build_media_imeta_tag(api_reference, api_url)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__media__decrypt_media_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "decrypt_media",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_imeta_tag = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_encrypted_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::media::decrypt_media(
+                        api_identity,
+                        api_group_id,
+                        api_imeta_tag,
+                        api_encrypted_data,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__media__download_media_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "download_media",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_imeta_tag = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_download =
+                decode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+                    <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+                );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::media::download_media(
+                            api_identity,
+                            api_group_id,
+                            api_imeta_tag,
+                            api_download,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__encrypt_media_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "encrypt_media",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_filename = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::media::encrypt_media(
+                        api_identity,
+                        api_group_id,
+                        api_data,
+                        api_mime_type,
+                        api_filename,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__media__parse_media_imeta_tag_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_media_imeta_tag",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_imeta_tag = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::media::parse_media_imeta_tag(api_imeta_tag)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__media__upload_media_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_media",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_filename = <String>::sse_decode(&mut deserializer);
+            let api_upload =
+                decode_DartFn_Inputs_list_prim_u_8_strict_String_Output_opt_String_AnyhowException(
+                    <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+                );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::media::upload_media(
+                            api_identity,
+                            api_group_id,
+                            api_data,
+                            api_mime_type,
+                            api_filename,
+                            api_upload,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__messages__get_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_event_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::messages::get_message(api_identity, api_event_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__messages__get_messages_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_messages",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_before =
+                <Option<crate::api::messages::MessageCursor>>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::messages::get_messages(
+                        api_identity,
+                        api_group_id,
+                        api_before,
+                        api_limit,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__messages__search_messages_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_messages",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::messages::search_messages(
+                        api_identity,
+                        api_group_id,
+                        api_query,
+                        api_limit,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__add_members_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_members",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_serialized_key_packages = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::add_members(
+                        api_identity,
+                        api_group_id,
+                        api_serialized_key_packages,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__close_nostr_mls_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "close_nostr_mls",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::close_nostr_mls(api_identity)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__create_commit_message_for_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_commit_message_for_group",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_nostr_group_id = <String>::sse_decode(&mut deserializer);
+            let api_serialized_commit = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::mls_api::create_commit_message_for_group(
+                            api_identity,
+                            api_nostr_group_id,
+                            api_serialized_commit,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__create_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_group",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_name = <String>::sse_decode(&mut deserializer);
+            let api_group_description = <String>::sse_decode(&mut deserializer);
+            let api_group_members_serialized_key_packages =
+                <Vec<String>>::sse_decode(&mut deserializer);
+            let api_group_members_pubkeys = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_group_creator_public_key = <String>::sse_decode(&mut deserializer);
+            let api_group_admin_public_keys = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_relays = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::create_group(
+                        api_identity,
+                        api_group_name,
+                        api_group_description,
+                        api_group_members_serialized_key_packages,
+                        api_group_members_pubkeys,
+                        api_group_creator_public_key,
+                        api_group_admin_public_keys,
+                        api_relays,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__create_key_package_for_event_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_key_package_for_event",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_public_key = <String>::sse_decode(&mut deserializer);
+            let api_relay = <Option<Vec<String>>>::sse_decode(&mut deserializer);
+            let api_client = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::create_key_package_for_event(
+                        api_identity,
+                        api_public_key,
+                        api_relay,
+                        api_client,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__create_message_for_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_message_for_group",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_rumor_event_string = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::create_message_for_group(
+                        api_identity,
+                        api_group_id,
+                        api_rumor_event_string,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__export_secret_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_secret",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::export_secret(api_identity, api_group_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__find_encoded_keypackage_from_welcome_event_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_encoded_keypackage_from_welcome_event",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_encoded_keypackages = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_wrapper_event_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_rumor_event_string = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::mls_api::find_encoded_keypackage_from_welcome_event(
+                            api_identity,
+                            api_encoded_keypackages,
+                            api_wrapper_event_id,
+                            api_rumor_event_string,
+                        )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__get_ciphersuite_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_ciphersuite",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::get_ciphersuite(api_identity)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__get_extensions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_extensions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::get_extensions(api_identity)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__get_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_group",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::get_group(api_identity, api_group_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__get_key_package_from_storage_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_key_package_from_storage",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_serialized_key_package = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::get_key_package_from_storage(
+                        api_identity,
+                        api_serialized_key_package,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__get_members_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_members",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::get_members(api_identity, api_group_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__get_pending_message_count_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_pending_message_count",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::mls_api::get_pending_message_count(api_identity, api_group_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__init_nostr_mls_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_nostr_mls",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::mls_api::init_nostr_mls(api_path, api_identity, api_password)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__join_group_from_welcome_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "join_group_from_welcome",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_wrapper_event_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_rumor_event_string = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::join_group_from_welcome(
+                        api_identity,
+                        api_wrapper_event_id,
+                        api_rumor_event_string,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__leave_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "leave_group",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::leave_group(api_identity, api_group_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__list_groups_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_groups",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_filter = <crate::api::types::GroupStateFilter>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::list_groups(api_identity, api_filter)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__list_nostr_mls_identities_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_nostr_mls_identities",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::list_nostr_mls_identities()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__list_pending_welcomes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_pending_welcomes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::list_pending_welcomes(api_identity)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__preview_group_from_welcome_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preview_group_from_welcome",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_wrapper_event_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_rumor_event_string = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::preview_group_from_welcome(
+                        api_identity,
+                        api_wrapper_event_id,
+                        api_rumor_event_string,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__process_commit_message_for_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "process_commit_message_for_group",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_message_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::process_commit_message_for_group(
+                        api_identity,
+                        api_group_id,
+                        api_message_bytes,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__process_message_for_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "process_message_for_group",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_event_string = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::process_message_for_group(
+                        api_identity,
+                        api_event_string,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mls_api__remove_members_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_members",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_member_pubkeys = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::mls_api::remove_members(
+                        api_identity,
+                        api_group_id,
+                        api_member_pubkeys,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__policy__GroupPolicy_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "GroupPolicy_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::policy::GroupPolicy::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__policy__get_group_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_group_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::policy::get_group_policy(api_identity, api_group_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__policy__list_commit_audit_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_commit_audit",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::policy::list_commit_audit(
+                        api_identity,
+                        api_group_id,
+                        api_limit,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__policy__set_group_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_group_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_policy = <crate::api::policy::GroupPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::policy::set_group_policy(
+                        api_identity,
+                        api_group_id,
+                        api_policy,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__recovery__cancel_group_recovery_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cancel_group_recovery",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::recovery::cancel_group_recovery(api_identity, api_group_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__recovery__compare_group_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "compare_group_state",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_epoch = <u64>::sse_decode(&mut deserializer);
+            let api_tree_hash = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::recovery::compare_group_state(
+                        api_identity,
+                        api_group_id,
+                        api_epoch,
+                        api_tree_hash,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__recovery__get_group_health_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_group_health",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::recovery::get_group_health(api_identity, api_group_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__recovery__readd_member_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "readd_member",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_member_pubkey = <String>::sse_decode(&mut deserializer);
+            let api_key_package = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::recovery::readd_member(
+                        api_identity,
+                        api_group_id,
+                        api_member_pubkey,
+                        api_key_package,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__recovery__start_group_recovery_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_group_recovery",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_relay = <Option<Vec<String>>>::sse_decode(&mut deserializer);
+            let api_client = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::recovery::start_group_recovery(
+                        api_identity,
+                        api_group_id,
+                        api_relay,
+                        api_client,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__retention__RetentionPolicy_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "RetentionPolicy_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::retention::RetentionPolicy::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__retention__apply_retention_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "apply_retention_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::retention::apply_retention_policy(api_identity)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__retention__compact_storage_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "compact_storage",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::retention::compact_storage(api_identity)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__retention__get_retention_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_retention_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::retention::get_retention_policy(api_identity)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__retention__purge_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "purge_group",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_group_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::retention::purge_group(api_identity, api_group_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__retention__set_retention_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_retention_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_policy =
+                <crate::api::retention::RetentionPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::retention::set_retention_policy(api_identity, api_policy)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__rotation__RotationPolicy_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "RotationPolicy_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::rotation::RotationPolicy::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__rotation__get_rotation_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_rotation_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::rotation::get_rotation_policy(api_identity)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__rotation__list_rotation_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_rotation_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_due_only = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::rotation::list_rotation_status(api_identity, api_due_only)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__rotation__set_rotation_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_rotation_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_policy = <crate::api::rotation::RotationPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok =
+                        crate::api::rotation::set_rotation_policy(api_identity, api_policy)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__signer__clear_signer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "clear_signer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::signer::clear_signer(api_identity)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__signer__use_dart_signer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "use_dart_signer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::signer::SignerScope>::sse_decode(&mut deserializer);
+            let api_public_key = <String>::sse_decode(&mut deserializer);
+            let api_sign_event = decode_DartFn_Inputs_String_Output_opt_String_AnyhowException(
+                <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+            );
+            let api_nip44_encrypt =
+                decode_DartFn_Inputs_String_String_Output_opt_String_AnyhowException(
+                    <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+                );
+            let api_nip44_decrypt =
+                decode_DartFn_Inputs_String_String_Output_opt_String_AnyhowException(
+                    <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+                );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::signer::use_dart_signer(
+                        api_identity,
+                        api_scope,
+                        api_public_key,
+                        api_sign_event,
+                        api_nip44_encrypt,
+                        api_nip44_decrypt,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__signer__use_nip46_signer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "use_nip46_signer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::signer::SignerScope>::sse_decode(&mut deserializer);
+            let api_public_key = <String>::sse_decode(&mut deserializer);
+            let api_send_request = decode_DartFn_Inputs_String_Output_opt_String_AnyhowException(
+                <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::signer::use_nip46_signer(
+                        api_identity,
+                        api_scope,
+                        api_public_key,
+                        api_send_request,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__storage__encrypt_existing_storage_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "encrypt_existing_storage",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::storage::encrypt_existing_storage(
+                        api_path,
+                        api_identity,
+                        api_password,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__storage__init_nostr_mls_with_key_source_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_nostr_mls_with_key_source",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_source = <crate::api::storage::StorageKeySource>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::storage::init_nostr_mls_with_key_source(
+                        api_path,
+                        api_identity,
+                        api_source,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__storage__init_nostr_mls_with_keystore_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "init_nostr_mls_with_keystore", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+let api_identity = <String>::sse_decode(&mut deserializer);
+let api_wrap_key = decode_DartFn_Inputs_String_list_prim_u_8_strict_Output_opt_list_prim_u_8_strict_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
+let api_unwrap_key = decode_DartFn_Inputs_String_list_prim_u_8_strict_Output_opt_list_prim_u_8_strict_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || async move {
+                         let output_ok = crate::api::storage::init_nostr_mls_with_keystore(api_path, api_identity, api_wrap_key, api_unwrap_key).await?;   Ok(output_ok)
+                    })().await)
+                } })
+}
+fn wire__crate__api__storage__rekey_storage_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rekey_storage",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_old_password = <String>::sse_decode(&mut deserializer);
+            let api_new_password = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::storage::rekey_storage(
+                        api_path,
+                        api_identity,
+                        api_old_password,
+                        api_new_password,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__welcomes__accept_welcome_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "accept_welcome",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::welcomes::accept_welcome(api_identity, api_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__welcomes__create_welcome_gift_wraps_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_welcome_gift_wraps",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_serialized_welcome_message = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_recipients =
+                <Vec<crate::api::welcomes::WelcomeRecipient>>::sse_decode(&mut deserializer);
+            let api_relays = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::welcomes::create_welcome_gift_wraps(
+                            api_identity,
+                            api_serialized_welcome_message,
+                            api_recipients,
+                            api_relays,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__welcomes__decline_welcome_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "decline_welcome",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::welcomes::decline_welcome(api_identity, api_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__welcomes__join_gift_wrapped_welcome_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "join_gift_wrapped_welcome",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_gift_wrap_event_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::welcomes::join_gift_wrapped_welcome(
+                            api_identity,
+                            api_gift_wrap_event_json,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__welcomes__list_welcomes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_welcomes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_filter =
+                <crate::api::welcomes::WelcomeStateFilter>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::welcomes::list_welcomes(api_identity, api_filter)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__welcomes__preview_gift_wrapped_welcome_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preview_gift_wrapped_welcome",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_gift_wrap_event_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::welcomes::preview_gift_wrapped_welcome(
+                            api_identity,
+                            api_gift_wrap_event_json,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__welcomes__receive_gift_wrapped_welcome_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "receive_gift_wrapped_welcome",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_gift_wrap_event_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::welcomes::receive_gift_wrapped_welcome(
+                            api_identity,
+                            api_gift_wrap_event_json,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__welcomes__receive_welcome_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "receive_welcome",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_wrapper_event_id = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_rumor_event_string = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>((move || {
+                    let output_ok = crate::api::welcomes::receive_welcome(
+                        api_identity,
+                        api_wrapper_event_id,
+                        api_rumor_event_string,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__welcomes__unwrap_welcome_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unwrap_welcome",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_identity = <String>::sse_decode(&mut deserializer);
+            let api_gift_wrap_event_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::MlsBridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::welcomes::unwrap_welcome(
+                            api_identity,
+                            api_gift_wrap_event_json,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+fn decode_DartFn_Inputs_String_Output_opt_String_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(String) -> flutter_rust_bridge::DartFnFuture<Option<String>> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(dart_opaque: flutter_rust_bridge::DartOpaque, arg0: String) -> Option<String> {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<Option<String>>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: String| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+fn decode_DartFn_Inputs_String_Output_opt_list_prim_u_8_strict_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(String) -> flutter_rust_bridge::DartFnFuture<Option<Vec<u8>>> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(dart_opaque: flutter_rust_bridge::DartOpaque, arg0: String) -> Option<Vec<u8>> {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<Option<Vec<u8>>>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: String| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+fn decode_DartFn_Inputs_String_String_Output_opt_String_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(String, String) -> flutter_rust_bridge::DartFnFuture<Option<String>> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: String,
+        arg1: String,
+    ) -> Option<String> {
+        let args = vec![
+            arg0.into_into_dart().into_dart(),
+            arg1.into_into_dart().into_dart(),
+        ];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<Option<String>>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: String, arg1: String| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+            arg1,
+        ))
+    }
+}
+fn decode_DartFn_Inputs_String_list_prim_u_8_strict_Output_opt_list_prim_u_8_strict_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(String, Vec<u8>) -> flutter_rust_bridge::DartFnFuture<Option<Vec<u8>>> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: String,
+        arg1: Vec<u8>,
+    ) -> Option<Vec<u8>> {
+        let args = vec![
+            arg0.into_into_dart().into_dart(),
+            arg1.into_into_dart().into_dart(),
+        ];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<Option<Vec<u8>>>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: String, arg1: Vec<u8>| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+            arg1,
+        ))
+    }
+}
+fn decode_DartFn_Inputs_list_prim_u_8_strict_String_Output_opt_String_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(Vec<u8>, String) -> flutter_rust_bridge::DartFnFuture<Option<String>> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: Vec<u8>,
+        arg1: String,
+    ) -> Option<String> {
+        let args = vec![
+            arg0.into_into_dart().into_dart(),
+            arg1.into_into_dart().into_dart(),
+        ];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<Option<String>>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: Vec<u8>, arg1: String| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+            arg1,
+        ))
+    }
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for flutter_rust_bridge::DartOpaque {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return unsafe { flutter_rust_bridge::for_generated::sse_decode_dart_opaque(inner) };
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::events::GroupEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::types::AddMembersResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_commitMessage = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_welcomeMessage = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::types::AddMembersResult {
+            commit_message: var_commitMessage,
+            welcome_message: var_welcomeMessage,
+        };
+    }
+}
+
+impl SseDecode for crate::api::batch::BatchEventResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_eventId = <Option<String>>::sse_decode(deserializer);
+        let mut var_processed =
+            <Option<crate::api::types::ProcessedMessage>>::sse_decode(deserializer);
+        let mut var_error = <Option<crate::api::error::MlsBridgeError>>::sse_decode(deserializer);
+        return crate::api::batch::BatchEventResult {
+            event_id: var_eventId,
+            processed: var_processed,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::policy::CommitAuditEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_epoch = <u64>::sse_decode(deserializer);
+        let mut var_sender = <Option<String>>::sse_decode(deserializer);
+        let mut var_reason = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <u64>::sse_decode(deserializer);
+        return crate::api::policy::CommitAuditEntry {
+            epoch: var_epoch,
+            sender: var_sender,
+            reason: var_reason,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::CommitResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_serializedCommit = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::types::CommitResult {
+            serialized_commit: var_serializedCommit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::CreateGroupResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_group = <crate::api::types::GroupInfo>::sse_decode(deserializer);
+        let mut var_serializedWelcomeMessage = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::types::CreateGroupResult {
+            group: var_group,
+            serialized_welcome_message: var_serializedWelcomeMessage,
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::DecryptedMessage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_eventId = <String>::sse_decode(deserializer);
+        let mut var_pubkey = <String>::sse_decode(deserializer);
+        let mut var_kind = <u16>::sse_decode(deserializer);
+        let mut var_mlsGroupId = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_createdAt = <u64>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_tags = <Vec<Vec<String>>>::sse_decode(deserializer);
+        let mut var_rumorJson = <String>::sse_decode(deserializer);
+        return crate::api::types::DecryptedMessage {
+            event_id: var_eventId,
+            pubkey: var_pubkey,
+            kind: var_kind,
+            mls_group_id: var_mlsGroupId,
+            created_at: var_createdAt,
+            content: var_content,
+            tags: var_tags,
+            rumor_json: var_rumorJson,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::EncryptedMedia {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_encryptedData = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_encryptedHash = <String>::sse_decode(deserializer);
+        let mut var_reference = <crate::api::media::MediaReference>::sse_decode(deserializer);
+        return crate::api::media::EncryptedMedia {
+            encrypted_data: var_encryptedData,
+            encrypted_hash: var_encryptedHash,
+            reference: var_reference,
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::EventResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_eventId = <String>::sse_decode(deserializer);
+        let mut var_eventJson = <String>::sse_decode(deserializer);
+        return crate::api::types::EventResult {
+            event_id: var_eventId,
+            event_json: var_eventJson,
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::ExportedSecret {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_secret = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_epoch = <u64>::sse_decode(deserializer);
+        return crate::api::types::ExportedSecret {
+            secret: var_secret,
+            epoch: var_epoch,
+        };
+    }
+}
+
+impl SseDecode for crate::api::welcomes::GiftWrappedWelcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_recipient = <String>::sse_decode(deserializer);
+        let mut var_event = <crate::api::types::EventResult>::sse_decode(deserializer);
+        return crate::api::welcomes::GiftWrappedWelcome {
+            recipient: var_recipient,
+            event: var_event,
+        };
+    }
+}
+
+impl SseDecode for crate::api::events::GroupEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_message =
+                    <crate::api::types::DecryptedMessage>::sse_decode(deserializer);
+                return crate::api::events::GroupEvent::MessageDecrypted {
+                    message: var_message,
+                };
+            }
+            1 => {
+                let mut var_mlsGroupId = <Vec<u8>>::sse_decode(deserializer);
+                let mut var_members = <Vec<String>>::sse_decode(deserializer);
+                return crate::api::events::GroupEvent::MembersAdded {
+                    mls_group_id: var_mlsGroupId,
+                    members: var_members,
+                };
+            }
+            2 => {
+                let mut var_mlsGroupId = <Vec<u8>>::sse_decode(deserializer);
+                let mut var_members = <Vec<String>>::sse_decode(deserializer);
+                return crate::api::events::GroupEvent::MembersRemoved {
+                    mls_group_id: var_mlsGroupId,
+                    members: var_members,
+                };
+            }
+            3 => {
+                let mut var_mlsGroupId = <Vec<u8>>::sse_decode(deserializer);
+                let mut var_epoch = <u64>::sse_decode(deserializer);
+                return crate::api::events::GroupEvent::EpochAdvanced {
+                    mls_group_id: var_mlsGroupId,
+                    epoch: var_epoch,
+                };
+            }
+            4 => {
+                let mut var_group = <crate::api::types::GroupInfo>::sse_decode(deserializer);
+                return crate::api::events::GroupEvent::GroupMetadataChanged { group: var_group };
+            }
+            5 => {
+                let mut var_wrapperEventId = <String>::sse_decode(deserializer);
+                let mut var_group = <crate::api::types::GroupInfo>::sse_decode(deserializer);
+                return crate::api::events::GroupEvent::WelcomeReceived {
+                    wrapper_event_id: var_wrapperEventId,
+                    group: var_group,
+                };
+            }
+            6 => {
+                let mut var_wrapperEventId = <String>::sse_decode(deserializer);
+                let mut var_keyPackageId = <String>::sse_decode(deserializer);
+                return crate::api::events::GroupEvent::KeyPackageConsumed {
+                    wrapper_event_id: var_wrapperEventId,
+                    key_package_id: var_keyPackageId,
+                };
+            }
+            7 => {
+                let mut var_mlsGroupId = <Vec<u8>>::sse_decode(deserializer);
+                let mut var_eventId = <Option<String>>::sse_decode(deserializer);
+                return crate::api::events::GroupEvent::PendingCommitRolledBack {
+                    mls_group_id: var_mlsGroupId,
+                    event_id: var_eventId,
+                };
+            }
+            8 => {
+                let mut var_mlsGroupId = <Vec<u8>>::sse_decode(deserializer);
+                return crate::api::events::GroupEvent::GroupPurged {
+                    mls_group_id: var_mlsGroupId,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::recovery::GroupHealth {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mlsGroupId = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_epoch = <u64>::sse_decode(deserializer);
+        let mut var_treeHash = <String>::sse_decode(deserializer);
+        let mut var_pendingMessageCount = <u32>::sse_decode(deserializer);
+        let mut var_oldestPendingMessageAt = <Option<u64>>::sse_decode(deserializer);
+        let mut var_undecryptableEventCount = <u32>::sse_decode(deserializer);
+        let mut var_lastUndecryptableEventAt = <Option<u64>>::sse_decode(deserializer);
+        let mut var_consecutiveFailures = <u32>::sse_decode(deserializer);
+        let mut var_lastFailure = <Option<String>>::sse_decode(deserializer);
+        let mut var_lastFailureAt = <Option<u64>>::sse_decode(deserializer);
+        let mut var_lastSuccessAt = <Option<u64>>::sse_decode(deserializer);
+        let mut var_recoveryRequestedAt = <Option<u64>>::sse_decode(deserializer);
+        let mut var_recoveryKeyPackageId = <Option<String>>::sse_decode(deserializer);
+        let mut var_status = <crate::api::recovery::GroupHealthStatus>::sse_decode(deserializer);
+        return crate::api::recovery::GroupHealth {
+            mls_group_id: var_mlsGroupId,
+            epoch: var_epoch,
+            tree_hash: var_treeHash,
+            pending_message_count: var_pendingMessageCount,
+            oldest_pending_message_at: var_oldestPendingMessageAt,
+            undecryptable_event_count: var_undecryptableEventCount,
+            last_undecryptable_event_at: var_lastUndecryptableEventAt,
+            consecutive_failures: var_consecutiveFailures,
+            last_failure: var_lastFailure,
+            last_failure_at: var_lastFailureAt,
+            last_success_at: var_lastSuccessAt,
+            recovery_requested_at: var_recoveryRequestedAt,
+            recovery_key_package_id: var_recoveryKeyPackageId,
+            status: var_status,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recovery::GroupHealthStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::recovery::GroupHealthStatus::Healthy,
+            1 => crate::api::recovery::GroupHealthStatus::Lagging,
+            2 => crate::api::recovery::GroupHealthStatus::Forked,
+            _ => unreachable!("Invalid variant for GroupHealthStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::GroupInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mlsGroupId = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_members = <Vec<String>>::sse_decode(deserializer);
+        let mut var_nostrGroupData = <crate::api::types::NostrGroupData>::sse_decode(deserializer);
+        return crate::api::types::GroupInfo {
+            mls_group_id: var_mlsGroupId,
+            members: var_members,
+            nostr_group_data: var_nostrGroupData,
+        };
+    }
+}
+
+impl SseDecode for crate::api::policy::GroupPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_addMembers = <crate::api::policy::PolicyRole>::sse_decode(deserializer);
+        let mut var_removeMembers = <crate::api::policy::PolicyRole>::sse_decode(deserializer);
+        let mut var_updateGroupData = <crate::api::policy::PolicyRole>::sse_decode(deserializer);
+        let mut var_allowSelfRemove = <bool>::sse_decode(deserializer);
+        return crate::api::policy::GroupPolicy {
+            add_members: var_addMembers,
+            remove_members: var_removeMembers,
+            update_group_data: var_updateGroupData,
+            allow_self_remove: var_allowSelfRemove,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recovery::GroupRecoveryRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mlsGroupId = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_keyPackage =
+            <crate::api::key_packages::ManagedKeyPackage>::sse_decode(deserializer);
+        let mut var_admins = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::recovery::GroupRecoveryRequest {
+            mls_group_id: var_mlsGroupId,
+            key_package: var_keyPackage,
+            admins: var_admins,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recovery::GroupStateComparison {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::recovery::GroupStateComparison::InSync,
+            1 => crate::api::recovery::GroupStateComparison::Behind,
+            2 => crate::api::recovery::GroupStateComparison::Ahead,
+            3 => crate::api::recovery::GroupStateComparison::Diverged,
+            _ => unreachable!("Invalid variant for GroupStateComparison: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::GroupStateFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::types::GroupStateFilter::All,
+            1 => crate::api::types::GroupStateFilter::Active,
+            2 => crate::api::types::GroupStateFilter::Left,
+            3 => crate::api::types::GroupStateFilter::Pending,
+            _ => unreachable!("Invalid variant for GroupStateFilter: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::GroupStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::types::GroupStatus::Active,
+            1 => crate::api::types::GroupStatus::Left,
+            2 => crate::api::types::GroupStatus::Pending,
+            _ => unreachable!("Invalid variant for GroupStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::GroupSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mlsGroupId = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_nostrGroupId = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_epoch = <u64>::sse_decode(deserializer);
+        let mut var_memberCount = <u32>::sse_decode(deserializer);
+        let mut var_lastMessageId = <Option<String>>::sse_decode(deserializer);
+        let mut var_lastMessageAt = <Option<u64>>::sse_decode(deserializer);
+        let mut var_status = <crate::api::types::GroupStatus>::sse_decode(deserializer);
+        return crate::api::types::GroupSummary {
+            mls_group_id: var_mlsGroupId,
+            nostr_group_id: var_nostrGroupId,
+            name: var_name,
+            description: var_description,
+            epoch: var_epoch,
+            member_count: var_memberCount,
+            last_message_id: var_lastMessageId,
+            last_message_at: var_lastMessageAt,
+            status: var_status,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::welcomes::InboxWelcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_sender = <String>::sse_decode(deserializer);
+        let mut var_group = <crate::api::types::GroupInfo>::sse_decode(deserializer);
+        let mut var_state = <crate::api::welcomes::WelcomeState>::sse_decode(deserializer);
+        let mut var_receivedAt = <u64>::sse_decode(deserializer);
+        return crate::api::welcomes::InboxWelcome {
+            id: var_id,
+            sender: var_sender,
+            group: var_group,
+            state: var_state,
+            received_at: var_receivedAt,
+        };
+    }
+}
+
+impl SseDecode for isize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::types::KeyPackageLookup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_found = <bool>::sse_decode(deserializer);
+        let mut var_keyPackage = <Option<String>>::sse_decode(deserializer);
+        return crate::api::types::KeyPackageLookup {
+            found: var_found,
+            key_package: var_keyPackage,
+        };
+    }
+}
+
+impl SseDecode for crate::api::key_packages::KeyPackagePolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_lastResortCount = <u32>::sse_decode(deserializer);
+        let mut var_oneTimeCount = <u32>::sse_decode(deserializer);
+        let mut var_maxAgeSecs = <u64>::sse_decode(deserializer);
+        return crate::api::key_packages::KeyPackagePolicy {
+            last_resort_count: var_lastResortCount,
+            one_time_count: var_oneTimeCount,
+            max_age_secs: var_maxAgeSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::KeyPackageResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_encodedKeyPackage = <String>::sse_decode(deserializer);
+        let mut var_tags = <Vec<Vec<String>>>::sse_decode(deserializer);
+        return crate::api::types::KeyPackageResult {
+            encoded_key_package: var_encodedKeyPackage,
+            tags: var_tags,
+        };
+    }
+}
+
+impl SseDecode for crate::api::key_packages::KeyPackageRotation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_toPublish =
+            <Vec<crate::api::key_packages::ManagedKeyPackage>>::sse_decode(deserializer);
+        let mut var_toDelete =
+            <Vec<crate::api::key_packages::ManagedKeyPackage>>::sse_decode(deserializer);
+        return crate::api::key_packages::KeyPackageRotation {
+            to_publish: var_toPublish,
+            to_delete: var_toDelete,
+        };
+    }
+}
+
+impl SseDecode for crate::api::key_packages::KeyPackageState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::key_packages::KeyPackageState::Unpublished,
+            1 => crate::api::key_packages::KeyPackageState::Published,
+            2 => crate::api::key_packages::KeyPackageState::Consumed,
+            3 => crate::api::key_packages::KeyPackageState::Declined,
+            4 => crate::api::key_packages::KeyPackageState::Retired,
+            5 => crate::api::key_packages::KeyPackageState::Deleted,
+            _ => unreachable!("Invalid variant for KeyPackageState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::keyring::KeyringPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_retainedEpochs = <u32>::sse_decode(deserializer);
+        let mut var_maxAgeSecs = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::keyring::KeyringPolicy {
+            retained_epochs: var_retainedEpochs,
+            max_age_secs: var_maxAgeSecs,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::batch::BatchEventResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::batch::BatchEventResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::policy::CommitAuditEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::policy::CommitAuditEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::types::DecryptedMessage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::types::DecryptedMessage>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::welcomes::GiftWrappedWelcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::welcomes::GiftWrappedWelcome>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::types::GroupSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::types::GroupSummary>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::welcomes::InboxWelcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::welcomes::InboxWelcome>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Vec<String>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Vec<u8>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::key_packages::ManagedKeyPackage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::key_packages::ManagedKeyPackage>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::commits::ProposalSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::commits::ProposalSummary>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::rotation::RotationStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::rotation::RotationStatus>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::welcomes::WelcomeRecipient> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::welcomes::WelcomeRecipient>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::types::WelcomeSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::types::WelcomeSummary>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::key_packages::ManagedKeyPackage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_encodedKeyPackage = <String>::sse_decode(deserializer);
+        let mut var_tags = <Vec<Vec<String>>>::sse_decode(deserializer);
+        let mut var_lastResort = <bool>::sse_decode(deserializer);
+        let mut var_state = <crate::api::key_packages::KeyPackageState>::sse_decode(deserializer);
+        let mut var_eventId = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <u64>::sse_decode(deserializer);
+        let mut var_publishedAt = <Option<u64>>::sse_decode(deserializer);
+        let mut var_consumedAt = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::key_packages::ManagedKeyPackage {
+            id: var_id,
+            encoded_key_package: var_encodedKeyPackage,
+            tags: var_tags,
+            last_resort: var_lastResort,
+            state: var_state,
+            event_id: var_eventId,
+            created_at: var_createdAt,
+            published_at: var_publishedAt,
+            consumed_at: var_consumedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::MediaReference {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <Option<String>>::sse_decode(deserializer);
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        let mut var_filename = <String>::sse_decode(deserializer);
+        let mut var_originalHash = <String>::sse_decode(deserializer);
+        let mut var_nonce = <String>::sse_decode(deserializer);
+        let mut var_epoch = <u64>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        return crate::api::media::MediaReference {
+            url: var_url,
+            mime_type: var_mimeType,
+            filename: var_filename,
+            original_hash: var_originalHash,
+            nonce: var_nonce,
+            epoch: var_epoch,
+            size: var_size,
+        };
+    }
+}
+
+impl SseDecode for crate::api::messages::MessageCursor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_createdAt = <u64>::sse_decode(deserializer);
+        let mut var_eventId = <String>::sse_decode(deserializer);
+        return crate::api::messages::MessageCursor {
+            created_at: var_createdAt,
+            event_id: var_eventId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::error::MlsBridgeError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::error::MlsBridgeError::NotInitialized;
+            }
+            1 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::MlsBridgeError::InvalidInput {
+                    field: var_field,
+                    message: var_message,
+                };
+            }
+            2 => {
+                return crate::api::error::MlsBridgeError::GroupNotFound;
+            }
+            3 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::MlsBridgeError::StaleEpoch {
+                    message: var_message,
+                };
+            }
+            4 => {
+                return crate::api::error::MlsBridgeError::OwnMessage;
+            }
+            5 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::MlsBridgeError::DecryptionFailed {
+                    message: var_message,
+                };
+            }
+            6 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::MlsBridgeError::StorageLocked {
+                    message: var_message,
+                };
+            }
+            7 => {
+                return crate::api::error::MlsBridgeError::KeyPackageNotFound;
+            }
+            8 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::MlsBridgeError::PermissionDenied {
+                    message: var_message,
+                };
+            }
+            9 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::MlsBridgeError::Signer {
+                    message: var_message,
+                };
+            }
+            10 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::MlsBridgeError::Transfer {
+                    message: var_message,
+                };
+            }
+            11 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::MlsBridgeError::Storage {
+                    message: var_message,
+                };
+            }
+            12 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::MlsBridgeError::Mls {
+                    message: var_message,
+                };
+            }
+            13 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::MlsBridgeError::Internal {
+                    message: var_message,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::types::NostrGroupData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nostrGroupId = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_adminPubkeys = <Vec<String>>::sse_decode(deserializer);
+        let mut var_relays = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::types::NostrGroupData {
+            nostr_group_id: var_nostrGroupId,
+            name: var_name,
+            description: var_description,
+            admin_pubkeys: var_adminPubkeys,
+            relays: var_relays,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::types::DecryptedMessage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::types::DecryptedMessage>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::messages::MessageCursor> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::messages::MessageCursor>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::error::MlsBridgeError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::error::MlsBridgeError>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::commits::PendingCommit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::commits::PendingCommit>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::types::ProcessedMessage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::types::ProcessedMessage>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<String>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u8>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::commits::PendingCommit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_epoch = <u64>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::commits::PendingCommitKind>::sse_decode(deserializer);
+        let mut var_eventId = <Option<String>>::sse_decode(deserializer);
+        let mut var_eventJson = <Option<String>>::sse_decode(deserializer);
+        return crate::api::commits::PendingCommit {
+            epoch: var_epoch,
+            kind: var_kind,
+            event_id: var_eventId,
+            event_json: var_eventJson,
+        };
+    }
+}
+
+impl SseDecode for crate::api::commits::PendingCommitKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::commits::PendingCommitKind::Commit,
+            1 => crate::api::commits::PendingCommitKind::Leave,
+            _ => unreachable!("Invalid variant for PendingCommitKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::policy::PolicyRole {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::policy::PolicyRole::Admins,
+            1 => crate::api::policy::PolicyRole::Members,
+            _ => unreachable!("Invalid variant for PolicyRole: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::ProcessedMessage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_message =
+            <Option<crate::api::types::DecryptedMessage>>::sse_decode(deserializer);
+        let mut var_addedMembers = <Vec<String>>::sse_decode(deserializer);
+        let mut var_removedMembers = <Vec<String>>::sse_decode(deserializer);
+        let mut var_commit = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_welcome = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_stagedMessageBytes = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_pending = <bool>::sse_decode(deserializer);
+        let mut var_releasedMessages =
+            <Vec<crate::api::types::DecryptedMessage>>::sse_decode(deserializer);
+        let mut var_superseded = <bool>::sse_decode(deserializer);
+        let mut var_ownCommitRolledBack = <bool>::sse_decode(deserializer);
+        return crate::api::types::ProcessedMessage {
+            message: var_message,
+            added_members: var_addedMembers,
+            removed_members: var_removedMembers,
+            commit: var_commit,
+            welcome: var_welcome,
+            staged_message_bytes: var_stagedMessageBytes,
+            pending: var_pending,
+            released_messages: var_releasedMessages,
+            superseded: var_superseded,
+            own_commit_rolled_back: var_ownCommitRolledBack,
+        };
+    }
+}
+
+impl SseDecode for crate::api::commits::ProposalCommitResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_commitEvent = <crate::api::types::EventResult>::sse_decode(deserializer);
+        let mut var_welcomeMessage = <Option<Vec<u8>>>::sse_decode(deserializer);
+        return crate::api::commits::ProposalCommitResult {
+            commit_event: var_commitEvent,
+            welcome_message: var_welcomeMessage,
+        };
+    }
+}
+
+impl SseDecode for crate::api::commits::ProposalKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::commits::ProposalKind::Add,
+            1 => crate::api::commits::ProposalKind::Remove,
+            2 => crate::api::commits::ProposalKind::Update,
+            3 => crate::api::commits::ProposalKind::GroupContextExtensions,
+            4 => crate::api::commits::ProposalKind::Other,
+            _ => unreachable!("Invalid variant for ProposalKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::commits::ProposalSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_proposalRef = <String>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::commits::ProposalKind>::sse_decode(deserializer);
+        let mut var_proposer = <Option<String>>::sse_decode(deserializer);
+        let mut var_target = <Option<String>>::sse_decode(deserializer);
+        return crate::api::commits::ProposalSummary {
+            proposal_ref: var_proposalRef,
+            kind: var_kind,
+            proposer: var_proposer,
+            target: var_target,
+        };
+    }
+}
+
+impl SseDecode for crate::api::retention::RetentionPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_messageMaxAgeSecs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_purgeInactiveGroups = <bool>::sse_decode(deserializer);
+        return crate::api::retention::RetentionPolicy {
+            message_max_age_secs: var_messageMaxAgeSecs,
+            purge_inactive_groups: var_purgeInactiveGroups,
+        };
+    }
+}
+
+impl SseDecode for crate::api::retention::RetentionReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_purgedGroups = <Vec<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_expiredMessages = <u32>::sse_decode(deserializer);
+        let mut var_forgottenKeyPackages = <u32>::sse_decode(deserializer);
+        return crate::api::retention::RetentionReport {
+            purged_groups: var_purgedGroups,
+            expired_messages: var_expiredMessages,
+            forgotten_key_packages: var_forgottenKeyPackages,
+        };
+    }
+}
+
+impl SseDecode for crate::api::rotation::RotationPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxMessages = <Option<u64>>::sse_decode(deserializer);
+        let mut var_maxAgeSecs = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::rotation::RotationPolicy {
+            max_messages: var_maxMessages,
+            max_age_secs: var_maxAgeSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::rotation::RotationStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mlsGroupId = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_lastRotatedAt = <u64>::sse_decode(deserializer);
+        let mut var_messagesSinceRotation = <u64>::sse_decode(deserializer);
+        let mut var_due = <bool>::sse_decode(deserializer);
+        return crate::api::rotation::RotationStatus {
+            mls_group_id: var_mlsGroupId,
+            last_rotated_at: var_lastRotatedAt,
+            messages_since_rotation: var_messagesSinceRotation,
+            due: var_due,
+        };
+    }
+}
+
+impl SseDecode for crate::api::signer::SignerScope {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::signer::SignerScope::Seals,
+            1 => crate::api::signer::SignerScope::SealsAndCommits,
+            _ => unreachable!("Invalid variant for SignerScope: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::storage::StorageKeySource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_passphrase = <String>::sse_decode(deserializer);
+                return crate::api::storage::StorageKeySource::Passphrase {
+                    passphrase: var_passphrase,
+                };
+            }
+            1 => {
+                let mut var_path = <String>::sse_decode(deserializer);
+                return crate::api::storage::StorageKeySource::KeyFile { path: var_path };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::welcomes::UnwrappedWelcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_wrapperEventId = <String>::sse_decode(deserializer);
+        let mut var_sender = <String>::sse_decode(deserializer);
+        let mut var_rumorJson = <String>::sse_decode(deserializer);
+        return crate::api::welcomes::UnwrappedWelcome {
+            wrapper_event_id: var_wrapperEventId,
+            sender: var_sender,
+            rumor_json: var_rumorJson,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::UploadedMedia {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_imetaTag = <Vec<String>>::sse_decode(deserializer);
+        let mut var_reference = <crate::api::media::MediaReference>::sse_decode(deserializer);
+        return crate::api::media::UploadedMedia {
+            imeta_tag: var_imetaTag,
+            reference: var_reference,
+        };
+    }
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::types::WelcomeKeyPackageMatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_found = <bool>::sse_decode(deserializer);
+        let mut var_matchedIndex = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::types::WelcomeKeyPackageMatch {
+            found: var_found,
+            matched_index: var_matchedIndex,
+        };
+    }
+}
+
+impl SseDecode for crate::api::welcomes::WelcomeList {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_welcomes = <Vec<crate::api::welcomes::InboxWelcome>>::sse_decode(deserializer);
+        let mut var_unreadable = <u32>::sse_decode(deserializer);
+        return crate::api::welcomes::WelcomeList {
+            welcomes: var_welcomes,
+            unreadable: var_unreadable,
+        };
+    }
+}
+
+impl SseDecode for crate::api::welcomes::WelcomeRecipient {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_publicKey = <String>::sse_decode(deserializer);
+        let mut var_keyPackageEventId = <String>::sse_decode(deserializer);
+        return crate::api::welcomes::WelcomeRecipient {
+            public_key: var_publicKey,
+            key_package_event_id: var_keyPackageEventId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::welcomes::WelcomeState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::welcomes::WelcomeState::Pending,
+            1 => crate::api::welcomes::WelcomeState::Accepted,
+            2 => crate::api::welcomes::WelcomeState::Declined,
+            _ => unreachable!("Invalid variant for WelcomeState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::welcomes::WelcomeStateFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::welcomes::WelcomeStateFilter::All,
+            1 => crate::api::welcomes::WelcomeStateFilter::Pending,
+            2 => crate::api::welcomes::WelcomeStateFilter::Accepted,
+            3 => crate::api::welcomes::WelcomeStateFilter::Declined,
+            _ => unreachable!("Invalid variant for WelcomeStateFilter: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::types::WelcomeSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_welcomeEventId = <String>::sse_decode(deserializer);
+        let mut var_wrapperEventId = <String>::sse_decode(deserializer);
+        let mut var_mlsGroupId = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_nostrGroupData = <crate::api::types::NostrGroupData>::sse_decode(deserializer);
+        let mut var_welcomer = <String>::sse_decode(deserializer);
+        let mut var_memberCount = <u32>::sse_decode(deserializer);
+        return crate::api::types::WelcomeSummary {
+            welcome_event_id: var_welcomeEventId,
+            wrapper_event_id: var_wrapperEventId,
+            mls_group_id: var_mlsGroupId,
+            nostr_group_data: var_nostrGroupData,
+            welcomer: var_welcomer,
+            member_count: var_memberCount,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => {
+            wire__crate__api__batch__process_messages_batch_impl(port, ptr, rust_vec_len, data_len)
+        }
+        2 => wire__crate__api__commits__abort_commit_impl(port, ptr, rust_vec_len, data_len),
+        3 => {
+            wire__crate__api__commits__clear_pending_commit_impl(port, ptr, rust_vec_len, data_len)
+        }
+        4 => wire__crate__api__commits__commit_pending_proposals_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => wire__crate__api__commits__confirm_commit_published_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => wire__crate__api__commits__get_pending_commit_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__commits__list_pending_proposals_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => {
+            wire__crate__api__commits__merge_pending_commit_impl(port, ptr, rust_vec_len, data_len)
+        }
+        9 => wire__crate__api__commits__propose_add_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__commits__propose_remove_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__commits__propose_update_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__commits__self_update_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__commits__update_group_data_impl(port, ptr, rust_vec_len, data_len),
+        15 => {
+            wire__crate__api__events__subscribe_group_events_impl(port, ptr, rust_vec_len, data_len)
+        }
+        16 => wire__crate__api__key_packages__KeyPackagePolicy_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        17 => wire__crate__api__key_packages__list_key_packages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        18 => wire__crate__api__key_packages__maintain_key_packages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        19 => wire__crate__api__key_packages__mark_key_package_deleted_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        20 => wire__crate__api__key_packages__mark_key_package_published_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => {
+            wire__crate__api__keyring__KeyringPolicy_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        22 => wire__crate__api__keyring__export_secret_for_epoch_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        23 => wire__crate__api__keyring__get_keyring_policy_impl(port, ptr, rust_vec_len, data_len),
+        24 => {
+            wire__crate__api__keyring__list_retained_epochs_impl(port, ptr, rust_vec_len, data_len)
+        }
+        25 => wire__crate__api__keyring__set_keyring_policy_impl(port, ptr, rust_vec_len, data_len),
+        26 => {
+            wire__crate__api__media__build_media_imeta_tag_impl(port, ptr, rust_vec_len, data_len)
+        }
+        27 => wire__crate__api__media__decrypt_media_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__media__download_media_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__media__encrypt_media_impl(port, ptr, rust_vec_len, data_len),
+        30 => {
+            wire__crate__api__media__parse_media_imeta_tag_impl(port, ptr, rust_vec_len, data_len)
+        }
+        31 => wire__crate__api__media__upload_media_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__messages__get_message_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__messages__get_messages_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__messages__search_messages_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__mls_api__add_members_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__mls_api__close_nostr_mls_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__mls_api__create_commit_message_for_group_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => wire__crate__api__mls_api__create_group_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__mls_api__create_key_package_for_event_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        40 => wire__crate__api__mls_api__create_message_for_group_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        41 => wire__crate__api__mls_api__export_secret_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__mls_api__find_encoded_keypackage_from_welcome_event_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => wire__crate__api__mls_api__get_ciphersuite_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__mls_api__get_extensions_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__mls_api__get_group_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__mls_api__get_key_package_from_storage_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        47 => wire__crate__api__mls_api__get_members_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__mls_api__get_pending_message_count_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        49 => wire__crate__api__mls_api__init_nostr_mls_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__mls_api__join_group_from_welcome_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        51 => wire__crate__api__mls_api__leave_group_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__mls_api__list_groups_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__mls_api__list_nostr_mls_identities_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        54 => {
+            wire__crate__api__mls_api__list_pending_welcomes_impl(port, ptr, rust_vec_len, data_len)
+        }
+        55 => wire__crate__api__mls_api__preview_group_from_welcome_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        56 => wire__crate__api__mls_api__process_commit_message_for_group_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        57 => wire__crate__api__mls_api__process_message_for_group_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        58 => wire__crate__api__mls_api__remove_members_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__policy__GroupPolicy_default_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__policy__get_group_policy_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__policy__list_commit_audit_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__policy__set_group_policy_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__recovery__cancel_group_recovery_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        64 => {
+            wire__crate__api__recovery__compare_group_state_impl(port, ptr, rust_vec_len, data_len)
+        }
+        65 => wire__crate__api__recovery__get_group_health_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__recovery__readd_member_impl(port, ptr, rust_vec_len, data_len),
+        67 => {
+            wire__crate__api__recovery__start_group_recovery_impl(port, ptr, rust_vec_len, data_len)
+        }
+        68 => wire__crate__api__retention__RetentionPolicy_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        69 => wire__crate__api__retention__apply_retention_policy_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        70 => wire__crate__api__retention__compact_storage_impl(port, ptr, rust_vec_len, data_len),
+        71 => wire__crate__api__retention__get_retention_policy_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        72 => wire__crate__api__retention__purge_group_impl(port, ptr, rust_vec_len, data_len),
+        73 => wire__crate__api__retention__set_retention_policy_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        74 => wire__crate__api__rotation__RotationPolicy_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        75 => {
+            wire__crate__api__rotation__get_rotation_policy_impl(port, ptr, rust_vec_len, data_len)
+        }
+        76 => {
+            wire__crate__api__rotation__list_rotation_status_impl(port, ptr, rust_vec_len, data_len)
+        }
+        77 => {
+            wire__crate__api__rotation__set_rotation_policy_impl(port, ptr, rust_vec_len, data_len)
+        }
+        78 => wire__crate__api__signer__clear_signer_impl(port, ptr, rust_vec_len, data_len),
+        79 => wire__crate__api__signer__use_dart_signer_impl(port, ptr, rust_vec_len, data_len),
+        80 => wire__crate__api__signer__use_nip46_signer_impl(port, ptr, rust_vec_len, data_len),
+        81 => wire__crate__api__storage__encrypt_existing_storage_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        82 => wire__crate__api__storage__init_nostr_mls_with_key_source_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        83 => wire__crate__api__storage__init_nostr_mls_with_keystore_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        84 => wire__crate__api__storage__rekey_storage_impl(port, ptr, rust_vec_len, data_len),
+        85 => wire__crate__api__welcomes__accept_welcome_impl(port, ptr, rust_vec_len, data_len),
+        86 => wire__crate__api__welcomes__create_welcome_gift_wraps_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        87 => wire__crate__api__welcomes__decline_welcome_impl(port, ptr, rust_vec_len, data_len),
+        88 => wire__crate__api__welcomes__join_gift_wrapped_welcome_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        89 => wire__crate__api__welcomes__list_welcomes_impl(port, ptr, rust_vec_len, data_len),
+        90 => wire__crate__api__welcomes__preview_gift_wrapped_welcome_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        91 => wire__crate__api__welcomes__receive_gift_wrapped_welcome_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        92 => wire__crate__api__welcomes__receive_welcome_impl(port, ptr, rust_vec_len, data_len),
+        93 => wire__crate__api__welcomes__unwrap_welcome_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        14 => wire__crate__api__error__MlsBridgeError_code_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::AddMembersResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.commit_message.into_into_dart().into_dart(),
+            self.welcome_message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::AddMembersResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::AddMembersResult>
+    for crate::api::types::AddMembersResult
+{
+    fn into_into_dart(self) -> crate::api::types::AddMembersResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::batch::BatchEventResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.event_id.into_into_dart().into_dart(),
+            self.processed.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::batch::BatchEventResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::batch::BatchEventResult>
+    for crate::api::batch::BatchEventResult
+{
+    fn into_into_dart(self) -> crate::api::batch::BatchEventResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::policy::CommitAuditEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.epoch.into_into_dart().into_dart(),
+            self.sender.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::policy::CommitAuditEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::policy::CommitAuditEntry>
+    for crate::api::policy::CommitAuditEntry
+{
+    fn into_into_dart(self) -> crate::api::policy::CommitAuditEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::CommitResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.serialized_commit.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::CommitResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::CommitResult>
+    for crate::api::types::CommitResult
+{
+    fn into_into_dart(self) -> crate::api::types::CommitResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::CreateGroupResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.group.into_into_dart().into_dart(),
+            self.serialized_welcome_message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::CreateGroupResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::CreateGroupResult>
+    for crate::api::types::CreateGroupResult
+{
+    fn into_into_dart(self) -> crate::api::types::CreateGroupResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::DecryptedMessage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.event_id.into_into_dart().into_dart(),
+            self.pubkey.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.mls_group_id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.rumor_json.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::DecryptedMessage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::DecryptedMessage>
+    for crate::api::types::DecryptedMessage
+{
+    fn into_into_dart(self) -> crate::api::types::DecryptedMessage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::EncryptedMedia {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.encrypted_data.into_into_dart().into_dart(),
+            self.encrypted_hash.into_into_dart().into_dart(),
+            self.reference.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::EncryptedMedia
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::EncryptedMedia>
+    for crate::api::media::EncryptedMedia
+{
+    fn into_into_dart(self) -> crate::api::media::EncryptedMedia {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::EventResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.event_id.into_into_dart().into_dart(),
+            self.event_json.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::EventResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::EventResult>
+    for crate::api::types::EventResult
+{
+    fn into_into_dart(self) -> crate::api::types::EventResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::ExportedSecret {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.secret.into_into_dart().into_dart(),
+            self.epoch.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::ExportedSecret
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::ExportedSecret>
+    for crate::api::types::ExportedSecret
+{
+    fn into_into_dart(self) -> crate::api::types::ExportedSecret {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::welcomes::GiftWrappedWelcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.recipient.into_into_dart().into_dart(),
+            self.event.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::welcomes::GiftWrappedWelcome
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::welcomes::GiftWrappedWelcome>
+    for crate::api::welcomes::GiftWrappedWelcome
+{
+    fn into_into_dart(self) -> crate::api::welcomes::GiftWrappedWelcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::events::GroupEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::events::GroupEvent::MessageDecrypted { message } => {
+                [0.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::events::GroupEvent::MembersAdded {
+                mls_group_id,
+                members,
+            } => [
+                1.into_dart(),
+                mls_group_id.into_into_dart().into_dart(),
+                members.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::GroupEvent::MembersRemoved {
+                mls_group_id,
+                members,
+            } => [
+                2.into_dart(),
+                mls_group_id.into_into_dart().into_dart(),
+                members.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::GroupEvent::EpochAdvanced {
+                mls_group_id,
+                epoch,
+            } => [
+                3.into_dart(),
+                mls_group_id.into_into_dart().into_dart(),
+                epoch.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::GroupEvent::GroupMetadataChanged { group } => {
+                [4.into_dart(), group.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::events::GroupEvent::WelcomeReceived {
+                wrapper_event_id,
+                group,
+            } => [
+                5.into_dart(),
+                wrapper_event_id.into_into_dart().into_dart(),
+                group.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::GroupEvent::KeyPackageConsumed {
+                wrapper_event_id,
+                key_package_id,
+            } => [
+                6.into_dart(),
+                wrapper_event_id.into_into_dart().into_dart(),
+                key_package_id.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::GroupEvent::PendingCommitRolledBack {
+                mls_group_id,
+                event_id,
+            } => [
+                7.into_dart(),
+                mls_group_id.into_into_dart().into_dart(),
+                event_id.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::GroupEvent::GroupPurged { mls_group_id } => {
+                [8.into_dart(), mls_group_id.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::events::GroupEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::events::GroupEvent>
+    for crate::api::events::GroupEvent
+{
+    fn into_into_dart(self) -> crate::api::events::GroupEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recovery::GroupHealth {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mls_group_id.into_into_dart().into_dart(),
+            self.epoch.into_into_dart().into_dart(),
+            self.tree_hash.into_into_dart().into_dart(),
+            self.pending_message_count.into_into_dart().into_dart(),
+            self.oldest_pending_message_at.into_into_dart().into_dart(),
+            self.undecryptable_event_count.into_into_dart().into_dart(),
+            self.last_undecryptable_event_at
+                .into_into_dart()
+                .into_dart(),
+            self.consecutive_failures.into_into_dart().into_dart(),
+            self.last_failure.into_into_dart().into_dart(),
+            self.last_failure_at.into_into_dart().into_dart(),
+            self.last_success_at.into_into_dart().into_dart(),
+            self.recovery_requested_at.into_into_dart().into_dart(),
+            self.recovery_key_package_id.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recovery::GroupHealth
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recovery::GroupHealth>
+    for crate::api::recovery::GroupHealth
+{
+    fn into_into_dart(self) -> crate::api::recovery::GroupHealth {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recovery::GroupHealthStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Healthy => 0.into_dart(),
+            Self::Lagging => 1.into_dart(),
+            Self::Forked => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recovery::GroupHealthStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recovery::GroupHealthStatus>
+    for crate::api::recovery::GroupHealthStatus
+{
+    fn into_into_dart(self) -> crate::api::recovery::GroupHealthStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::GroupInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mls_group_id.into_into_dart().into_dart(),
+            self.members.into_into_dart().into_dart(),
+            self.nostr_group_data.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::types::GroupInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::GroupInfo>
+    for crate::api::types::GroupInfo
+{
+    fn into_into_dart(self) -> crate::api::types::GroupInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::policy::GroupPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.add_members.into_into_dart().into_dart(),
+            self.remove_members.into_into_dart().into_dart(),
+            self.update_group_data.into_into_dart().into_dart(),
+            self.allow_self_remove.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::policy::GroupPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::policy::GroupPolicy>
+    for crate::api::policy::GroupPolicy
+{
+    fn into_into_dart(self) -> crate::api::policy::GroupPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recovery::GroupRecoveryRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mls_group_id.into_into_dart().into_dart(),
+            self.key_package.into_into_dart().into_dart(),
+            self.admins.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recovery::GroupRecoveryRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recovery::GroupRecoveryRequest>
+    for crate::api::recovery::GroupRecoveryRequest
+{
+    fn into_into_dart(self) -> crate::api::recovery::GroupRecoveryRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recovery::GroupStateComparison {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::InSync => 0.into_dart(),
+            Self::Behind => 1.into_dart(),
+            Self::Ahead => 2.into_dart(),
+            Self::Diverged => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recovery::GroupStateComparison
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recovery::GroupStateComparison>
+    for crate::api::recovery::GroupStateComparison
+{
+    fn into_into_dart(self) -> crate::api::recovery::GroupStateComparison {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::GroupStateFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::All => 0.into_dart(),
+            Self::Active => 1.into_dart(),
+            Self::Left => 2.into_dart(),
+            Self::Pending => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::GroupStateFilter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::GroupStateFilter>
+    for crate::api::types::GroupStateFilter
+{
+    fn into_into_dart(self) -> crate::api::types::GroupStateFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::GroupStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Active => 0.into_dart(),
+            Self::Left => 1.into_dart(),
+            Self::Pending => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::GroupStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::GroupStatus>
+    for crate::api::types::GroupStatus
+{
+    fn into_into_dart(self) -> crate::api::types::GroupStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::GroupSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mls_group_id.into_into_dart().into_dart(),
+            self.nostr_group_id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.epoch.into_into_dart().into_dart(),
+            self.member_count.into_into_dart().into_dart(),
+            self.last_message_id.into_into_dart().into_dart(),
+            self.last_message_at.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::GroupSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::GroupSummary>
+    for crate::api::types::GroupSummary
+{
+    fn into_into_dart(self) -> crate::api::types::GroupSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::welcomes::InboxWelcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.sender.into_into_dart().into_dart(),
+            self.group.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.received_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::welcomes::InboxWelcome
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::welcomes::InboxWelcome>
+    for crate::api::welcomes::InboxWelcome
+{
+    fn into_into_dart(self) -> crate::api::welcomes::InboxWelcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::KeyPackageLookup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.found.into_into_dart().into_dart(),
+            self.key_package.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::KeyPackageLookup
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::KeyPackageLookup>
+    for crate::api::types::KeyPackageLookup
+{
+    fn into_into_dart(self) -> crate::api::types::KeyPackageLookup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::key_packages::KeyPackagePolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.last_resort_count.into_into_dart().into_dart(),
+            self.one_time_count.into_into_dart().into_dart(),
+            self.max_age_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::key_packages::KeyPackagePolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::key_packages::KeyPackagePolicy>
+    for crate::api::key_packages::KeyPackagePolicy
+{
+    fn into_into_dart(self) -> crate::api::key_packages::KeyPackagePolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::KeyPackageResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.encoded_key_package.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::KeyPackageResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::KeyPackageResult>
+    for crate::api::types::KeyPackageResult
+{
+    fn into_into_dart(self) -> crate::api::types::KeyPackageResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::key_packages::KeyPackageRotation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.to_publish.into_into_dart().into_dart(),
+            self.to_delete.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::key_packages::KeyPackageRotation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::key_packages::KeyPackageRotation>
+    for crate::api::key_packages::KeyPackageRotation
+{
+    fn into_into_dart(self) -> crate::api::key_packages::KeyPackageRotation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::key_packages::KeyPackageState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Unpublished => 0.into_dart(),
+            Self::Published => 1.into_dart(),
+            Self::Consumed => 2.into_dart(),
+            Self::Declined => 3.into_dart(),
+            Self::Retired => 4.into_dart(),
+            Self::Deleted => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::key_packages::KeyPackageState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::key_packages::KeyPackageState>
+    for crate::api::key_packages::KeyPackageState
+{
+    fn into_into_dart(self) -> crate::api::key_packages::KeyPackageState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::keyring::KeyringPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.retained_epochs.into_into_dart().into_dart(),
+            self.max_age_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::keyring::KeyringPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keyring::KeyringPolicy>
+    for crate::api::keyring::KeyringPolicy
+{
+    fn into_into_dart(self) -> crate::api::keyring::KeyringPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::key_packages::ManagedKeyPackage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.encoded_key_package.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.last_resort.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.event_id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.published_at.into_into_dart().into_dart(),
+            self.consumed_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::key_packages::ManagedKeyPackage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::key_packages::ManagedKeyPackage>
+    for crate::api::key_packages::ManagedKeyPackage
+{
+    fn into_into_dart(self) -> crate::api::key_packages::ManagedKeyPackage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::MediaReference {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.filename.into_into_dart().into_dart(),
+            self.original_hash.into_into_dart().into_dart(),
+            self.nonce.into_into_dart().into_dart(),
+            self.epoch.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::MediaReference
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::MediaReference>
+    for crate::api::media::MediaReference
+{
+    fn into_into_dart(self) -> crate::api::media::MediaReference {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::messages::MessageCursor {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.created_at.into_into_dart().into_dart(),
+            self.event_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::messages::MessageCursor
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messages::MessageCursor>
+    for crate::api::messages::MessageCursor
+{
+    fn into_into_dart(self) -> crate::api::messages::MessageCursor {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::error::MlsBridgeError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::error::MlsBridgeError::NotInitialized => [0.into_dart()].into_dart(),
+            crate::api::error::MlsBridgeError::InvalidInput { field, message } => [
+                1.into_dart(),
+                field.into_into_dart().into_dart(),
+                message.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::error::MlsBridgeError::GroupNotFound => [2.into_dart()].into_dart(),
+            crate::api::error::MlsBridgeError::StaleEpoch { message } => {
+                [3.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::MlsBridgeError::OwnMessage => [4.into_dart()].into_dart(),
+            crate::api::error::MlsBridgeError::DecryptionFailed { message } => {
+                [5.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::MlsBridgeError::StorageLocked { message } => {
+                [6.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::MlsBridgeError::KeyPackageNotFound => [7.into_dart()].into_dart(),
+            crate::api::error::MlsBridgeError::PermissionDenied { message } => {
+                [8.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::MlsBridgeError::Signer { message } => {
+                [9.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::MlsBridgeError::Transfer { message } => {
+                [10.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::MlsBridgeError::Storage { message } => {
+                [11.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::MlsBridgeError::Mls { message } => {
+                [12.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::MlsBridgeError::Internal { message } => {
+                [13.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::error::MlsBridgeError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::error::MlsBridgeError>
+    for crate::api::error::MlsBridgeError
+{
+    fn into_into_dart(self) -> crate::api::error::MlsBridgeError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::NostrGroupData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.nostr_group_id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.admin_pubkeys.into_into_dart().into_dart(),
+            self.relays.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::NostrGroupData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::NostrGroupData>
+    for crate::api::types::NostrGroupData
+{
+    fn into_into_dart(self) -> crate::api::types::NostrGroupData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::commits::PendingCommit {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.epoch.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.event_id.into_into_dart().into_dart(),
+            self.event_json.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::commits::PendingCommit
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::commits::PendingCommit>
+    for crate::api::commits::PendingCommit
+{
+    fn into_into_dart(self) -> crate::api::commits::PendingCommit {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::commits::PendingCommitKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Commit => 0.into_dart(),
+            Self::Leave => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::commits::PendingCommitKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::commits::PendingCommitKind>
+    for crate::api::commits::PendingCommitKind
+{
+    fn into_into_dart(self) -> crate::api::commits::PendingCommitKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::policy::PolicyRole {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Admins => 0.into_dart(),
+            Self::Members => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::policy::PolicyRole
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::policy::PolicyRole>
+    for crate::api::policy::PolicyRole
+{
+    fn into_into_dart(self) -> crate::api::policy::PolicyRole {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::ProcessedMessage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.message.into_into_dart().into_dart(),
+            self.added_members.into_into_dart().into_dart(),
+            self.removed_members.into_into_dart().into_dart(),
+            self.commit.into_into_dart().into_dart(),
+            self.welcome.into_into_dart().into_dart(),
+            self.staged_message_bytes.into_into_dart().into_dart(),
+            self.pending.into_into_dart().into_dart(),
+            self.released_messages.into_into_dart().into_dart(),
+            self.superseded.into_into_dart().into_dart(),
+            self.own_commit_rolled_back.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::ProcessedMessage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::ProcessedMessage>
+    for crate::api::types::ProcessedMessage
+{
+    fn into_into_dart(self) -> crate::api::types::ProcessedMessage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::commits::ProposalCommitResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.commit_event.into_into_dart().into_dart(),
+            self.welcome_message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::commits::ProposalCommitResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::commits::ProposalCommitResult>
+    for crate::api::commits::ProposalCommitResult
+{
+    fn into_into_dart(self) -> crate::api::commits::ProposalCommitResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::commits::ProposalKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Add => 0.into_dart(),
+            Self::Remove => 1.into_dart(),
+            Self::Update => 2.into_dart(),
+            Self::GroupContextExtensions => 3.into_dart(),
+            Self::Other => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::commits::ProposalKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::commits::ProposalKind>
+    for crate::api::commits::ProposalKind
+{
+    fn into_into_dart(self) -> crate::api::commits::ProposalKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::commits::ProposalSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.proposal_ref.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.proposer.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::commits::ProposalSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::commits::ProposalSummary>
+    for crate::api::commits::ProposalSummary
+{
+    fn into_into_dart(self) -> crate::api::commits::ProposalSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::retention::RetentionPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.message_max_age_secs.into_into_dart().into_dart(),
+            self.purge_inactive_groups.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::retention::RetentionPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::retention::RetentionPolicy>
+    for crate::api::retention::RetentionPolicy
+{
+    fn into_into_dart(self) -> crate::api::retention::RetentionPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::retention::RetentionReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.purged_groups.into_into_dart().into_dart(),
+            self.expired_messages.into_into_dart().into_dart(),
+            self.forgotten_key_packages.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::retention::RetentionReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::retention::RetentionReport>
+    for crate::api::retention::RetentionReport
+{
+    fn into_into_dart(self) -> crate::api::retention::RetentionReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::rotation::RotationPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_messages.into_into_dart().into_dart(),
+            self.max_age_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::rotation::RotationPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::rotation::RotationPolicy>
+    for crate::api::rotation::RotationPolicy
+{
+    fn into_into_dart(self) -> crate::api::rotation::RotationPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::rotation::RotationStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mls_group_id.into_into_dart().into_dart(),
+            self.last_rotated_at.into_into_dart().into_dart(),
+            self.messages_since_rotation.into_into_dart().into_dart(),
+            self.due.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::rotation::RotationStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::rotation::RotationStatus>
+    for crate::api::rotation::RotationStatus
+{
+    fn into_into_dart(self) -> crate::api::rotation::RotationStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::signer::SignerScope {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Seals => 0.into_dart(),
+            Self::SealsAndCommits => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::signer::SignerScope
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::signer::SignerScope>
+    for crate::api::signer::SignerScope
+{
+    fn into_into_dart(self) -> crate::api::signer::SignerScope {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::storage::StorageKeySource {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::storage::StorageKeySource::Passphrase { passphrase } => {
+                [0.into_dart(), passphrase.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::storage::StorageKeySource::KeyFile { path } => {
+                [1.into_dart(), path.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::storage::StorageKeySource
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::storage::StorageKeySource>
+    for crate::api::storage::StorageKeySource
+{
+    fn into_into_dart(self) -> crate::api::storage::StorageKeySource {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::welcomes::UnwrappedWelcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.wrapper_event_id.into_into_dart().into_dart(),
+            self.sender.into_into_dart().into_dart(),
+            self.rumor_json.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::welcomes::UnwrappedWelcome
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::welcomes::UnwrappedWelcome>
+    for crate::api::welcomes::UnwrappedWelcome
+{
+    fn into_into_dart(self) -> crate::api::welcomes::UnwrappedWelcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::UploadedMedia {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.imeta_tag.into_into_dart().into_dart(),
+            self.reference.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::UploadedMedia
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::UploadedMedia>
+    for crate::api::media::UploadedMedia
+{
+    fn into_into_dart(self) -> crate::api::media::UploadedMedia {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::WelcomeKeyPackageMatch {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.found.into_into_dart().into_dart(),
+            self.matched_index.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::WelcomeKeyPackageMatch
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::WelcomeKeyPackageMatch>
+    for crate::api::types::WelcomeKeyPackageMatch
+{
+    fn into_into_dart(self) -> crate::api::types::WelcomeKeyPackageMatch {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::welcomes::WelcomeList {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.welcomes.into_into_dart().into_dart(),
+            self.unreadable.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::welcomes::WelcomeList
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::welcomes::WelcomeList>
+    for crate::api::welcomes::WelcomeList
+{
+    fn into_into_dart(self) -> crate::api::welcomes::WelcomeList {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::welcomes::WelcomeRecipient {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.public_key.into_into_dart().into_dart(),
+            self.key_package_event_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::welcomes::WelcomeRecipient
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::welcomes::WelcomeRecipient>
+    for crate::api::welcomes::WelcomeRecipient
+{
+    fn into_into_dart(self) -> crate::api::welcomes::WelcomeRecipient {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::welcomes::WelcomeState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Pending => 0.into_dart(),
+            Self::Accepted => 1.into_dart(),
+            Self::Declined => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::welcomes::WelcomeState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::welcomes::WelcomeState>
+    for crate::api::welcomes::WelcomeState
+{
+    fn into_into_dart(self) -> crate::api::welcomes::WelcomeState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::welcomes::WelcomeStateFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::All => 0.into_dart(),
+            Self::Pending => 1.into_dart(),
+            Self::Accepted => 2.into_dart(),
+            Self::Declined => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::welcomes::WelcomeStateFilter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::welcomes::WelcomeStateFilter>
+    for crate::api::welcomes::WelcomeStateFilter
+{
+    fn into_into_dart(self) -> crate::api::welcomes::WelcomeStateFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::types::WelcomeSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.welcome_event_id.into_into_dart().into_dart(),
+            self.wrapper_event_id.into_into_dart().into_dart(),
+            self.mls_group_id.into_into_dart().into_dart(),
+            self.nostr_group_data.into_into_dart().into_dart(),
+            self.welcomer.into_into_dart().into_dart(),
+            self.member_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::types::WelcomeSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::types::WelcomeSummary>
+    for crate::api::types::WelcomeSummary
+{
+    fn into_into_dart(self) -> crate::api::types::WelcomeSummary {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::DartOpaque {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.encode(), serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::events::GroupEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::types::AddMembersResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.commit_message, serializer);
+        <Vec<u8>>::sse_encode(self.welcome_message, serializer);
+    }
+}
+
+impl SseEncode for crate::api::batch::BatchEventResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.event_id, serializer);
+        <Option<crate::api::types::ProcessedMessage>>::sse_encode(self.processed, serializer);
+        <Option<crate::api::error::MlsBridgeError>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::policy::CommitAuditEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.epoch, serializer);
+        <Option<String>>::sse_encode(self.sender, serializer);
+        <String>::sse_encode(self.reason, serializer);
+        <u64>::sse_encode(self.created_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::types::CommitResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.serialized_commit, serializer);
+    }
+}
+
+impl SseEncode for crate::api::types::CreateGroupResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::types::GroupInfo>::sse_encode(self.group, serializer);
+        <Vec<u8>>::sse_encode(self.serialized_welcome_message, serializer);
+    }
+}
+
+impl SseEncode for crate::api::types::DecryptedMessage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.event_id, serializer);
+        <String>::sse_encode(self.pubkey, serializer);
+        <u16>::sse_encode(self.kind, serializer);
+        <Vec<u8>>::sse_encode(self.mls_group_id, serializer);
+        <u64>::sse_encode(self.created_at, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Vec<Vec<String>>>::sse_encode(self.tags, serializer);
+        <String>::sse_encode(self.rumor_json, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::EncryptedMedia {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.encrypted_data, serializer);
+        <String>::sse_encode(self.encrypted_hash, serializer);
+        <crate::api::media::MediaReference>::sse_encode(self.reference, serializer);
+    }
+}
+
+impl SseEncode for crate::api::types::EventResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.event_id, serializer);
+        <String>::sse_encode(self.event_json, serializer);
+    }
+}
+
+impl SseEncode for crate::api::types::ExportedSecret {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.secret, serializer);
+        <u64>::sse_encode(self.epoch, serializer);
+    }
+}
+
+impl SseEncode for crate::api::welcomes::GiftWrappedWelcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.recipient, serializer);
+        <crate::api::types::EventResult>::sse_encode(self.event, serializer);
+    }
+}
+
+impl SseEncode for crate::api::events::GroupEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::events::GroupEvent::MessageDecrypted { message } => {
+                <i32>::sse_encode(0, serializer);
+                <crate::api::types::DecryptedMessage>::sse_encode(message, serializer);
+            }
+            crate::api::events::GroupEvent::MembersAdded {
+                mls_group_id,
+                members,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <Vec<u8>>::sse_encode(mls_group_id, serializer);
+                <Vec<String>>::sse_encode(members, serializer);
+            }
+            crate::api::events::GroupEvent::MembersRemoved {
+                mls_group_id,
+                members,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <Vec<u8>>::sse_encode(mls_group_id, serializer);
+                <Vec<String>>::sse_encode(members, serializer);
+            }
+            crate::api::events::GroupEvent::EpochAdvanced {
+                mls_group_id,
+                epoch,
+            } => {
+                <i32>::sse_encode(3, serializer);
+                <Vec<u8>>::sse_encode(mls_group_id, serializer);
+                <u64>::sse_encode(epoch, serializer);
+            }
+            crate::api::events::GroupEvent::GroupMetadataChanged { group } => {
+                <i32>::sse_encode(4, serializer);
+                <crate::api::types::GroupInfo>::sse_encode(group, serializer);
+            }
+            crate::api::events::GroupEvent::WelcomeReceived {
+                wrapper_event_id,
+                group,
+            } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(wrapper_event_id, serializer);
+                <crate::api::types::GroupInfo>::sse_encode(group, serializer);
+            }
+            crate::api::events::GroupEvent::KeyPackageConsumed {
+                wrapper_event_id,
+                key_package_id,
+            } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(wrapper_event_id, serializer);
+                <String>::sse_encode(key_package_id, serializer);
+            }
+            crate::api::events::GroupEvent::PendingCommitRolledBack {
+                mls_group_id,
+                event_id,
+            } => {
+                <i32>::sse_encode(7, serializer);
+                <Vec<u8>>::sse_encode(mls_group_id, serializer);
+                <Option<String>>::sse_encode(event_id, serializer);
+            }
+            crate::api::events::GroupEvent::GroupPurged { mls_group_id } => {
+                <i32>::sse_encode(8, serializer);
+                <Vec<u8>>::sse_encode(mls_group_id, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::recovery::GroupHealth {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.mls_group_id, serializer);
+        <u64>::sse_encode(self.epoch, serializer);
+        <String>::sse_encode(self.tree_hash, serializer);
+        <u32>::sse_encode(self.pending_message_count, serializer);
+        <Option<u64>>::sse_encode(self.oldest_pending_message_at, serializer);
+        <u32>::sse_encode(self.undecryptable_event_count, serializer);
+        <Option<u64>>::sse_encode(self.last_undecryptable_event_at, serializer);
+        <u32>::sse_encode(self.consecutive_failures, serializer);
+        <Option<String>>::sse_encode(self.last_failure, serializer);
+        <Option<u64>>::sse_encode(self.last_failure_at, serializer);
+        <Option<u64>>::sse_encode(self.last_success_at, serializer);
+        <Option<u64>>::sse_encode(self.recovery_requested_at, serializer);
+        <Option<String>>::sse_encode(self.recovery_key_package_id, serializer);
+        <crate::api::recovery::GroupHealthStatus>::sse_encode(self.status, serializer);
+    }
+}
+
+impl SseEncode for crate::api::recovery::GroupHealthStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::recovery::GroupHealthStatus::Healthy => 0,
+                crate::api::recovery::GroupHealthStatus::Lagging => 1,
+                crate::api::recovery::GroupHealthStatus::Forked => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::types::GroupInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.mls_group_id, serializer);
+        <Vec<String>>::sse_encode(self.members, serializer);
+        <crate::api::types::NostrGroupData>::sse_encode(self.nostr_group_data, serializer);
+    }
+}
+
+impl SseEncode for crate::api::policy::GroupPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::policy::PolicyRole>::sse_encode(self.add_members, serializer);
+        <crate::api::policy::PolicyRole>::sse_encode(self.remove_members, serializer);
+        <crate::api::policy::PolicyRole>::sse_encode(self.update_group_data, serializer);
+        <bool>::sse_encode(self.allow_self_remove, serializer);
+    }
+}
+
+impl SseEncode for crate::api::recovery::GroupRecoveryRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.mls_group_id, serializer);
+        <crate::api::key_packages::ManagedKeyPackage>::sse_encode(self.key_package, serializer);
+        <Vec<String>>::sse_encode(self.admins, serializer);
+    }
+}
+
+impl SseEncode for crate::api::recovery::GroupStateComparison {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::recovery::GroupStateComparison::InSync => 0,
+                crate::api::recovery::GroupStateComparison::Behind => 1,
+                crate::api::recovery::GroupStateComparison::Ahead => 2,
+                crate::api::recovery::GroupStateComparison::Diverged => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::types::GroupStateFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::types::GroupStateFilter::All => 0,
+                crate::api::types::GroupStateFilter::Active => 1,
+                crate::api::types::GroupStateFilter::Left => 2,
+                crate::api::types::GroupStateFilter::Pending => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::types::GroupStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::types::GroupStatus::Active => 0,
+                crate::api::types::GroupStatus::Left => 1,
+                crate::api::types::GroupStatus::Pending => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::types::GroupSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.mls_group_id, serializer);
+        <String>::sse_encode(self.nostr_group_id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.description, serializer);
+        <u64>::sse_encode(self.epoch, serializer);
+        <u32>::sse_encode(self.member_count, serializer);
+        <Option<String>>::sse_encode(self.last_message_id, serializer);
+        <Option<u64>>::sse_encode(self.last_message_at, serializer);
+        <crate::api::types::GroupStatus>::sse_encode(self.status, serializer);
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::welcomes::InboxWelcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.sender, serializer);
+        <crate::api::types::GroupInfo>::sse_encode(self.group, serializer);
+        <crate::api::welcomes::WelcomeState>::sse_encode(self.state, serializer);
+        <u64>::sse_encode(self.received_at, serializer);
+    }
+}
+
+impl SseEncode for isize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer
+            .cursor
+            .write_i64::<NativeEndian>(self as _)
+            .unwrap();
+    }
+}
+
+impl SseEncode for crate::api::types::KeyPackageLookup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.found, serializer);
+        <Option<String>>::sse_encode(self.key_package, serializer);
+    }
+}
+
+impl SseEncode for crate::api::key_packages::KeyPackagePolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.last_resort_count, serializer);
+        <u32>::sse_encode(self.one_time_count, serializer);
+        <u64>::sse_encode(self.max_age_secs, serializer);
+    }
+}
+
+impl SseEncode for crate::api::types::KeyPackageResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.encoded_key_package, serializer);
+        <Vec<Vec<String>>>::sse_encode(self.tags, serializer);
+    }
+}
+
+impl SseEncode for crate::api::key_packages::KeyPackageRotation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::key_packages::ManagedKeyPackage>>::sse_encode(self.to_publish, serializer);
+        <Vec<crate::api::key_packages::ManagedKeyPackage>>::sse_encode(self.to_delete, serializer);
+    }
+}
+
+impl SseEncode for crate::api::key_packages::KeyPackageState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::key_packages::KeyPackageState::Unpublished => 0,
+                crate::api::key_packages::KeyPackageState::Published => 1,
+                crate::api::key_packages::KeyPackageState::Consumed => 2,
+                crate::api::key_packages::KeyPackageState::Declined => 3,
+                crate::api::key_packages::KeyPackageState::Retired => 4,
+                crate::api::key_packages::KeyPackageState::Deleted => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::keyring::KeyringPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.retained_epochs, serializer);
+        <Option<u64>>::sse_encode(self.max_age_secs, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::batch::BatchEventResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::batch::BatchEventResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::policy::CommitAuditEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::policy::CommitAuditEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::types::DecryptedMessage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::types::DecryptedMessage>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::welcomes::GiftWrappedWelcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::welcomes::GiftWrappedWelcome>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::types::GroupSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::types::GroupSummary>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::welcomes::InboxWelcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::welcomes::InboxWelcome>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Vec<String>>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Vec<u8>>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::key_packages::ManagedKeyPackage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::key_packages::ManagedKeyPackage>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u64>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::commits::ProposalSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::commits::ProposalSummary>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::rotation::RotationStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::rotation::RotationStatus>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::welcomes::WelcomeRecipient> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::welcomes::WelcomeRecipient>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::types::WelcomeSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::types::WelcomeSummary>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::key_packages::ManagedKeyPackage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.encoded_key_package, serializer);
+        <Vec<Vec<String>>>::sse_encode(self.tags, serializer);
+        <bool>::sse_encode(self.last_resort, serializer);
+        <crate::api::key_packages::KeyPackageState>::sse_encode(self.state, serializer);
+        <Option<String>>::sse_encode(self.event_id, serializer);
+        <u64>::sse_encode(self.created_at, serializer);
+        <Option<u64>>::sse_encode(self.published_at, serializer);
+        <Option<u64>>::sse_encode(self.consumed_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::MediaReference {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.mime_type, serializer);
+        <String>::sse_encode(self.filename, serializer);
+        <String>::sse_encode(self.original_hash, serializer);
+        <String>::sse_encode(self.nonce, serializer);
+        <u64>::sse_encode(self.epoch, serializer);
+        <u64>::sse_encode(self.size, serializer);
+    }
+}
+
+impl SseEncode for crate::api::messages::MessageCursor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.created_at, serializer);
+        <String>::sse_encode(self.event_id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::error::MlsBridgeError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::error::MlsBridgeError::NotInitialized => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::error::MlsBridgeError::InvalidInput { field, message } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::error::MlsBridgeError::GroupNotFound => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::error::MlsBridgeError::StaleEpoch { message } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::error::MlsBridgeError::OwnMessage => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::api::error::MlsBridgeError::DecryptionFailed { message } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::error::MlsBridgeError::StorageLocked { message } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::error::MlsBridgeError::KeyPackageNotFound => {
+                <i32>::sse_encode(7, serializer);
+            }
+            crate::api::error::MlsBridgeError::PermissionDenied { message } => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::error::MlsBridgeError::Signer { message } => {
+                <i32>::sse_encode(9, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::error::MlsBridgeError::Transfer { message } => {
+                <i32>::sse_encode(10, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::error::MlsBridgeError::Storage { message } => {
+                <i32>::sse_encode(11, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::error::MlsBridgeError::Mls { message } => {
+                <i32>::sse_encode(12, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::error::MlsBridgeError::Internal { message } => {
+                <i32>::sse_encode(13, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::types::NostrGroupData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.nostr_group_id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.description, serializer);
+        <Vec<String>>::sse_encode(self.admin_pubkeys, serializer);
+        <Vec<String>>::sse_encode(self.relays, serializer);
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::types::DecryptedMessage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::types::DecryptedMessage>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::messages::MessageCursor> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::messages::MessageCursor>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseEncode for Option<crate::api::error::MlsBridgeError> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::error::MlsBridgeError>::sse_encode(value, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Option<crate::api::commits::PendingCommit> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::commits::PendingCommit>::sse_encode(value, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for Option<crate::api::types::ProcessedMessage> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::types::ProcessedMessage>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseDecode for Option<Vec<String>> {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<String>>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for [u8; 32] {
+impl SseEncode for Option<Vec<String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::from_vec_to_array(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<String>>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Option<Vec<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<u8>>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for crate::api::commits::PendingCommit {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.epoch, serializer);
+        <crate::api::commits::PendingCommitKind>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.event_id, serializer);
+        <Option<String>>::sse_encode(self.event_json, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__mls_api__add_members_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__mls_api__commit_proposal_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__mls_api__create_commit_message_for_group_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        4 => wire__crate__api__mls_api__create_group_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__mls_api__create_key_package_for_event_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        6 => wire__crate__api__mls_api__create_message_for_group_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        7 => wire__crate__api__mls_api__export_secret_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__mls_api__find_encoded_keypackage_from_welcome_event_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        9 => wire__crate__api__mls_api__get_ciphersuite_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__mls_api__get_extensions_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__mls_api__get_group_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__mls_api__get_key_package_from_storage_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        13 => wire__crate__api__mls_api__get_members_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__mls_api__init_nostr_mls_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__mls_api__join_group_from_welcome_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        16 => wire__crate__api__mls_api__leave_group_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__mls_api__preview_group_from_welcome_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        18 => wire__crate__api__mls_api__process_commit_message_for_group_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        19 => wire__crate__api__mls_api__process_message_for_group_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        20 => wire__crate__api__mls_api__remove_members_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::api::commits::PendingCommitKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::commits::PendingCommitKind::Commit => 0,
+                crate::api::commits::PendingCommitKind::Leave => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl SseEncode for crate::api::policy::PolicyRole {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::policy::PolicyRole::Admins => 0,
+                crate::api::policy::PolicyRole::Members => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-// Section: rust2dart
+impl SseEncode for crate::api::types::ProcessedMessage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::api::types::DecryptedMessage>>::sse_encode(self.message, serializer);
+        <Vec<String>>::sse_encode(self.added_members, serializer);
+        <Vec<String>>::sse_encode(self.removed_members, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.commit, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.welcome, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.staged_message_bytes, serializer);
+        <bool>::sse_encode(self.pending, serializer);
+        <Vec<crate::api::types::DecryptedMessage>>::sse_encode(self.released_messages, serializer);
+        <bool>::sse_encode(self.superseded, serializer);
+        <bool>::sse_encode(self.own_commit_rolled_back, serializer);
+    }
+}
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for crate::api::commits::ProposalCommitResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <crate::api::types::EventResult>::sse_encode(self.commit_event, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.welcome_message, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::commits::ProposalKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::commits::ProposalKind::Add => 0,
+                crate::api::commits::ProposalKind::Remove => 1,
+                crate::api::commits::ProposalKind::Update => 2,
+                crate::api::commits::ProposalKind::GroupContextExtensions => 3,
+                crate::api::commits::ProposalKind::Other => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for crate::api::commits::ProposalSummary {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.proposal_ref, serializer);
+        <crate::api::commits::ProposalKind>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.proposer, serializer);
+        <Option<String>>::sse_encode(self.target, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::retention::RetentionPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <Option<u64>>::sse_encode(self.message_max_age_secs, serializer);
+        <bool>::sse_encode(self.purge_inactive_groups, serializer);
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for crate::api::retention::RetentionReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        <Vec<Vec<u8>>>::sse_encode(self.purged_groups, serializer);
+        <u32>::sse_encode(self.expired_messages, serializer);
+        <u32>::sse_encode(self.forgotten_key_packages, serializer);
     }
 }
 
-impl SseEncode for Option<Vec<String>> {
+impl SseEncode for crate::api::rotation::RotationPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<String>>::sse_encode(value, serializer);
+        <Option<u64>>::sse_encode(self.max_messages, serializer);
+        <Option<u64>>::sse_encode(self.max_age_secs, serializer);
+    }
+}
+
+impl SseEncode for crate::api::rotation::RotationStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.mls_group_id, serializer);
+        <u64>::sse_encode(self.last_rotated_at, serializer);
+        <u64>::sse_encode(self.messages_since_rotation, serializer);
+        <bool>::sse_encode(self.due, serializer);
+    }
+}
+
+impl SseEncode for crate::api::signer::SignerScope {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::signer::SignerScope::Seals => 0,
+                crate::api::signer::SignerScope::SealsAndCommits => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::storage::StorageKeySource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::storage::StorageKeySource::Passphrase { passphrase } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(passphrase, serializer);
+            }
+            crate::api::storage::StorageKeySource::KeyFile { path } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(path, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
 
+impl SseEncode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1057,30 +7695,106 @@ impl SseEncode for u8 {
     }
 }
 
-impl SseEncode for [u8; 32] {
+impl SseEncode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+}
+
+impl SseEncode for crate::api::welcomes::UnwrappedWelcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.wrapper_event_id, serializer);
+        <String>::sse_encode(self.sender, serializer);
+        <String>::sse_encode(self.rumor_json, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::UploadedMedia {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(
-            {
-                let boxed: Box<[_]> = Box::new(self);
-                boxed.into_vec()
+        <Vec<String>>::sse_encode(self.imeta_tag, serializer);
+        <crate::api::media::MediaReference>::sse_encode(self.reference, serializer);
+    }
+}
+
+impl SseEncode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer
+            .cursor
+            .write_u64::<NativeEndian>(self as _)
+            .unwrap();
+    }
+}
+
+impl SseEncode for crate::api::types::WelcomeKeyPackageMatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.found, serializer);
+        <Option<u32>>::sse_encode(self.matched_index, serializer);
+    }
+}
+
+impl SseEncode for crate::api::welcomes::WelcomeList {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::welcomes::InboxWelcome>>::sse_encode(self.welcomes, serializer);
+        <u32>::sse_encode(self.unreadable, serializer);
+    }
+}
+
+impl SseEncode for crate::api::welcomes::WelcomeRecipient {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.public_key, serializer);
+        <String>::sse_encode(self.key_package_event_id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::welcomes::WelcomeState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::welcomes::WelcomeState::Pending => 0,
+                crate::api::welcomes::WelcomeState::Accepted => 1,
+                crate::api::welcomes::WelcomeState::Declined => 2,
+                _ => {
+                    unimplemented!("");
+                }
             },
             serializer,
         );
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::api::welcomes::WelcomeStateFilter {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(
+            match self {
+                crate::api::welcomes::WelcomeStateFilter::All => 0,
+                crate::api::welcomes::WelcomeStateFilter::Pending => 1,
+                crate::api::welcomes::WelcomeStateFilter::Accepted => 2,
+                crate::api::welcomes::WelcomeStateFilter::Declined => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::types::WelcomeSummary {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <String>::sse_encode(self.welcome_event_id, serializer);
+        <String>::sse_encode(self.wrapper_event_id, serializer);
+        <Vec<u8>>::sse_encode(self.mls_group_id, serializer);
+        <crate::api::types::NostrGroupData>::sse_encode(self.nostr_group_data, serializer);
+        <String>::sse_encode(self.welcomer, serializer);
+        <u32>::sse_encode(self.member_count, serializer);
     }
 }
 
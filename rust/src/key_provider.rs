@@ -0,0 +1,513 @@
+//! Database keys kept out of Dart: a random key per identity, stored wrapped next to its database
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use rand::RngCore;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use zeroize::Zeroizing;
+
+use crate::api::error::MlsBridgeError;
+use crate::api::mls_api::open_identity;
+use crate::api::storage::{db_path, ensure_closed};
+use crate::store;
+
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const SALT_LEN: usize = 16;
+
+/// Argon2id memory in KiB, passes and lanes of new passphrase blobs
+/// Also the ceiling of the costs `unwrap_key` accepts from a blob, so they may only ever be
+/// raised: lowering one would lock out the databases wrapped with the previous costs.
+const ARGON2_COST: (u32, u32, u32) = (64 * 1024, 3, 1);
+
+/// Version byte of the blobs wrapped by `PassphraseKeyProvider`
+const PASSPHRASE_BLOB_VERSION: u8 = 1;
+/// Version byte of the blobs wrapped by `FileKeyProvider`
+const FILE_BLOB_VERSION: u8 = 1;
+
+/// Wraps and unwraps the random key encrypting an identity's database
+/// Rust embedders implement it for their keystore; Flutter apps reach the platform keystore
+/// through `init_nostr_mls_with_keystore`. The bridge ships `PassphraseKeyProvider` as a
+/// fallback and `FileKeyProvider` for tests.
+pub trait KeyProvider: Send + Sync {
+    /// Wrap a new database key, the blob is stored next to the database
+    fn wrap_key(&self, identity: &str, key: &[u8]) -> Result<Vec<u8>, MlsBridgeError>;
+
+    /// Unwrap the database key from its stored blob
+    /// A blob that does not unwrap fails with `StorageLocked`
+    fn unwrap_key(
+        &self,
+        identity: &str,
+        wrapped: &[u8],
+    ) -> Result<Zeroizing<Vec<u8>>, MlsBridgeError>;
+}
+
+/// Wraps the database key with a key derived from a passphrase with Argon2id
+pub struct PassphraseKeyProvider {
+    passphrase: Zeroizing<String>,
+}
+
+/// Wraps the database key with a key read from a file, created on first use
+/// Anyone who can read the file can open the database; meant for tests and development.
+pub struct FileKeyProvider {
+    path: PathBuf,
+}
+
+/// Initialize the NostrMls instance of an identity with a database key from a key provider
+/// On first use a random key is created and wrapped, and an existing unencrypted database is
+/// encrypted with it.
+/// Parameters: path - directory of the database, identity - key used by every other call
+pub fn init_nostr_mls_with_key_provider(
+    path: String,
+    identity: String,
+    provider: &dyn KeyProvider,
+) -> Result<(), MlsBridgeError> {
+    let db_path = db_path(&path, &identity);
+    let password = storage_password(&db_path, &identity, provider)?;
+
+    open_identity(&db_path, identity, Some(password.as_str()))
+}
+
+impl PassphraseKeyProvider {
+    pub fn new(passphrase: String) -> Self {
+        Self {
+            passphrase: Zeroizing::new(passphrase),
+        }
+    }
+
+    /// Only the costs of a stored blob can be invalid, so invalid costs make it unreadable
+    fn derive(
+        &self,
+        salt: &[u8],
+        (m_cost, t_cost, p_cost): (u32, u32, u32),
+    ) -> Result<Zeroizing<[u8; KEY_LEN]>, MlsBridgeError> {
+        let params =
+            Params::new(m_cost, t_cost, p_cost, Some(KEY_LEN)).map_err(|_| unreadable_blob())?;
+        let mut key = Zeroizing::new([0u8; KEY_LEN]);
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(self.passphrase.as_bytes(), salt, key.as_mut())
+            .map_err(MlsBridgeError::internal)?;
+        Ok(key)
+    }
+
+    /// Blob layout: version, Argon2 memory, passes and lanes as little endian u32, salt, sealed key
+    fn wrap_with_costs(
+        &self,
+        identity: &str,
+        key: &[u8],
+        costs: (u32, u32, u32),
+    ) -> Result<Vec<u8>, MlsBridgeError> {
+        let mut salt = [0u8; SALT_LEN];
+        rand::thread_rng().fill_bytes(&mut salt);
+        let wrapping_key = self.derive(&salt, costs)?;
+
+        let (m_cost, t_cost, p_cost) = costs;
+        let mut wrapped = vec![PASSPHRASE_BLOB_VERSION];
+        wrapped.extend_from_slice(&m_cost.to_le_bytes());
+        wrapped.extend_from_slice(&t_cost.to_le_bytes());
+        wrapped.extend_from_slice(&p_cost.to_le_bytes());
+        wrapped.extend_from_slice(&salt);
+        wrapped.extend(seal(&wrapping_key, identity, key)?);
+        Ok(wrapped)
+    }
+}
+
+impl KeyProvider for PassphraseKeyProvider {
+    fn wrap_key(&self, identity: &str, key: &[u8]) -> Result<Vec<u8>, MlsBridgeError> {
+        self.wrap_with_costs(identity, key, ARGON2_COST)
+    }
+
+    fn unwrap_key(
+        &self,
+        identity: &str,
+        wrapped: &[u8],
+    ) -> Result<Zeroizing<Vec<u8>>, MlsBridgeError> {
+        let header_len = 1 + 3 * 4 + SALT_LEN;
+        if wrapped.len() < header_len || wrapped[0] != PASSPHRASE_BLOB_VERSION {
+            return Err(unreadable_blob());
+        }
+
+        let cost = |offset: usize| -> Result<u32, MlsBridgeError> {
+            let bytes = wrapped[offset..offset + 4]
+                .try_into()
+                .map_err(MlsBridgeError::internal)?;
+            Ok(u32::from_le_bytes(bytes))
+        };
+        let costs = (cost(1)?, cost(5)?, cost(9)?);
+        // A tampered blob must not make Argon2 allocate or run beyond what wrap_key uses
+        let (max_m_cost, max_t_cost, max_p_cost) = ARGON2_COST;
+        if costs.0 > max_m_cost || costs.1 > max_t_cost || costs.2 > max_p_cost {
+            return Err(unreadable_blob());
+        }
+        let wrapping_key = self.derive(&wrapped[13..header_len], costs)?;
+
+        open(&wrapping_key, identity, &wrapped[header_len..]).map_err(|_| {
+            MlsBridgeError::StorageLocked {
+                message: "wrong passphrase".to_string(),
+            }
+        })
+    }
+}
+
+impl FileKeyProvider {
+    pub fn new(path: String) -> Self {
+        Self {
+            path: PathBuf::from(path),
+        }
+    }
+
+    fn wrapping_key(&self) -> Result<Zeroizing<[u8; KEY_LEN]>, MlsBridgeError> {
+        let mut key = Zeroizing::new([0u8; KEY_LEN]);
+        match fs::read(&self.path) {
+            Ok(bytes) => {
+                let bytes = Zeroizing::new(bytes);
+                if bytes.len() != KEY_LEN {
+                    return Err(MlsBridgeError::invalid_input(
+                        "path",
+                        format!("key file must hold {} bytes", KEY_LEN),
+                    ));
+                }
+                key.copy_from_slice(&bytes);
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                rand::thread_rng().fill_bytes(key.as_mut());
+                write_atomically(&self.path, key.as_ref())?;
+            }
+            Err(e) => return Err(MlsBridgeError::storage(e)),
+        }
+        Ok(key)
+    }
+}
+
+impl KeyProvider for FileKeyProvider {
+    /// Blob layout: version, sealed key
+    fn wrap_key(&self, identity: &str, key: &[u8]) -> Result<Vec<u8>, MlsBridgeError> {
+        let mut wrapped = vec![FILE_BLOB_VERSION];
+        wrapped.extend(seal(&self.wrapping_key()?, identity, key)?);
+        Ok(wrapped)
+    }
+
+    fn unwrap_key(
+        &self,
+        identity: &str,
+        wrapped: &[u8],
+    ) -> Result<Zeroizing<Vec<u8>>, MlsBridgeError> {
+        if wrapped.first() != Some(&FILE_BLOB_VERSION) {
+            return Err(unreadable_blob());
+        }
+
+        open(&self.wrapping_key()?, identity, &wrapped[1..]).map_err(|_| {
+            MlsBridgeError::StorageLocked {
+                message: "the key file does not unwrap the database key".to_string(),
+            }
+        })
+    }
+}
+
+/// Get the SQLCipher key of an identity's database, creating and wrapping it on first use
+fn storage_password(
+    db_path: &Path,
+    identity: &str,
+    provider: &dyn KeyProvider,
+) -> Result<Zeroizing<String>, MlsBridgeError> {
+    match read_wrapped_key(db_path)? {
+        Some(wrapped) => unwrapped_key_password(&provider.unwrap_key(identity, &wrapped)?),
+        None => {
+            // An existing unencrypted database is migrated, which needs it closed
+            ensure_closed(identity)?;
+            let key = new_database_key(db_path)?;
+            let wrapped = provider.wrap_key(identity, key.as_ref())?;
+            install_database_key(db_path, &key, &wrapped)
+        }
+    }
+}
+
+/// Read the wrapped key stored next to a database
+/// Returns: `None` if the database has no key yet
+pub(crate) fn read_wrapped_key(db_path: &Path) -> Result<Option<Vec<u8>>, MlsBridgeError> {
+    match fs::read(wrapped_key_path(db_path)) {
+        Ok(wrapped) => Ok(Some(wrapped)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(MlsBridgeError::storage(e)),
+    }
+}
+
+/// Get the SQLCipher key of a database from its unwrapped key
+pub(crate) fn unwrapped_key_password(key: &[u8]) -> Result<Zeroizing<String>, MlsBridgeError> {
+    if key.len() != KEY_LEN {
+        return Err(unreadable_blob());
+    }
+    Ok(raw_key_password(key))
+}
+
+/// Create the random key of a database that has none yet
+pub(crate) fn new_database_key(db_path: &Path) -> Result<Zeroizing<[u8; KEY_LEN]>, MlsBridgeError> {
+    ensure_not_password_encrypted(db_path)?;
+
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    rand::thread_rng().fill_bytes(key.as_mut());
+    Ok(key)
+}
+
+/// Store the wrapped new key of a database and encrypt an existing unencrypted database with it
+/// Returns: the SQLCipher key of the database
+pub(crate) fn install_database_key(
+    db_path: &Path,
+    key: &[u8; KEY_LEN],
+    wrapped: &[u8],
+) -> Result<Zeroizing<String>, MlsBridgeError> {
+    // Checked again, the database may have changed while the key was being wrapped
+    let encrypted = ensure_not_password_encrypted(db_path)?;
+    let key_path = wrapped_key_path(db_path);
+    let password = raw_key_password(key);
+
+    // The wrapped key is stored first: a database encrypted with a lost key is gone
+    write_atomically(&key_path, wrapped)?;
+    if encrypted == Some(false) {
+        if let Err(e) = store::reencrypt(db_path, None, Some(password.as_str())) {
+            let _ = fs::remove_file(&key_path);
+            return Err(e);
+        }
+    }
+
+    Ok(password)
+}
+
+/// Refuse a database encrypted with a password, which has to be decrypted first
+/// Returns: `Some(false)` for an unencrypted database, `None` if there is none yet
+fn ensure_not_password_encrypted(db_path: &Path) -> Result<Option<bool>, MlsBridgeError> {
+    let encrypted = store::is_encrypted(db_path).map_err(MlsBridgeError::storage)?;
+    if encrypted == Some(true) {
+        return Err(MlsBridgeError::StorageLocked {
+            message: "the database is encrypted with a password, remove it with \
+                      rekey_storage before using a key provider"
+                .to_string(),
+        });
+    }
+    Ok(encrypted)
+}
+
+/// Where the wrapped key of a database is stored
+fn wrapped_key_path(db_path: &Path) -> PathBuf {
+    let mut path = db_path.as_os_str().to_owned();
+    path.push(".key");
+    PathBuf::from(path)
+}
+
+/// SQLCipher raw key syntax, which uses the key as is instead of deriving one from a passphrase
+fn raw_key_password(key: &[u8]) -> Zeroizing<String> {
+    let mut password = Zeroizing::new(String::with_capacity(3 + 2 * key.len()));
+    password.push_str("x'");
+    for byte in key {
+        let _ = write!(password, "{byte:02X}");
+    }
+    password.push('\'');
+    password
+}
+
+/// Encrypt a database key under a wrapping key, bound to the identity
+/// Layout: nonce, ciphertext
+fn seal(
+    wrapping_key: &[u8; KEY_LEN],
+    identity: &str,
+    key: &[u8],
+) -> Result<Vec<u8>, MlsBridgeError> {
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    let ciphertext = ChaCha20Poly1305::new(Key::from_slice(wrapping_key))
+        .encrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: key,
+                aad: identity.as_bytes(),
+            },
+        )
+        .map_err(MlsBridgeError::internal)?;
+
+    let mut sealed = nonce.to_vec();
+    sealed.extend(ciphertext);
+    Ok(sealed)
+}
+
+fn open(
+    wrapping_key: &[u8; KEY_LEN],
+    identity: &str,
+    sealed: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, chacha20poly1305::aead::Error> {
+    if sealed.len() < NONCE_LEN {
+        return Err(chacha20poly1305::aead::Error);
+    }
+
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    ChaCha20Poly1305::new(Key::from_slice(wrapping_key))
+        .decrypt(
+            Nonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: identity.as_bytes(),
+            },
+        )
+        .map(Zeroizing::new)
+}
+
+/// Write a file through a temporary one, so a crash never leaves it half written
+fn write_atomically(path: &Path, contents: &[u8]) -> Result<(), MlsBridgeError> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+
+    fs::write(&tmp, contents).map_err(MlsBridgeError::storage)?;
+    fs::rename(&tmp, path).map_err(MlsBridgeError::storage)
+}
+
+fn unreadable_blob() -> MlsBridgeError {
+    MlsBridgeError::StorageLocked {
+        message: "the wrapped database key is not readable by this key provider".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::mls_api::close_nostr_mls;
+    use crate::test_utils::{TestDir, TestIdentity};
+
+    /// Cheapest costs Argon2 accepts, so tests do not pay for `ARGON2_COST`
+    const TEST_COST: (u32, u32, u32) = (8, 1, 1);
+
+    fn is_locked(result: Result<Zeroizing<Vec<u8>>, MlsBridgeError>) -> bool {
+        matches!(result, Err(MlsBridgeError::StorageLocked { .. }))
+    }
+
+    #[test]
+    fn sealed_key_opens_only_for_its_identity() {
+        let wrapping_key = [7u8; KEY_LEN];
+        let sealed = seal(&wrapping_key, "alice", b"database key").unwrap();
+
+        assert_eq!(
+            open(&wrapping_key, "alice", &sealed).unwrap().as_slice(),
+            b"database key"
+        );
+        assert!(open(&wrapping_key, "bob", &sealed).is_err());
+        assert!(open(&[8u8; KEY_LEN], "alice", &sealed).is_err());
+    }
+
+    #[test]
+    fn tampered_or_truncated_seal_does_not_open() {
+        let wrapping_key = [7u8; KEY_LEN];
+        let mut sealed = seal(&wrapping_key, "alice", b"database key").unwrap();
+
+        assert!(open(&wrapping_key, "alice", &sealed[..NONCE_LEN - 1]).is_err());
+        let last = sealed.len() - 1;
+        sealed[last] ^= 1;
+        assert!(open(&wrapping_key, "alice", &sealed).is_err());
+    }
+
+    #[test]
+    fn passphrase_blob_round_trips() {
+        let provider = PassphraseKeyProvider::new("passphrase".to_string());
+        let wrapped = provider
+            .wrap_with_costs("alice", &[1u8; KEY_LEN], TEST_COST)
+            .unwrap();
+
+        assert_eq!(wrapped[0], PASSPHRASE_BLOB_VERSION);
+        assert_eq!(
+            provider.unwrap_key("alice", &wrapped).unwrap().as_slice(),
+            &[1u8; KEY_LEN]
+        );
+        assert!(is_locked(provider.unwrap_key("bob", &wrapped)));
+        assert!(is_locked(
+            PassphraseKeyProvider::new("other".to_string()).unwrap_key("alice", &wrapped)
+        ));
+    }
+
+    #[test]
+    fn malformed_passphrase_blob_is_unreadable() {
+        let provider = PassphraseKeyProvider::new("passphrase".to_string());
+        let wrapped = provider
+            .wrap_with_costs("alice", &[1u8; KEY_LEN], TEST_COST)
+            .unwrap();
+
+        let mut other_version = wrapped.clone();
+        other_version[0] = PASSPHRASE_BLOB_VERSION + 1;
+        assert!(is_locked(provider.unwrap_key("alice", &other_version)));
+        assert!(is_locked(provider.unwrap_key("alice", &wrapped[..20])));
+        assert!(is_locked(provider.unwrap_key("alice", &[])));
+    }
+
+    #[test]
+    fn passphrase_blob_costs_are_bounded() {
+        let provider = PassphraseKeyProvider::new("passphrase".to_string());
+        let wrapped = provider
+            .wrap_with_costs("alice", &[1u8; KEY_LEN], TEST_COST)
+            .unwrap();
+        let with_cost = |offset: usize, cost: u32| {
+            let mut tampered = wrapped.clone();
+            tampered[offset..offset + 4].copy_from_slice(&cost.to_le_bytes());
+            tampered
+        };
+
+        let (max_m_cost, max_t_cost, max_p_cost) = ARGON2_COST;
+        for tampered in [
+            with_cost(1, max_m_cost + 1),
+            with_cost(1, u32::MAX),
+            with_cost(5, max_t_cost + 1),
+            with_cost(9, max_p_cost + 1),
+            with_cost(1, 0),
+            with_cost(5, 0),
+        ] {
+            assert!(is_locked(provider.unwrap_key("alice", &tampered)));
+        }
+    }
+
+    #[test]
+    fn key_file_blob_round_trips() {
+        let dir = TestDir::new();
+        let path = PathBuf::from(dir.path()).join("key-file");
+        let provider = FileKeyProvider::new(path.to_string_lossy().into_owned());
+
+        let wrapped = provider.wrap_key("alice", &[1u8; KEY_LEN]).unwrap();
+        assert_eq!(fs::read(&path).unwrap().len(), KEY_LEN);
+        assert_eq!(
+            provider.unwrap_key("alice", &wrapped).unwrap().as_slice(),
+            &[1u8; KEY_LEN]
+        );
+        assert!(is_locked(provider.unwrap_key("bob", &wrapped)));
+        assert!(is_locked(provider.unwrap_key("alice", &wrapped[1..])));
+    }
+
+    #[test]
+    fn open_unencrypted_database_is_not_migrated() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let provider = FileKeyProvider::new(
+            PathBuf::from(dir.path())
+                .join("key-file")
+                .to_string_lossy()
+                .into_owned(),
+        );
+
+        assert!(matches!(
+            init_nostr_mls_with_key_provider(dir.path(), alice.identity.clone(), &provider),
+            Err(MlsBridgeError::InvalidInput { .. })
+        ));
+        let db_path = db_path(&dir.path(), &alice.identity);
+        assert!(read_wrapped_key(&db_path).unwrap().is_none());
+
+        close_nostr_mls(alice.identity.clone()).unwrap();
+        init_nostr_mls_with_key_provider(dir.path(), alice.identity.clone(), &provider)
+            .expect("migrate the closed database");
+        assert!(read_wrapped_key(&db_path).unwrap().is_some());
+    }
+
+    #[test]
+    fn raw_key_password_is_sqlcipher_hex_syntax() {
+        assert_eq!(raw_key_password(&[0x0a, 0xff]).as_str(), "x'0AFF'");
+        assert!(unwrapped_key_password(&[0u8; KEY_LEN - 1]).is_err());
+    }
+}
@@ -1,2 +1,8 @@
 pub mod api;
 mod frb_generated;
+pub mod key_provider;
+mod mls;
+mod registry;
+mod store;
+#[cfg(test)]
+mod test_utils;
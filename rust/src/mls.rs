@@ -0,0 +1,198 @@
+//! Direct access to the openmls groups stored by nostr-mls, for operations nostr-mls does not offer
+
+use nostr_mls::extension::NostrGroupDataExtension;
+use nostr_mls::prelude::*;
+use nostr_mls::NostrMls;
+use nostr_mls_sqlite_storage::NostrMlsSqliteStorage;
+use openmls_basic_credential::SignatureKeyPair;
+use openmls_traits::storage::StorageProvider as _;
+use openmls_traits::OpenMlsProvider;
+use tls_codec::{Deserialize as _, Serialize as _};
+
+use crate::api::error::MlsBridgeError;
+
+pub(crate) fn load_group(
+    nostr_mls: &NostrMls<NostrMlsSqliteStorage>,
+    group_id: &GroupId,
+) -> Result<MlsGroup, MlsBridgeError> {
+    MlsGroup::load(nostr_mls.provider.storage(), group_id)
+        .map_err(MlsBridgeError::storage)?
+        .ok_or(MlsBridgeError::GroupNotFound)
+}
+
+/// Load the signing key of our own leaf in a group
+pub(crate) fn load_signer(
+    nostr_mls: &NostrMls<NostrMlsSqliteStorage>,
+    group: &MlsGroup,
+) -> Result<SignatureKeyPair, MlsBridgeError> {
+    let own_leaf = group.own_leaf().ok_or(MlsBridgeError::GroupNotFound)?;
+
+    SignatureKeyPair::read(
+        nostr_mls.provider.storage(),
+        own_leaf.signature_key().as_slice(),
+        group.ciphersuite().signature_algorithm(),
+    )
+    .ok_or_else(|| MlsBridgeError::internal("signing key of our leaf not found"))
+}
+
+/// Nostr public key of a member from its basic credential
+pub(crate) fn credential_pubkey(credential: &Credential) -> Option<PublicKey> {
+    let credential = BasicCredential::try_from(credential.clone()).ok()?;
+    let hex_str = std::str::from_utf8(credential.identity()).ok()?;
+    PublicKey::from_hex(hex_str).ok()
+}
+
+/// Our own nostr public key in a group
+pub(crate) fn own_pubkey(group: &MlsGroup) -> Result<PublicKey, MlsBridgeError> {
+    group
+        .own_leaf()
+        .and_then(|leaf| credential_pubkey(leaf.credential()))
+        .ok_or(MlsBridgeError::GroupNotFound)
+}
+
+/// Leaf index of a member from its nostr public key
+pub(crate) fn member_leaf_index(group: &MlsGroup, public_key: &PublicKey) -> Option<LeafNodeIndex> {
+    group
+        .members()
+        .find(|member| credential_pubkey(&member.credential).as_ref() == Some(public_key))
+        .map(|member| member.index)
+}
+
+/// Nostr group data of an MLS group, as carried in its group context
+pub(crate) fn group_data(group: &MlsGroup) -> Result<NostrGroupDataExtension, MlsBridgeError> {
+    Ok(NostrGroupDataExtension::from_group(group)?)
+}
+
+/// Group context extensions of a group with its Nostr group data replaced
+pub(crate) fn extensions_with_group_data(
+    group: &MlsGroup,
+    group_data: &NostrGroupDataExtension,
+) -> Result<Extensions, MlsBridgeError> {
+    let extension_type = NostrGroupDataExtension::EXTENSION_TYPE;
+    let encoded = group_data
+        .as_raw()
+        .tls_serialize_detached()
+        .map_err(MlsBridgeError::mls)?;
+
+    let mut extensions: Vec<Extension> = group
+        .extensions()
+        .iter()
+        .filter(|extension| !matches!(extension, Extension::Unknown(t, _) if *t == extension_type))
+        .cloned()
+        .collect();
+    extensions.push(Extension::Unknown(
+        extension_type,
+        UnknownExtension(encoded),
+    ));
+
+    Extensions::from_vec(extensions).map_err(MlsBridgeError::mls)
+}
+
+/// Bring the nostr-mls row of a group up to date with its MLS group, the caller holding the
+/// group lock
+/// Commits merged through openmls directly are not seen by nostr-mls, whose row would otherwise
+/// keep the epoch, state, name and admins of when the group was joined.
+pub(crate) fn sync_group_row(
+    nostr_mls: &NostrMls<NostrMlsSqliteStorage>,
+    group: &MlsGroup,
+) -> Result<(), MlsBridgeError> {
+    let mut row = nostr_mls
+        .get_group(group.group_id())?
+        .ok_or(MlsBridgeError::GroupNotFound)?;
+
+    row.epoch = group.epoch().as_u64();
+    row.state = if group.is_active() {
+        GroupState::Active
+    } else {
+        GroupState::Inactive
+    };
+    let group_data = group_data(group)?;
+    row.name = group_data.name;
+    row.description = group_data.description;
+    row.admin_pubkeys = group_data.admins;
+
+    nostr_mls
+        .provider
+        .storage()
+        .save_group(row)
+        .map_err(MlsBridgeError::storage)
+}
+
+/// Process an incoming commit up to its staged state, without merging it
+/// Returns: the commit's sender and the staged commit
+pub(crate) fn stage_commit(
+    nostr_mls: &NostrMls<NostrMlsSqliteStorage>,
+    group: &mut MlsGroup,
+    message_bytes: &[u8],
+) -> Result<(Sender, StagedCommit), MlsBridgeError> {
+    let message = MlsMessageIn::tls_deserialize_exact(message_bytes)
+        .map_err(|e| MlsBridgeError::invalid_input("message_bytes", e))?;
+    let protocol_message = message
+        .try_into_protocol_message()
+        .map_err(|e| MlsBridgeError::invalid_input("message_bytes", e))?;
+
+    let processed = group
+        .process_message(&nostr_mls.provider, protocol_message)
+        .map_err(MlsBridgeError::from)?;
+    let sender = processed.sender().clone();
+
+    match processed.into_content() {
+        ProcessedMessageContent::StagedCommitMessage(staged_commit) => Ok((sender, *staged_commit)),
+        _ => Err(MlsBridgeError::invalid_input(
+            "message_bytes",
+            "not a commit message",
+        )),
+    }
+}
+
+/// Whether we hold the private material of a key package a kind-444 welcome rumor was
+/// encrypted to
+pub(crate) fn has_welcome_key_package(
+    nostr_mls: &NostrMls<NostrMlsSqliteStorage>,
+    rumor_event: &UnsignedEvent,
+) -> Result<bool, MlsBridgeError> {
+    for key_package_ref in welcome_key_package_refs(rumor_event)? {
+        if key_package_bundle(nostr_mls, &key_package_ref)?.is_some() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// References of the key packages a kind-444 welcome rumor was encrypted to, ours or not
+pub(crate) fn welcome_key_package_refs(
+    rumor_event: &UnsignedEvent,
+) -> Result<Vec<KeyPackageRef>, MlsBridgeError> {
+    let welcome_bytes = hex::decode(&rumor_event.content)
+        .map_err(|e| MlsBridgeError::invalid_input("rumor_event_string", e))?;
+    let message = MlsMessageIn::tls_deserialize_exact(welcome_bytes)
+        .map_err(|e| MlsBridgeError::invalid_input("rumor_event_string", e))?;
+    let MlsMessageBodyIn::Welcome(welcome) = message.extract() else {
+        return Err(MlsBridgeError::invalid_input(
+            "rumor_event_string",
+            "not a welcome message",
+        ));
+    };
+
+    Ok(welcome
+        .secrets()
+        .iter()
+        .map(|secrets| secrets.new_member())
+        .collect())
+}
+
+/// The key package we hold the private material of for a reference, if any
+pub(crate) fn key_package_bundle(
+    nostr_mls: &NostrMls<NostrMlsSqliteStorage>,
+    key_package_ref: &KeyPackageRef,
+) -> Result<Option<KeyPackageBundle>, MlsBridgeError> {
+    nostr_mls
+        .provider
+        .storage()
+        .key_package(key_package_ref)
+        .map_err(MlsBridgeError::storage)
+}
+
+pub(crate) fn serialize_message(message: &MlsMessageOut) -> Result<Vec<u8>, MlsBridgeError> {
+    message.to_bytes().map_err(MlsBridgeError::mls)
+}
@@ -0,0 +1,298 @@
+use lazy_static::lazy_static;
+use nostr_mls::prelude::*;
+use nostr_mls::NostrMls;
+use nostr_mls_sqlite_storage::NostrMlsSqliteStorage;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock, Weak};
+
+use crate::api::error::MlsBridgeError;
+use crate::api::events::GroupEvent;
+use crate::api::signer::{Signer, SignerScope};
+use crate::frb_generated::StreamSink;
+use crate::store::BridgeStore;
+
+lazy_static! {
+    /// Open NostrMls instances keyed by identity
+    static ref NOSTR_MLS: RwLock<HashMap<String, Arc<MlsInstance>>> = RwLock::new(HashMap::new());
+    /// Removed or replaced instances keyed by identity, alive while calls started before the
+    /// removal run
+    static ref CLOSING: Mutex<HashMap<String, Vec<Weak<MlsInstance>>>> = Mutex::new(HashMap::new());
+}
+
+/// Where the group events of an identity are pushed
+pub(crate) enum EventSink {
+    Dart(StreamSink<GroupEvent>),
+    /// Events collected by a test
+    #[cfg(test)]
+    Channel(std::sync::mpsc::Sender<GroupEvent>),
+}
+
+impl EventSink {
+    /// Returns: whether the receiving end is still open
+    fn add(&self, event: GroupEvent) -> bool {
+        match self {
+            Self::Dart(sink) => sink.add(event).is_ok(),
+            #[cfg(test)]
+            Self::Channel(sender) => sender.send(event).is_ok(),
+        }
+    }
+}
+
+/// An open NostrMls instance of one identity
+///
+/// The sqlite storage serialises its own connection, so read calls use `nostr_mls` directly
+/// while calls changing a group's state first take that group's lock.
+pub(crate) struct MlsInstance {
+    pub(crate) nostr_mls: NostrMls<NostrMlsSqliteStorage>,
+    pub(crate) store: BridgeStore,
+    /// Held weakly, so a group's lock lives only while a call uses it
+    group_locks: Mutex<HashMap<Vec<u8>, Weak<Mutex<()>>>>,
+    subscribers: Mutex<Vec<EventSink>>,
+    signer: Mutex<(Arc<Signer>, SignerScope)>,
+}
+
+impl MlsInstance {
+    pub(crate) fn new(nostr_mls: NostrMls<NostrMlsSqliteStorage>, store: BridgeStore) -> Self {
+        Self {
+            nostr_mls,
+            store,
+            group_locks: Mutex::new(HashMap::new()),
+            subscribers: Mutex::new(Vec::new()),
+            signer: Mutex::new((Arc::new(Signer::Unset), SignerScope::Seals)),
+        }
+    }
+
+    pub(crate) fn set_signer(&self, signer: Signer, scope: SignerScope) {
+        *lock(&self.signer) = (Arc::new(signer), scope);
+    }
+
+    /// Get the identity's signer for gift wrap seals, held outside the lock while it runs
+    pub(crate) fn signer(&self) -> Arc<Signer> {
+        lock(&self.signer).0.clone()
+    }
+
+    /// Get the identity's signer for our kind-445 commit and proposal events, `None` when they
+    /// keep their random key
+    pub(crate) fn commit_signer(&self) -> Option<Arc<Signer>> {
+        let (signer, scope) = &*lock(&self.signer);
+        (*scope == SignerScope::SealsAndCommits).then(|| signer.clone())
+    }
+
+    pub(crate) fn subscribe(&self, sink: EventSink) {
+        lock(&self.subscribers).push(sink);
+    }
+
+    pub(crate) fn has_subscribers(&self) -> bool {
+        !lock(&self.subscribers).is_empty()
+    }
+
+    /// Push an event to every subscriber, dropping the ones Dart has closed
+    pub(crate) fn emit(&self, event: GroupEvent) {
+        lock(&self.subscribers).retain(|sink| sink.add(event.clone()));
+    }
+
+    /// Get the lock serialising state changes of a group
+    /// Locks no call holds anymore are pruned when a new one is created
+    pub(crate) fn group_lock(&self, group_id: &GroupId) -> Arc<Mutex<()>> {
+        let mut group_locks = lock(&self.group_locks);
+        if let Some(group_lock) = group_locks.get(group_id.as_slice()).and_then(Weak::upgrade) {
+            return group_lock;
+        }
+
+        group_locks.retain(|_, group_lock| group_lock.strong_count() > 0);
+        let group_lock = Arc::new(Mutex::new(()));
+        group_locks.insert(group_id.to_vec(), Arc::downgrade(&group_lock));
+        group_lock
+    }
+
+    /// Find the local group a kind-445 event belongs to from its `h` tag
+    pub(crate) fn group_id_for_event(&self, event: &Event) -> Option<GroupId> {
+        let nostr_group_id = nostr_group_id_for_event(event)?;
+
+        self.nostr_mls
+            .get_groups()
+            .ok()?
+            .into_iter()
+            .find(|group| group.nostr_group_id == nostr_group_id)
+            .map(|group| group.mls_group_id)
+    }
+}
+
+/// Read the nostr group id from the `h` tag of a kind-445 event
+pub(crate) fn nostr_group_id_for_event(event: &Event) -> Option<[u8; 32]> {
+    event
+        .tags
+        .iter()
+        .find(|tag| tag.kind() == TagKind::h())
+        .and_then(|tag| tag.content())
+        .and_then(|content| hex::decode(content).ok())
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+}
+
+/// Acquire a mutex, recovering the data if a previous holder panicked
+pub(crate) fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Register the instance of an identity
+/// A previous instance is replaced and closes like a removed one, see `remove`.
+pub(crate) fn insert(identity: String, instance: MlsInstance) {
+    let replaced = NOSTR_MLS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(identity.clone(), Arc::new(instance));
+
+    if let Some(replaced) = replaced {
+        close(&identity, &replaced);
+    }
+}
+
+/// Unregister the instance of an identity
+/// Calls already running on it finish before its storage is closed, see `is_closed`.
+/// Returns: the removed instance, `None` if none was open
+pub(crate) fn remove(identity: &str) -> Option<Arc<MlsInstance>> {
+    let instance = NOSTR_MLS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(identity)?;
+
+    close(identity, &instance);
+    Some(instance)
+}
+
+/// Track an unregistered instance until the calls still holding it are done
+fn close(identity: &str, instance: &Arc<MlsInstance>) {
+    let mut closing = lock(&CLOSING);
+    closing.retain(|_, instances| {
+        instances.retain(|instance| instance.strong_count() > 0);
+        !instances.is_empty()
+    });
+    closing
+        .entry(identity.to_string())
+        .or_default()
+        .push(Arc::downgrade(instance));
+}
+
+/// Whether the storage of an identity is closed: no instance is open and no call still holds
+/// one removed or replaced earlier
+pub(crate) fn is_closed(identity: &str) -> bool {
+    if NOSTR_MLS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .contains_key(identity)
+    {
+        return false;
+    }
+    !lock(&CLOSING)
+        .get(identity)
+        .is_some_and(|instances| instances.iter().any(|instance| instance.strong_count() > 0))
+}
+
+pub(crate) fn identities() -> Vec<String> {
+    let mut identities: Vec<String> = NOSTR_MLS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .keys()
+        .cloned()
+        .collect();
+    identities.sort();
+    identities
+}
+
+pub(crate) fn get(identity: &str) -> Result<Arc<MlsInstance>, MlsBridgeError> {
+    NOSTR_MLS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(identity)
+        .cloned()
+        .ok_or(MlsBridgeError::NotInitialized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::mls_api::{
+        close_nostr_mls, create_message_for_group, get_members, init_nostr_mls,
+        process_message_for_group,
+    };
+    use crate::test_utils::{create_test_group, TestDir, TestIdentity};
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn held_group_lock_blocks_no_other_group() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let bob = TestIdentity::open(&dir);
+        let busy = create_test_group(&alice, &[&bob]);
+        let other = create_test_group(&alice, &[&bob]);
+
+        let rumor = EventBuilder::new(Kind::Custom(9), "hello").build(alice.keys.public_key());
+        let message = create_message_for_group(
+            alice.identity.clone(),
+            other.mls_group_id.clone(),
+            rumor.as_json(),
+        )
+        .expect("create message");
+
+        // Stands in for a slow process_message_for_group on the busy group
+        let instance = bob.instance();
+        let busy_lock = instance.group_lock(&GroupId::from_slice(&busy.mls_group_id));
+        let _guard = lock(&busy_lock);
+
+        let (done, finished) = mpsc::channel();
+        let identity = bob.identity.clone();
+        let busy_group_id = busy.mls_group_id.clone();
+        thread::spawn(move || {
+            let members = get_members(identity.clone(), busy_group_id);
+            let processed = process_message_for_group(identity, message.event_json);
+            let _ = done.send((members, processed));
+        });
+
+        let (members, processed) = finished
+            .recv_timeout(Duration::from_secs(10))
+            .expect("calls finish while another group is locked");
+        assert_eq!(members.expect("read members").len(), 2);
+        assert_eq!(
+            processed
+                .expect("process message")
+                .message
+                .expect("decrypted message")
+                .content,
+            "hello"
+        );
+    }
+
+    #[test]
+    fn unused_group_locks_are_pruned() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let instance = alice.instance();
+        let first = GroupId::from_slice(&[1; 32]);
+        let second = GroupId::from_slice(&[2; 32]);
+
+        let held = instance.group_lock(&first);
+        assert!(Arc::ptr_eq(&held, &instance.group_lock(&first)));
+        drop(held);
+
+        let _second = instance.group_lock(&second);
+        assert_eq!(lock(&instance.group_locks).len(), 1);
+    }
+
+    #[test]
+    fn replaced_instance_closes_like_a_removed_one() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        // Stands in for a call started before the identity was opened again
+        let running = alice.instance();
+
+        init_nostr_mls(dir.path(), alice.identity.clone(), None).expect("open identity again");
+        assert!(!Arc::ptr_eq(&running, &alice.instance()));
+        close_nostr_mls(alice.identity.clone()).expect("close identity");
+
+        assert!(!is_closed(&alice.identity));
+        drop(running);
+        assert!(is_closed(&alice.identity));
+    }
+}
@@ -0,0 +1,1766 @@
+use rusqlite::{params, Connection, OptionalExtension};
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::api::error::MlsBridgeError;
+use crate::registry::lock;
+
+/// Tables owned by the bridge, stored next to the nostr-mls tables in `<identity>-mls.db`
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS bridge_pending_messages (
+    event_id TEXT PRIMARY KEY,
+    nostr_group_id TEXT NOT NULL,
+    epoch INTEGER NOT NULL,
+    created_at INTEGER NOT NULL,
+    event_json TEXT NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0
+);
+CREATE INDEX IF NOT EXISTS bridge_pending_messages_group
+    ON bridge_pending_messages (nostr_group_id, epoch);
+
+CREATE VIRTUAL TABLE IF NOT EXISTS bridge_message_fts USING fts5 (
+    content,
+    event_id UNINDEXED,
+    mls_group_id UNINDEXED
+);
+CREATE TABLE IF NOT EXISTS bridge_message_fts_indexed (
+    event_id TEXT PRIMARY KEY
+);
+
+CREATE TABLE IF NOT EXISTS bridge_message_index (
+    event_id TEXT PRIMARY KEY,
+    mls_group_id TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    received_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS bridge_message_index_page
+    ON bridge_message_index (mls_group_id, created_at, event_id);
+CREATE INDEX IF NOT EXISTS bridge_message_index_received
+    ON bridge_message_index (mls_group_id, received_at);
+CREATE TABLE IF NOT EXISTS bridge_message_index_groups (
+    mls_group_id TEXT PRIMARY KEY
+);
+
+CREATE TABLE IF NOT EXISTS bridge_key_packages (
+    id TEXT PRIMARY KEY,
+    encoded_key_package TEXT NOT NULL,
+    tags_json TEXT NOT NULL,
+    last_resort INTEGER NOT NULL,
+    state TEXT NOT NULL,
+    event_id TEXT,
+    created_at INTEGER NOT NULL,
+    published_at INTEGER,
+    consumed_at INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS bridge_welcome_inbox (
+    id TEXT PRIMARY KEY,
+    rumor_json TEXT NOT NULL,
+    sender TEXT NOT NULL,
+    mls_group_id BLOB NOT NULL,
+    nostr_group_id TEXT NOT NULL,
+    group_name TEXT NOT NULL,
+    group_description TEXT NOT NULL,
+    admin_pubkeys_json TEXT NOT NULL,
+    relays_json TEXT NOT NULL,
+    members_json TEXT NOT NULL,
+    state TEXT NOT NULL,
+    received_at INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS bridge_group_policies (
+    mls_group_id TEXT PRIMARY KEY,
+    add_members TEXT NOT NULL,
+    remove_members TEXT NOT NULL,
+    update_group_data TEXT NOT NULL,
+    allow_self_remove INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS bridge_commit_audit (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    mls_group_id TEXT NOT NULL,
+    epoch INTEGER NOT NULL,
+    sender TEXT,
+    reason TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS bridge_commit_audit_group
+    ON bridge_commit_audit (mls_group_id, id);
+
+CREATE TABLE IF NOT EXISTS bridge_rotation_policy (
+    id INTEGER PRIMARY KEY CHECK (id = 0),
+    max_messages INTEGER,
+    max_age_secs INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS bridge_leaf_rotations (
+    mls_group_id TEXT PRIMARY KEY,
+    rotated_at INTEGER NOT NULL,
+    messages_since INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS bridge_pending_commits (
+    mls_group_id TEXT PRIMARY KEY,
+    epoch INTEGER NOT NULL,
+    event_id TEXT,
+    created_at INTEGER,
+    event_json TEXT,
+    proposal_ref TEXT
+);
+
+CREATE TABLE IF NOT EXISTS bridge_commit_events (
+    message_hash TEXT PRIMARY KEY,
+    mls_group_id TEXT NOT NULL,
+    epoch INTEGER NOT NULL,
+    event_id TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS bridge_commit_events_group
+    ON bridge_commit_events (mls_group_id, epoch);
+
+CREATE TABLE IF NOT EXISTS bridge_commit_candidates (
+    message_hash TEXT PRIMARY KEY,
+    mls_group_id TEXT NOT NULL,
+    epoch INTEGER NOT NULL,
+    event_id TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS bridge_commit_candidates_group
+    ON bridge_commit_candidates (mls_group_id, epoch);
+
+CREATE TABLE IF NOT EXISTS bridge_group_health (
+    mls_group_id TEXT PRIMARY KEY,
+    consecutive_failures INTEGER NOT NULL,
+    last_failure TEXT,
+    last_failure_at INTEGER,
+    last_success_at INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS bridge_undecryptable_events (
+    mls_group_id TEXT PRIMARY KEY,
+    count INTEGER NOT NULL,
+    last_at INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS bridge_group_recovery (
+    mls_group_id TEXT PRIMARY KEY,
+    key_package_id TEXT NOT NULL,
+    requested_at INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS bridge_keyring_policy (
+    id INTEGER PRIMARY KEY CHECK (id = 0),
+    retained_epochs INTEGER NOT NULL,
+    max_age_secs INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS bridge_exporter_secrets (
+    mls_group_id TEXT NOT NULL,
+    epoch INTEGER NOT NULL,
+    secret BLOB NOT NULL,
+    created_at INTEGER NOT NULL,
+    PRIMARY KEY (mls_group_id, epoch)
+);
+
+CREATE TABLE IF NOT EXISTS bridge_retention_policy (
+    id INTEGER PRIMARY KEY CHECK (id = 0),
+    message_max_age_secs INTEGER,
+    purge_inactive_groups INTEGER NOT NULL
+);
+";
+
+/// Bridge tables keyed by the hex encoded `mls_group_id`, deleted by `purge_group`
+/// `bridge_message_index_groups` is not: its mark keeps nostr-mls's copy of the messages of a
+/// purged group from being indexed again.
+const BRIDGE_GROUP_TABLES: [&str; 12] = [
+    "bridge_group_policies",
+    "bridge_commit_audit",
+    "bridge_leaf_rotations",
+    "bridge_pending_commits",
+    "bridge_commit_events",
+    "bridge_commit_candidates",
+    "bridge_group_health",
+    "bridge_undecryptable_events",
+    "bridge_group_recovery",
+    "bridge_exporter_secrets",
+    "bridge_message_fts",
+    "bridge_message_index",
+];
+
+/// A kind-445 event that could not be decrypted at the epoch it was received in
+pub(crate) struct PendingMessage {
+    pub(crate) event_id: String,
+    pub(crate) event_json: String,
+    pub(crate) attempts: u32,
+}
+
+/// A decrypted message as the page and full-text indexes need it
+pub(crate) struct IndexedMessage {
+    pub(crate) event_id: String,
+    pub(crate) created_at: u64,
+    pub(crate) content: String,
+    /// When the bridge stored the message, unlike `created_at` not set by its sender
+    pub(crate) received_at: u64,
+}
+
+/// A key package created by the key package manager
+pub(crate) struct StoredKeyPackage {
+    pub(crate) id: String,
+    pub(crate) encoded_key_package: String,
+    pub(crate) tags_json: String,
+    pub(crate) last_resort: bool,
+    /// One of `unpublished`, `published`, `consumed` or `retired`
+    pub(crate) state: String,
+    pub(crate) event_id: Option<String>,
+    pub(crate) created_at: u64,
+    pub(crate) published_at: Option<u64>,
+    pub(crate) consumed_at: Option<u64>,
+}
+
+/// A welcome kept in the inbox until it is accepted or declined
+pub(crate) struct StoredWelcome {
+    /// Hex encoded id of the gift wrap carrying the welcome
+    pub(crate) id: String,
+    pub(crate) rumor_json: String,
+    pub(crate) sender: String,
+    pub(crate) mls_group_id: Vec<u8>,
+    pub(crate) nostr_group_id: String,
+    pub(crate) group_name: String,
+    pub(crate) group_description: String,
+    pub(crate) admin_pubkeys_json: String,
+    pub(crate) relays_json: String,
+    pub(crate) members_json: String,
+    /// One of `pending`, `accepted` or `declined`
+    pub(crate) state: String,
+    pub(crate) received_at: u64,
+}
+
+/// Who may add, remove and update the metadata of a group, as stored
+/// Roles are `admins` or `members`
+pub(crate) struct StoredGroupPolicy {
+    pub(crate) add_members: String,
+    pub(crate) remove_members: String,
+    pub(crate) update_group_data: String,
+    pub(crate) allow_self_remove: bool,
+}
+
+/// A commit rejected by the group policy
+pub(crate) struct CommitAuditRecord {
+    pub(crate) mls_group_id: String,
+    pub(crate) epoch: u64,
+    pub(crate) sender: Option<String>,
+    pub(crate) reason: String,
+    pub(crate) created_at: u64,
+}
+
+/// When an identity's leaves should be rotated, `None` disabling a criterion
+pub(crate) struct StoredRotationPolicy {
+    pub(crate) max_messages: Option<u64>,
+    pub(crate) max_age_secs: Option<u64>,
+}
+
+/// Messages seen in a group since our leaf was last rotated
+/// `rotated_at` is the first time the group was tracked until our first self-update.
+pub(crate) struct LeafRotationRecord {
+    pub(crate) mls_group_id: String,
+    pub(crate) rotated_at: u64,
+    pub(crate) messages_since: u64,
+}
+
+/// The kind-445 event of a commit competing for an epoch, ours or received
+pub(crate) struct CommitRecord {
+    pub(crate) epoch: u64,
+    pub(crate) event_id: String,
+    pub(crate) created_at: u64,
+}
+
+/// Our commit, or leave proposal, waiting for its event to be published
+pub(crate) struct StoredPendingCommit {
+    pub(crate) epoch: u64,
+    /// Set once the commit is wrapped in its kind-445 event
+    pub(crate) event_id: Option<String>,
+    pub(crate) created_at: Option<u64>,
+    pub(crate) event_json: Option<String>,
+    /// Hex encoded reference of our leave proposal, `None` for a commit
+    pub(crate) proposal_ref: Option<String>,
+}
+
+/// Outcome of the recent messages and commits processed for a group
+pub(crate) struct StoredGroupHealth {
+    pub(crate) consecutive_failures: u32,
+    pub(crate) last_failure: Option<String>,
+    pub(crate) last_failure_at: Option<u64>,
+    pub(crate) last_success_at: Option<u64>,
+}
+
+/// A group we asked to be re-added to, waiting for the welcome
+pub(crate) struct StoredGroupRecovery {
+    /// Id of the managed key package created for the re-add
+    pub(crate) key_package_id: String,
+    pub(crate) requested_at: u64,
+}
+
+/// How many past exporter secrets an identity keeps, `None` disabling the age limit
+pub(crate) struct StoredKeyringPolicy {
+    pub(crate) retained_epochs: u32,
+    pub(crate) max_age_secs: Option<u64>,
+}
+
+/// What an identity keeps of old messages and of the groups it is no longer in
+pub(crate) struct StoredRetentionPolicy {
+    pub(crate) message_max_age_secs: Option<u64>,
+    pub(crate) purge_inactive_groups: bool,
+}
+
+/// Bridge state persisted in the identity's database
+pub(crate) struct BridgeStore {
+    conn: Mutex<Connection>,
+}
+
+impl BridgeStore {
+    pub(crate) fn open(path: &Path, password: Option<&str>) -> Result<Self, MlsBridgeError> {
+        let conn = Connection::open(path)?;
+        if let Some(password) = password {
+            conn.pragma_update(None, "key", password)?;
+        }
+        // nostr-mls keeps its own connection to the same file
+        conn.busy_timeout(Duration::from_secs(5))?;
+        // Rows deleted through this connection are overwritten, not just unlinked
+        conn.pragma_update(None, "secure_delete", true)?;
+        conn.execute_batch(SCHEMA)?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    pub(crate) fn save_pending_message(
+        &self,
+        event_id: &str,
+        nostr_group_id: &str,
+        epoch: u64,
+        created_at: u64,
+        event_json: &str,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT INTO bridge_pending_messages
+                 (event_id, nostr_group_id, epoch, created_at, event_json)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT (event_id) DO UPDATE SET epoch = excluded.epoch",
+            params![event_id, nostr_group_id, epoch, created_at, event_json],
+        )?;
+        Ok(())
+    }
+
+    /// Pending messages of a group received before `epoch`, oldest first
+    pub(crate) fn pending_messages_before(
+        &self,
+        nostr_group_id: &str,
+        epoch: u64,
+    ) -> Result<Vec<PendingMessage>, MlsBridgeError> {
+        let conn = lock(&self.conn);
+        let mut stmt = conn.prepare(
+            "SELECT event_id, event_json, attempts FROM bridge_pending_messages
+             WHERE nostr_group_id = ?1 AND epoch < ?2
+             ORDER BY created_at, event_id",
+        )?;
+        let messages = stmt
+            .query_map(params![nostr_group_id, epoch], |row| {
+                Ok(PendingMessage {
+                    event_id: row.get(0)?,
+                    event_json: row.get(1)?,
+                    attempts: row.get(2)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(messages)
+    }
+
+    pub(crate) fn count_pending_messages(
+        &self,
+        nostr_group_id: &str,
+    ) -> Result<u32, MlsBridgeError> {
+        let count: Option<u32> = lock(&self.conn)
+            .query_row(
+                "SELECT COUNT(*) FROM bridge_pending_messages WHERE nostr_group_id = ?1",
+                params![nostr_group_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(count.unwrap_or(0))
+    }
+
+    /// `created_at` of the oldest buffered event of a group
+    pub(crate) fn oldest_pending_message(
+        &self,
+        nostr_group_id: &str,
+    ) -> Result<Option<u64>, MlsBridgeError> {
+        let created_at = lock(&self.conn).query_row(
+            "SELECT MIN(created_at) FROM bridge_pending_messages WHERE nostr_group_id = ?1",
+            params![nostr_group_id],
+            |row| row.get(0),
+        )?;
+        Ok(created_at)
+    }
+
+    pub(crate) fn touch_pending_message(&self, event_id: &str) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "UPDATE bridge_pending_messages SET attempts = attempts + 1 WHERE event_id = ?1",
+            params![event_id],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn delete_pending_message(&self, event_id: &str) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "DELETE FROM bridge_pending_messages WHERE event_id = ?1",
+            params![event_id],
+        )?;
+        Ok(())
+    }
+
+    /// Add a decrypted message to the page and full-text indexes, ignoring already indexed ones
+    pub(crate) fn index_message(
+        &self,
+        mls_group_id: &str,
+        message: &IndexedMessage,
+    ) -> Result<(), MlsBridgeError> {
+        let mut conn = lock(&self.conn);
+        let tx = conn.transaction()?;
+        insert_indexed_message(&tx, mls_group_id, message)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Whether the messages a group stored before the indexes existed were indexed
+    pub(crate) fn is_group_indexed(&self, mls_group_id: &str) -> Result<bool, MlsBridgeError> {
+        let indexed = lock(&self.conn)
+            .query_row(
+                "SELECT 1 FROM bridge_message_index_groups WHERE mls_group_id = ?1",
+                params![mls_group_id],
+                |_| Ok(()),
+            )
+            .optional()?;
+        Ok(indexed.is_some())
+    }
+
+    /// Whether a message is in the page index, i.e. was neither expired nor purged
+    pub(crate) fn is_message_indexed(&self, event_id: &str) -> Result<bool, MlsBridgeError> {
+        let indexed = lock(&self.conn)
+            .query_row(
+                "SELECT 1 FROM bridge_message_index WHERE event_id = ?1",
+                params![event_id],
+                |_| Ok(()),
+            )
+            .optional()?;
+        Ok(indexed.is_some())
+    }
+
+    /// Index every stored message of a group at once, marking the group as indexed
+    pub(crate) fn index_group_messages(
+        &self,
+        mls_group_id: &str,
+        messages: &[IndexedMessage],
+    ) -> Result<(), MlsBridgeError> {
+        let mut conn = lock(&self.conn);
+        let tx = conn.transaction()?;
+        for message in messages {
+            insert_indexed_message(&tx, mls_group_id, message)?;
+        }
+        tx.execute(
+            "INSERT INTO bridge_message_index_groups (mls_group_id) VALUES (?1)
+             ON CONFLICT (mls_group_id) DO NOTHING",
+            params![mls_group_id],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// One page of the messages of a group, newest first
+    /// `before` is the (created_at, event id) of the last message of the previous page, so
+    /// messages of the same second are neither skipped nor repeated across pages.
+    /// Returns: hex encoded rumor ids
+    pub(crate) fn page_messages(
+        &self,
+        mls_group_id: &str,
+        before: Option<(u64, &str)>,
+        limit: u32,
+    ) -> Result<Vec<String>, MlsBridgeError> {
+        let (before_created_at, before_event_id) = before.unzip();
+        let conn = lock(&self.conn);
+        let mut stmt = conn.prepare(
+            "SELECT event_id FROM bridge_message_index
+             WHERE mls_group_id = ?1 AND (?2 IS NULL OR (created_at, event_id) < (?2, ?3))
+             ORDER BY created_at DESC, event_id DESC LIMIT ?4",
+        )?;
+        let event_ids = stmt
+            .query_map(
+                params![mls_group_id, before_created_at, before_event_id, limit],
+                |row| row.get(0),
+            )?
+            .collect::<Result<Vec<String>, _>>()?;
+        Ok(event_ids)
+    }
+
+    /// Search the full-text index of a group
+    /// Returns: matching event ids, best match first
+    pub(crate) fn search_messages(
+        &self,
+        mls_group_id: &str,
+        query: &str,
+        limit: u32,
+    ) -> Result<Vec<String>, MlsBridgeError> {
+        // Quote every term so user input is never parsed as FTS5 syntax
+        let match_query = query
+            .split_whitespace()
+            .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(" ");
+        if match_query.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let conn = lock(&self.conn);
+        let mut stmt = conn.prepare(
+            "SELECT event_id FROM bridge_message_fts
+             WHERE bridge_message_fts MATCH ?1 AND mls_group_id = ?2
+             ORDER BY rank LIMIT ?3",
+        )?;
+        let event_ids = stmt
+            .query_map(params![match_query, mls_group_id, limit], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+        Ok(event_ids)
+    }
+
+    pub(crate) fn save_key_package(
+        &self,
+        key_package: &StoredKeyPackage,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT INTO bridge_key_packages
+                 (id, encoded_key_package, tags_json, last_resort, state, event_id,
+                  created_at, published_at, consumed_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+             ON CONFLICT (id) DO UPDATE SET
+                 state = excluded.state,
+                 event_id = excluded.event_id,
+                 published_at = excluded.published_at,
+                 consumed_at = excluded.consumed_at",
+            params![
+                key_package.id,
+                key_package.encoded_key_package,
+                key_package.tags_json,
+                key_package.last_resort,
+                key_package.state,
+                key_package.event_id,
+                key_package.created_at,
+                key_package.published_at,
+                key_package.consumed_at,
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn key_package(&self, id: &str) -> Result<Option<StoredKeyPackage>, MlsBridgeError> {
+        let key_package = lock(&self.conn)
+            .query_row(
+                "SELECT id, encoded_key_package, tags_json, last_resort, state, event_id,
+                        created_at, published_at, consumed_at
+                 FROM bridge_key_packages WHERE id = ?1",
+                params![id],
+                key_package_from_row,
+            )
+            .optional()?;
+        Ok(key_package)
+    }
+
+    /// All managed key packages, oldest first
+    pub(crate) fn key_packages(&self) -> Result<Vec<StoredKeyPackage>, MlsBridgeError> {
+        let conn = lock(&self.conn);
+        let mut stmt = conn.prepare(
+            "SELECT id, encoded_key_package, tags_json, last_resort, state, event_id,
+                    created_at, published_at, consumed_at
+             FROM bridge_key_packages
+             ORDER BY created_at, id",
+        )?;
+        let key_packages = stmt
+            .query_map([], key_package_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(key_packages)
+    }
+
+    /// Insert a welcome unless it is already in the inbox
+    pub(crate) fn save_welcome(&self, welcome: &StoredWelcome) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT INTO bridge_welcome_inbox
+                 (id, rumor_json, sender, mls_group_id, nostr_group_id, group_name,
+                  group_description, admin_pubkeys_json, relays_json, members_json, state,
+                  received_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+             ON CONFLICT (id) DO NOTHING",
+            params![
+                welcome.id,
+                welcome.rumor_json,
+                welcome.sender,
+                welcome.mls_group_id,
+                welcome.nostr_group_id,
+                welcome.group_name,
+                welcome.group_description,
+                welcome.admin_pubkeys_json,
+                welcome.relays_json,
+                welcome.members_json,
+                welcome.state,
+                welcome.received_at,
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn welcome(&self, id: &str) -> Result<Option<StoredWelcome>, MlsBridgeError> {
+        let welcome = lock(&self.conn)
+            .query_row(
+                "SELECT id, rumor_json, sender, mls_group_id, nostr_group_id, group_name,
+                        group_description, admin_pubkeys_json, relays_json, members_json, state,
+                        received_at
+                 FROM bridge_welcome_inbox WHERE id = ?1",
+                params![id],
+                welcome_from_row,
+            )
+            .optional()?;
+        Ok(welcome)
+    }
+
+    /// Welcomes of the inbox, newest first
+    /// Returns: every welcome, a row that cannot be read failing on its own
+    pub(crate) fn welcomes(
+        &self,
+    ) -> Result<Vec<Result<StoredWelcome, MlsBridgeError>>, MlsBridgeError> {
+        let conn = lock(&self.conn);
+        let mut stmt = conn.prepare(
+            "SELECT id, rumor_json, sender, mls_group_id, nostr_group_id, group_name,
+                    group_description, admin_pubkeys_json, relays_json, members_json, state,
+                    received_at
+             FROM bridge_welcome_inbox
+             ORDER BY received_at DESC, id",
+        )?;
+        let welcomes = stmt
+            .query_map([], welcome_from_row)?
+            .map(|welcome| welcome.map_err(MlsBridgeError::from))
+            .collect();
+        Ok(welcomes)
+    }
+
+    /// Returns: whether the welcome is in the inbox
+    pub(crate) fn set_welcome_state(&self, id: &str, state: &str) -> Result<bool, MlsBridgeError> {
+        let updated = lock(&self.conn).execute(
+            "UPDATE bridge_welcome_inbox SET state = ?2 WHERE id = ?1",
+            params![id, state],
+        )?;
+        Ok(updated > 0)
+    }
+
+    pub(crate) fn group_policy(
+        &self,
+        mls_group_id: &str,
+    ) -> Result<Option<StoredGroupPolicy>, MlsBridgeError> {
+        let policy = lock(&self.conn)
+            .query_row(
+                "SELECT add_members, remove_members, update_group_data, allow_self_remove
+                 FROM bridge_group_policies WHERE mls_group_id = ?1",
+                params![mls_group_id],
+                |row| {
+                    Ok(StoredGroupPolicy {
+                        add_members: row.get(0)?,
+                        remove_members: row.get(1)?,
+                        update_group_data: row.get(2)?,
+                        allow_self_remove: row.get(3)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(policy)
+    }
+
+    pub(crate) fn save_group_policy(
+        &self,
+        mls_group_id: &str,
+        policy: &StoredGroupPolicy,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT INTO bridge_group_policies
+                 (mls_group_id, add_members, remove_members, update_group_data, allow_self_remove)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT (mls_group_id) DO UPDATE SET
+                 add_members = excluded.add_members,
+                 remove_members = excluded.remove_members,
+                 update_group_data = excluded.update_group_data,
+                 allow_self_remove = excluded.allow_self_remove",
+            params![
+                mls_group_id,
+                policy.add_members,
+                policy.remove_members,
+                policy.update_group_data,
+                policy.allow_self_remove,
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn add_commit_audit(
+        &self,
+        record: &CommitAuditRecord,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT INTO bridge_commit_audit (mls_group_id, epoch, sender, reason, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                record.mls_group_id,
+                record.epoch,
+                record.sender,
+                record.reason,
+                record.created_at,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Audit records of a group, newest first
+    pub(crate) fn commit_audit(
+        &self,
+        mls_group_id: &str,
+        limit: u32,
+    ) -> Result<Vec<CommitAuditRecord>, MlsBridgeError> {
+        let conn = lock(&self.conn);
+        let mut stmt = conn.prepare(
+            "SELECT mls_group_id, epoch, sender, reason, created_at FROM bridge_commit_audit
+             WHERE mls_group_id = ?1
+             ORDER BY id DESC LIMIT ?2",
+        )?;
+        let records = stmt
+            .query_map(params![mls_group_id, limit], |row| {
+                Ok(CommitAuditRecord {
+                    mls_group_id: row.get(0)?,
+                    epoch: row.get(1)?,
+                    sender: row.get(2)?,
+                    reason: row.get(3)?,
+                    created_at: row.get(4)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(records)
+    }
+
+    pub(crate) fn rotation_policy(&self) -> Result<Option<StoredRotationPolicy>, MlsBridgeError> {
+        let policy = lock(&self.conn)
+            .query_row(
+                "SELECT max_messages, max_age_secs FROM bridge_rotation_policy WHERE id = 0",
+                [],
+                |row| {
+                    Ok(StoredRotationPolicy {
+                        max_messages: row.get(0)?,
+                        max_age_secs: row.get(1)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(policy)
+    }
+
+    pub(crate) fn save_rotation_policy(
+        &self,
+        policy: &StoredRotationPolicy,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT INTO bridge_rotation_policy (id, max_messages, max_age_secs)
+             VALUES (0, ?1, ?2)
+             ON CONFLICT (id) DO UPDATE SET
+                 max_messages = excluded.max_messages,
+                 max_age_secs = excluded.max_age_secs",
+            params![policy.max_messages, policy.max_age_secs],
+        )?;
+        Ok(())
+    }
+
+    /// Count a message sent or received in a group, starting its tracking if needed
+    pub(crate) fn count_group_message(
+        &self,
+        mls_group_id: &str,
+        now: u64,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT INTO bridge_leaf_rotations (mls_group_id, rotated_at, messages_since)
+             VALUES (?1, ?2, 1)
+             ON CONFLICT (mls_group_id) DO UPDATE SET messages_since = messages_since + 1",
+            params![mls_group_id, now],
+        )?;
+        Ok(())
+    }
+
+    /// Reset the counters of a group after our leaf was rotated
+    pub(crate) fn reset_leaf_rotation(
+        &self,
+        mls_group_id: &str,
+        rotated_at: u64,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT INTO bridge_leaf_rotations (mls_group_id, rotated_at, messages_since)
+             VALUES (?1, ?2, 0)
+             ON CONFLICT (mls_group_id) DO UPDATE SET
+                 rotated_at = excluded.rotated_at,
+                 messages_since = 0",
+            params![mls_group_id, rotated_at],
+        )?;
+        Ok(())
+    }
+
+    /// Rotation record of a group, tracking it from `now` if it was not tracked yet
+    pub(crate) fn leaf_rotation(
+        &self,
+        mls_group_id: &str,
+        now: u64,
+    ) -> Result<LeafRotationRecord, MlsBridgeError> {
+        let conn = lock(&self.conn);
+        conn.execute(
+            "INSERT OR IGNORE INTO bridge_leaf_rotations (mls_group_id, rotated_at, messages_since)
+             VALUES (?1, ?2, 0)",
+            params![mls_group_id, now],
+        )?;
+        let record = conn.query_row(
+            "SELECT mls_group_id, rotated_at, messages_since FROM bridge_leaf_rotations
+             WHERE mls_group_id = ?1",
+            params![mls_group_id],
+            |row| {
+                Ok(LeafRotationRecord {
+                    mls_group_id: row.get(0)?,
+                    rotated_at: row.get(1)?,
+                    messages_since: row.get(2)?,
+                })
+            },
+        )?;
+        Ok(record)
+    }
+
+    /// Our commit waiting to be merged in a group
+    pub(crate) fn pending_commit(
+        &self,
+        mls_group_id: &str,
+    ) -> Result<Option<StoredPendingCommit>, MlsBridgeError> {
+        let record = lock(&self.conn)
+            .query_row(
+                "SELECT epoch, event_id, created_at, event_json, proposal_ref
+                 FROM bridge_pending_commits WHERE mls_group_id = ?1",
+                params![mls_group_id],
+                |row| {
+                    Ok(StoredPendingCommit {
+                        epoch: row.get(0)?,
+                        event_id: row.get(1)?,
+                        created_at: row.get(2)?,
+                        event_json: row.get(3)?,
+                        proposal_ref: row.get(4)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(record)
+    }
+
+    pub(crate) fn save_pending_commit(
+        &self,
+        mls_group_id: &str,
+        record: &StoredPendingCommit,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT OR REPLACE INTO bridge_pending_commits
+                 (mls_group_id, epoch, event_id, created_at, event_json, proposal_ref)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                mls_group_id,
+                record.epoch,
+                record.event_id,
+                record.created_at,
+                record.event_json,
+                record.proposal_ref,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Attach the kind-445 event wrapping our pending commit
+    pub(crate) fn set_pending_commit_event(
+        &self,
+        mls_group_id: &str,
+        event_id: &str,
+        created_at: u64,
+        event_json: &str,
+    ) -> Result<bool, MlsBridgeError> {
+        let updated = lock(&self.conn).execute(
+            "UPDATE bridge_pending_commits SET event_id = ?2, created_at = ?3, event_json = ?4
+             WHERE mls_group_id = ?1",
+            params![mls_group_id, event_id, created_at, event_json],
+        )?;
+        Ok(updated > 0)
+    }
+
+    pub(crate) fn delete_pending_commit(&self, mls_group_id: &str) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "DELETE FROM bridge_pending_commits WHERE mls_group_id = ?1",
+            params![mls_group_id],
+        )?;
+        Ok(())
+    }
+
+    /// Remember the event of a received commit, keyed by the hash of its MLS message
+    /// The commit is not validated yet, so it takes no part in the ordering of its epoch.
+    pub(crate) fn save_commit_event(
+        &self,
+        message_hash: &str,
+        mls_group_id: &str,
+        record: &CommitRecord,
+    ) -> Result<(), MlsBridgeError> {
+        insert_commit_record(
+            &lock(&self.conn),
+            "bridge_commit_events",
+            message_hash,
+            mls_group_id,
+            record,
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn commit_event(
+        &self,
+        message_hash: &str,
+    ) -> Result<Option<CommitRecord>, MlsBridgeError> {
+        let record = lock(&self.conn)
+            .query_row(
+                "SELECT epoch, event_id, created_at FROM bridge_commit_events
+                 WHERE message_hash = ?1",
+                params![message_hash],
+                commit_record_from_row,
+            )
+            .optional()?;
+        Ok(record)
+    }
+
+    /// Make a received commit that passed the policy check a candidate of its epoch
+    pub(crate) fn save_commit_candidate(
+        &self,
+        message_hash: &str,
+        mls_group_id: &str,
+        record: &CommitRecord,
+    ) -> Result<(), MlsBridgeError> {
+        insert_commit_record(
+            &lock(&self.conn),
+            "bridge_commit_candidates",
+            message_hash,
+            mls_group_id,
+            record,
+        )?;
+        Ok(())
+    }
+
+    /// Forget a received commit that was rejected, as its event and as a candidate
+    pub(crate) fn delete_commit(&self, message_hash: &str) -> Result<(), MlsBridgeError> {
+        let conn = lock(&self.conn);
+        conn.execute(
+            "DELETE FROM bridge_commit_events WHERE message_hash = ?1",
+            params![message_hash],
+        )?;
+        conn.execute(
+            "DELETE FROM bridge_commit_candidates WHERE message_hash = ?1",
+            params![message_hash],
+        )?;
+        Ok(())
+    }
+
+    /// The validated commit of an epoch that wins the NIP-EE ordering: earliest `created_at`,
+    /// then lowest event id
+    pub(crate) fn winning_commit_candidate(
+        &self,
+        mls_group_id: &str,
+        epoch: u64,
+    ) -> Result<Option<CommitRecord>, MlsBridgeError> {
+        let record = lock(&self.conn)
+            .query_row(
+                "SELECT epoch, event_id, created_at FROM bridge_commit_candidates
+                 WHERE mls_group_id = ?1 AND epoch = ?2
+                 ORDER BY created_at ASC, event_id ASC LIMIT 1",
+                params![mls_group_id, epoch],
+                commit_record_from_row,
+            )
+            .optional()?;
+        Ok(record)
+    }
+
+    /// Forget the received commits of the epochs a group has moved past
+    pub(crate) fn delete_commit_candidates_before(
+        &self,
+        mls_group_id: &str,
+        epoch: u64,
+    ) -> Result<(), MlsBridgeError> {
+        let conn = lock(&self.conn);
+        conn.execute(
+            "DELETE FROM bridge_commit_events WHERE mls_group_id = ?1 AND epoch < ?2",
+            params![mls_group_id, epoch],
+        )?;
+        conn.execute(
+            "DELETE FROM bridge_commit_candidates WHERE mls_group_id = ?1 AND epoch < ?2",
+            params![mls_group_id, epoch],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn record_group_success(
+        &self,
+        mls_group_id: &str,
+        now: u64,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT INTO bridge_group_health (mls_group_id, consecutive_failures, last_success_at)
+             VALUES (?1, 0, ?2)
+             ON CONFLICT (mls_group_id) DO UPDATE SET
+                 consecutive_failures = 0,
+                 last_success_at = excluded.last_success_at",
+            params![mls_group_id, now],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn record_group_failure(
+        &self,
+        mls_group_id: &str,
+        failure: &str,
+        now: u64,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT INTO bridge_group_health
+                 (mls_group_id, consecutive_failures, last_failure, last_failure_at)
+             VALUES (?1, 1, ?2, ?3)
+             ON CONFLICT (mls_group_id) DO UPDATE SET
+                 consecutive_failures = consecutive_failures + 1,
+                 last_failure = excluded.last_failure,
+                 last_failure_at = excluded.last_failure_at",
+            params![mls_group_id, failure, now],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn group_health(
+        &self,
+        mls_group_id: &str,
+    ) -> Result<Option<StoredGroupHealth>, MlsBridgeError> {
+        let health = lock(&self.conn)
+            .query_row(
+                "SELECT consecutive_failures, last_failure, last_failure_at, last_success_at
+                 FROM bridge_group_health WHERE mls_group_id = ?1",
+                params![mls_group_id],
+                |row| {
+                    Ok(StoredGroupHealth {
+                        consecutive_failures: row.get(0)?,
+                        last_failure: row.get(1)?,
+                        last_failure_at: row.get(2)?,
+                        last_success_at: row.get(3)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(health)
+    }
+
+    pub(crate) fn record_undecryptable_event(
+        &self,
+        mls_group_id: &str,
+        now: u64,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT INTO bridge_undecryptable_events (mls_group_id, count, last_at)
+             VALUES (?1, 1, ?2)
+             ON CONFLICT (mls_group_id) DO UPDATE SET
+                 count = count + 1,
+                 last_at = excluded.last_at",
+            params![mls_group_id, now],
+        )?;
+        Ok(())
+    }
+
+    /// Number of dropped events of a group and when the last one was dropped
+    pub(crate) fn undecryptable_events(
+        &self,
+        mls_group_id: &str,
+    ) -> Result<Option<(u32, u64)>, MlsBridgeError> {
+        let events = lock(&self.conn)
+            .query_row(
+                "SELECT count, last_at FROM bridge_undecryptable_events WHERE mls_group_id = ?1",
+                params![mls_group_id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        Ok(events)
+    }
+
+    pub(crate) fn delete_group_health(&self, mls_group_id: &str) -> Result<(), MlsBridgeError> {
+        let conn = lock(&self.conn);
+        conn.execute(
+            "DELETE FROM bridge_group_health WHERE mls_group_id = ?1",
+            params![mls_group_id],
+        )?;
+        conn.execute(
+            "DELETE FROM bridge_undecryptable_events WHERE mls_group_id = ?1",
+            params![mls_group_id],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn save_group_recovery(
+        &self,
+        mls_group_id: &str,
+        recovery: &StoredGroupRecovery,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT OR REPLACE INTO bridge_group_recovery
+                 (mls_group_id, key_package_id, requested_at)
+             VALUES (?1, ?2, ?3)",
+            params![mls_group_id, recovery.key_package_id, recovery.requested_at],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn group_recovery(
+        &self,
+        mls_group_id: &str,
+    ) -> Result<Option<StoredGroupRecovery>, MlsBridgeError> {
+        let recovery = lock(&self.conn)
+            .query_row(
+                "SELECT key_package_id, requested_at FROM bridge_group_recovery
+                 WHERE mls_group_id = ?1",
+                params![mls_group_id],
+                |row| {
+                    Ok(StoredGroupRecovery {
+                        key_package_id: row.get(0)?,
+                        requested_at: row.get(1)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(recovery)
+    }
+
+    pub(crate) fn has_group_recoveries(&self) -> Result<bool, MlsBridgeError> {
+        let exists = lock(&self.conn).query_row(
+            "SELECT EXISTS (SELECT 1 FROM bridge_group_recovery)",
+            [],
+            |row| row.get(0),
+        )?;
+        Ok(exists)
+    }
+
+    /// Returns whether a recovery was in progress
+    pub(crate) fn delete_group_recovery(&self, mls_group_id: &str) -> Result<bool, MlsBridgeError> {
+        let deleted = lock(&self.conn).execute(
+            "DELETE FROM bridge_group_recovery WHERE mls_group_id = ?1",
+            params![mls_group_id],
+        )?;
+        Ok(deleted > 0)
+    }
+
+    pub(crate) fn keyring_policy(&self) -> Result<Option<StoredKeyringPolicy>, MlsBridgeError> {
+        let policy = lock(&self.conn)
+            .query_row(
+                "SELECT retained_epochs, max_age_secs FROM bridge_keyring_policy WHERE id = 0",
+                [],
+                |row| {
+                    Ok(StoredKeyringPolicy {
+                        retained_epochs: row.get(0)?,
+                        max_age_secs: row.get(1)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(policy)
+    }
+
+    pub(crate) fn save_keyring_policy(
+        &self,
+        policy: &StoredKeyringPolicy,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT INTO bridge_keyring_policy (id, retained_epochs, max_age_secs)
+             VALUES (0, ?1, ?2)
+             ON CONFLICT (id) DO UPDATE SET
+                 retained_epochs = excluded.retained_epochs,
+                 max_age_secs = excluded.max_age_secs",
+            params![policy.retained_epochs, policy.max_age_secs],
+        )?;
+        Ok(())
+    }
+
+    /// Keep the exporter secret of an epoch, `created_at` being when it was first seen
+    pub(crate) fn save_exporter_secret(
+        &self,
+        mls_group_id: &str,
+        epoch: u64,
+        secret: &[u8],
+        created_at: u64,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT OR IGNORE INTO bridge_exporter_secrets (mls_group_id, epoch, secret, created_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![mls_group_id, epoch, secret, created_at],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn exporter_secret(
+        &self,
+        mls_group_id: &str,
+        epoch: u64,
+    ) -> Result<Option<Vec<u8>>, MlsBridgeError> {
+        let secret = lock(&self.conn)
+            .query_row(
+                "SELECT secret FROM bridge_exporter_secrets WHERE mls_group_id = ?1 AND epoch = ?2",
+                params![mls_group_id, epoch],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(secret)
+    }
+
+    pub(crate) fn exporter_secret_epochs(
+        &self,
+        mls_group_id: &str,
+    ) -> Result<Vec<u64>, MlsBridgeError> {
+        let conn = lock(&self.conn);
+        let mut stmt = conn.prepare(
+            "SELECT epoch FROM bridge_exporter_secrets WHERE mls_group_id = ?1 ORDER BY epoch",
+        )?;
+        let epochs = stmt
+            .query_map(params![mls_group_id], |row| row.get(0))?
+            .collect::<Result<Vec<u64>, _>>()?;
+        Ok(epochs)
+    }
+
+    /// Forget the secrets of the epochs before `current_epoch` that are older than `min_epoch`
+    /// or were first seen before `created_before`
+    /// nostr-mls's copy of each secret forgotten is deleted with it; epochs the keyring never
+    /// recorded are left to nostr-mls.
+    /// Returns: the number of secrets forgotten
+    pub(crate) fn delete_exporter_secrets(
+        &self,
+        mls_group_id: &[u8],
+        current_epoch: u64,
+        min_epoch: u64,
+        created_before: Option<u64>,
+    ) -> Result<usize, MlsBridgeError> {
+        let hex_group_id = hex::encode(mls_group_id);
+        let mut conn = lock(&self.conn);
+        let tx = conn.transaction()?;
+
+        check_nostr_mls_schema(&tx)?;
+        let epochs = tx
+            .prepare(
+                "SELECT epoch FROM bridge_exporter_secrets
+                 WHERE mls_group_id = ?1 AND epoch < ?2 AND (epoch < ?3 OR created_at < ?4)",
+            )?
+            .query_map(
+                params![
+                    hex_group_id,
+                    current_epoch,
+                    min_epoch,
+                    created_before.unwrap_or(0)
+                ],
+                |row| row.get(0),
+            )?
+            .collect::<Result<Vec<u64>, _>>()?;
+        for epoch in &epochs {
+            tx.execute(
+                "DELETE FROM bridge_exporter_secrets WHERE mls_group_id = ?1 AND epoch = ?2",
+                params![hex_group_id, epoch],
+            )?;
+            tx.execute(
+                "DELETE FROM group_exporter_secrets WHERE mls_group_id = ?1 AND epoch = ?2",
+                params![mls_group_id, epoch],
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(epochs.len())
+    }
+
+    /// Forget every exporter secret kept for a group, e.g. of a stale copy we rejoined
+    pub(crate) fn delete_group_exporter_secrets(
+        &self,
+        mls_group_id: &str,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "DELETE FROM bridge_exporter_secrets WHERE mls_group_id = ?1",
+            params![mls_group_id],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn retention_policy(&self) -> Result<Option<StoredRetentionPolicy>, MlsBridgeError> {
+        let policy = lock(&self.conn)
+            .query_row(
+                "SELECT message_max_age_secs, purge_inactive_groups
+                 FROM bridge_retention_policy WHERE id = 0",
+                [],
+                |row| {
+                    Ok(StoredRetentionPolicy {
+                        message_max_age_secs: row.get(0)?,
+                        purge_inactive_groups: row.get(1)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(policy)
+    }
+
+    pub(crate) fn save_retention_policy(
+        &self,
+        policy: &StoredRetentionPolicy,
+    ) -> Result<(), MlsBridgeError> {
+        lock(&self.conn).execute(
+            "INSERT INTO bridge_retention_policy (id, message_max_age_secs, purge_inactive_groups)
+             VALUES (0, ?1, ?2)
+             ON CONFLICT (id) DO UPDATE SET
+                 message_max_age_secs = excluded.message_max_age_secs,
+                 purge_inactive_groups = excluded.purge_inactive_groups",
+            params![policy.message_max_age_secs, policy.purge_inactive_groups],
+        )?;
+        Ok(())
+    }
+
+    /// Delete everything the bridge stored about a group
+    /// The MLS group state and the exporter secrets are deleted by the caller, through openmls
+    /// and the keyring.
+    pub(crate) fn purge_group(
+        &self,
+        mls_group_id: &[u8],
+        nostr_group_id: &str,
+    ) -> Result<(), MlsBridgeError> {
+        let hex_group_id = hex::encode(mls_group_id);
+        let mut conn = lock(&self.conn);
+        let tx = conn.transaction()?;
+
+        tx.execute(
+            "DELETE FROM bridge_message_fts_indexed WHERE event_id IN
+                 (SELECT event_id FROM bridge_message_fts WHERE mls_group_id = ?1)",
+            params![hex_group_id],
+        )?;
+        for table in BRIDGE_GROUP_TABLES {
+            tx.execute(
+                &format!("DELETE FROM {table} WHERE mls_group_id = ?1"),
+                params![hex_group_id],
+            )?;
+        }
+        tx.execute(
+            "DELETE FROM bridge_pending_messages WHERE nostr_group_id = ?1",
+            params![nostr_group_id],
+        )?;
+        tx.execute(
+            "DELETE FROM bridge_welcome_inbox WHERE mls_group_id = ?1",
+            params![mls_group_id],
+        )?;
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Messages of a group the bridge stored before `received_before`
+    /// Returns: hex encoded rumor ids
+    pub(crate) fn messages_received_before(
+        &self,
+        mls_group_id: &str,
+        received_before: u64,
+    ) -> Result<Vec<String>, MlsBridgeError> {
+        let conn = lock(&self.conn);
+        let mut stmt = conn.prepare(
+            "SELECT event_id FROM bridge_message_index
+             WHERE mls_group_id = ?1 AND received_at < ?2",
+        )?;
+        let event_ids = stmt
+            .query_map(params![mls_group_id, received_before], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+        Ok(event_ids)
+    }
+
+    /// Drop messages from the page and search indexes, so the bridge no longer returns them
+    /// Parameters: event_ids - hex encoded rumor ids
+    /// Returns: the number of messages dropped
+    pub(crate) fn delete_messages(&self, event_ids: &[String]) -> Result<usize, MlsBridgeError> {
+        let mut conn = lock(&self.conn);
+        let tx = conn.transaction()?;
+
+        let mut deleted = 0;
+        for event_id in event_ids {
+            deleted += tx.execute(
+                "DELETE FROM bridge_message_index WHERE event_id = ?1",
+                params![event_id],
+            )?;
+            tx.execute(
+                "DELETE FROM bridge_message_fts WHERE event_id = ?1",
+                params![event_id],
+            )?;
+            tx.execute(
+                "DELETE FROM bridge_message_fts_indexed WHERE event_id = ?1",
+                params![event_id],
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(deleted)
+    }
+
+    /// Returns: the number of key packages deleted
+    pub(crate) fn delete_key_packages_in_state(
+        &self,
+        state: &str,
+    ) -> Result<usize, MlsBridgeError> {
+        let deleted = lock(&self.conn).execute(
+            "DELETE FROM bridge_key_packages WHERE state = ?1",
+            params![state],
+        )?;
+        Ok(deleted)
+    }
+
+    /// Rewrite the database file so deleted rows, including those nostr-mls deleted through
+    /// its own connection, cannot be recovered from free pages or the write-ahead log
+    pub(crate) fn compact(&self) -> Result<(), MlsBridgeError> {
+        let conn = lock(&self.conn);
+        conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+        conn.execute_batch("VACUUM")?;
+        Ok(())
+    }
+}
+
+/// Header of an unencrypted sqlite database, SQLCipher encrypts it like every other page
+const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+/// Check that a database opens with a password, reporting why it does not
+/// A missing or empty file is a new database and opens with any password.
+pub(crate) fn check_password(path: &Path, password: Option<&str>) -> Result<(), MlsBridgeError> {
+    let Some(encrypted) = is_encrypted(path).map_err(MlsBridgeError::storage)? else {
+        return Ok(());
+    };
+
+    let locked = |message: &str| {
+        Err(MlsBridgeError::StorageLocked {
+            message: message.to_string(),
+        })
+    };
+    match (encrypted, password) {
+        (false, None) => Ok(()),
+        (false, Some(_)) => {
+            locked("the database is not encrypted, set a password with encrypt_existing_storage")
+        }
+        (true, None) => locked("the database is encrypted, a password is required"),
+        (true, Some(password)) => match open_with_password(path, Some(password)) {
+            Ok(_) => Ok(()),
+            Err(MlsBridgeError::StorageLocked { .. }) => locked("wrong password"),
+            Err(e) => Err(e),
+        },
+    }
+}
+
+/// Rewrite a database under a new password, `None` storing it unencrypted
+/// The database is exported to a new file which then replaces the original, so an interrupted
+/// change leaves the original untouched.
+pub(crate) fn reencrypt(
+    path: &Path,
+    old_password: Option<&str>,
+    new_password: Option<&str>,
+) -> Result<(), MlsBridgeError> {
+    if is_encrypted(path)
+        .map_err(MlsBridgeError::storage)?
+        .is_none()
+    {
+        return Err(MlsBridgeError::invalid_input(
+            "path",
+            "no database to rekey",
+        ));
+    }
+    check_password(path, old_password)?;
+
+    let mut rekeyed = path.as_os_str().to_owned();
+    rekeyed.push(".rekey");
+    let rekeyed = PathBuf::from(rekeyed);
+    remove_if_exists(&rekeyed)?;
+
+    {
+        let conn = open_with_password(path, old_password)?;
+        conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+        let user_version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+
+        conn.execute(
+            "ATTACH DATABASE ?1 AS rekeyed KEY ?2",
+            params![rekeyed.to_string_lossy(), new_password.unwrap_or("")],
+        )?;
+        conn.query_row("SELECT sqlcipher_export('rekeyed')", [], |_| Ok(()))?;
+        conn.pragma_update(Some("rekeyed"), "user_version", user_version)?;
+        conn.execute_batch("DETACH DATABASE rekeyed")?;
+    }
+
+    fs::rename(&rekeyed, path).map_err(MlsBridgeError::storage)?;
+    for suffix in ["-wal", "-shm"] {
+        let mut sidecar = path.as_os_str().to_owned();
+        sidecar.push(suffix);
+        remove_if_exists(Path::new(&sidecar))?;
+    }
+
+    Ok(())
+}
+
+/// Whether a database file is encrypted, `None` if it does not exist or is empty
+pub(crate) fn is_encrypted(path: &Path) -> io::Result<Option<bool>> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    let mut header = Vec::with_capacity(SQLITE_HEADER.len());
+    file.by_ref()
+        .take(SQLITE_HEADER.len() as u64)
+        .read_to_end(&mut header)?;
+    if header.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(header != SQLITE_HEADER))
+}
+
+/// Open a database and read from it, so a wrong key fails here rather than on first use
+fn open_with_password(path: &Path, password: Option<&str>) -> Result<Connection, MlsBridgeError> {
+    let conn = Connection::open(path)?;
+    if let Some(password) = password {
+        conn.pragma_update(None, "key", password)?;
+    }
+    conn.query_row("SELECT COUNT(*) FROM sqlite_master", [], |_| Ok(()))?;
+    Ok(conn)
+}
+
+fn remove_if_exists(path: &Path) -> Result<(), MlsBridgeError> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(MlsBridgeError::storage(e)),
+        _ => Ok(()),
+    }
+}
+
+/// Whether a table has a column, false if the table does not exist
+fn has_column(conn: &Connection, table: &str, column: &str) -> rusqlite::Result<bool> {
+    conn.query_row(
+        "SELECT EXISTS (SELECT 1 FROM pragma_table_info(?1) WHERE name = ?2)",
+        params![table, column],
+        |row| row.get(0),
+    )
+}
+
+/// Check that nostr-mls's `group_exporter_secrets` table is the one of the pinned nostr-mls
+/// revision before deleting from it: the schema is not ours
+fn check_nostr_mls_schema(conn: &Connection) -> Result<(), MlsBridgeError> {
+    for column in ["mls_group_id", "epoch"] {
+        if !has_column(conn, "group_exporter_secrets", column)? {
+            return Err(MlsBridgeError::storage(format!(
+                "unexpected nostr-mls schema: group_exporter_secrets has no {column} column"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn insert_indexed_message(
+    conn: &Connection,
+    mls_group_id: &str,
+    message: &IndexedMessage,
+) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO bridge_message_index (event_id, mls_group_id, created_at, received_at)
+         VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT (event_id) DO NOTHING",
+        params![
+            message.event_id,
+            mls_group_id,
+            message.created_at,
+            message.received_at
+        ],
+    )?;
+    let inserted = conn.execute(
+        "INSERT INTO bridge_message_fts_indexed (event_id) VALUES (?1)
+         ON CONFLICT (event_id) DO NOTHING",
+        params![message.event_id],
+    )?;
+    if inserted > 0 {
+        conn.execute(
+            "INSERT INTO bridge_message_fts (content, event_id, mls_group_id)
+             VALUES (?1, ?2, ?3)",
+            params![message.content, message.event_id, mls_group_id],
+        )?;
+    }
+    Ok(())
+}
+
+fn insert_commit_record(
+    conn: &Connection,
+    table: &str,
+    message_hash: &str,
+    mls_group_id: &str,
+    record: &CommitRecord,
+) -> rusqlite::Result<()> {
+    conn.execute(
+        &format!(
+            "INSERT OR IGNORE INTO {table}
+                 (message_hash, mls_group_id, epoch, event_id, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)"
+        ),
+        params![
+            message_hash,
+            mls_group_id,
+            record.epoch,
+            record.event_id,
+            record.created_at
+        ],
+    )?;
+    Ok(())
+}
+
+fn commit_record_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<CommitRecord> {
+    Ok(CommitRecord {
+        epoch: row.get(0)?,
+        event_id: row.get(1)?,
+        created_at: row.get(2)?,
+    })
+}
+
+fn welcome_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<StoredWelcome> {
+    Ok(StoredWelcome {
+        id: row.get(0)?,
+        rumor_json: row.get(1)?,
+        sender: row.get(2)?,
+        mls_group_id: row.get(3)?,
+        nostr_group_id: row.get(4)?,
+        group_name: row.get(5)?,
+        group_description: row.get(6)?,
+        admin_pubkeys_json: row.get(7)?,
+        relays_json: row.get(8)?,
+        members_json: row.get(9)?,
+        state: row.get(10)?,
+        received_at: row.get(11)?,
+    })
+}
+
+fn key_package_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<StoredKeyPackage> {
+    Ok(StoredKeyPackage {
+        id: row.get(0)?,
+        encoded_key_package: row.get(1)?,
+        tags_json: row.get(2)?,
+        last_resort: row.get(3)?,
+        state: row.get(4)?,
+        event_id: row.get(5)?,
+        created_at: row.get(6)?,
+        published_at: row.get(7)?,
+        consumed_at: row.get(8)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{TestDir, TestIdentity};
+
+    const GROUP: &str = "0a0b";
+
+    fn open_store(dir: &TestDir) -> BridgeStore {
+        BridgeStore::open(&Path::new(&dir.path()).join("bridge.db"), None).unwrap()
+    }
+
+    fn index(store: &BridgeStore, event_id: &str, created_at: u64, content: &str) {
+        store
+            .index_message(
+                GROUP,
+                &IndexedMessage {
+                    event_id: event_id.to_string(),
+                    created_at,
+                    content: content.to_string(),
+                    received_at: created_at,
+                },
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn pages_through_messages_of_the_same_second() {
+        let dir = TestDir::new();
+        let store = open_store(&dir);
+        index(&store, "00", 99, "earlier");
+        for event_id in ["01", "02", "03", "04", "05"] {
+            index(&store, event_id, 100, "same second");
+        }
+
+        let mut pages = Vec::new();
+        let mut before: Option<(u64, String)> = None;
+        loop {
+            let page = store
+                .page_messages(
+                    GROUP,
+                    before
+                        .as_ref()
+                        .map(|(created_at, id)| (*created_at, id.as_str())),
+                    2,
+                )
+                .unwrap();
+            let Some(last) = page.last() else {
+                break;
+            };
+            let created_at = if last == "00" { 99 } else { 100 };
+            before = Some((created_at, last.clone()));
+            pages.push(page);
+        }
+
+        assert_eq!(
+            pages,
+            vec![
+                vec!["05".to_string(), "04".to_string()],
+                vec!["03".to_string(), "02".to_string()],
+                vec!["01".to_string(), "00".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn search_terms_are_never_parsed_as_fts_syntax() {
+        let dir = TestDir::new();
+        let store = open_store(&dir);
+        index(&store, "01", 100, "say \"hi\" there");
+        index(&store, "02", 100, "foo bar");
+        index(&store, "03", 100, "foobar");
+        index(&store, "04", 100, "near the end");
+        index(&store, "05", 100, "a well-known fact");
+        let search = |query: &str| store.search_messages(GROUP, query, 10).unwrap();
+
+        assert_eq!(search("\"hi\""), vec!["01".to_string()]);
+        assert_eq!(search("hi\""), vec!["01".to_string()]);
+        assert_eq!(search("\""), Vec::<String>::new());
+        // `*` is not a prefix query
+        assert_eq!(search("foo*"), vec!["02".to_string()]);
+        // `NEAR` is a word, not an operator
+        assert_eq!(search("NEAR"), vec!["04".to_string()]);
+        assert_eq!(search("foo NEAR bar"), Vec::<String>::new());
+        assert_eq!(search("NEAR(foo bar)"), Vec::<String>::new());
+        // `-` does not exclude a column or term
+        assert_eq!(search("-known"), vec!["05".to_string()]);
+        assert_eq!(search("well-known"), vec!["05".to_string()]);
+        assert_eq!(search("-"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn nostr_mls_schema_is_the_pinned_one() {
+        let dir = TestDir::new();
+        let alice = TestIdentity::open(&dir);
+        let instance = alice.instance();
+
+        check_nostr_mls_schema(&lock(&instance.store.conn)).expect("pinned nostr-mls schema");
+    }
+}
@@ -0,0 +1,199 @@
+//! Identities and groups shared by the tests of the bridge modules
+
+use nostr_mls::prelude::*;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::api::error::MlsBridgeError;
+use crate::api::mls_api::{
+    close_nostr_mls, create_group, create_key_package_for_event, init_nostr_mls,
+    join_group_from_welcome, process_commit_message_for_group, process_message_for_group,
+};
+use crate::api::signer::{use_dart_signer, SignerScope};
+use crate::api::types::{GroupInfo, ProcessedMessage};
+use crate::registry::{self, MlsInstance};
+
+pub(crate) const TEST_RELAY: &str = "wss://relay.example.com";
+
+/// A directory holding the databases of a test, deleted when dropped
+pub(crate) struct TestDir(PathBuf);
+
+impl TestDir {
+    pub(crate) fn new() -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "nostr-mls-test-{}",
+            hex::encode(rand::random::<[u8; 8]>())
+        ));
+        std::fs::create_dir_all(&path).expect("create test directory");
+        Self(path)
+    }
+
+    pub(crate) fn path(&self) -> String {
+        self.0.to_string_lossy().into_owned()
+    }
+}
+
+impl Drop for TestDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// An identity opened in a test directory, closed when dropped
+pub(crate) struct TestIdentity {
+    pub(crate) keys: Keys,
+    pub(crate) identity: String,
+}
+
+impl TestIdentity {
+    pub(crate) fn open(dir: &TestDir) -> Self {
+        let keys = Keys::generate();
+        let identity = keys.public_key().to_hex();
+        init_nostr_mls(dir.path(), identity.clone(), None).expect("open identity");
+        Self { keys, identity }
+    }
+
+    pub(crate) fn instance(&self) -> Arc<MlsInstance> {
+        registry::get(&self.identity).expect("identity is open")
+    }
+
+    /// Close and open the identity again, as after an app restart
+    pub(crate) fn reopen(&self, dir: &TestDir) {
+        close_nostr_mls(self.identity.clone()).expect("close identity");
+        init_nostr_mls(dir.path(), self.identity.clone(), None).expect("reopen identity");
+    }
+}
+
+impl Drop for TestIdentity {
+    fn drop(&mut self) {
+        let _ = close_nostr_mls(self.identity.clone());
+    }
+}
+
+/// Sign with the identity's keys as a Dart signer holding them would
+pub(crate) fn use_test_signer(member: &TestIdentity, scope: SignerScope) {
+    let sign_keys = member.keys.clone();
+    let encrypt_keys = member.keys.clone();
+    let decrypt_keys = member.keys.clone();
+    use_dart_signer(
+        member.identity.clone(),
+        scope,
+        member.identity.clone(),
+        move |json| {
+            let signed = UnsignedEvent::from_json(json)
+                .ok()
+                .and_then(|unsigned| unsigned.sign_with_keys(&sign_keys).ok())
+                .map(|event| event.as_json());
+            Box::pin(async move { signed })
+        },
+        move |public_key, plaintext| {
+            let encrypted = PublicKey::from_hex(&public_key)
+                .ok()
+                .and_then(|public_key| {
+                    nip44::encrypt(
+                        encrypt_keys.secret_key(),
+                        &public_key,
+                        plaintext,
+                        nip44::Version::V2,
+                    )
+                    .ok()
+                });
+            Box::pin(async move { encrypted })
+        },
+        move |public_key, payload| {
+            let decrypted = PublicKey::from_hex(&public_key)
+                .ok()
+                .and_then(|public_key| {
+                    nip44::decrypt(decrypt_keys.secret_key(), &public_key, payload).ok()
+                });
+            Box::pin(async move { decrypted })
+        },
+    )
+    .expect("use test signer");
+}
+
+/// Create a group administered by `admin`, joined by `members` through its welcome
+pub(crate) fn create_test_group(admin: &TestIdentity, members: &[&TestIdentity]) -> GroupInfo {
+    let key_packages = members
+        .iter()
+        .map(|member| {
+            create_key_package_for_event(
+                member.identity.clone(),
+                member.identity.clone(),
+                None,
+                None,
+            )
+            .expect("create key package")
+            .encoded_key_package
+        })
+        .collect();
+
+    let created = create_group(
+        admin.identity.clone(),
+        "test group".to_string(),
+        "group of a test".to_string(),
+        key_packages,
+        members
+            .iter()
+            .map(|member| member.identity.clone())
+            .collect(),
+        admin.identity.clone(),
+        vec![admin.identity.clone()],
+        vec![TEST_RELAY.to_string()],
+    )
+    .expect("create group");
+
+    for member in members {
+        join_test_welcome(member, admin, &created.serialized_welcome_message);
+    }
+    created.group
+}
+
+/// Join a group from a welcome as if it was received in a gift wrap from `sender`
+pub(crate) fn join_test_welcome(
+    member: &TestIdentity,
+    sender: &TestIdentity,
+    welcome_message: &[u8],
+) -> GroupInfo {
+    let mut rumor = EventBuilder::new(Kind::MlsWelcome, hex::encode(welcome_message))
+        .tags([
+            Tag::event(EventId::from_byte_array(rand::random())),
+            Tag::custom(TagKind::Relays, [TEST_RELAY]),
+        ])
+        .build(sender.keys.public_key());
+    rumor.ensure_id();
+
+    join_group_from_welcome(
+        member.identity.clone(),
+        rand::random::<[u8; 32]>().to_vec(),
+        rumor.as_json(),
+    )
+    .expect("join group from welcome")
+}
+
+/// Receive a kind-445 commit event as a member does: decrypt it, then apply its staged commit
+pub(crate) fn receive_test_commit(
+    member: &TestIdentity,
+    event_json: &str,
+) -> Result<ProcessedMessage, MlsBridgeError> {
+    let event = Event::from_json(event_json).expect("parse event");
+    let group_id = member
+        .instance()
+        .group_id_for_event(&event)
+        .expect("event of a joined group");
+
+    let processed = process_message_for_group(member.identity.clone(), event_json.to_string())?;
+    let message_bytes = processed
+        .staged_message_bytes
+        .expect("event wraps a commit");
+    process_commit_message_for_group(member.identity.clone(), group_id.to_vec(), message_bytes)
+}
+
+/// A kind-445 event of a group, signed by a random key like the real ones
+pub(crate) fn test_group_event(nostr_group_id: &str, content: &str, created_at: u64) -> Event {
+    EventBuilder::new(Kind::MlsGroupMessage, content)
+        .tag(Tag::custom(TagKind::h(), [nostr_group_id]))
+        .custom_created_at(Timestamp::from(created_at))
+        .sign_with_keys(&Keys::generate())
+        .expect("sign event")
+}